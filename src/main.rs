@@ -6,12 +6,28 @@ Program Details: <Program Description Here>
 
 mod modules;
 
-use crate::modules::database::{create_database_client, DatabaseClient, DatabaseTable};
+use crate::modules::database::{create_database_client, percent_encode, DatabaseTable};
+use crate::modules::dialog::{self, Dialog};
+use crate::modules::focus::{self, FocusManager};
+use crate::modules::form::Form;
 use crate::modules::label::Label;
+use crate::modules::layout::{self, Anchor, Breakpoint, BreakpointWatcher, Column, Place, Row, Size};
 use crate::modules::scale::use_virtual_resolution;
+use crate::modules::spinner::Spinner;
+use crate::modules::tasks::{self, TaskHandle};
 use crate::modules::text_button::TextButton;
-use crate::modules::text_input::TextInput;
+use crate::modules::tooltip;
 use macroquad::prelude::*;
+use std::rc::Rc;
+
+/// What a Login or Create found out in the background
+enum AccountResult {
+    LoggedIn(DatabaseTable),
+    WrongLogin,
+    AlreadyExists,
+    Created(String, Option<DatabaseTable>), // The new username, and the row the database sent back
+}
+
 /// Set up window settings before the app runs
 fn window_conf() -> Conf {
     Conf {
@@ -28,70 +44,197 @@ fn window_conf() -> Conf {
 
 #[macroquad::main(window_conf)]
 async fn main() {
-    let btn_text = TextButton::new(300.0, 400.0, 200.0, 60.0, "Create", BLUE, RED, 30);
-    let btn_text2 = TextButton::new(100.0, 400.0, 200.0, 60.0, "Login", BLUE, RED, 30);
-    let btn_text3 = TextButton::new(500.0, 400.0, 200.0, 60.0, "SAVE", BLUE, RED, 30);
-    let level = TextButton::new(300.0, 700.0, 200.0, 60.0, "Level Up", BLUE, GOLD, 30);
-    let mut txtuser = TextInput::new(250.0, 150.0, 300.0, 40.0, 25.0);
-    let mut txtpassword = TextInput::new(250.0, 250.0, 300.0, 40.0, 25.0);
-    let mut lbl_out = Label::new("Game", 50.0, 100.0, 30);
-    txtuser.set_prompt("Enter Username");
-    txtuser.set_prompt_color(DARKGRAY);
-    txtpassword.set_prompt("Enter Password");
-    txtpassword.set_prompt_color(DARKGRAY);
-    let client = create_database_client();
-    let mut score = 0;
+    // Everything is put in place by arrange() on the first frame
+    let mut btn_text3 = TextButton::new(0.0, 0.0, 200.0, 60.0, "SAVE", BLUE, RED, 30);
+    btn_text3.set_tooltip("Saves your level to the database");
+    btn_text3.with_busy_text("Saving...");
+    let mut level = TextButton::new(0.0, 0.0, 200.0, 60.0, "Level Up", BLUE, GOLD, 30);
+    // Login is first so pressing Enter in either box logs in
+    let mut frm_account = Form::new(0.0, 0.0, 300.0, 25.0);
+    frm_account
+        .add_text("username", "Username")
+        .add_password("password", "Password")
+        .with_prompt("username", "Enter Username")
+        .with_prompt("password", "Enter Password")
+        .required("username")
+        .required("password")
+        // Match the varchar(32) columns in draysTable
+        .with_max_length("username", 32)
+        .with_max_length("password", 32)
+        .add_button("Login")
+        .add_button("Create");
+    let mut lbl_out = Label::new("Game", 0.0, 0.0, 30);
+    // Keep the label readable over the red and green background
+    lbl_out.with_outline(WHITE, 2.0);
+    // Tab goes username -> password -> Login -> Create -> SAVE -> Level Up
+    let mut tab_order = frm_account.focus_ids();
+    tab_order.extend([btn_text3.focus_id(), level.focus_id()]);
+    focus::set_tab_order(&tab_order);
+    // Click to focus, Escape or clicking empty space to unfocus
+    let mut focus_manager = FocusManager::new();
+    // Shared (Rc) so background saves can hold on to it
+    let client = Rc::new(create_database_client());
     let mut new_record = DatabaseTable {
         id: None, // Will be auto-generated
         username: "".to_string(),
         password: "".to_string(),
         level: 1,
     };
+    // Suggest existing usernames while typing in the username box, loaded in the background
+    let username_loader = {
+        let client = client.clone();
+        tasks::spawn(async move { client.fetch_table::<DatabaseTable>("draysTable").await.map_err(|e| e.to_string()) })
+    };
+    let mut spn_usernames = Spinner::new(0.0, 0.0, 12.0);
+    spn_usernames.with_color(WHITE);
+    // Login or Create while it talks to the database (the game keeps drawing meanwhile)
+    let mut account_task: Option<TaskHandle<Result<AccountResult, String>>> = None;
+    // Lay the screen out again when the window goes between phone sized and desktop sized
+    let mut breakpoints = BreakpointWatcher::new();
+    let mut rect_panel = Rect::default();
     loop {
-        use_virtual_resolution(1024.0, 768.0);
+        let rearrange = breakpoints.changed().is_some();
+        let compact = breakpoints.current() == Some(Breakpoint::Compact);
+        // Phones and portrait windows get a tall virtual resolution
+        if compact {
+            use_virtual_resolution(600.0, 1000.0);
+        } else {
+            use_virtual_resolution(1024.0, 768.0);
+        }
+        if rearrange {
+            rect_panel = arrange(compact, &mut frm_account, &mut lbl_out, &mut btn_text3, &mut level, &mut spn_usernames);
+        }
         clear_background(RED);
 
-        draw_rectangle(100.0, 100.0, 500.0, 400.0, GREEN);
-        if btn_text.click() {
-           
-            new_record.username = txtuser.get_text();
-            new_record.password = txtpassword.get_text();
-              let records: Vec<DatabaseTable> = client.fetch_table("draysTable").await.unwrap();
-            for record in records {
-            if record.username == new_record.username && record.password == new_record.password {
-                    
-                    lbl_out.set_text(format!("user already exists"));
+        draw_rectangle(rect_panel.x, rect_panel.y, rect_panel.w, rect_panel.h, GREEN);
+        let pressed = frm_account.draw();
+        // submit() shows "... is required" under empty boxes and gives None (presses wait for the last one to finish)
+        if let Some(mut values) = pressed.as_ref().filter(|_| account_task.is_none()).and_then(|_| frm_account.submit()) {
+            let username = frm_account.get_text("username");
+            let password = frm_account.get_text("password");
+            let creating = pressed.as_deref() == Some("Create");
+            let client = client.clone();
+            account_task = Some(tasks::spawn(async move {
+                let records: Vec<DatabaseTable> = client.fetch_table("draysTable").await.map_err(|e| e.to_string())?;
+                let existing = records.into_iter().find(|record| record.username == username && record.password == password);
+                if !creating {
+                    return Ok(existing.map_or(AccountResult::WrongLogin, AccountResult::LoggedIn));
                 }
-            }
-            else{
-            new_record.level = 1;
-            let _inserted: Vec<DatabaseTable> = client.insert_record("draysTable", &new_record).await.unwrap();
-            lbl_out.set_text(format!("level: {}", new_record.level));}
-        };
-
-        if btn_text2.click() {
-            let records: Vec<DatabaseTable> = client.fetch_table("draysTable").await.unwrap();
-            for record in records {
-                if record.username == txtuser.get_text() && record.password == txtpassword.get_text() {
+                if existing.is_some() {
+                    return Ok(AccountResult::AlreadyExists);
+                }
+                values.insert("level".to_string(), 1.into());
+                // The form's values go straight in, the database sends back the row with its id
+                let inserted = client.insert_record("draysTable", &values).await.map_err(|e| e.to_string())?;
+                let record = inserted.into_iter().next().and_then(|row| serde_json::from_value(serde_json::Value::Object(row)).ok());
+                Ok(AccountResult::Created(username, record))
+            }));
+        }
+        if let Some(result) = account_task.as_ref().and_then(TaskHandle::take) {
+            account_task = None;
+            match result {
+                Ok(AccountResult::LoggedIn(record)) => {
                     new_record = record;
                     lbl_out.set_text(format!("level: {}", new_record.level));
                 }
+                Ok(AccountResult::WrongLogin) => {
+                    frm_account.set_error("password", "Wrong username or password");
+                }
+                Ok(AccountResult::AlreadyExists) => {
+                    frm_account.set_error("username", "User already exists");
+                }
+                Ok(AccountResult::Created(username, record)) => {
+                    if let Some(record) = record {
+                        new_record = record;
+                    }
+                    if let Some(txt_user) = frm_account.input("username") {
+                        let mut usernames = txt_user.get_suggestions().to_vec();
+                        usernames.push(username);
+                        txt_user.set_suggestions(usernames);
+                    }
+                    lbl_out.set_text(format!("level: {}", new_record.level));
+                }
+                Err(e) => {
+                    Dialog::alert(format!("Couldn't reach the database: {}", e));
+                }
             }
         }
+        // Save in the background so the game keeps drawing, the button shows "Saving..." meanwhile
         if btn_text3.click() {
-            
-             let _result = client
-        .update_records("draysTable", &format!("username=eq.{}&password=eq.{}", new_record.username, new_record.password), &new_record)
-        .await.unwrap();
-    
+            let client = client.clone();
+            let record = new_record.clone();
+            btn_text3.run_async(async move {
+                client
+                    .update_records::<DatabaseTable>("draysTable", &format!("username=eq.{}&password=eq.{}", percent_encode(&record.username), percent_encode(&record.password)), &record)
+                    .await
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            });
+        }
+        if let Some(result) = btn_text3.action_result() {
+            match result {
+                Ok(()) => {
+                    lbl_out.set_text(format!("level: {} (saved)", new_record.level));
+                }
+                Err(e) => {
+                    lbl_out.set_text(format!("level: {} (not saved)", new_record.level));
+                    Dialog::alert(format!("Save failed: {}", e));
+                }
+            };
         }
-        if level.click() {
+        if level.click_repeating(0.5, 10.0) {
             new_record.level += 1;
             lbl_out.set_text(format!("level: {}", new_record.level));
         }
+        if let Some(Ok(records)) = username_loader.take() {
+            if let Some(txt_user) = frm_account.input("username") {
+                txt_user.set_suggestions(records.into_iter().map(|record| record.username).collect());
+            }
+        }
         lbl_out.draw();
-        txtpassword.draw();
-        txtuser.draw();
+        spn_usernames.draw_while(&username_loader);
+        tooltip::draw();
+        dialog::draw();
+        focus_manager.update();
+        tasks::run_pending();
         next_frame().await;
     }
 }
+
+/// Put the widgets in place for a phone sized (compact) or desktop sized window, returns the green panel's area
+fn arrange(
+    compact: bool,
+    frm_account: &mut Form,
+    lbl_out: &mut Label,
+    btn_save: &mut TextButton,
+    btn_level: &mut TextButton,
+    spn_usernames: &mut Spinner,
+) -> Rect {
+    let screen = layout::screen();
+    // Server error messages can be long, keep them on screen
+    lbl_out.place(layout::anchored(Anchor::TopLeft, screen.w - 100.0, 30.0, 50.0, 70.0));
+
+    let rect_panel = if compact {
+        layout::anchored(Anchor::Top, 500.0, 400.0, 0.0, 140.0)
+    } else {
+        layout::anchored(Anchor::Left, 500.0, 400.0, 100.0, 0.0)
+    };
+    // Centered near the top of the green panel
+    frm_account.place(layout::anchored_in(rect_panel, Anchor::Top, 300.0, frm_account.get_height(), 0.0, 30.0));
+    // Just right of the username box
+    if let Some(txt_user) = frm_account.input("username") {
+        spn_usernames.set_position(txt_user.get_x() + txt_user.get_width() + 25.0, txt_user.get_y() + txt_user.get_height() / 2.0);
+    }
+
+    // SAVE and Level Up side by side along the bottom, or stacked on a phone
+    if compact {
+        let mut col_actions = Column::new();
+        col_actions.with_spacing(20.0).add(Size::Flex(1.0)).add(Size::Flex(1.0));
+        col_actions.place(layout::anchored(Anchor::Bottom, 300.0, 140.0, 0.0, 60.0), &mut [btn_save, btn_level]);
+    } else {
+        let mut row_actions = Row::new();
+        row_actions.with_spacing(40.0).add(Size::Flex(1.0)).add(Size::Flex(1.0));
+        row_actions.place(layout::anchored(Anchor::Bottom, 440.0, 60.0, 0.0, 40.0), &mut [btn_save, btn_level]);
+    }
+    rect_panel
+}
@@ -0,0 +1,327 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A stack of sections with clickable headers that slide open and closed, so long settings
+screens fit on a small virtual resolution
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod accordion;
+
+This also needs these modules:
+    pub mod animation;
+    pub mod scale;
+
+Add with the other use statements:
+    use crate::modules::accordion::Accordion;
+
+Then above the loop section:
+    let mut acc_settings = Accordion::new(50.0, 50.0, 400.0);
+    acc_settings
+        .add_section("Account", 150.0)
+        .add_section("Sound", 100.0)
+        .add_section("Display", 200.0);
+    acc_settings.set_open(0, true);
+Where the parameters are x, y and width, and each section has a title and the height of
+its contents when open.
+
+Then in the loop, draw the headers first and then each section's contents between
+begin_section and end_section:
+    acc_settings.draw();
+    if acc_settings.begin_section(0) {
+        let area = acc_settings.content_rect(0);
+        txt_name.set_position(area.x + 10.0, area.y + 10.0);
+        txt_name.draw();
+        acc_settings.end_section();
+    }
+    if acc_settings.begin_section(1) {
+        let area = acc_settings.content_rect(1);
+        sld_volume.set_position(area.x + 10.0, area.y + 10.0);
+        sld_volume.draw();
+        acc_settings.end_section();
+    }
+begin_section returns false while a section is closed (then skip it and don't call
+end_section). While a section slides open or closed its contents are cut off at the
+part that is showing, and the sections under it move down or up with it.
+
+HOW IT WORKS:
+    Clicking a header opens or closes its section. By default only one section is open at a
+    time, opening one closes the others. Turn that off with with_multiple_open(true).
+
+OTHER METHODS:
+    acc_settings.with_multiple_open(true);          // Let several sections be open at once
+    acc_settings.with_animation(0.2);               // Seconds to slide (0.0 to jump)
+    acc_settings.with_header_height(40.0);          // Default 36
+    acc_settings.with_colors(DARKBLUE, WHITE, Color::new(0.95, 0.95, 0.95, 1.0)); // Header, header text and body
+    acc_settings.set_content_height(2, 250.0);      // Change a section's size
+    acc_settings.toggle(1);
+    if acc_settings.is_open(1) { }
+    let height = acc_settings.get_height();         // Everything, as it is drawn right now
+    acc_settings.set_position(10.0, 10.0);
+    if let Some(section) = acc_settings.draw() {
+        // A header was clicked this frame
+    }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::animation::{Easing, Tween};
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::theme::ThemeColor;
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+struct Section {
+    title: String,
+    content_height: f32,
+    open: bool,
+    slide: Tween, // How far open the section is, 0.0 closed to 1.0 open
+}
+
+impl Section {
+    // How far open, as a fraction
+    fn openness(&self) -> f32 {
+        self.slide.value().clamp(0.0, 1.0)
+    }
+}
+
+pub struct Accordion {
+    x: f32,
+    y: f32,
+    width: f32,
+    sections: Vec<Section>,
+    multiple_open: bool,
+    duration: f32,
+    header_height: f32,
+    header_color: ThemeColor,
+    header_text_color: ThemeColor,
+    body_color: ThemeColor,
+    clipping: bool, // True between begin_section and end_section
+}
+
+impl Accordion {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            sections: Vec::new(),
+            multiple_open: false,
+            duration: 0.2,
+            header_height: 36.0,
+            header_color: ThemeColor::Primary,
+            header_text_color: ThemeColor::OnPrimary,
+            body_color: ThemeColor::Surface,
+            clipping: false,
+        }
+    }
+
+    // Add a closed section, content_height is how tall it is when open
+    #[allow(unused)]
+    pub fn add_section(&mut self, title: &str, content_height: f32) -> &mut Self {
+        self.sections.push(Section {
+            title: title.to_string(),
+            content_height: content_height.max(0.0),
+            open: false,
+            slide: Tween::new(0.0, 0.0, self.duration, Easing::EaseInOut),
+        });
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_multiple_open(&mut self, multiple: bool) -> &mut Self {
+        self.multiple_open = multiple;
+        if !multiple {
+            // Keep only the first open section
+            let first_open = self.sections.iter().position(|section| section.open);
+            for index in 0..self.sections.len() {
+                if Some(index) != first_open {
+                    self.slide(index, false);
+                }
+            }
+        }
+        self
+    }
+
+    // Seconds a section takes to slide open or closed
+    #[allow(unused)]
+    pub fn with_animation(&mut self, duration: f32) -> &mut Self {
+        self.duration = duration.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_header_height(&mut self, height: f32) -> &mut Self {
+        self.header_height = height;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_colors(&mut self, header: impl Into<ThemeColor>, header_text: impl Into<ThemeColor>, body: impl Into<ThemeColor>) -> &mut Self {
+        self.header_color = header.into();
+        self.header_text_color = header_text.into();
+        self.body_color = body.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_content_height(&mut self, index: usize, height: f32) -> &mut Self {
+        if let Some(section) = self.sections.get_mut(index) {
+            section.content_height = height.max(0.0);
+        }
+        self
+    }
+
+    // Open or close a section (opening closes the others unless several can be open)
+    #[allow(unused)]
+    pub fn set_open(&mut self, index: usize, open: bool) -> &mut Self {
+        if index >= self.sections.len() {
+            return self;
+        }
+        if open && !self.multiple_open {
+            for other in 0..self.sections.len() {
+                if other != index {
+                    self.slide(other, false);
+                }
+            }
+        }
+        self.slide(index, open);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn toggle(&mut self, index: usize) -> &mut Self {
+        let open = self.is_open(index);
+        self.set_open(index, !open)
+    }
+
+    // Whether a section is open (or opening)
+    #[allow(unused)]
+    pub fn is_open(&self, index: usize) -> bool {
+        self.sections.get(index).is_some_and(|section| section.open)
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    // Height of the headers and the open parts of the sections right now
+    #[allow(unused)]
+    pub fn get_height(&self) -> f32 {
+        self.sections
+            .iter()
+            .map(|section| self.header_height + section.content_height * section.openness())
+            .sum()
+    }
+
+    // Where a section's contents go, at full height (the part showing is what begin_section clips to)
+    #[allow(unused)]
+    pub fn content_rect(&self, index: usize) -> Rect {
+        let height = self.sections.get(index).map_or(0.0, |section| section.content_height);
+        Rect::new(self.x, self.header_top(index) + self.header_height, self.width, height)
+    }
+
+    // Handle header clicks and draw the headers and section backgrounds.
+    // Returns the section whose header was clicked this frame
+    #[allow(unused)]
+    pub fn draw(&mut self) -> Option<usize> {
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+        let clicked = (0..self.sections.len()).find(|&index| {
+            is_mouse_button_pressed(MouseButton::Left) && self.header_rect(index).contains(mouse)
+        });
+        if let Some(index) = clicked {
+            self.toggle(index);
+        }
+
+        for (index, section) in self.sections.iter().enumerate() {
+            let header = self.header_rect(index);
+            let hovered = header.contains(mouse);
+            let color = if hovered { shade(self.header_color.color(), 0.15) } else { self.header_color.color() };
+            draw_rectangle(header.x, header.y, header.w, header.h, color);
+            draw_line(header.x, header.y + header.h, header.x + header.w, header.y + header.h, 1.0, shade(self.header_color.color(), -0.2));
+
+            // Arrow pointing right when closed, turning to point down as it opens
+            let arrow_size = self.header_height * 0.18;
+            let center = Vec2::new(header.x + self.header_height / 2.0, header.y + header.h / 2.0);
+            let angle = section.openness() * std::f32::consts::FRAC_PI_2;
+            let point = |x: f32, y: f32| center + Vec2::new(x * angle.cos() - y * angle.sin(), x * angle.sin() + y * angle.cos()) * arrow_size;
+            draw_triangle(point(1.0, 0.0), point(-0.6, -0.9), point(-0.6, 0.9), self.header_text_color.color());
+
+            let font_size = (self.header_height * 0.55) as u16;
+            let size = measure_text(&section.title, None, font_size, 1.0);
+            draw_text(
+                &section.title,
+                header.x + self.header_height,
+                header.y + (header.h - size.height) / 2.0 + size.offset_y,
+                font_size as f32,
+                self.header_text_color.color(),
+            );
+
+            let shown = section.content_height * section.openness();
+            if shown > 0.0 {
+                draw_rectangle(header.x, header.y + header.h, header.w, shown, self.body_color.color());
+            }
+        }
+        clicked
+    }
+
+    // Start clipping to the showing part of a section, false while it is closed
+    #[allow(unused)]
+    pub fn begin_section(&mut self, index: usize) -> bool {
+        self.end_section();
+        let Some(section) = self.sections.get(index) else {
+            return false;
+        };
+        let shown = section.content_height * section.openness();
+        if shown <= 0.0 {
+            return false;
+        }
+        let area = self.content_rect(index);
+        push_clip_rect(Rect::new(area.x, area.y, area.w, shown));
+        self.clipping = true;
+        true
+    }
+
+    // Stop clipping to the section (only needed when begin_section returned true)
+    #[allow(unused)]
+    pub fn end_section(&mut self) {
+        if self.clipping {
+            pop_clip_rect();
+            self.clipping = false;
+        }
+    }
+
+    // Start sliding a section towards open or closed, from wherever it is now
+    fn slide(&mut self, index: usize, open: bool) {
+        let duration = self.duration;
+        let section = &mut self.sections[index];
+        if section.open == open {
+            return;
+        }
+        section.open = open;
+        let target = if open { 1.0 } else { 0.0 };
+        section.slide = Tween::new(section.openness(), target, duration, Easing::EaseInOut);
+        section.slide.start();
+    }
+
+    fn header_top(&self, index: usize) -> f32 {
+        self.y
+            + self.sections[..index.min(self.sections.len())]
+                .iter()
+                .map(|section| self.header_height + section.content_height * section.openness())
+                .sum::<f32>()
+    }
+
+    fn header_rect(&self, index: usize) -> Rect {
+        Rect::new(self.x, self.header_top(index), self.width, self.header_height)
+    }
+}
+
+// Lighten (positive) or darken (negative) a color
+fn shade(color: Color, amount: f32) -> Color {
+    let adjust = |channel: f32| if amount >= 0.0 { channel + (1.0 - channel) * amount } else { channel * (1.0 + amount) };
+    Color::new(adjust(color.r), adjust(color.g), adjust(color.b), color.a)
+}
@@ -0,0 +1,145 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Simple tweens (a value that moves from one number to another over time) and easing curves
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod animation;
+
+Add with the other use statements:
+    use crate::modules::animation::{self, Easing, Tween};
+
+HOW IT WORKS:
+    A Tween remembers when it was started and works out its value from the time, so it
+    doesn't need updating every frame - just ask for value() when drawing. Before it is
+    started and after it finishes the value sits at the end value. The easing changes how
+    the value moves: Linear is constant speed, EaseOut starts fast and slows down, etc.
+
+Usage examples:
+    // Slide a panel in from the left over half a second
+    let mut slide = Tween::new(-300.0, 0.0, 0.5, Easing::EaseOut);
+    slide.start();
+
+    // In the loop
+    draw_rectangle(slide.value(), 100.0, 300.0, 200.0, GRAY);
+    if slide.is_finished() { }
+
+    // Easing a value yourself (t goes from 0.0 to 1.0)
+    let eased = Easing::EaseInOut.apply(0.25);
+    let color = animation::lerp_color(RED, BLUE, eased);
+*/
+
+use macroquad::prelude::*;
+
+// How a tween speeds up and slows down
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum Easing {
+    Linear,    // Same speed the whole way
+    EaseIn,    // Starts slow, ends fast
+    EaseOut,   // Starts fast, ends slow
+    EaseInOut, // Slow at both ends
+    Back,      // Overshoots the end a little then settles
+}
+
+impl Easing {
+    // Map progress (0.0 - 1.0) to eased progress
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Back => {
+                let overshoot = 1.70158;
+                let t = t - 1.0;
+                1.0 + (overshoot + 1.0) * t * t * t + overshoot * t * t
+            }
+        }
+    }
+}
+
+// A value that moves from `from` to `to` over `duration` seconds once started
+#[derive(Clone, Copy, Debug)]
+pub struct Tween {
+    from: f32,
+    to: f32,
+    duration: f32,
+    easing: Easing,
+    start_time: Option<f64>, // None until started
+}
+
+impl Tween {
+    pub fn new(from: f32, to: f32, duration: f32, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing,
+            start_time: None,
+        }
+    }
+
+    // Start (or restart) the tween from the beginning
+    pub fn start(&mut self) {
+        self.start_time = Some(get_time());
+    }
+
+    // Stop the tween, its value jumps to the end value
+    #[allow(unused)]
+    pub fn stop(&mut self) {
+        self.start_time = None;
+    }
+
+    // How far through the tween is (0.0 - 1.0), 1.0 when it isn't running
+    pub fn progress(&self) -> f32 {
+        match self.start_time {
+            Some(start) if self.duration > 0.0 => ((get_time() - start) as f32 / self.duration).min(1.0),
+            _ => 1.0,
+        }
+    }
+
+    // Current value
+    pub fn value(&self) -> f32 {
+        lerp(self.from, self.to, self.easing.apply(self.progress()))
+    }
+
+    #[allow(unused)]
+    pub fn is_running(&self) -> bool {
+        self.start_time.is_some() && self.progress() < 1.0
+    }
+
+    #[allow(unused)]
+    pub fn is_finished(&self) -> bool {
+        !self.is_running()
+    }
+
+    #[allow(unused)]
+    pub fn set_duration(&mut self, duration: f32) -> &mut Self {
+        self.duration = duration.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_easing(&mut self, easing: Easing) -> &mut Self {
+        self.easing = easing;
+        self
+    }
+}
+
+/// Blend between two numbers (t = 0.0 gives a, t = 1.0 gives b)
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Blend between two colors, including alpha
+pub fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    Color::new(lerp(a.r, b.r, t), lerp(a.g, b.g, t), lerp(a.b, b.b, t), lerp(a.a, b.a, t))
+}
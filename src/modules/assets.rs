@@ -0,0 +1,273 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Helpers for loading assets (fonts and raw files) that work on both native and web builds
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod assets;
+
+Add with the other use statements:
+    use crate::modules::assets;
+
+Native builds also need this in the Cargo.toml file (for loading from URLs), under
+[target.'cfg(not(target_arch = "wasm32"))'.dependencies]:
+    ureq = { version = "2.9", features = ["json"] }
+
+HOW IT WORKS:
+    On native builds files are read from disk relative to where the program runs, and
+    paths starting with http:// or https:// are downloaded. On the web they are downloaded
+    with the browser's fetch, relative to index.html (full URLs work too). Fonts and
+    textures are remembered by path, so loading the same one again is instant and returns
+    the same font or texture.
+    A Font or Texture2D is only a handle to the loaded data, so cloning one to give to
+    several widgets is cheap and doesn't load or copy anything.
+
+Usage examples:
+    // Load a font once before the main loop
+    let font = assets::load_font("assets/love.ttf").await.unwrap();
+    txt_input.set_font(font.clone());
+    lbl_out.with_font(font);
+
+    // Or handle a missing file
+    match assets::load_font("assets/love.ttf").await {
+        Ok(font) => { txt_input.set_font(font); }
+        Err(e) => println!("Couldn't load font: {}", e),
+    }
+
+    // Load an image (png, jpg, ...) as a texture
+    let avatar = assets::load_texture("assets/avatar.png").await.unwrap();
+    draw_texture(&avatar, 10.0, 10.0, WHITE);
+
+    // Load any file as bytes
+    let bytes = assets::load_bytes("assets/level1.json").await.unwrap();
+
+    // Once loaded, get them again by path without .await
+    if let Some(font) = assets::font("assets/love.ttf") { lbl_title.with_font(font); }
+    let avatar = assets::texture("assets/avatar.png");        // None if it isn't loaded
+    let ready = assets::is_loaded("assets/avatar.png");
+
+LOADING SCREEN:
+    List everything the game needs and load it all before the loop, drawing a progress
+    bar in the meantime:
+        let mut preload = Assets::new();
+        preload.font("assets/love.ttf")
+            .texture("assets/avatar.png")
+            .texture("https://example.com/banner.png");
+        let result = preload.load(|progress| {
+            clear_background(BLACK);
+            draw_rectangle(100.0, 380.0, 824.0 * progress.fraction(), 20.0, WHITE);
+            draw_text(&progress.current, 100.0, 360.0, 24.0, GRAY);
+        }).await;
+        if let Err(e) = result { println!("Couldn't load: {}", e); }
+    The callback runs once before anything loads and again after each file, with a frame
+    shown each time so the screen moves along. A file that fails doesn't stop the rest,
+    the Err lists every one that failed. Afterwards get them with assets::font and
+    assets::texture.
+    Import it with:
+        use crate::modules::assets::{self, Assets};
+
+    progress.loaded          // Files done so far
+    progress.total           // Files in the list
+    progress.current         // Path of the file loading next ("" when all are done)
+    progress.fraction()      // loaded / total, from 0.0 to 1.0
+    let count = preload.len();
+*/
+
+use macroquad::prelude::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    // Fonts that have already been loaded, by path
+    static FONTS: RefCell<HashMap<String, Font>> = RefCell::new(HashMap::new());
+    // Textures that have already been loaded, by path
+    static TEXTURES: RefCell<HashMap<String, Texture2D>> = RefCell::new(HashMap::new());
+}
+
+// What kind of file an Assets entry is
+enum Kind {
+    Font,
+    Texture,
+}
+
+// A list of fonts and textures to load together behind a loading screen
+pub struct Assets {
+    entries: Vec<(Kind, String)>,
+}
+
+// How far an Assets load has gotten
+#[allow(unused)]
+pub struct Progress {
+    pub loaded: usize,
+    pub total: usize,
+    pub current: String, // Path of the file loading next
+}
+
+impl Progress {
+    // loaded / total, from 0.0 to 1.0
+    #[allow(unused)]
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+}
+
+impl Assets {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    // Add a font to the list
+    #[allow(unused)]
+    pub fn font(&mut self, path: &str) -> &mut Self {
+        self.entries.push((Kind::Font, path.to_string()));
+        self
+    }
+
+    // Add an image to the list
+    #[allow(unused)]
+    pub fn texture(&mut self, path: &str) -> &mut Self {
+        self.entries.push((Kind::Texture, path.to_string()));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Load everything in the list one at a time, calling on_progress and showing a frame
+    // before the first file and after each one. Err lists the files that failed
+    #[allow(unused)]
+    pub async fn load<F: FnMut(&Progress)>(&self, mut on_progress: F) -> Result<(), String> {
+        let total = self.entries.len();
+        let mut failed = Vec::new();
+        for (loaded, (kind, path)) in self.entries.iter().enumerate() {
+            on_progress(&Progress { loaded, total, current: path.clone() });
+            next_frame().await;
+            let result = match kind {
+                Kind::Font => load_font(path).await.map(|_| ()),
+                Kind::Texture => load_texture(path).await.map(|_| ()),
+            };
+            if let Err(e) = result {
+                failed.push(e.to_string());
+            }
+        }
+        on_progress(&Progress { loaded: total, total, current: String::new() });
+        next_frame().await;
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(failed.join("\n"))
+        }
+    }
+}
+
+impl Default for Assets {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A font that was already loaded, by the path it was loaded with
+#[allow(unused)]
+pub fn font(path: &str) -> Option<Font> {
+    FONTS.with(|fonts| fonts.borrow().get(path).cloned())
+}
+
+/// A texture that was already loaded, by the path it was loaded with
+#[allow(unused)]
+pub fn texture(path: &str) -> Option<Texture2D> {
+    TEXTURES.with(|textures| textures.borrow().get(path).cloned())
+}
+
+/// Whether a font or texture has been loaded from this path
+#[allow(unused)]
+pub fn is_loaded(path: &str) -> bool {
+    font(path).is_some() || texture(path).is_some()
+}
+
+/// Load a .ttf font (cached by path)
+#[allow(unused)]
+pub async fn load_font(path: &str) -> Result<Font, Box<dyn std::error::Error>> {
+    if let Some(font) = font(path) {
+        return Ok(font);
+    }
+
+    let bytes = load_bytes(path).await?;
+    let font = load_ttf_font_from_bytes(&bytes).map_err(|e| format!("Failed to read font {}: {:?}", path, e))?;
+    FONTS.with(|fonts| fonts.borrow_mut().insert(path.to_string(), font.clone()));
+    Ok(font)
+}
+
+/// Load an image file as a texture (cached by path)
+#[allow(unused)]
+pub async fn load_texture(path: &str) -> Result<Texture2D, Box<dyn std::error::Error>> {
+    if let Some(texture) = texture(path) {
+        return Ok(texture);
+    }
+
+    let bytes = load_bytes(path).await?;
+    let image = Image::from_file_with_format(&bytes, None).map_err(|e| format!("Failed to read image {}: {:?}", path, e))?;
+    let texture = Texture2D::from_image(&image);
+    TEXTURES.with(|textures| textures.borrow_mut().insert(path.to_string(), texture.clone()));
+    Ok(texture)
+}
+
+/// Load a file as bytes
+#[allow(unused)]
+pub async fn load_bytes(path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        load_bytes_web(path).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if path.starts_with("http://") || path.starts_with("https://") {
+            return load_bytes_url(path);
+        }
+        load_file(path).await.map_err(|e| format!("Failed to load {}: {:?}", path, e).into())
+    }
+}
+
+/// Native download with ureq (waits for the whole file)
+#[cfg(not(target_arch = "wasm32"))]
+fn load_bytes_url(url: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    use std::io::Read;
+
+    let response = ureq::get(url).call().map_err(|e| format!("Failed to load {}: {}", url, e))?;
+    let mut bytes = Vec::new();
+    response.into_reader().read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Web version using the browser's fetch
+#[cfg(target_arch = "wasm32")]
+async fn load_bytes_web(path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    use wasm_bindgen_futures::JsFuture;
+    use wasm_bindgen::JsCast;
+    use web_sys::{Response, window};
+
+    let win = window().ok_or("Failed to get window")?;
+    let resp_value = JsFuture::from(win.fetch_with_str(path)).await.map_err(|_| "Fetch failed")?;
+    let resp: Response = resp_value.dyn_into().map_err(|_| "Failed to cast response")?;
+
+    if !resp.ok() {
+        return Err(format!("HTTP error {} loading {}", resp.status(), path).into());
+    }
+
+    let buffer = JsFuture::from(resp.array_buffer().map_err(|_| "Failed to get body")?)
+        .await
+        .map_err(|_| "Failed to read response body")?;
+    Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+}
@@ -0,0 +1,206 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Plays sounds for UI feedback (button clicks, errors, things that worked)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod audio;
+
+This also needs these modules:
+    pub mod assets;
+    pub mod settings;
+
+Add with the other use statements:
+    use crate::modules::audio;
+
+Sound needs macroquad's audio feature, which this project turns on with its own feature.
+Add the following to Cargo.toml under [features]:
+    audio = ["macroquad/audio"]
+Then run with:
+    cargo run --features audio
+On Linux this needs the ALSA library (sudo apt install libasound2-dev). Without the
+feature everything still compiles and runs, it just doesn't make any sound.
+
+HOW IT WORKS:
+    Sounds are loaded with load_sound (through the assets module so it works on the web).
+    Sounds can be registered under a name and played by name from anywhere. Three names
+    are played by the widgets by themselves when something happens:
+        "click"     every button without its own click sound, when clicked
+        "error"     a Form's submit finding a problem, a button's run_async action failing
+        "success"   a button's run_async action finishing without an error
+    A click sound can be set for a single button with TextButton::on_click_sound. Volume
+    and mute apply to every sound played through this module. Mute is saved with the
+    settings module, so a player who muted the game finds it muted next time too.
+
+Usage examples:
+    // Load before the main loop
+    let click = audio::load_sound("assets/click.wav").await.unwrap();
+
+    // Every button plays this when clicked
+    audio::set_click_sound(Some(click.clone()));
+
+    // Or just one button
+    btn_save.on_click_sound(click.clone());
+
+    // Play a sound yourself
+    audio::play(&click);
+
+    // Volume (0.0 - 1.0) and mute
+    audio::set_volume(0.5);
+    audio::set_muted(true);                      // Remembered next time the game runs
+    let muted = audio::toggle_muted();           // For a mute button, gives the new setting
+
+NAMED SOUNDS:
+    audio::load_named("error", "assets/error.wav").await.unwrap();   // Load and register
+    audio::load_named("success", "assets/ding.wav").await.unwrap();
+    audio::register("coin", coin_sound);         // A sound you loaded yourself
+    audio::play_named("coin");                   // Returns false if nothing is called that
+    audio::play_event(SoundEvent::Error);        // The same sound the widgets play
+    audio::unregister("coin");
+    let known = audio::is_registered("coin");
+    Import SoundEvent with:
+        use crate::modules::audio::{self, SoundEvent};
+*/
+
+use macroquad::audio::{load_sound_from_bytes, play_sound, PlaySoundParams, Sound};
+use crate::modules::assets;
+use crate::modules::settings;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+// Where mute is kept in the settings
+const MUTED_SETTING: &str = "audio.muted";
+
+thread_local! {
+    // Sounds registered by name
+    static SOUNDS: RefCell<HashMap<String, Sound>> = RefCell::new(HashMap::new());
+    static VOLUME: Cell<f32> = const { Cell::new(1.0) };
+    // Read from the settings the first time it is needed
+    static MUTED: Cell<bool> = Cell::new(settings::get_bool(MUTED_SETTING).unwrap_or(false));
+}
+
+// Something that happened in the UI that can have a sound
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(unused)]
+pub enum SoundEvent {
+    Click,
+    Error,
+    Success,
+}
+
+impl SoundEvent {
+    // The name of the sound played for it
+    #[allow(unused)]
+    pub fn name(self) -> &'static str {
+        match self {
+            SoundEvent::Click => "click",
+            SoundEvent::Error => "error",
+            SoundEvent::Success => "success",
+        }
+    }
+}
+
+/// Load a .wav or .ogg file as a sound
+#[allow(unused)]
+pub async fn load_sound(path: &str) -> Result<Sound, Box<dyn std::error::Error>> {
+    let bytes = assets::load_bytes(path).await?;
+    load_sound_from_bytes(&bytes)
+        .await
+        .map_err(|e| format!("Failed to read sound {}: {:?}", path, e).into())
+}
+
+/// Play a sound once at the current volume (does nothing while muted)
+#[allow(unused)]
+pub fn play(sound: &Sound) {
+    if MUTED.with(Cell::get) {
+        return;
+    }
+    play_sound(sound, PlaySoundParams { looped: false, volume: VOLUME.with(Cell::get) });
+}
+
+/// Play a widget's own click sound, or the shared click sound if it doesn't have one
+pub fn play_click(sound: Option<&Sound>) {
+    match sound {
+        Some(sound) => play(sound),
+        None => play_event(SoundEvent::Click),
+    }
+}
+
+/// Set the sound every button plays when clicked (None for silence), same as registering "click"
+#[allow(unused)]
+pub fn set_click_sound(sound: Option<Sound>) {
+    match sound {
+        Some(sound) => register(SoundEvent::Click.name(), sound),
+        None => unregister(SoundEvent::Click.name()),
+    }
+}
+
+/// Give a sound a name to play it by (replaces any sound already called that)
+#[allow(unused)]
+pub fn register(name: &str, sound: Sound) {
+    SOUNDS.with(|sounds| sounds.borrow_mut().insert(name.to_string(), sound));
+}
+
+/// Load a sound and register it under a name
+#[allow(unused)]
+pub async fn load_named(name: &str, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let sound = load_sound(path).await?;
+    register(name, sound);
+    Ok(())
+}
+
+#[allow(unused)]
+pub fn unregister(name: &str) {
+    SOUNDS.with(|sounds| sounds.borrow_mut().remove(name));
+}
+
+#[allow(unused)]
+pub fn is_registered(name: &str) -> bool {
+    SOUNDS.with(|sounds| sounds.borrow().contains_key(name))
+}
+
+/// Play the sound registered under a name, returns false if there isn't one
+#[allow(unused)]
+pub fn play_named(name: &str) -> bool {
+    let Some(sound) = SOUNDS.with(|sounds| sounds.borrow().get(name).cloned()) else {
+        return false;
+    };
+    play(&sound);
+    true
+}
+
+/// Play the sound for something that happened in the UI, if one is registered
+pub fn play_event(event: SoundEvent) {
+    play_named(event.name());
+}
+
+/// Set the volume of sounds played through this module (0.0 - 1.0)
+#[allow(unused)]
+pub fn set_volume(volume: f32) {
+    VOLUME.with(|v| v.set(volume.clamp(0.0, 1.0)));
+}
+
+#[allow(unused)]
+pub fn get_volume() -> f32 {
+    VOLUME.with(Cell::get)
+}
+
+/// Turn every sound played through this module off or back on (saved in the settings)
+#[allow(unused)]
+pub fn set_muted(muted: bool) {
+    MUTED.with(|m| m.set(muted));
+    settings::set_bool(MUTED_SETTING, muted);
+}
+
+/// Switch mute on or off, returns whether it is muted now
+#[allow(unused)]
+pub fn toggle_muted() -> bool {
+    let muted = !is_muted();
+    set_muted(muted);
+    muted
+}
+
+#[allow(unused)]
+pub fn is_muted() -> bool {
+    MUTED.with(Cell::get)
+}
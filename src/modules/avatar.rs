@@ -0,0 +1,346 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A round profile picture that is downloaded from a Supabase Storage bucket, showing the
+first letter of the name until (or instead of) the picture
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod avatar;
+
+This also needs these modules:
+    pub mod database;
+    pub mod spinner;
+    pub mod tasks;
+
+Add with the other use statements:
+    use crate::modules::avatar::Avatar;
+
+Then above the loop section (the client is shared with Rc so downloads can run in the background):
+    let client = Rc::new(create_database_client());
+    let mut avt_player = Avatar::new(20.0, 20.0, 64.0, "dray");
+    avt_player.load(client.clone(), "avatars", "dray.png");
+Where the parameters are x, y, size (the circle's width and height) and the name the letter
+comes from. load takes the client, the bucket and the file's path in the bucket.
+The bucket needs a policy letting your key read it (see STORAGE EXAMPLES in database.rs).
+
+Then in the loop you would use:
+    avt_player.draw();
+Don't forget tasks::run_pending(); at the end of the loop, downloads and uploads run through it.
+
+UPLOADING A NEW PICTURE:
+    let bytes = std::fs::read("new_avatar.png").unwrap();
+    avt_player.upload(client.clone(), "avatars", "dray.png", bytes, "image/png");
+
+    // Then in the loop, once it's done
+    if let Some(result) = avt_player.upload_result() {
+        match result {
+            Ok(()) => println!("Picture saved"),
+            Err(e) => println!("Upload failed: {}", e),
+        }
+    }
+    The new picture shows as soon as the upload works.
+
+HOW IT WORKS:
+    Pictures are kept after they are downloaded, so avatars for the same bucket and path
+    (in a list of players, or after switching screens) only download once. The picture is
+    cropped to the square in its middle and drawn inside the circle. Until it arrives (or if
+    there isn't one) the circle shows the name's first letter on a color picked from the
+    name, so each player keeps the same color. A small spinner shows while downloading.
+
+OTHER METHODS:
+    avt_player.set_name("someone");                  // Changes the letter and color
+    avt_player.with_border(WHITE, 2.0);              // Ring around the circle (0.0 thickness for none)
+    avt_player.with_text_color(WHITE);
+    avt_player.clear();                              // Back to the letter
+    avt_player.set_position(10.0, 10.0);
+    avt_player.set_size(48.0);
+    if avt_player.is_loading() { }
+    if avt_player.is_uploading() { }
+    if let Some(error) = avt_player.get_error() {
+        println!("Couldn't download the avatar: {}", error);
+    }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::database::DatabaseClient;
+use crate::modules::spinner::Spinner;
+use crate::modules::tasks::{self, TaskHandle};
+use crate::modules::theme::ThemeColor;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use unicode_segmentation::UnicodeSegmentation;
+
+// Triangles around the edge of the circle
+const SEGMENTS: usize = 48;
+
+// Colors behind the letter, picked by the name
+const FALLBACK_COLORS: [Color; 8] = [
+    Color::new(0.90, 0.30, 0.24, 1.0),
+    Color::new(0.91, 0.49, 0.13, 1.0),
+    Color::new(0.95, 0.61, 0.07, 1.0),
+    Color::new(0.18, 0.80, 0.44, 1.0),
+    Color::new(0.10, 0.74, 0.61, 1.0),
+    Color::new(0.20, 0.60, 0.86, 1.0),
+    Color::new(0.61, 0.35, 0.71, 1.0),
+    Color::new(0.20, 0.29, 0.37, 1.0),
+];
+
+thread_local! {
+    // Pictures that have already been downloaded, by "bucket/path"
+    static PICTURES: RefCell<HashMap<String, Texture2D>> = RefCell::new(HashMap::new());
+}
+
+pub struct Avatar {
+    x: f32,
+    y: f32,
+    size: f32,
+    name: String,
+    initial: String,
+    background: Color,
+    text_color: Color,
+    border_color: ThemeColor,
+    border_thickness: f32,
+    texture: Option<Texture2D>,
+    download: Option<TaskHandle<Result<Texture2D, String>>>,
+    upload: Option<TaskHandle<Result<Texture2D, String>>>,
+    upload_result: Option<Result<(), String>>,
+    error: Option<String>,
+    spinner: Spinner,
+}
+
+impl Avatar {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, size: f32, name: &str) -> Self {
+        let mut avatar = Self {
+            x,
+            y,
+            size,
+            name: String::new(),
+            initial: String::new(),
+            background: FALLBACK_COLORS[0],
+            text_color: WHITE,
+            border_color: ThemeColor::Surface,
+            border_thickness: 0.0,
+            texture: None,
+            download: None,
+            upload: None,
+            upload_result: None,
+            error: None,
+            spinner: avatar_spinner(x, y, size),
+        };
+        avatar.set_name(name);
+        avatar
+    }
+
+    // Download the picture in the background (right away if it was downloaded before)
+    #[allow(unused)]
+    pub fn load(&mut self, client: Rc<DatabaseClient>, bucket: &str, path: &str) -> &mut Self {
+        let key = format!("{}/{}", bucket, path);
+        self.error = None;
+        if let Some(texture) = PICTURES.with(|pictures| pictures.borrow().get(&key).cloned()) {
+            self.texture = Some(texture);
+            self.download = None;
+            return self;
+        }
+
+        let (bucket, path) = (bucket.to_string(), path.to_string());
+        self.download = Some(tasks::spawn(async move {
+            let bytes = client.download_file(&bucket, &path).await.map_err(|e| e.to_string())?;
+            let texture = texture_from_bytes(&bytes)?;
+            PICTURES.with(|pictures| pictures.borrow_mut().insert(key, texture.clone()));
+            Ok(texture)
+        }));
+        self
+    }
+
+    // Upload a new picture (replacing the one at that path) and show it once it's saved
+    #[allow(unused)]
+    pub fn upload(&mut self, client: Rc<DatabaseClient>, bucket: &str, path: &str, bytes: Vec<u8>, content_type: &str) -> &mut Self {
+        let key = format!("{}/{}", bucket, path);
+        let (bucket, path, content_type) = (bucket.to_string(), path.to_string(), content_type.to_string());
+        self.upload_result = None;
+        self.upload = Some(tasks::spawn(async move {
+            // Checked first so a file that isn't a picture never gets uploaded
+            let texture = texture_from_bytes(&bytes)?;
+            client.upload_file(&bucket, &path, &bytes, &content_type).await.map_err(|e| e.to_string())?;
+            PICTURES.with(|pictures| pictures.borrow_mut().insert(key, texture.clone()));
+            Ok(texture)
+        }));
+        self
+    }
+
+    // How the last upload went, returned once on the frame after it finishes
+    #[allow(unused)]
+    pub fn upload_result(&mut self) -> Option<Result<(), String>> {
+        self.poll();
+        self.upload_result.take()
+    }
+
+    // Change the name the letter and its color come from
+    #[allow(unused)]
+    pub fn set_name(&mut self, name: &str) -> &mut Self {
+        self.name = name.to_string();
+        self.initial = name.trim().graphemes(true).next().unwrap_or("?").to_uppercase();
+        self.background = fallback_color(name);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    #[allow(unused)]
+    pub fn with_border(&mut self, color: impl Into<ThemeColor>, thickness: f32) -> &mut Self {
+        self.border_color = color.into();
+        self.border_thickness = thickness.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_text_color(&mut self, color: Color) -> &mut Self {
+        self.text_color = color;
+        self
+    }
+
+    // Show the letter again instead of the picture
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        self.texture = None;
+        self.download = None;
+        self.error = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self.spinner = avatar_spinner(self.x, self.y, self.size);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, size: f32) -> &mut Self {
+        self.size = size;
+        self.spinner = avatar_spinner(self.x, self.y, self.size);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_loading(&self) -> bool {
+        self.download.is_some()
+    }
+
+    #[allow(unused)]
+    pub fn is_uploading(&self) -> bool {
+        self.upload.is_some()
+    }
+
+    // Why the last download failed, if it did
+    #[allow(unused)]
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        self.poll();
+        let radius = self.size / 2.0;
+        let (center_x, center_y) = (self.x + radius, self.y + radius);
+
+        match &self.texture {
+            Some(texture) => draw_mesh(&circle_mesh(texture, center_x, center_y, radius)),
+            None => {
+                draw_circle(center_x, center_y, radius, self.background);
+                if self.download.is_none() {
+                    let font_size = (self.size * 0.5) as u16;
+                    let dims = measure_text(&self.initial, None, font_size, 1.0);
+                    draw_text(
+                        &self.initial,
+                        center_x - dims.width / 2.0,
+                        center_y + dims.offset_y / 2.0,
+                        font_size as f32,
+                        self.text_color,
+                    );
+                }
+            }
+        }
+        if self.download.is_some() || self.upload.is_some() {
+            self.spinner.draw();
+        }
+        if self.border_thickness > 0.0 {
+            draw_circle_lines(center_x, center_y, radius, self.border_thickness, self.border_color.color());
+        }
+    }
+
+    // Pick up finished downloads and uploads
+    fn poll(&mut self) {
+        if let Some(result) = self.download.as_ref().and_then(|task| task.take()) {
+            self.download = None;
+            match result {
+                Ok(texture) => self.texture = Some(texture),
+                Err(error) => self.error = Some(error),
+            }
+        }
+        if let Some(result) = self.upload.as_ref().and_then(|task| task.take()) {
+            self.upload = None;
+            self.upload_result = Some(result.map(|texture| {
+                // A download still running would bring back the old picture
+                self.download = None;
+                self.texture = Some(texture);
+            }));
+        }
+    }
+}
+
+// Read a downloaded file into a texture
+fn texture_from_bytes(bytes: &[u8]) -> Result<Texture2D, String> {
+    let image = Image::from_file_with_format(bytes, None).map_err(|e| format!("Failed to read picture: {:?}", e))?;
+    Ok(Texture2D::from_image(&image))
+}
+
+// The same name always gets the same color
+fn fallback_color(name: &str) -> Color {
+    let hash = name.to_lowercase().bytes().fold(0u32, |hash, byte| hash.wrapping_mul(31).wrapping_add(byte as u32));
+    FALLBACK_COLORS[hash as usize % FALLBACK_COLORS.len()]
+}
+
+// A circle of triangles showing the square in the middle of the texture
+fn circle_mesh(texture: &Texture2D, center_x: f32, center_y: f32, radius: f32) -> Mesh {
+    let (width, height) = (texture.width().max(1.0), texture.height().max(1.0));
+    let side = width.min(height);
+    // Half of the square's size, as a fraction of the texture
+    let (half_u, half_v) = (side / width / 2.0, side / height / 2.0);
+
+    let mut vertices = vec![Vertex::new(center_x, center_y, 0.0, 0.5, 0.5, WHITE)];
+    let mut indices = Vec::with_capacity(SEGMENTS * 3);
+    for i in 0..=SEGMENTS {
+        let angle = i as f32 / SEGMENTS as f32 * std::f32::consts::TAU;
+        let (sin, cos) = angle.sin_cos();
+        vertices.push(Vertex::new(
+            center_x + cos * radius,
+            center_y + sin * radius,
+            0.0,
+            0.5 + cos * half_u,
+            0.5 + sin * half_v,
+            WHITE,
+        ));
+        if i > 0 {
+            indices.extend_from_slice(&[0, i as u16, i as u16 + 1]);
+        }
+    }
+    Mesh {
+        vertices,
+        indices,
+        texture: Some(texture.clone()),
+    }
+}
+
+// Spinner in the middle of the circle
+fn avatar_spinner(x: f32, y: f32, size: f32) -> Spinner {
+    let mut spinner = Spinner::new(x + size / 2.0, y + size / 2.0, (size * 0.2).clamp(4.0, 20.0));
+    spinner.with_color(WHITE);
+    spinner
+}
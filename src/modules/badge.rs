@@ -0,0 +1,225 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A small count bubble drawn on the corner of another widget (unread messages, changes
+waiting to be saved, ...)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod badge;
+
+This also needs these modules:
+    pub mod animation;
+    pub mod scale;
+
+Add with the other use statements:
+    use crate::modules::badge::Badge;
+
+Then above the loop section:
+    let mut bdg_save = Badge::new();
+    bdg_save.set_count(3);
+
+Or have it work the count out itself every draw:
+    let unsaved = Rc::new(Cell::new(0));
+    let mut bdg_save = Badge::bound({
+        let unsaved = unsaved.clone();
+        move || unsaved.get()
+    });
+    // Or from a widget that lives in main, by checking it yourself each frame:
+    bdg_save.set_count(grd_users.changed_rows().len());
+
+Then in the loop, after drawing the widget it sits on:
+    if btn_save.click() { }
+    bdg_save.draw_on(btn_save.get_rect());
+draw_on takes the area of the widget (any Rect works), the bubble is centered on its
+top-right corner. Nothing is drawn while the count is 0.
+
+HOW IT WORKS:
+    The bubble is a circle for one digit and stretches into a pill for more. Counts over
+    the max (99 by default) show as "99+". It pops bigger for a moment whenever the count
+    goes up so the change gets noticed.
+
+OTHER METHODS:
+    bdg_save.with_corner(Anchor::BottomRight);      // Which corner (any Anchor, scale::Anchor)
+    bdg_save.with_colors(RED, WHITE);               // Bubble and text
+    bdg_save.with_font_size(16);                    // Default 16, the bubble grows with it
+    bdg_save.with_max(9);                           // Above this shows "9+"
+    bdg_save.with_show_zero(true);                  // Keep showing "0"
+    bdg_save.with_border(WHITE, 2.0);               // Ring to separate it from the widget
+    bdg_save.bind_count(move || queue.len());       // Start using a closure later
+    bdg_save.unbind();                              // Stop using the closure, keep the last count
+    let count = bdg_save.get_count();
+    bdg_save.draw_at(300.0, 100.0);                 // Center the bubble on a point instead
+*/
+
+use macroquad::prelude::*;
+use crate::modules::animation::{Easing, Tween};
+use crate::modules::scale::Anchor;
+use crate::modules::theme::ThemeColor;
+
+// Works out the count every draw when bound
+type CountSource = Box<dyn Fn() -> usize>;
+
+pub struct Badge {
+    count: usize,
+    source: Option<CountSource>,
+    corner: Anchor,
+    color: ThemeColor,
+    text_color: ThemeColor,
+    border_color: ThemeColor,
+    border_thickness: f32,
+    font_size: u16,
+    max: usize,
+    show_zero: bool,
+    pop: Tween, // Extra size after the count goes up, shrinking back to 1.0
+}
+
+impl Badge {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            source: None,
+            corner: Anchor::TopRight,
+            color: ThemeColor::Error,
+            text_color: ThemeColor::OnPrimary,
+            border_color: ThemeColor::Surface,
+            border_thickness: 0.0,
+            font_size: 16,
+            max: 99,
+            show_zero: false,
+            pop: Tween::new(1.35, 1.0, 0.25, Easing::EaseOut),
+        }
+    }
+
+    // Constructor for a badge whose count comes from a closure, run again every draw
+    #[allow(unused)]
+    pub fn bound<F: Fn() -> usize + 'static>(source: F) -> Self {
+        let mut badge = Self::new();
+        badge.count = source();
+        badge.source = Some(Box::new(source));
+        badge
+    }
+
+    #[allow(unused)]
+    pub fn bind_count<F: Fn() -> usize + 'static>(&mut self, source: F) -> &mut Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn unbind(&mut self) -> &mut Self {
+        self.source = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_count(&mut self, count: usize) -> &mut Self {
+        if count > self.count {
+            self.pop.start();
+        }
+        self.count = count;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    #[allow(unused)]
+    pub fn with_corner(&mut self, corner: Anchor) -> &mut Self {
+        self.corner = corner;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_colors(&mut self, color: impl Into<ThemeColor>, text_color: impl Into<ThemeColor>) -> &mut Self {
+        self.color = color.into();
+        self.text_color = text_color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_border(&mut self, color: impl Into<ThemeColor>, thickness: f32) -> &mut Self {
+        self.border_color = color.into();
+        self.border_thickness = thickness.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_max(&mut self, max: usize) -> &mut Self {
+        self.max = max;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_show_zero(&mut self, show_zero: bool) -> &mut Self {
+        self.show_zero = show_zero;
+        self
+    }
+
+    // Draw on a corner of an area (usually the widget's rect)
+    #[allow(unused)]
+    pub fn draw_on(&mut self, area: Rect) {
+        let x = match self.corner {
+            Anchor::TopLeft | Anchor::Left | Anchor::BottomLeft => area.x,
+            Anchor::Top | Anchor::Center | Anchor::Bottom => area.x + area.w / 2.0,
+            Anchor::TopRight | Anchor::Right | Anchor::BottomRight => area.x + area.w,
+        };
+        let y = match self.corner {
+            Anchor::TopLeft | Anchor::Top | Anchor::TopRight => area.y,
+            Anchor::Left | Anchor::Center | Anchor::Right => area.y + area.h / 2.0,
+            Anchor::BottomLeft | Anchor::Bottom | Anchor::BottomRight => area.y + area.h,
+        };
+        self.draw_at(x, y);
+    }
+
+    // Draw centered on a point
+    #[allow(unused)]
+    pub fn draw_at(&mut self, x: f32, y: f32) {
+        if let Some(source) = &self.source {
+            let count = source();
+            self.set_count(count);
+        }
+        if self.count == 0 && !self.show_zero {
+            return;
+        }
+
+        let text = if self.count > self.max { format!("{}+", self.max) } else { self.count.to_string() };
+        let font_size = (self.font_size as f32 * self.pop.value()).round().max(1.0) as u16;
+        let size = measure_text(&text, None, font_size, 1.0);
+        // A circle for short counts, a pill as wide as the text for longer ones
+        let height = font_size as f32 * 1.25;
+        let width = (size.width + font_size as f32 * 0.6).max(height);
+        let radius = height / 2.0;
+        let (left, top) = (x - width / 2.0, y - height / 2.0);
+
+        if self.border_thickness > 0.0 {
+            draw_pill(left - self.border_thickness, top - self.border_thickness, width + self.border_thickness * 2.0, radius + self.border_thickness, self.border_color.color());
+        }
+        draw_pill(left, top, width, radius, self.color.color());
+        draw_text(&text, x - size.width / 2.0, y - size.height / 2.0 + size.offset_y, font_size as f32, self.text_color.color());
+    }
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// A rectangle with fully rounded ends
+fn draw_pill(x: f32, y: f32, width: f32, radius: f32, color: Color) {
+    let middle = width - radius * 2.0;
+    if middle > 0.0 {
+        draw_rectangle(x + radius, y, middle, radius * 2.0, color);
+    }
+    draw_circle(x + radius, y + radius, radius, color);
+    draw_circle(x + width - radius, y + radius, radius, color);
+}
@@ -0,0 +1,127 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Groups toggle TextButtons so exactly one of them is selected at a time (like radio buttons)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod button_group;
+
+This also needs the text_button module:
+    pub mod text_button;
+
+Add with the other use statements:
+    use crate::modules::button_group::ButtonGroup;
+
+Then above the loop section:
+    let mut grp_difficulty = ButtonGroup::new();
+    grp_difficulty
+        .add(TextButton::new(100.0, 100.0, 150.0, 50.0, "Easy", BLUE, GREEN, 25))
+        .add(TextButton::new(260.0, 100.0, 150.0, 50.0, "Normal", BLUE, GREEN, 25))
+        .add(TextButton::new(420.0, 100.0, 150.0, 50.0, "Hard", BLUE, GREEN, 25));
+The first button added starts selected. Buttons are switched to toggle mode, so the
+selected one is drawn with the toggled color (see TextButton::with_toggled_color).
+
+Then in the loop you would use:
+    if let Some(index) = grp_difficulty.click() {
+        // The selection changed to button number `index`
+    }
+    let difficulty = grp_difficulty.selected_index();
+
+Other methods:
+    grp_difficulty.set_selected(2);                          // Select from code
+    grp_difficulty.get_mut(0).unwrap().set_text("Very easy"); // Change a button
+    let count = grp_difficulty.len();
+*/
+
+use crate::modules::text_button::TextButton;
+
+pub struct ButtonGroup {
+    buttons: Vec<TextButton>,
+    selected: usize,
+}
+
+impl ButtonGroup {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self {
+            buttons: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    // Add a button to the end of the group
+    #[allow(unused)]
+    pub fn add(&mut self, mut button: TextButton) -> &mut Self {
+        button.with_toggle(true);
+        button.set_toggled(self.buttons.len() == self.selected);
+        self.buttons.push(button);
+        self
+    }
+
+    // Index of the selected button
+    #[allow(unused)]
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    // Select a button from code (ignored if the index is out of range)
+    #[allow(unused)]
+    pub fn set_selected(&mut self, index: usize) -> &mut Self {
+        if index < self.buttons.len() {
+            self.selected = index;
+            self.sync_toggles();
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get(&self, index: usize) -> Option<&TextButton> {
+        self.buttons.get(index)
+    }
+
+    #[allow(unused)]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut TextButton> {
+        self.buttons.get_mut(index)
+    }
+
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.buttons.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.buttons.is_empty()
+    }
+
+    // Draw every button, returns the new index when a different button gets selected
+    #[allow(unused)]
+    pub fn click(&mut self) -> Option<usize> {
+        let mut clicked = None;
+        for (index, button) in self.buttons.iter().enumerate() {
+            if button.click() {
+                clicked = Some(index);
+            }
+        }
+        // Clicking a button flips its toggle, so put every toggle back to match the selection
+        let changed = clicked.filter(|&index| index != self.selected);
+        if let Some(index) = clicked {
+            self.selected = index;
+            self.sync_toggles();
+        }
+        changed
+    }
+
+    // Only the selected button is toggled on
+    fn sync_toggles(&mut self) {
+        for (index, button) in self.buttons.iter_mut().enumerate() {
+            button.set_toggled(index == self.selected);
+        }
+    }
+}
+
+impl Default for ButtonGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
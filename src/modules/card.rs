@@ -0,0 +1,302 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A card with a background, drop shadow and optional header and footer rows that stacks
+the widgets inside it from top to bottom (for dashboards showing records as cards)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod card;
+
+This also needs the scale module (for clipping the contents):
+    pub mod scale;
+
+Add with the other use statements:
+    use crate::modules::card::Card;
+
+Then above the loop section:
+    let mut crd_player = Card::new(50.0, 50.0, 260.0);
+    crd_player.with_header("dray").with_footer("Joined 2026-10-16");
+Where the parameters are x, y and width. The height grows to fit what is put inside.
+
+Then in the loop, put each widget in the next slot between begin() and end():
+    crd_player.begin();
+    let slot = crd_player.next_slot(30.0);          // The next 30 pixels down, padding already taken off
+    lbl_level.set_position(slot.x, slot.y);
+    lbl_level.draw();
+    let slot = crd_player.next_slot(40.0);
+    btn_view.update_position(slot.x, slot.y, Some(slot.w), Some(slot.h));
+    if btn_view.click() { }
+    crd_player.end();
+next_slot gives the area for the next widget, under the one before it with a gap between
+them. end() draws the footer under the last slot. Anything drawn between begin() and end()
+is cut off at the card's edges.
+
+CARDS FROM RECORDS:
+    for (i, record) in records.iter().enumerate() {
+        let mut card = Card::new(20.0 + (i % 3) as f32 * 280.0, 20.0 + (i / 3) as f32 * 180.0, 260.0);
+        card.with_header(&record.username).with_fixed_height(160.0);
+        card.begin();
+        let slot = card.next_slot(24.0);
+        draw_text(&format!("Level {}", record.level), slot.x, slot.y + 20.0, 24.0, BLACK);
+        card.end();
+    }
+
+HOW IT WORKS:
+    The card is drawn at the height its contents took last frame, so on the first frame it
+    may be short by a frame. Give it a fixed height with with_fixed_height to avoid that
+    (then contents that don't fit are cut off).
+
+OTHER METHODS:
+    crd_player.with_padding(16.0);                       // Space inside the edges (default 12)
+    crd_player.with_spacing(6.0);                        // Gap between slots (default from the theme)
+    crd_player.with_round(12.0);                         // Corner radius (default from the theme, 0 for square)
+    crd_player.with_shadow(Color::new(0.0, 0.0, 0.0, 0.3), 4.0); // Color and offset (0 offset for none)
+    crd_player.with_colors(WHITE, DARKBLUE, WHITE, GRAY); // Body, header, header text and footer text
+    crd_player.with_font_size(22);                       // Header size, the footer is a bit smaller
+    crd_player.clear_header();
+    crd_player.clear_footer();
+    crd_player.set_position(10.0, 10.0);
+    crd_player.set_width(300.0);
+    let area = crd_player.get_rect();                    // The whole card, as drawn last
+    if crd_player.clicked() { }                          // Pressed anywhere on the card this frame
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::theme::{self, draw_round_rect, ThemeColor};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+pub struct Card {
+    x: f32,
+    y: f32,
+    width: f32,
+    fixed_height: Option<f32>,
+    header: Option<String>,
+    footer: Option<String>,
+    padding: f32,
+    spacing: Option<f32>,       // None uses the theme's
+    corner_radius: Option<f32>, // None uses the theme's
+    font_size: u16,
+    body_color: ThemeColor,
+    header_color: ThemeColor,
+    header_text_color: ThemeColor,
+    footer_text_color: ThemeColor,
+    shadow_color: ThemeColor,
+    shadow_offset: f32,
+    cursor_y: f32,       // Where the next slot starts
+    slots_height: f32,   // Height of the slots added last frame, for sizing the card
+    first_slot: bool,    // No gap before the first slot
+    clipping: bool,      // True between begin() and end()
+}
+
+impl Card {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            fixed_height: None,
+            header: None,
+            footer: None,
+            padding: 12.0,
+            spacing: None,
+            corner_radius: None,
+            font_size: 22,
+            body_color: ThemeColor::Surface,
+            header_color: ThemeColor::Primary,
+            header_text_color: ThemeColor::OnPrimary,
+            footer_text_color: ThemeColor::TextMuted,
+            shadow_color: ThemeColor::Shadow,
+            shadow_offset: 4.0,
+            cursor_y: 0.0,
+            slots_height: 0.0,
+            first_slot: true,
+            clipping: false,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn with_header(&mut self, text: &str) -> &mut Self {
+        self.header = Some(text.to_string());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_header(&mut self) -> &mut Self {
+        self.header = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_footer(&mut self, text: &str) -> &mut Self {
+        self.footer = Some(text.to_string());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_footer(&mut self) -> &mut Self {
+        self.footer = None;
+        self
+    }
+
+    // Keep the card this tall instead of fitting the contents
+    #[allow(unused)]
+    pub fn with_fixed_height(&mut self, height: f32) -> &mut Self {
+        self.fixed_height = Some(height);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_padding(&mut self, padding: f32) -> &mut Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_spacing(&mut self, spacing: f32) -> &mut Self {
+        self.spacing = Some(spacing.max(0.0));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_round(&mut self, radius: f32) -> &mut Self {
+        self.corner_radius = Some(radius.max(0.0));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_shadow(&mut self, color: impl Into<ThemeColor>, offset: f32) -> &mut Self {
+        self.shadow_color = color.into();
+        self.shadow_offset = offset;
+        self
+    }
+
+    // Colors for the body, header bar, header text and footer text
+    #[allow(unused)]
+    pub fn with_colors(&mut self, body: impl Into<ThemeColor>, header: impl Into<ThemeColor>, header_text: impl Into<ThemeColor>, footer_text: impl Into<ThemeColor>) -> &mut Self {
+        self.body_color = body.into();
+        self.header_color = header.into();
+        self.header_text_color = header_text.into();
+        self.footer_text_color = footer_text.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_width(&mut self, width: f32) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    // The whole card (using last frame's contents for the height)
+    #[allow(unused)]
+    pub fn get_rect(&self) -> Rect {
+        Rect::new(self.x, self.y, self.width, self.height())
+    }
+
+    // Whether the card was pressed this frame
+    #[allow(unused)]
+    pub fn clicked(&self) -> bool {
+        let (mouse_x, mouse_y) = mouse_position();
+        is_mouse_button_pressed(MouseButton::Left) && self.get_rect().contains(Vec2::new(mouse_x, mouse_y))
+    }
+
+    // Draw the card and header, and start clipping to it
+    #[allow(unused)]
+    pub fn begin(&mut self) {
+        let height = self.height();
+        let radius = self.corner_radius.unwrap_or_else(theme::corner_radius);
+        if self.shadow_offset != 0.0 {
+            draw_round_rect(self.x + self.shadow_offset, self.y + self.shadow_offset, self.width, height, radius, self.shadow_color.color());
+        }
+        draw_round_rect(self.x, self.y, self.width, height, radius, self.body_color.color());
+
+        if let Some(header) = &self.header {
+            let header_height = self.header_height();
+            // Rounded on top only, the square bottom corners are covered by the body
+            draw_round_rect(self.x, self.y, self.width, header_height, radius, self.header_color.color());
+            draw_rectangle(self.x, self.y + header_height / 2.0, self.width, header_height / 2.0, self.header_color.color());
+            let size = measure_text(header, None, self.font_size, 1.0);
+            push_clip_rect(Rect::new(self.x, self.y, self.width - self.padding, header_height));
+            draw_text(
+                header,
+                self.x + self.padding,
+                self.y + (header_height - size.height) / 2.0 + size.offset_y,
+                self.font_size as f32,
+                self.header_text_color.color(),
+            );
+            pop_clip_rect();
+        }
+
+        self.cursor_y = self.y + self.header_height() + self.padding;
+        self.first_slot = true;
+        push_clip_rect(Rect::new(self.x, self.y, self.width, height));
+        self.clipping = true;
+    }
+
+    // The area for the next widget down, as wide as the card inside the padding
+    #[allow(unused)]
+    pub fn next_slot(&mut self, height: f32) -> Rect {
+        if !self.first_slot {
+            self.cursor_y += self.spacing.unwrap_or_else(theme::spacing);
+        }
+        self.first_slot = false;
+        let slot = Rect::new(self.x + self.padding, self.cursor_y, (self.width - self.padding * 2.0).max(0.0), height);
+        self.cursor_y += height;
+        slot
+    }
+
+    // Draw the footer under the last slot and stop clipping
+    #[allow(unused)]
+    pub fn end(&mut self) {
+        self.slots_height = self.cursor_y - (self.y + self.header_height() + self.padding);
+        if let Some(footer) = &self.footer {
+            let footer_size = self.footer_font_size();
+            let footer_top = self.y + self.height() - self.footer_height();
+            draw_line(self.x + self.padding, footer_top, self.x + self.width - self.padding, footer_top, 1.0, theme::palette().surface_alt);
+            let size = measure_text(footer, None, footer_size, 1.0);
+            draw_text(
+                footer,
+                self.x + self.padding,
+                footer_top + (self.footer_height() - size.height) / 2.0 + size.offset_y,
+                footer_size as f32,
+                self.footer_text_color.color(),
+            );
+        }
+        if self.clipping {
+            pop_clip_rect();
+            self.clipping = false;
+        }
+    }
+
+    fn height(&self) -> f32 {
+        self.fixed_height
+            .unwrap_or(self.header_height() + self.padding * 2.0 + self.slots_height + self.footer_height())
+    }
+
+    fn header_height(&self) -> f32 {
+        if self.header.is_some() { self.font_size as f32 * 1.8 } else { 0.0 }
+    }
+
+    fn footer_font_size(&self) -> u16 {
+        (self.font_size as f32 * 0.8) as u16
+    }
+
+    fn footer_height(&self) -> f32 {
+        if self.footer.is_some() { self.footer_font_size() as f32 * 1.8 } else { 0.0 }
+    }
+}
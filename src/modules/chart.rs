@@ -0,0 +1,341 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A simple line or bar chart with axes, for plotting numbers from the database
+(a player's level over time, how many players are on each level, ...)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod chart;
+
+This also needs the format module (for the numbers on the axis):
+    pub mod format;
+
+Add with the other use statements:
+    use crate::modules::chart::{Chart, ChartMode};
+
+Then above the loop section:
+    let mut cht_levels = Chart::new(100.0, 100.0, 500.0, 300.0);
+    cht_levels.with_title("Players per level").with_mode(ChartMode::Bar);
+Where the parameters are x, y, width and height (axis labels are drawn inside this area)
+
+Give it the numbers (and optionally a label for each one, shown along the bottom):
+    let records: Vec<DatabaseTable> = client.fetch_table("draysTable").await.unwrap();
+    cht_levels.set_values(&records.iter().map(|record| record.level as f64).collect::<Vec<f64>>());
+    cht_levels.set_labels(&records.iter().map(|record| record.username.clone()).collect::<Vec<String>>());
+
+Then in the loop you would use:
+    cht_levels.draw();
+Hovering over the chart shows the value under the mouse.
+
+CUSTOMIZATION:
+    cht_levels.with_mode(ChartMode::Line);          // Line (default) or Bar
+    cht_levels.with_colors(BLUE, DARKGRAY, Some(WHITE)); // Data, axes and text, background (None for none, default from the theme)
+    cht_levels.with_y_range(Some(0.0), None);       // Fix the bottom and/or top (None = fit the data)
+    cht_levels.with_font_size(16);                  // Default 16
+    cht_levels.clear_title();
+    cht_levels.set_position(50.0, 50.0);
+    cht_levels.set_size(600.0, 400.0);
+    if let Some(index) = cht_levels.hovered_index() { } // Which value the mouse is over
+*/
+
+use macroquad::prelude::*;
+use crate::modules::format;
+use crate::modules::theme::{self, ThemeColor};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// How the values are drawn
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum ChartMode {
+    Line, // Points joined by lines
+    Bar,  // A bar for each value
+}
+
+// Space kept for the labels beside and under the axes
+const LEFT_MARGIN: f32 = 56.0;
+const BOTTOM_MARGIN: f32 = 28.0;
+const PADDING: f32 = 10.0;
+
+pub struct Chart {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    mode: ChartMode,
+    values: Vec<f64>,
+    labels: Vec<String>,
+    title: Option<String>,
+    y_min: Option<f64>,
+    y_max: Option<f64>,
+    data_color: ThemeColor,
+    axis_color: ThemeColor,
+    background: Option<ThemeColor>,
+    font_size: u16,
+    hovered: Option<usize>,
+}
+
+impl Chart {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            mode: ChartMode::Line,
+            values: Vec::new(),
+            labels: Vec::new(),
+            title: None,
+            y_min: None,
+            y_max: None,
+            data_color: ThemeColor::Primary,
+            axis_color: ThemeColor::Border,
+            background: Some(ThemeColor::Surface),
+            font_size: 16,
+            hovered: None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn set_values(&mut self, values: &[f64]) -> &mut Self {
+        self.values = values.to_vec();
+        self.hovered = None;
+        self
+    }
+
+    // One label per value, shown along the bottom (as many as fit) and when hovered
+    #[allow(unused)]
+    pub fn set_labels<T: ToString>(&mut self, labels: &[T]) -> &mut Self {
+        self.labels = labels.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_mode(&mut self, mode: ChartMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_title(&mut self, title: &str) -> &mut Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_title(&mut self) -> &mut Self {
+        self.title = None;
+        self
+    }
+
+    // Fix the bottom and/or top of the value axis (None fits it to the data)
+    #[allow(unused)]
+    pub fn with_y_range(&mut self, min: Option<f64>, max: Option<f64>) -> &mut Self {
+        self.y_min = min;
+        self.y_max = max;
+        self
+    }
+
+    // Data color, axis and text color, and background (None for no background)
+    #[allow(unused)]
+    pub fn with_colors(&mut self, data: impl Into<ThemeColor>, axis: impl Into<ThemeColor>, background: Option<Color>) -> &mut Self {
+        self.data_color = data.into();
+        self.axis_color = axis.into();
+        self.background = background.map(ThemeColor::Custom);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    // Index of the value under the mouse (as of the last draw)
+    #[allow(unused)]
+    pub fn hovered_index(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        if let Some(background) = self.background {
+            draw_rectangle(self.x, self.y, self.width, self.height, background.color());
+        }
+        let plot = self.plot_rect();
+        if let Some(title) = &self.title {
+            let size = measure_text(title, None, self.font_size, 1.0);
+            draw_text(title, self.x + (self.width - size.width) / 2.0, self.y + PADDING + size.offset_y, self.font_size as f32, self.axis_color.color());
+        }
+
+        // Value axis with evenly spaced, rounded steps
+        let (low, high, step) = self.axis_range();
+        let to_y = |value: f64| plot.y + plot.h - ((value - low) / (high - low)) as f32 * plot.h;
+        let mut tick = low;
+        while tick <= high + step * 0.001 {
+            let y = to_y(tick);
+            draw_line(plot.x, y, plot.x + plot.w, y, 1.0, self.axis_color.color().with_alpha(0.15));
+            let text = value_text(tick);
+            let size = measure_text(&text, None, self.font_size, 1.0);
+            draw_text(&text, plot.x - size.width - 6.0, y + size.height / 2.0, self.font_size as f32, self.axis_color.color());
+            tick += step;
+        }
+        draw_line(plot.x, plot.y, plot.x, plot.y + plot.h, 1.0, self.axis_color.color());
+        draw_line(plot.x, plot.y + plot.h, plot.x + plot.w, plot.y + plot.h, 1.0, self.axis_color.color());
+
+        if self.values.is_empty() {
+            return;
+        }
+        let slot = plot.w / self.values.len() as f32;
+        let center_x = |index: usize| plot.x + slot * (index as f32 + 0.5);
+        let baseline = to_y(low.max(0.0).min(high));
+
+        // Hovered value is the one whose slot the mouse is in
+        let (mouse_x, mouse_y) = mouse_position();
+        self.hovered = plot
+            .contains(Vec2::new(mouse_x, mouse_y))
+            .then(|| (((mouse_x - plot.x) / slot) as usize).min(self.values.len() - 1));
+
+        match self.mode {
+            ChartMode::Bar => {
+                let bar_width = (slot * 0.7).max(1.0);
+                for (index, &value) in self.values.iter().enumerate() {
+                    let top = to_y(value.clamp(low, high));
+                    let color = if self.hovered == Some(index) { lighten(self.data_color.color()) } else { self.data_color.color() };
+                    draw_rectangle(center_x(index) - bar_width / 2.0, top.min(baseline), bar_width, (baseline - top).abs(), color);
+                }
+            }
+            ChartMode::Line => {
+                let points: Vec<Vec2> =
+                    self.values.iter().enumerate().map(|(index, &value)| Vec2::new(center_x(index), to_y(value.clamp(low, high)))).collect();
+                for pair in points.windows(2) {
+                    draw_line(pair[0].x, pair[0].y, pair[1].x, pair[1].y, 2.0, self.data_color.color());
+                }
+                // Dots only when there is room for them
+                if slot >= 8.0 {
+                    for point in &points {
+                        draw_circle(point.x, point.y, 3.0, self.data_color.color());
+                    }
+                }
+                if let Some(point) = self.hovered.map(|index| points[index]) {
+                    draw_line(point.x, plot.y, point.x, plot.y + plot.h, 1.0, self.axis_color.color().with_alpha(0.4));
+                    draw_circle(point.x, point.y, 5.0, lighten(self.data_color.color()));
+                }
+            }
+        }
+
+        self.draw_labels(plot, slot);
+        if let Some(index) = self.hovered {
+            self.draw_readout(index, mouse_x, mouse_y);
+        }
+    }
+
+    // Labels under the axis, skipping some when they would overlap
+    fn draw_labels(&self, plot: Rect, slot: f32) {
+        if self.labels.is_empty() {
+            return;
+        }
+        let widest = self.labels.iter().map(|label| measure_text(label, None, self.font_size, 1.0).width).fold(0.0, f32::max);
+        let every = ((widest + 8.0) / slot).ceil().max(1.0) as usize;
+        for (index, label) in self.labels.iter().enumerate().take(self.values.len()).step_by(every) {
+            let size = measure_text(label, None, self.font_size, 1.0);
+            let x = plot.x + slot * (index as f32 + 0.5) - size.width / 2.0;
+            draw_text(label, x, plot.y + plot.h + 6.0 + size.offset_y, self.font_size as f32, self.axis_color.color());
+        }
+    }
+
+    // Box by the mouse with the hovered value (and its label)
+    fn draw_readout(&self, index: usize, mouse_x: f32, mouse_y: f32) {
+        let value = value_text(self.values[index]);
+        let text = match self.labels.get(index) {
+            Some(label) => format!("{}: {}", label, value),
+            None => value,
+        };
+        let size = measure_text(&text, None, self.font_size, 1.0);
+        let width = size.width + 12.0;
+        let height = self.font_size as f32 + 8.0;
+        // Kept inside the chart
+        let x = (mouse_x + 12.0).min(self.x + self.width - width).max(self.x);
+        let y = (mouse_y - height - 6.0).max(self.y);
+        // Colored like a tooltip
+        let palette = theme::palette();
+        draw_rectangle(x, y, width, height, palette.text.with_alpha(0.85));
+        draw_text(&text, x + 6.0, y + (height - size.height) / 2.0 + size.offset_y, self.font_size as f32, palette.surface);
+    }
+
+    // Area inside the axes
+    fn plot_rect(&self) -> Rect {
+        let top = if self.title.is_some() { PADDING * 2.0 + self.font_size as f32 } else { PADDING };
+        Rect::new(
+            self.x + LEFT_MARGIN,
+            self.y + top,
+            (self.width - LEFT_MARGIN - PADDING).max(1.0),
+            (self.height - top - BOTTOM_MARGIN).max(1.0),
+        )
+    }
+
+    // Bottom and top of the value axis and the step between its lines
+    fn axis_range(&self) -> (f64, f64, f64) {
+        let data_min = self.values.iter().copied().fold(f64::INFINITY, f64::min);
+        let data_max = self.values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        // Bars start from zero, so zero is always on the axis for them
+        let (mut min, mut max) = if data_min.is_finite() { (data_min, data_max) } else { (0.0, 1.0) };
+        if self.mode == ChartMode::Bar {
+            min = min.min(0.0);
+            max = max.max(0.0);
+        }
+        let min = self.y_min.unwrap_or(min);
+        let max = self.y_max.unwrap_or(max);
+        let (min, max) = if max > min { (min, max) } else { (min - 1.0, min + 1.0) };
+
+        let step = nice_step((max - min) / 4.0);
+        let low = if self.y_min.is_some() { min } else { (min / step).floor() * step };
+        let high = if self.y_max.is_some() { max } else { (max / step).ceil() * step };
+        (low, high.max(low + step), step)
+    }
+}
+
+// A round step size (1, 2 or 5 times a power of ten) close to the rough one
+fn nice_step(rough: f64) -> f64 {
+    let magnitude = 10f64.powf(rough.log10().floor());
+    let fraction = rough / magnitude;
+    let nice = if fraction <= 1.0 {
+        1.0
+    } else if fraction <= 2.0 {
+        2.0
+    } else if fraction <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+// Whole numbers with commas, anything else with up to two decimals
+fn value_text(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format::thousands(value as i64)
+    } else {
+        let text = format!("{:.2}", value);
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+fn lighten(color: Color) -> Color {
+    Color::new((color.r + 0.2).min(1.0), (color.g + 0.2).min(1.0), (color.b + 0.2).min(1.0), color.a)
+}
@@ -0,0 +1,259 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Adds a cross-platform clipboard for copying and pasting text
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod clipboard;
+
+Add the following to Cargo.toml:
+    [target.'cfg(not(target_arch = "wasm32"))'.dependencies]
+    arboard = "3.4"
+
+    And add "Navigator", "Clipboard", "Permissions", "PermissionStatus" and "PermissionState"
+    to the web-sys features list for wasm.
+
+Add with the other use statements:
+    use crate::modules::clipboard;
+
+HOW IT WORKS:
+    On native builds the system clipboard is used through arboard.
+    On the web the browser's async Clipboard API is used. Reading from it returns
+    a Promise, so pasting is a two step process: ask for the text, then collect it
+    on a later frame once the browser hands it over. Native builds answer straight away,
+    so the same code works on both targets.
+
+    Browsers only let a page read the clipboard once the player allows it (and only over
+    https or localhost). The first read asks the browser what it will allow, and a read
+    that is refused marks the clipboard Denied. Text copied inside the game is always
+    remembered too, so copying and pasting between the game's own widgets keeps working
+    when the system clipboard can't be read.
+
+Usage examples:
+    // Copy text
+    clipboard::set_text("Hello World");
+
+    // Paste text - call every frame until it gives Some (straight away on native)
+    if let Some(text) = clipboard::get_text() {
+        txt_input.set_text(text);
+    }
+
+    // Or ask once...
+    clipboard::request_text();
+    // ...then check every frame until it arrives
+    if let Some(text) = clipboard::take_text() {
+        txt_input.set_text(text);
+    }
+
+    // Whether reading the system clipboard is allowed
+    match clipboard::permission() {
+        ClipboardPermission::Granted => {}
+        ClipboardPermission::Prompt => {}       // The browser will ask the player on the next paste
+        ClipboardPermission::Denied => {}       // Only text copied inside the game can be pasted
+        ClipboardPermission::Unavailable => {}  // No system clipboard (e.g. a page served over http)
+    }
+
+Note: TextInput and TextArea already handle ctrl/cmd+C, X and V using this module, and
+DataGrid copies rows with it.
+*/
+
+use std::cell::{Cell, RefCell};
+
+// What the system clipboard allows
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum ClipboardPermission {
+    Granted,
+    Prompt,      // Not decided yet, the browser asks on the first read
+    Denied,
+    Unavailable, // There is no system clipboard to use
+}
+
+thread_local! {
+    // Text that has been read from the clipboard but not collected yet
+    static PENDING_TEXT: RefCell<Option<String>> = const { RefCell::new(None) };
+
+    // The last text copied inside the game, pasted when the system clipboard can't be read
+    static LOCAL_TEXT: RefCell<Option<String>> = const { RefCell::new(None) };
+
+    // True while a read is waiting on the browser
+    static READING: Cell<bool> = const { Cell::new(false) };
+
+    static PERMISSION: Cell<ClipboardPermission> = const { Cell::new(ClipboardPermission::Prompt) };
+
+    // Whether the browser has been asked for the read permission yet
+    #[cfg(target_arch = "wasm32")]
+    static PERMISSION_ASKED: Cell<bool> = const { Cell::new(false) };
+
+    // Keep the native clipboard alive, on Linux the copied text is only served while it exists
+    #[cfg(not(target_arch = "wasm32"))]
+    static NATIVE_CLIPBOARD: RefCell<Option<arboard::Clipboard>> = const { RefCell::new(None) };
+}
+
+// Run a closure with the native clipboard, creating it the first time it is needed
+#[cfg(not(target_arch = "wasm32"))]
+fn with_native_clipboard<R>(f: impl FnOnce(&mut arboard::Clipboard) -> Option<R>) -> Option<R> {
+    NATIVE_CLIPBOARD.with(|clipboard| {
+        let mut clipboard = clipboard.borrow_mut();
+        if clipboard.is_none() {
+            *clipboard = arboard::Clipboard::new().ok();
+            PERMISSION.with(|permission| {
+                permission.set(if clipboard.is_some() { ClipboardPermission::Granted } else { ClipboardPermission::Unavailable })
+            });
+        }
+        clipboard.as_mut().and_then(f)
+    })
+}
+
+// The browser's clipboard, None when the page can't use it (not https, or an old browser)
+#[cfg(target_arch = "wasm32")]
+fn web_clipboard() -> Option<web_sys::Clipboard> {
+    let navigator = web_sys::window()?.navigator();
+    // navigator.clipboard is missing outside secure contexts
+    let clipboard = js_sys::Reflect::get(&navigator, &"clipboard".into()).ok()?;
+    if clipboard.is_undefined() {
+        PERMISSION.with(|permission| permission.set(ClipboardPermission::Unavailable));
+        return None;
+    }
+    ask_permission(&navigator);
+    Some(navigator.clipboard())
+}
+
+// Ask the browser (once) whether reading is allowed, and keep up with the player changing it
+#[cfg(target_arch = "wasm32")]
+fn ask_permission(navigator: &web_sys::Navigator) {
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    if PERMISSION_ASKED.with(|asked| asked.replace(true)) {
+        return;
+    }
+    let Ok(permissions) = navigator.permissions() else {
+        return;
+    };
+    let descriptor = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&descriptor, &"name".into(), &"clipboard-read".into());
+    // Some browsers (Firefox) don't know this permission, then it stays Prompt
+    let Ok(promise) = permissions.query(&descriptor) else {
+        return;
+    };
+    wasm_bindgen_futures::spawn_local(async move {
+        let Ok(status) = JsFuture::from(promise).await else {
+            return;
+        };
+        let status: web_sys::PermissionStatus = status.unchecked_into();
+        set_web_permission(status.state());
+        let watched = status.clone();
+        let on_change = Closure::<dyn FnMut()>::new(move || set_web_permission(watched.state()));
+        status.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+        // Lives as long as the page
+        on_change.forget();
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+fn set_web_permission(state: web_sys::PermissionState) {
+    let permission = match state {
+        web_sys::PermissionState::Granted => ClipboardPermission::Granted,
+        web_sys::PermissionState::Denied => ClipboardPermission::Denied,
+        _ => ClipboardPermission::Prompt,
+    };
+    PERMISSION.with(|current| current.set(permission));
+}
+
+/// Hand over the text copied inside the game, for when the system clipboard can't be read
+fn use_local_text() {
+    let text = LOCAL_TEXT.with(|local| local.borrow().clone());
+    PENDING_TEXT.with(|pending| *pending.borrow_mut() = text);
+}
+
+/// Copy text to the system clipboard
+#[allow(unused)]
+pub fn set_text(text: &str) {
+    LOCAL_TEXT.with(|local| *local.borrow_mut() = Some(text.to_string()));
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        with_native_clipboard(|clipboard| clipboard.set_text(text.to_string()).ok());
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(clipboard) = web_clipboard() {
+            // The returned Promise is ignored, pasting inside the game still works from LOCAL_TEXT if it fails
+            let _ = clipboard.write_text(text);
+        }
+    }
+}
+
+/// Ask for the current clipboard text, collect it later with take_text()
+#[allow(unused)]
+pub fn request_text() {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        match with_native_clipboard(|clipboard| clipboard.get_text().ok()) {
+            Some(text) => PENDING_TEXT.with(|pending| *pending.borrow_mut() = Some(text)),
+            None => use_local_text(),
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen_futures::JsFuture;
+
+        let Some(clipboard) = web_clipboard() else {
+            use_local_text();
+            return;
+        };
+        // Asking again after a no only gets another no, paste the game's own text instead
+        if PERMISSION.with(Cell::get) == ClipboardPermission::Denied {
+            use_local_text();
+            return;
+        }
+        // One read at a time
+        if READING.with(|reading| reading.replace(true)) {
+            return;
+        }
+        let promise = clipboard.read_text();
+        wasm_bindgen_futures::spawn_local(async move {
+            match JsFuture::from(promise).await {
+                Ok(value) => {
+                    PERMISSION.with(|permission| permission.set(ClipboardPermission::Granted));
+                    PENDING_TEXT.with(|pending| *pending.borrow_mut() = value.as_string());
+                }
+                Err(_) => {
+                    // The player (or the browser) said no
+                    PERMISSION.with(|permission| permission.set(ClipboardPermission::Denied));
+                    use_local_text();
+                }
+            }
+            READING.with(|reading| reading.set(false));
+        });
+    }
+}
+
+/// Paste in one call: Some(text) once the clipboard has answered, call every frame until then
+/// (native builds answer straight away, the web answers a few frames later)
+#[allow(unused)]
+pub fn get_text() -> Option<String> {
+    if let Some(text) = take_text() {
+        return Some(text);
+    }
+    if !READING.with(Cell::get) {
+        request_text();
+    }
+    take_text()
+}
+
+/// Whether reading the system clipboard is allowed
+#[allow(unused)]
+pub fn permission() -> ClipboardPermission {
+    PERMISSION.with(Cell::get)
+}
+
+/// Collect text requested with request_text(), returns None until it is available
+#[allow(unused)]
+pub fn take_text() -> Option<String> {
+    PENDING_TEXT.with(|pending| pending.borrow_mut().take())
+}
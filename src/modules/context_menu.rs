@@ -0,0 +1,194 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A right-click menu that pops up at the mouse with actions like "Edit" and "Delete"
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod context_menu;
+
+This also needs these modules:
+    pub mod focus;
+    pub mod menu_bar;
+    pub mod scale;
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::context_menu::{self, ContextMenu};
+
+Then at the end of the loop, after the widgets (and before tooltip::draw / dialog::draw):
+    context_menu::draw();
+
+Build the menu when it is asked for, so the closures can hold what was right-clicked:
+    if let Some(index) = grd_users.right_clicked_row() {
+        let record = records[index].clone();
+        let mut menu = ContextMenu::new();
+        menu.add_item("Edit", "", move || println!("Edit {}", record.username))
+            .add_separator()
+            .add_item("Delete", "Delete", move || println!("Delete row {}", index));
+        menu.open_at_mouse();
+    }
+
+    // For anything else, check for a right-click on an area
+    if context_menu::requested(Rect::new(100.0, 100.0, 200.0, 50.0)) {
+        // Build and open a menu like above
+    }
+
+HOW IT WORKS:
+    Only one context menu is open at a time, opening another replaces it. It is moved to
+    stay inside the screen. Click an item to run it, or click anywhere else (or press
+    Escape) to close it without doing anything. Up / Down and Enter work too. While it is
+    open the widgets under it don't get the mouse or the keyboard (focus comes back when
+    it closes). The shortcut text is only shown beside
+    the item, as a reminder of the key that does the same thing elsewhere.
+    The closures have to own what they use, share values with Rc<Cell<...>> or
+    Rc<RefCell<...>> to change things in main.
+
+OTHER FUNCTIONS:
+    menu.set_item_enabled("Delete", false);      // Greyed out, before opening it
+    menu.open(200.0, 150.0);                      // Open somewhere other than the mouse
+    if context_menu::is_open() { }
+    context_menu::close();
+    context_menu::set_style(20, WHITE, SKYBLUE, BLACK); // Font size, menu, highlight, text (default from the theme)
+    if let Some(item) = context_menu::draw() {
+        // item is the label of the chosen item, on the frame it was chosen
+    }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::focus;
+use crate::modules::menu_bar::{Menu, MenuStyle};
+use crate::modules::scale;
+use crate::modules::theme::ThemeColor;
+use std::cell::{Cell, RefCell};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+thread_local! {
+    // The open menu, where it was opened, and whether it opened this frame
+    static OPEN: RefCell<Option<(Menu, Vec2, bool)>> = const { RefCell::new(None) };
+    static STYLE: Cell<MenuStyle> = Cell::new(MenuStyle::default());
+}
+
+pub struct ContextMenu {
+    menu: Menu,
+}
+
+impl ContextMenu {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self { menu: Menu::new("") }
+    }
+
+    // Add an item, with shortcut text to show beside it ("" for none) and what it does
+    #[allow(unused)]
+    pub fn add_item<F: FnMut() + 'static>(&mut self, label: &str, shortcut: &str, callback: F) -> &mut Self {
+        self.menu.add_item(label, shortcut, callback);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn add_separator(&mut self) -> &mut Self {
+        self.menu.add_separator();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_item_enabled(&mut self, label: &str, enabled: bool) -> &mut Self {
+        self.menu.set_item_enabled(label, enabled);
+        self
+    }
+
+    // Show the menu with its top-left corner at a point (replaces any open context menu)
+    #[allow(unused)]
+    pub fn open(mut self, x: f32, y: f32) {
+        self.menu.reset();
+        // Typing goes nowhere while the menu is open (Up / Down / Enter are the menu's)
+        if !is_open() {
+            focus::push_modal(&[]);
+        }
+        OPEN.with(|open| *open.borrow_mut() = Some((self.menu, Vec2::new(x, y), true)));
+        // Block the mouse right away so widgets drawn later this frame ignore it too
+        scale::set_mouse_blocked(true);
+    }
+
+    // Show the menu at the mouse
+    #[allow(unused)]
+    pub fn open_at_mouse(self) {
+        let (mouse_x, mouse_y) = mouse_position();
+        self.open(mouse_x, mouse_y);
+    }
+}
+
+impl Default for ContextMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether the right mouse button was pressed on an area this frame (time to open a menu)
+#[allow(unused)]
+pub fn requested(area: Rect) -> bool {
+    let (mouse_x, mouse_y) = mouse_position();
+    is_mouse_button_pressed(MouseButton::Right) && area.contains(Vec2::new(mouse_x, mouse_y))
+}
+
+/// Draw the open context menu and handle it, call near the end of the loop.
+/// Returns the label of the item chosen (after its closure has run)
+#[allow(unused)]
+pub fn draw() -> Option<String> {
+    let (mut menu, origin, fresh) = OPEN.with(|open| open.borrow_mut().take())?;
+
+    // The menu blocks the mouse for everything else, but not for itself
+    scale::set_mouse_blocked(false);
+    let style = STYLE.with(Cell::get);
+    let chosen = menu.update(origin, &style);
+
+    let (mouse_x, mouse_y) = mouse_position();
+    let pressed = is_mouse_button_pressed(MouseButton::Left) || is_mouse_button_pressed(MouseButton::Right);
+    // Not on the frame it opened, the right-click that opened it is still "pressed"
+    let dismissed = !fresh && (is_key_pressed(KeyCode::Escape) || (pressed && !menu.rect(origin, &style).contains(Vec2::new(mouse_x, mouse_y))));
+
+    if dismissed && is_key_pressed(KeyCode::Escape) {
+        focus::use_escape();
+    }
+    if let Some(index) = chosen {
+        // Closed before the item runs, so the item can open another menu or a dialog
+        focus::pop_modal();
+        return menu.activate(index);
+    }
+    if dismissed {
+        focus::pop_modal();
+    } else {
+        OPEN.with(|open| *open.borrow_mut() = Some((menu, origin, false)));
+        scale::set_mouse_blocked(true);
+    }
+    None
+}
+
+/// Whether a context menu is showing
+#[allow(unused)]
+pub fn is_open() -> bool {
+    OPEN.with(|open| open.borrow().is_some())
+}
+
+/// Close the context menu without choosing anything
+#[allow(unused)]
+pub fn close() {
+    if OPEN.with(|open| open.borrow_mut().take()).is_some() {
+        scale::set_mouse_blocked(false);
+        focus::pop_modal();
+    }
+}
+
+/// Set the font size and the menu, highlight and text colors for context menus
+#[allow(unused)]
+pub fn set_style(font_size: u16, menu: impl Into<ThemeColor>, highlight: impl Into<ThemeColor>, text: impl Into<ThemeColor>) {
+    STYLE.with(|style| {
+        style.set(MenuStyle {
+            font_size,
+            menu_color: menu.into(),
+            highlight_color: highlight.into(),
+            text_color: text.into(),
+        })
+    });
+}
@@ -0,0 +1,828 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A table for browsing database records, with sorting, scrolling and resizable columns
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod data_grid;
+
+This also needs the scale module (for clipping), the clipboard module (for copying rows),
+and for editing the text_input, focus and database modules:
+    pub mod scale;
+    pub mod clipboard;
+    pub mod text_input;
+    pub mod focus;
+    pub mod database;
+
+Add with the other use statements:
+    use crate::modules::data_grid::DataGrid;
+
+Then to use this you would put the following above the loop:
+    let records: Vec<DatabaseTable> = client.fetch_table("draysTable").await.unwrap();
+    let mut grd_users = DataGrid::new(50.0, 50.0, 600.0, 400.0);
+    grd_users.set_records(&records);
+Where the parameters are x, y, width, height
+
+HOW IT WORKS:
+    Each record is turned into JSON (it just has to derive Serialize, like DatabaseTable
+    does) and each field becomes a column, in the same order as the struct. Numbers sort
+    as numbers and everything else sorts as text, ignoring case.
+    Mouse controls:
+        Click a column heading          sort by it (click again to reverse)
+        Drag the edge of a heading      resize the column
+        Mouse wheel                     scroll up and down (Shift + wheel scrolls sideways)
+        Click a row                     select it
+        Ctrl/Cmd + C                    copy the selected row (while the mouse is over the grid)
+
+CUSTOMIZATION:
+    grd_users.with_font_size(20);                        // Default 18
+    grd_users.with_row_height(30.0);                     // Default 28
+    grd_users.with_colors(LIGHTGRAY, WHITE, Color::new(0.93, 0.93, 0.93, 1.0), BLACK);
+    // Heading background, row background, every other row background, text (default from the theme)
+    grd_users.hide_column("password");
+    grd_users.set_column_width("username", 200.0);
+    grd_users.sort_by("level", false);                   // Column and ascending (false = biggest first)
+
+EDITING:
+    grd_users.with_editing(true);        // Double-click a cell to change it
+    grd_users.with_key_column("id");     // Column that identifies a row in the database (default "id")
+A text box opens over the cell; Enter or clicking somewhere else keeps the change and
+Escape cancels it. Numbers have to stay numbers (true/false for true/false columns) and
+the key column can't be edited. Changed cells are shaded until they are saved:
+    match grd_users.save_changes(&client, "draysTable").await {
+        Ok(saved) => println!("Saved {} rows", saved),
+        Err(e) => println!("Save failed: {}", e),
+    }
+Only the changed fields of each changed row are sent (one PATCH per row, filtered by the
+key column). Rows that failed to save stay changed so you can try again. A row with an
+empty key is skipped and named in the error once the other rows are saved.
+    let unsaved = grd_users.has_changes();
+    let rows = grd_users.changed_rows();        // Record indices with unsaved changes
+    grd_users.discard_changes();                // Put every changed cell back
+    let edited: Vec<DatabaseTable> = grd_users.to_records(); // The records with the changes in them
+
+READING IT:
+    if let Some(index) = grd_users.selected_row() {
+        let record = &records[index]; // Index into the records you passed in
+    }
+    let count = grd_users.row_count();
+    if let Some(index) = grd_users.right_clicked_row() {
+        // A row was right-clicked this frame (it is selected too), e.g. open a context_menu
+    }
+
+COPYING:
+    grd_users.copy_selected_row();   // e.g. from a context_menu's "Copy" item
+    grd_users.copy_row(index);
+Copies the shown (not hidden) cells of the row, separated by tabs, so it pastes into a
+spreadsheet as one row. Text boxes paste it too.
+
+Then in the loop you would use:
+    grd_users.draw();
+*/
+
+use macroquad::prelude::*;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use crate::modules::clipboard;
+use crate::modules::database::{percent_encode, DatabaseClient};
+use crate::modules::focus;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::text_input::TextInput;
+use crate::modules::theme::{self, ThemeColor};
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Distance from a column edge that grabs it for resizing
+const RESIZE_GRAB: f32 = 5.0;
+const MIN_COLUMN_WIDTH: f32 = 40.0;
+const CELL_PADDING: f32 = 8.0;
+const SCROLLBAR_WIDTH: f32 = 6.0;
+// Two clicks on the same cell closer together than this open the editor
+const DOUBLE_CLICK_TIME: f64 = 0.4;
+
+struct Column {
+    name: String,
+    width: f32,
+    hidden: bool,
+}
+
+// Text box open over a cell being edited
+struct CellEditor {
+    record: usize,
+    column: usize,
+    input: TextInput,
+    fresh: bool, // True on the frame it opened (it hasn't had a chance to take focus yet)
+}
+
+pub struct DataGrid {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    font_size: u16,
+    row_height: f32,
+    header_color: ThemeColor,
+    row_color: ThemeColor,
+    alt_row_color: ThemeColor,
+    text_color: ThemeColor,
+    columns: Vec<Column>,
+    rows: Vec<Vec<String>>,          // Cell text for each record, in column order
+    values: Vec<Vec<Value>>,         // The JSON values behind the cell text
+    order: Vec<usize>,               // Record indices in the order they are shown
+    sort: Option<(usize, bool)>,     // Column being sorted by and whether it is ascending
+    selected: Option<usize>,         // Selected record index
+    scroll_x: f32,
+    scroll_y: f32,
+    resizing: Option<(usize, f32, f32)>, // Column, mouse x and width when the drag started
+    right_clicked: Option<usize>,        // Record right-clicked this frame
+    editable: bool,
+    key_column: String,
+    editor: Option<CellEditor>,
+    last_click: Option<(usize, usize, f64)>,   // Record, column and time of the last click on a cell
+    changed: BTreeMap<usize, BTreeSet<usize>>, // Record -> columns changed since the last save
+    originals: HashMap<(usize, usize), Value>, // Value a changed cell had before it was edited
+}
+
+impl DataGrid {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            font_size: 18,
+            row_height: 28.0,
+            header_color: ThemeColor::SurfaceAlt,
+            row_color: ThemeColor::Surface,
+            alt_row_color: ThemeColor::Background,
+            text_color: ThemeColor::Text,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            values: Vec::new(),
+            order: Vec::new(),
+            sort: None,
+            selected: None,
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            resizing: None,
+            right_clicked: None,
+            editable: false,
+            key_column: String::from("id"),
+            editor: None,
+            last_click: None,
+            changed: BTreeMap::new(),
+            originals: HashMap::new(),
+        }
+    }
+
+    // Show a new list of records (columns, widths and sorting are kept where they still fit)
+    #[allow(unused)]
+    pub fn set_records<T: Serialize>(&mut self, records: &[T]) -> &mut Self {
+        let rows: Vec<Vec<(String, Value)>> = records.iter().map(record_fields).collect();
+
+        // Every field that shows up in any record, in the order it first appears
+        for (name, _) in rows.iter().flatten() {
+            if !self.columns.iter().any(|column| &column.name == name) {
+                let width = self.text_width(name) + CELL_PADDING * 2.0 + 16.0;
+                self.columns.push(Column { name: name.clone(), width: width.max(MIN_COLUMN_WIDTH), hidden: false });
+            }
+        }
+
+        self.values = rows
+            .iter()
+            .map(|fields| {
+                self.columns
+                    .iter()
+                    .map(|column| fields.iter().find(|(name, _)| *name == column.name).map_or(Value::Null, |(_, value)| value.clone()))
+                    .collect()
+            })
+            .collect();
+        self.rows = self.values.iter().map(|row| row.iter().map(cell_text).collect()).collect();
+        // New records, so any edits in progress or unsaved no longer line up
+        self.editor = None;
+        self.last_click = None;
+        self.changed.clear();
+        self.originals.clear();
+
+        // Widen columns to fit their longest value (up to a limit so one long value doesn't take over)
+        for (index, column) in self.columns.iter_mut().enumerate() {
+            let widest = self.rows.iter().map(|row| measure_text(&row[index], None, self.font_size, 1.0).width).fold(0.0, f32::max);
+            column.width = column.width.max((widest + CELL_PADDING * 2.0).min(300.0));
+        }
+
+        self.order = (0..self.rows.len()).collect();
+        self.selected = self.selected.filter(|&index| index < self.rows.len());
+        self.apply_sort();
+        self.clamp_scroll();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_row_height(&mut self, row_height: f32) -> &mut Self {
+        self.row_height = row_height.max(1.0);
+        self.clamp_scroll();
+        self
+    }
+
+    // Method to set the heading, row, alternate row and text colors
+    #[allow(unused)]
+    pub fn with_colors(&mut self, header: impl Into<ThemeColor>, row: impl Into<ThemeColor>, alt_row: impl Into<ThemeColor>, text: impl Into<ThemeColor>) -> &mut Self {
+        self.header_color = header.into();
+        self.row_color = row.into();
+        self.alt_row_color = alt_row.into();
+        self.text_color = text.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self.clamp_scroll();
+        self
+    }
+
+    // Hide a column (e.g. passwords)
+    #[allow(unused)]
+    pub fn hide_column(&mut self, name: &str) -> &mut Self {
+        self.set_column_hidden(name, true)
+    }
+
+    #[allow(unused)]
+    pub fn show_column(&mut self, name: &str) -> &mut Self {
+        self.set_column_hidden(name, false)
+    }
+
+    #[allow(unused)]
+    pub fn set_column_width(&mut self, name: &str, width: f32) -> &mut Self {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.name == name) {
+            column.width = width.max(MIN_COLUMN_WIDTH);
+        }
+        self.clamp_scroll();
+        self
+    }
+
+    // Sort by a column from code (ignored if there is no column with that name)
+    #[allow(unused)]
+    pub fn sort_by(&mut self, name: &str, ascending: bool) -> &mut Self {
+        if let Some(index) = self.column_index(name) {
+            self.sort = Some((index, ascending));
+            self.apply_sort();
+        }
+        self
+    }
+
+    // Go back to the order the records were given in
+    #[allow(unused)]
+    pub fn clear_sort(&mut self) -> &mut Self {
+        self.sort = None;
+        self.order = (0..self.rows.len()).collect();
+        self
+    }
+
+    // Turn double-click editing on or off
+    #[allow(unused)]
+    pub fn with_editing(&mut self, editable: bool) -> &mut Self {
+        self.editable = editable;
+        if !editable {
+            self.editor = None;
+        }
+        self
+    }
+
+    // Column that identifies each row when saving (default "id")
+    #[allow(unused)]
+    pub fn with_key_column(&mut self, name: &str) -> &mut Self {
+        self.key_column = name.to_string();
+        self
+    }
+
+    // Whether any cell has been changed since the last save
+    #[allow(unused)]
+    pub fn has_changes(&self) -> bool {
+        !self.changed.is_empty()
+    }
+
+    // Record indices of rows with unsaved changes
+    #[allow(unused)]
+    pub fn changed_rows(&self) -> Vec<usize> {
+        self.changed.keys().copied().collect()
+    }
+
+    // Put every changed cell back to what it was
+    #[allow(unused)]
+    pub fn discard_changes(&mut self) -> &mut Self {
+        for ((record, column), value) in self.originals.drain() {
+            self.rows[record][column] = cell_text(&value);
+            self.values[record][column] = value;
+        }
+        self.changed.clear();
+        self.editor = None;
+        self
+    }
+
+    // The records as they are now (with any edits), turned back into your struct
+    #[allow(unused)]
+    pub fn to_records<T: DeserializeOwned>(&self) -> Vec<T> {
+        self.values
+            .iter()
+            .filter_map(|row| {
+                let object: Map<String, Value> = self.columns.iter().map(|column| column.name.clone()).zip(row.iter().cloned()).collect();
+                serde_json::from_value(Value::Object(object)).ok()
+            })
+            .collect()
+    }
+
+    // Send each changed row to the database (only its changed fields), returns how many rows were saved.
+    // Rows without a key are skipped and kept as changed, and reported in the error after the rest are saved
+    #[allow(unused)]
+    pub async fn save_changes(&mut self, client: &DatabaseClient, table: &str) -> Result<usize, Box<dyn std::error::Error>> {
+        let key = self.column_index(&self.key_column).ok_or_else(|| format!("No \"{}\" column to save rows by", self.key_column))?;
+        let mut saved = 0;
+        let mut keyless = Vec::new();
+        for (record, columns) in self.changed.clone() {
+            // Without a key the filter would match nothing (or every row), so leave it for the user to fix
+            let key_value = cell_text(&self.values[record][key]);
+            if key_value.is_empty() {
+                keyless.push(record);
+                continue;
+            }
+            let filter = format!("{}=eq.{}", self.key_column, percent_encode(&key_value));
+            let fields: Map<String, Value> = columns
+                .iter()
+                .map(|&column| (self.columns[column].name.clone(), self.values[record][column].clone()))
+                .collect();
+            client.update_records::<Value>(table, &filter, &Value::Object(fields)).await?;
+
+            // Saved, so this row's current values are the new starting point
+            self.changed.remove(&record);
+            self.originals.retain(|(changed_record, _), _| *changed_record != record);
+            saved += 1;
+        }
+        if !keyless.is_empty() {
+            keyless.sort_unstable();
+            let rows: Vec<String> = keyless.iter().map(|record| record.to_string()).collect();
+            return Err(format!("Saved {} rows, but rows {} have no \"{}\" to save them by", saved, rows.join(", "), self.key_column).into());
+        }
+        Ok(saved)
+    }
+
+    // Index (into the records given to set_records) of the selected row
+    #[allow(unused)]
+    pub fn selected_row(&self) -> Option<usize> {
+        self.selected
+    }
+
+    #[allow(unused)]
+    pub fn set_selected_row(&mut self, index: Option<usize>) -> &mut Self {
+        self.selected = index.filter(|&index| index < self.rows.len());
+        self
+    }
+
+    // Index of the record right-clicked this frame, if any
+    #[allow(unused)]
+    pub fn right_clicked_row(&self) -> Option<usize> {
+        self.right_clicked
+    }
+
+    // Copy a record's shown cells to the clipboard, separated by tabs (false if there is no such record)
+    #[allow(unused)]
+    pub fn copy_row(&self, index: usize) -> bool {
+        let Some(row) = self.rows.get(index) else {
+            return false;
+        };
+        let cells: Vec<&str> = self.columns.iter().enumerate().filter(|(_, column)| !column.hidden).map(|(column, _)| row[column].as_str()).collect();
+        clipboard::set_text(&cells.join("\t"));
+        true
+    }
+
+    #[allow(unused)]
+    pub fn copy_selected_row(&self) -> bool {
+        match self.selected {
+            Some(index) => self.copy_row(index),
+            None => false,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    // Names of the columns in the order they are shown (hidden ones included)
+    #[allow(unused)]
+    pub fn column_names(&self) -> Vec<&str> {
+        self.columns.iter().map(|column| column.name.as_str()).collect()
+    }
+
+    // Handles the mouse and draws the grid
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        self.update_input();
+
+        draw_rectangle(self.x, self.y, self.width, self.height, self.row_color.color());
+        push_clip_rect(Rect::new(self.x, self.y, self.width, self.height));
+
+        // Rows (only the ones that can be seen)
+        let (mouse_x, mouse_y) = mouse_position();
+        let palette = theme::palette();
+        let body = self.body_rect();
+        let first = (self.scroll_y / self.row_height).floor().max(0.0) as usize;
+        let visible = (body.h / self.row_height).ceil() as usize + 1;
+        push_clip_rect(body);
+        for (position, &record) in self.order.iter().enumerate().skip(first).take(visible) {
+            let row_y = body.y + position as f32 * self.row_height - self.scroll_y;
+            let row_rect = Rect::new(self.x, row_y, self.width, self.row_height);
+            let background = if position % 2 == 1 { self.alt_row_color.color() } else { self.row_color.color() };
+            draw_rectangle(row_rect.x, row_rect.y, row_rect.w, row_rect.h, background);
+            // The selected and hovered rows are tinted with the theme's accent
+            let tint = if self.selected == Some(record) {
+                0.4
+            } else if row_rect.contains(Vec2::new(mouse_x, mouse_y)) && body.contains(Vec2::new(mouse_x, mouse_y)) {
+                0.15
+            } else {
+                0.0
+            };
+            if tint > 0.0 {
+                draw_rectangle(row_rect.x, row_rect.y, row_rect.w, row_rect.h, palette.primary.with_alpha(tint));
+            }
+            let changed = self.changed.get(&record);
+            for (column_index, column_x, column) in self.visible_columns() {
+                // Unsaved changes are shaded
+                if changed.is_some_and(|columns| columns.contains(&column_index)) {
+                    draw_rectangle(column_x, row_y, column.width, self.row_height, Color::new(1.0, 0.85, 0.3, 0.45));
+                }
+                self.draw_cell(&self.rows[record][column_index], column_x, row_y, column.width);
+            }
+        }
+        pop_clip_rect();
+
+        self.update_editor();
+
+        // Headings on top
+        draw_rectangle(self.x, self.y, self.width, self.row_height, self.header_color.color());
+        for (column_index, column_x, column) in self.visible_columns() {
+            self.draw_cell(&column.name, column_x, self.y, column.width);
+            if let Some((_, ascending)) = self.sort.filter(|(sorted, _)| *sorted == column_index) {
+                draw_sort_arrow(column_x + column.width - CELL_PADDING - 5.0, self.y + self.row_height / 2.0, ascending, self.text_color.color());
+            }
+            let edge = column_x + column.width;
+            draw_line(edge, self.y, edge, self.y + self.height, 1.0, palette.border.with_alpha(0.3));
+        }
+        draw_line(self.x, self.y + self.row_height, self.x + self.width, self.y + self.row_height, 1.0, palette.border);
+
+        // Scrollbar when there are more rows than fit
+        let content_height = self.rows.len() as f32 * self.row_height;
+        if content_height > body.h {
+            let thumb_height = (body.h / content_height * body.h).max(20.0);
+            let thumb_y = body.y + self.scroll_y / self.max_scroll_y() * (body.h - thumb_height);
+            draw_rectangle(self.x + self.width - SCROLLBAR_WIDTH - 2.0, thumb_y, SCROLLBAR_WIDTH, thumb_height, palette.text.with_alpha(0.3));
+        }
+        pop_clip_rect();
+
+        draw_rectangle_lines(self.x, self.y, self.width, self.height, 1.0, palette.border);
+    }
+
+    fn update_input(&mut self) {
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+        let hovered = Rect::new(self.x, self.y, self.width, self.height).contains(mouse);
+
+        // Right-clicking a row selects it and reports it (for a context menu)
+        self.right_clicked = None;
+        if hovered && mouse_y >= self.y + self.row_height && is_mouse_button_pressed(MouseButton::Right) {
+            let position = ((mouse_y - self.body_rect().y + self.scroll_y) / self.row_height).floor() as usize;
+            if let Some(&record) = self.order.get(position) {
+                self.finish_edit(true);
+                self.selected = Some(record);
+                self.right_clicked = Some(record);
+            }
+        }
+
+        // Ctrl/Cmd+C copies the selected row, unless a text box (like the cell editor) wants the keys
+        let command_down = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl)
+            || is_key_down(KeyCode::LeftSuper) || is_key_down(KeyCode::RightSuper);
+        if hovered && command_down && is_key_pressed(KeyCode::C) && self.editor.is_none() && focus::focused().is_none() {
+            self.copy_selected_row();
+        }
+
+        // Clicking outside the cell being edited keeps the change (the text box handles clicks inside it)
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let editor_cell = self.editor.as_ref().and_then(|editor| self.cell_rect(editor.record, editor.column));
+            if editor_cell.is_some_and(|cell| !cell.contains(mouse)) {
+                self.finish_edit(true);
+            } else if editor_cell.is_some() {
+                return;
+            }
+        }
+
+        // Column resizing
+        if let Some((column, start_x, start_width)) = self.resizing {
+            if is_mouse_button_down(MouseButton::Left) {
+                self.columns[column].width = (start_width + mouse_x - start_x).max(MIN_COLUMN_WIDTH);
+                self.clamp_scroll();
+            } else {
+                self.resizing = None;
+            }
+            return;
+        }
+
+        if hovered {
+            let (wheel_x, wheel_y) = mouse_wheel();
+            let shift = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+            if wheel_y != 0.0 && shift {
+                self.scroll_x -= wheel_y.signum() * 60.0;
+            } else if wheel_y != 0.0 {
+                self.scroll_y -= wheel_y.signum() * self.row_height * 3.0;
+            }
+            if wheel_x != 0.0 {
+                self.scroll_x -= wheel_x.signum() * 60.0;
+            }
+            // The text box doesn't move with the rows, so finish editing before scrolling
+            if (wheel_x != 0.0 || wheel_y != 0.0) && self.editor.is_some() {
+                self.finish_edit(true);
+            }
+            self.clamp_scroll();
+        }
+
+        if !hovered || !is_mouse_button_pressed(MouseButton::Left) {
+            return;
+        }
+
+        let in_header = mouse_y < self.y + self.row_height;
+        if in_header {
+            // An edge grabs for resizing, anywhere else on the heading sorts
+            let columns: Vec<(usize, f32, f32)> = self.visible_columns().map(|(index, x, column)| (index, x, column.width)).collect();
+            if let Some(&(index, _, width)) = columns.iter().find(|(_, x, width)| (mouse_x - (x + width)).abs() <= RESIZE_GRAB) {
+                self.resizing = Some((index, mouse_x, width));
+            } else if let Some(&(index, _, _)) = columns.iter().find(|(_, x, width)| mouse_x >= *x && mouse_x < x + width) {
+                let ascending = match self.sort {
+                    Some((sorted, ascending)) if sorted == index => !ascending,
+                    _ => true,
+                };
+                self.sort = Some((index, ascending));
+                self.apply_sort();
+            }
+        } else {
+            let position = ((mouse_y - self.body_rect().y + self.scroll_y) / self.row_height).floor() as usize;
+            if let Some(&record) = self.order.get(position) {
+                self.selected = Some(record);
+                let column = self.visible_columns().find(|(_, x, column)| mouse_x >= *x && mouse_x < x + column.width).map(|(index, _, _)| index);
+                if let Some(column) = column {
+                    let now = get_time();
+                    let double_click = self
+                        .last_click
+                        .is_some_and(|(last_record, last_column, time)| last_record == record && last_column == column && now - time <= DOUBLE_CLICK_TIME);
+                    if double_click {
+                        self.last_click = None;
+                        self.start_edit(record, column);
+                    } else {
+                        self.last_click = Some((record, column, now));
+                    }
+                }
+            }
+        }
+    }
+
+    // Open a text box over a cell
+    fn start_edit(&mut self, record: usize, column: usize) {
+        if !self.editable || self.columns[column].name == self.key_column {
+            return;
+        }
+        let Some(cell) = self.cell_rect(record, column) else { return };
+        let mut input = TextInput::new(cell.x, cell.y, cell.w, cell.h, self.font_size as f32);
+        input.set_text(self.rows[record][column].clone());
+        input.set_active(true);
+        self.editor = Some(CellEditor { record, column, input, fresh: true });
+    }
+
+    // Draw the text box and handle Enter / Escape / losing focus
+    fn update_editor(&mut self) {
+        let Some(editor) = &mut self.editor else { return };
+        editor.input.draw();
+        let fresh = std::mem::replace(&mut editor.fresh, false);
+        if editor.input.submitted() {
+            self.finish_edit(true);
+        } else if is_key_pressed(KeyCode::Escape) {
+            focus::use_escape();
+            self.finish_edit(false);
+        } else if !fresh && !editor.input.is_active() {
+            // Focus moved somewhere else (Tab or a click on another widget)
+            self.finish_edit(true);
+        }
+    }
+
+    // Close the text box, keeping what was typed if `keep` and it is a valid value for the cell
+    fn finish_edit(&mut self, keep: bool) {
+        let Some(mut editor) = self.editor.take() else { return };
+        if !keep {
+            return;
+        }
+        let (record, column) = (editor.record, editor.column);
+        let current = &self.values[record][column];
+        let value = match parse_cell(&editor.input.get_text(), current) {
+            Ok(value) => value,
+            Err(message) => {
+                // Not valid for this column, keep the text box open to fix it
+                editor.input.set_error(Some(message));
+                editor.input.set_active(true);
+                self.editor = Some(editor);
+                return;
+            }
+        };
+        if &value == current {
+            return;
+        }
+
+        let original = self.originals.entry((record, column)).or_insert_with(|| current.clone()).clone();
+        if value == original {
+            // Changed back to what it was, so it isn't a change any more
+            self.originals.remove(&(record, column));
+            if let Some(columns) = self.changed.get_mut(&record) {
+                columns.remove(&column);
+                if columns.is_empty() {
+                    self.changed.remove(&record);
+                }
+            }
+        } else {
+            self.changed.entry(record).or_default().insert(column);
+        }
+        self.rows[record][column] = cell_text(&value);
+        self.values[record][column] = value;
+    }
+
+    // Where a cell is on screen (None for hidden columns or records that aren't shown)
+    fn cell_rect(&self, record: usize, column: usize) -> Option<Rect> {
+        let position = self.order.iter().position(|&shown| shown == record)?;
+        let (_, x, info) = self.visible_columns().find(|(index, _, _)| *index == column)?;
+        let y = self.body_rect().y + position as f32 * self.row_height - self.scroll_y;
+        Some(Rect::new(x, y, info.width, self.row_height))
+    }
+
+    // Columns that are shown, with their index and left edge on screen
+    fn visible_columns(&self) -> impl Iterator<Item = (usize, f32, &Column)> {
+        let mut x = self.x - self.scroll_x;
+        self.columns.iter().enumerate().filter(|(_, column)| !column.hidden).map(move |(index, column)| {
+            let column_x = x;
+            x += column.width;
+            (index, column_x, column)
+        })
+    }
+
+    // Draw text in a cell, cut off at its right edge
+    fn draw_cell(&self, text: &str, x: f32, y: f32, width: f32) {
+        push_clip_rect(Rect::new(x, y, width - 1.0, self.row_height));
+        let size = measure_text(text, None, self.font_size, 1.0);
+        let baseline = y + (self.row_height - size.height) / 2.0 + size.offset_y;
+        draw_text(text, x + CELL_PADDING, baseline, self.font_size as f32, self.text_color.color());
+        pop_clip_rect();
+    }
+
+    fn apply_sort(&mut self) {
+        let Some((column, ascending)) = self.sort else { return };
+        if column >= self.columns.len() {
+            self.sort = None;
+            return;
+        }
+        let rows = &self.rows;
+        self.order.sort_by(|&a, &b| {
+            let ordering = compare_cells(&rows[a][column], &rows[b][column]);
+            if ascending { ordering } else { ordering.reverse() }
+        });
+    }
+
+    fn set_column_hidden(&mut self, name: &str, hidden: bool) -> &mut Self {
+        if let Some(column) = self.columns.iter_mut().find(|column| column.name == name) {
+            column.hidden = hidden;
+        }
+        self.clamp_scroll();
+        self
+    }
+
+    fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column.name == name)
+    }
+
+    // Area under the headings where rows are drawn
+    fn body_rect(&self) -> Rect {
+        Rect::new(self.x, self.y + self.row_height, self.width, (self.height - self.row_height).max(0.0))
+    }
+
+    fn max_scroll_y(&self) -> f32 {
+        (self.rows.len() as f32 * self.row_height - self.body_rect().h).max(0.0)
+    }
+
+    fn max_scroll_x(&self) -> f32 {
+        let total: f32 = self.columns.iter().filter(|column| !column.hidden).map(|column| column.width).sum();
+        (total - self.width).max(0.0)
+    }
+
+    fn clamp_scroll(&mut self) {
+        self.scroll_y = self.scroll_y.clamp(0.0, self.max_scroll_y());
+        self.scroll_x = self.scroll_x.clamp(0.0, self.max_scroll_x());
+    }
+
+    fn text_width(&self, text: &str) -> f32 {
+        measure_text(text, None, self.font_size, 1.0).width
+    }
+}
+
+// Small triangle pointing up (ascending) or down
+fn draw_sort_arrow(x: f32, y: f32, ascending: bool, color: Color) {
+    let half = 4.0;
+    let (tip, base) = if ascending { (y - half, y + half) } else { (y + half, y - half) };
+    draw_triangle(Vec2::new(x, tip), Vec2::new(x - half, base), Vec2::new(x + half, base), color);
+}
+
+// Numbers compare as numbers, anything else as text ignoring case
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+// Turn typed text into a value of the same kind as the cell had
+fn parse_cell(text: &str, current: &Value) -> Result<Value, &'static str> {
+    let trimmed = text.trim();
+    match current {
+        Value::Number(number) => {
+            if number.is_f64() {
+                trimmed.parse::<f64>().ok().and_then(serde_json::Number::from_f64).map(Value::Number).ok_or("Must be a number")
+            } else {
+                trimmed.parse::<i64>().map(Value::from).map_err(|_| "Must be a whole number")
+            }
+        }
+        Value::Bool(_) => match trimmed.to_lowercase().as_str() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err("Must be true or false"),
+        },
+        Value::Null if trimmed.is_empty() => Ok(Value::Null),
+        Value::Array(_) | Value::Object(_) => serde_json::from_str(text).map_err(|_| "Must be valid JSON"),
+        _ => Ok(Value::String(text.to_string())),
+    }
+}
+
+// How a JSON value is shown in a cell
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+// A record's fields in the order the struct has them (serde_json's own map sorts them by name)
+fn record_fields<T: Serialize>(record: &T) -> Vec<(String, Value)> {
+    serde_json::to_string(record)
+        .ok()
+        .and_then(|json| serde_json::from_str::<OrderedFields>(&json).ok())
+        .map_or(Vec::new(), |fields| fields.0)
+}
+
+struct OrderedFields(Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for OrderedFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = OrderedFields;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a JSON object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<OrderedFields, A::Error> {
+                let mut fields = Vec::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    fields.push((key, map.next_value::<Value>()?));
+                }
+                Ok(OrderedFields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
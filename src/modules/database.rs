@@ -22,6 +22,10 @@ Add the following to Cargo.toml under [dependencies]:
     [target.'cfg(not(target_arch = "wasm32"))'.dependencies]
     ureq = { version = "2.9", features = ["json"] }
 
+Native builds also need the tasks module (requests are sent on their own thread so the game
+keeps drawing while they wait):
+    pub mod tasks;
+
 Add with the other use statements:
     use crate::modules::database::{DatabaseClient, DatabaseTable, create_database_client};
 
@@ -235,6 +239,10 @@ ADVANCED FILTERING EXAMPLES:
     // Ordering and limiting
     "select=*&order=created_at.desc&limit=10"
 
+    // Values typed by the user can hold &, = or spaces, so encode them first
+    use crate::modules::database::percent_encode;
+    let filter = format!("name=eq.{}", percent_encode(&typed_name));
+
 ERROR HANDLING:
     // Option 1: Use unwrap() for simple cases (will panic on error)
     let records = client.fetch_table::<DatabaseTable>("messages").await.unwrap();
@@ -260,6 +268,26 @@ ERROR HANDLING:
         Ok(())
     }
 
+STORAGE EXAMPLES (files in Supabase Storage buckets, e.g. profile pictures):
+    // Upload (replaces a file already at that path)
+    let bytes = std::fs::read("avatar.png").unwrap();
+    client.upload_file("avatars", "dray.png", &bytes, "image/png").await.unwrap();
+
+    // Download
+    let bytes = client.download_file("avatars", "dray.png").await.unwrap();
+
+    // Link to a file in a public bucket
+    let url = client.storage_public_url("avatars", "dray.png");
+
+    Paths can have folders ("players/dray.png") and any characters in the names, they are
+    encoded for the URL for you.
+
+    Buckets need policies too (Storage -> Policies in the dashboard), e.g. for anon uploads:
+    CREATE POLICY allow_anon_upload ON storage.objects
+      FOR INSERT TO anon WITH CHECK (bucket_id = 'avatars');
+    CREATE POLICY allow_anon_overwrite ON storage.objects
+      FOR UPDATE TO anon USING (bucket_id = 'avatars');
+
 CUSTOM STRUCT EXAMPLE:
     #[derive(Debug, Deserialize, Serialize, Clone)]
     pub struct User {
@@ -276,6 +304,10 @@ CUSTOM STRUCT EXAMPLE:
 */
 
 use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::io::Read;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::modules::tasks;
 
 // ============================================================================
 // DATABASE SETUP SECTION - CUSTOMIZE FOR YOUR DATABASE
@@ -327,6 +359,23 @@ pub fn create_supabase_client(project_url: &str, anon_key: &str) -> DatabaseClie
     DatabaseClient::new(project_url.to_string(), anon_key.to_string())
 }
 
+/// Make text safe to put in a URL query or filter (letters, digits and -_.~ stay, everything else becomes %XX)
+#[allow(unused)]
+pub fn percent_encode(text: &str) -> String {
+    let mut encoded = String::new();
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// A bucket and a file path joined for a storage URL, each folder and file name encoded (the / between them kept)
+fn storage_path(bucket: &str, path: &str) -> String {
+    std::iter::once(bucket).chain(path.split('/')).map(percent_encode).collect::<Vec<_>>().join("/")
+}
 
 pub struct DatabaseClient {
     base_url: String,
@@ -414,14 +463,8 @@ impl DatabaseClient {
     #[allow(unused)]
     #[cfg(not(target_arch = "wasm32"))]
     async fn fetch_json_native(&self, url: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let response = ureq::get(url)
-            .set("apikey", &self.api_key)
-            .set("Authorization", &format!("Bearer {}", self.api_key))
-            .set("Content-Type", "application/json")
-            .call()?;
-
-        let json_string = response.into_string()?;
-        Ok(json_string)
+        let bytes = self.send_native("GET", url, &[("Content-Type", "application/json")], None).await?;
+        Ok(String::from_utf8(bytes)?)
     }
 
     /// Insert a record into a table
@@ -570,26 +613,8 @@ impl DatabaseClient {
     #[allow(unused)]
     #[cfg(not(target_arch = "wasm32"))]
     async fn post_json_native(&self, url: &str, json_body: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let response = ureq::post(url)
-            .set("apikey", &self.api_key)
-            .set("Authorization", &format!("Bearer {}", self.api_key))
-            .set("Content-Type", "application/json")
-            .set("Prefer", "return=representation")
-            .send_string(json_body);
-
-        match response {
-            Ok(resp) => {
-                let json_string = resp.into_string()?;
-                Ok(json_string)
-            }
-            Err(ureq::Error::Status(code, response)) => {
-                let error_body = response.into_string().unwrap_or_else(|_| "Could not read error body".to_string());
-                Err(format!("HTTP {} error: {}", code, error_body).into())
-            }
-            Err(e) => {
-                Err(e.into())
-            }
-        }
+        let bytes = self.send_native("POST", url, &[("Content-Type", "application/json"), ("Prefer", "return=representation")], Some(json_body.as_bytes())).await?;
+        Ok(String::from_utf8(bytes)?)
     }
 
     /// Web version using WASM bindings for PATCH requests
@@ -629,26 +654,8 @@ impl DatabaseClient {
     #[allow(unused)]
     #[cfg(not(target_arch = "wasm32"))]
     async fn patch_json_native(&self, url: &str, json_body: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let response = ureq::patch(url)
-            .set("apikey", &self.api_key)
-            .set("Authorization", &format!("Bearer {}", self.api_key))
-            .set("Content-Type", "application/json")
-            .set("Prefer", "return=representation")
-            .send_string(json_body);
-
-        match response {
-            Ok(resp) => {
-                let json_string = resp.into_string()?;
-                Ok(json_string)
-            }
-            Err(ureq::Error::Status(code, response)) => {
-                let error_body = response.into_string().unwrap_or_else(|_| "Could not read error body".to_string());
-                Err(format!("HTTP {} error: {}", code, error_body).into())
-            }
-            Err(e) => {
-                Err(e.into())
-            }
-        }
+        let bytes = self.send_native("PATCH", url, &[("Content-Type", "application/json"), ("Prefer", "return=representation")], Some(json_body.as_bytes())).await?;
+        Ok(String::from_utf8(bytes)?)
     }
 
     /// Generic method to delete JSON data
@@ -699,25 +706,131 @@ impl DatabaseClient {
     #[allow(unused)]
     #[cfg(not(target_arch = "wasm32"))]
     async fn delete_json_native(&self, url: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let response = ureq::delete(url)
-            .set("apikey", &self.api_key)
-            .set("Authorization", &format!("Bearer {}", self.api_key))
-            .set("Content-Type", "application/json")
-            .set("Prefer", "return=representation")
-            .call();
-
-        match response {
-            Ok(resp) => {
-                let json_string = resp.into_string()?;
-                Ok(json_string)
-            }
-            Err(ureq::Error::Status(code, response)) => {
-                let error_body = response.into_string().unwrap_or_else(|_| "Could not read error body".to_string());
-                Err(format!("HTTP {} error: {}", code, error_body).into())
+        let bytes = self.send_native("DELETE", url, &[("Content-Type", "application/json"), ("Prefer", "return=representation")], None).await?;
+        Ok(String::from_utf8(bytes)?)
+    }
+
+    /// Address of a file in a public storage bucket
+    #[allow(unused)]
+    pub fn storage_public_url(&self, bucket: &str, path: &str) -> String {
+        format!("{}/storage/v1/object/public/{}", self.base_url, storage_path(bucket, path))
+    }
+
+    /// Download a file from a storage bucket
+    #[allow(unused)]
+    pub async fn download_file(&self, bucket: &str, path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let url = format!("{}/storage/v1/object/{}", self.base_url, storage_path(bucket, path));
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.send_bytes_web("GET", &url, None, "").await
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.send_bytes_native("GET", &url, None, "").await
+        }
+    }
+
+    /// Upload a file to a storage bucket, replacing any file already at that path
+    #[allow(unused)]
+    pub async fn upload_file(&self, bucket: &str, path: &str, bytes: &[u8], content_type: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let url = format!("{}/storage/v1/object/{}", self.base_url, storage_path(bucket, path));
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.send_bytes_web("POST", &url, Some(bytes), content_type).await.map(|_| ())
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.send_bytes_native("POST", &url, Some(bytes), content_type).await.map(|_| ())
+        }
+    }
+
+    /// Web version of a storage request (the body and response are raw bytes)
+    #[allow(unused)]
+    #[cfg(target_arch = "wasm32")]
+    async fn send_bytes_web(&self, method: &str, url: &str, body: Option<&[u8]>, content_type: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        use wasm_bindgen_futures::JsFuture;
+        use wasm_bindgen::JsCast;
+        use web_sys::{Request, RequestInit, RequestMode, Headers, Response, window};
+
+        let opts = RequestInit::new();
+        opts.set_method(method);
+        opts.set_mode(RequestMode::Cors);
+
+        let headers = Headers::new().map_err(|_| "Failed to create headers")?;
+        headers.append("apikey", &self.api_key).map_err(|_| "Failed to add apikey header")?;
+        headers.append("Authorization", &format!("Bearer {}", self.api_key)).map_err(|_| "Failed to add Authorization header")?;
+        if let Some(bytes) = body {
+            headers.append("Content-Type", content_type).map_err(|_| "Failed to add Content-Type header")?;
+            headers.append("x-upsert", "true").map_err(|_| "Failed to add x-upsert header")?;
+            opts.set_body(&js_sys::Uint8Array::from(bytes));
+        }
+        opts.set_headers(&headers);
+
+        let req = Request::new_with_str_and_init(url, &opts).map_err(|_| "Failed to create request")?;
+        let win = window().ok_or("Failed to get window")?;
+        let resp_value = JsFuture::from(win.fetch_with_request(&req)).await.map_err(|_| "Storage request failed")?;
+        let resp: Response = resp_value.dyn_into().map_err(|_| "Failed to cast response")?;
+
+        if !resp.ok() {
+            return Err(format!("HTTP error: {}", resp.status()).into());
+        }
+
+        let buffer = JsFuture::from(resp.array_buffer().map_err(|_| "Failed to get body")?).await.map_err(|_| "Failed to read response body")?;
+        Ok(js_sys::Uint8Array::new(&buffer).to_vec())
+    }
+
+    /// Native version of a storage request using ureq
+    #[allow(unused)]
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn send_bytes_native(&self, method: &str, url: &str, body: Option<&[u8]>, content_type: &str) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let headers: &[(&str, &str)] = match body {
+            Some(_) => &[("Content-Type", content_type), ("x-upsert", "true")],
+            None => &[],
+        };
+        self.send_native(method, url, headers, body).await
+    }
+
+    /// Native requests are sent with ureq on their own thread (tasks::run_on_thread), so the
+    /// game keeps drawing while it waits for the server. Gives back the response body
+    #[allow(unused)]
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn send_native(&self, method: &str, url: &str, headers: &[(&str, &str)], body: Option<&[u8]>) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let (method, url) = (method.to_string(), url.to_string());
+        let mut all_headers = vec![
+            ("apikey".to_string(), self.api_key.clone()),
+            ("Authorization".to_string(), format!("Bearer {}", self.api_key)),
+        ];
+        all_headers.extend(headers.iter().map(|(name, value)| (name.to_string(), value.to_string())));
+        let body = body.map(<[u8]>::to_vec);
+
+        let result = tasks::run_on_thread(move || -> Result<Vec<u8>, String> {
+            let mut request = ureq::request(&method, &url);
+            for (name, value) in &all_headers {
+                request = request.set(name, value);
             }
-            Err(e) => {
-                Err(e.into())
+            let response = match &body {
+                Some(bytes) => request.send_bytes(bytes),
+                None => request.call(),
+            };
+
+            match response {
+                Ok(resp) => {
+                    let mut bytes = Vec::new();
+                    resp.into_reader().read_to_end(&mut bytes).map_err(|e| e.to_string())?;
+                    Ok(bytes)
+                }
+                Err(ureq::Error::Status(code, response)) => {
+                    let error_body = response.into_string().unwrap_or_else(|_| "Could not read error body".to_string());
+                    Err(format!("HTTP {} error: {}", code, error_body))
+                }
+                Err(e) => {
+                    Err(e.to_string())
+                }
             }
-        }
+        })
+        .await;
+        result.map_err(Into::into)
     }
 }
\ No newline at end of file
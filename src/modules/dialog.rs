@@ -0,0 +1,282 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Pop-up dialogs (alert, yes/no confirm and text prompt) that sit on top of everything else
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod dialog;
+
+This also needs these modules:
+    pub mod scale;
+    pub mod focus;
+    pub mod label;
+    pub mod text_button;
+    pub mod text_input;
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::dialog::{self, Dialog};
+
+Then at the end of the loop, after everything else is drawn (and after tooltip::draw):
+    dialog::draw();
+    next_frame().await;
+
+HOW IT WORKS:
+    Calling Dialog::alert, Dialog::confirm or Dialog::prompt opens a dialog straight away.
+    While it is open the rest of the screen is dimmed and other widgets can't be clicked,
+    hovered or tabbed to - only the dialog's own buttons work. The dialog is centered in
+    the virtual resolution. When a button is pressed the dialog closes and the matching
+    closure runs. Escape is the same as No/Cancel. Opening a dialog while another one is
+    open stacks it on top, and the one underneath comes back when it closes.
+    Blocking the mouse works through scale::mouse_position_world, which every widget uses.
+
+    The closures have to own what they use, share values with Rc<Cell<...>> or
+    Rc<RefCell<...>> to change things in main.
+
+Usage examples:
+    // Just a message with an OK button
+    Dialog::alert("Your level was saved");
+
+    // Yes / No
+    let delete_requested = Rc::new(Cell::new(false));
+    let flag = delete_requested.clone();
+    Dialog::confirm("Delete account?", move || flag.set(true), || println!("Kept the account"));
+
+    // Ask for some text (the second value is what the box starts with)
+    let new_name = Rc::new(RefCell::new(None));
+    let name = new_name.clone();
+    Dialog::prompt("New username:", "", move |text| *name.borrow_mut() = Some(text), || {});
+
+    // Check if a dialog is showing (e.g. to pause the game)
+    if dialog::is_open() { }
+
+    // Change the colors (panel, text, and the dimming over the rest of the screen)
+    // The panel and text follow the theme until this is called
+    dialog::set_colors(WHITE, BLACK, Color::new(0.0, 0.0, 0.0, 0.5));
+*/
+
+use macroquad::prelude::*;
+use crate::modules::focus;
+use crate::modules::label::{Label, TextAlign};
+use crate::modules::scale;
+use crate::modules::text_button::TextButton;
+use crate::modules::text_input::TextInput;
+use crate::modules::theme::{self, ThemeColor};
+use std::cell::{Cell, RefCell};
+
+// Space between the edge of the panel and what is inside it
+const PADDING: f32 = 24.0;
+const PANEL_WIDTH: f32 = 440.0;
+const FONT_SIZE: u16 = 24;
+const INPUT_HEIGHT: f32 = 40.0;
+const BUTTON_WIDTH: f32 = 120.0;
+const BUTTON_HEIGHT: f32 = 44.0;
+const GAP: f32 = 16.0;
+
+// Closure run with the prompt's text (empty for alert and confirm) when OK/Yes is pressed
+type OkCallback = Box<dyn FnOnce(String)>;
+// Closure run when Cancel/No is pressed
+type CancelCallback = Box<dyn FnOnce()>;
+
+thread_local! {
+    // Open dialogs, the last one is on top
+    static DIALOGS: RefCell<Vec<Dialog>> = const { RefCell::new(Vec::new()) };
+    static PANEL_COLOR: Cell<ThemeColor> = const { Cell::new(ThemeColor::Surface) };
+    static TEXT_COLOR: Cell<ThemeColor> = const { Cell::new(ThemeColor::Text) };
+    static DIM_COLOR: Cell<Color> = const { Cell::new(Color::new(0.0, 0.0, 0.0, 0.5)) };
+}
+
+pub struct Dialog {
+    message: Label,
+    input: Option<TextInput>,
+    ok_button: TextButton,
+    cancel_button: Option<TextButton>,
+    on_ok: Option<OkCallback>,
+    on_cancel: Option<CancelCallback>,
+    fresh: bool,                   // True until the first draw, so the click that opened it is ignored
+}
+
+impl Dialog {
+    // Open a dialog showing a message with an OK button
+    #[allow(unused)]
+    pub fn alert<T: Into<String>>(message: T) {
+        Self::open(message.into(), None, "OK", None, Box::new(|_| {}), Box::new(|| {}));
+    }
+
+    // Open a dialog with Yes and No buttons
+    #[allow(unused)]
+    pub fn confirm<T, Y, N>(message: T, on_yes: Y, on_no: N)
+    where
+        T: Into<String>,
+        Y: FnOnce() + 'static,
+        N: FnOnce() + 'static,
+    {
+        Self::open(message.into(), None, "Yes", Some("No"), Box::new(move |_| on_yes()), Box::new(on_no));
+    }
+
+    // Open a dialog with a text box, OK and Cancel (on_ok gets the text typed in)
+    #[allow(unused)]
+    pub fn prompt<T, O, C>(message: T, initial_text: &str, on_ok: O, on_cancel: C)
+    where
+        T: Into<String>,
+        O: FnOnce(String) + 'static,
+        C: FnOnce() + 'static,
+    {
+        Self::open(message.into(), Some(initial_text), "OK", Some("Cancel"), Box::new(on_ok), Box::new(on_cancel));
+    }
+
+    fn open(message: String, input_text: Option<&str>, ok_text: &str, cancel_text: Option<&str>, on_ok: OkCallback, on_cancel: CancelCallback) {
+        let text_color = TEXT_COLOR.with(Cell::get);
+        let mut label = Label::new(message, 0.0, 0.0, FONT_SIZE);
+        label
+            .with_colors(text_color, None)
+            .set_max_width(PANEL_WIDTH - PADDING * 2.0)
+            .with_alignment(TextAlign::Center);
+
+        let input = input_text.map(|text| {
+            let mut input = TextInput::new(0.0, 0.0, PANEL_WIDTH - PADDING * 2.0, INPUT_HEIGHT, 22.0);
+            input.set_text(text);
+            input
+        });
+        let ok_button = TextButton::themed(0.0, 0.0, BUTTON_WIDTH, BUTTON_HEIGHT, ok_text, 22);
+        let cancel_button = cancel_text.map(|text| TextButton::themed(0.0, 0.0, BUTTON_WIDTH, BUTTON_HEIGHT, text, 22));
+
+        let dialog = Dialog {
+            message: label,
+            input,
+            ok_button,
+            cancel_button,
+            on_ok: Some(on_ok),
+            on_cancel: Some(on_cancel),
+            fresh: true,
+        };
+
+        // Only the dialog's widgets can be tabbed to while it is open
+        let mut tab_order: Vec<usize> = dialog.input.iter().map(TextInput::focus_id).collect();
+        tab_order.push(dialog.ok_button.focus_id());
+        tab_order.extend(dialog.cancel_button.iter().map(TextButton::focus_id));
+        focus::push_modal(&tab_order);
+
+        // Block the mouse right away so widgets drawn later this frame ignore it too
+        scale::set_mouse_blocked(true);
+        DIALOGS.with(|dialogs| dialogs.borrow_mut().push(dialog));
+    }
+
+    // Lay out, draw and handle input, returns Some(answer) once a button is pressed
+    // (Some(text) for OK/Yes, None for Cancel/No)
+    fn update(&mut self, view: Rect) -> Option<Option<String>> {
+        let message_size = self.message.measure();
+        let input_height = if self.input.is_some() { INPUT_HEIGHT + GAP } else { 0.0 };
+        let panel_height = PADDING + message_size.h + GAP + input_height + BUTTON_HEIGHT + PADDING;
+        let panel = Rect::new(
+            view.x + (view.w - PANEL_WIDTH) / 2.0,
+            view.y + (view.h - panel_height) / 2.0,
+            PANEL_WIDTH,
+            panel_height,
+        );
+
+        draw_rectangle(panel.x, panel.y, panel.w, panel.h, PANEL_COLOR.with(Cell::get).color());
+        draw_rectangle_lines(panel.x, panel.y, panel.w, panel.h, 2.0, theme::palette().border);
+
+        // Label positions are the text's left edge and baseline, the box sits 5 pixels to the left
+        let message_x = panel.x + (panel.w - message_size.w) / 2.0 + (self.message.get_x() - message_size.x);
+        self.message.set_position(message_x, panel.y + PADDING + FONT_SIZE as f32);
+        self.message.draw();
+
+        let mut y = panel.y + PADDING + message_size.h + GAP;
+        let mut submitted = false;
+        if let Some(input) = &mut self.input {
+            input.set_position(panel.x + PADDING, y);
+            input.draw();
+            submitted = input.submitted();
+            y += INPUT_HEIGHT + GAP;
+        }
+
+        // Buttons centered along the bottom
+        let button_count = if self.cancel_button.is_some() { 2.0 } else { 1.0 };
+        let buttons_width = BUTTON_WIDTH * button_count + GAP * (button_count - 1.0);
+        let button_x = panel.x + (panel.w - buttons_width) / 2.0;
+        self.ok_button.update_position(button_x, y, None, None);
+        let ok_clicked = self.ok_button.click();
+        let mut cancel_clicked = false;
+        if let Some(cancel_button) = &mut self.cancel_button {
+            cancel_button.update_position(button_x + BUTTON_WIDTH + GAP, y, None, None);
+            cancel_clicked = cancel_button.click();
+        }
+
+        if self.fresh {
+            return None;
+        }
+        if ok_clicked || submitted {
+            return Some(Some(self.input.as_ref().map(TextInput::get_text).unwrap_or_default()));
+        }
+        if cancel_clicked || is_key_pressed(KeyCode::Escape) {
+            focus::use_escape();
+            // An alert only has OK, so Escape counts as OK there
+            return Some(if self.cancel_button.is_some() { None } else { Some(String::new()) });
+        }
+        None
+    }
+
+    // The widget that gets focus when the dialog first shows
+    fn first_focus(&self) -> usize {
+        self.input.as_ref().map_or(self.ok_button.focus_id(), TextInput::focus_id)
+    }
+}
+
+/// Draw the dialog on top (if one is open) and handle its buttons, call at the end of the loop
+pub fn draw() {
+    let Some(mut dialog) = DIALOGS.with(|dialogs| dialogs.borrow_mut().pop()) else {
+        return;
+    };
+
+    let view = scale::visible_area();
+    draw_rectangle(view.x, view.y, view.w, view.h, DIM_COLOR.with(Cell::get));
+
+    // Only the dialog sees the mouse (and not on its first frame, so the click that opened it can't press a button)
+    scale::set_mouse_blocked(dialog.fresh);
+    let answer = dialog.update(view);
+    if dialog.fresh {
+        dialog.fresh = false;
+        focus::set_focus(dialog.first_focus());
+    }
+
+    match answer {
+        None => DIALOGS.with(|dialogs| dialogs.borrow_mut().push(dialog)),
+        Some(answer) => {
+            let on_ok = dialog.on_ok.take();
+            let on_cancel = dialog.on_cancel.take();
+            // Dropping the dialog removes its widgets from the focus module before the old focus comes back
+            drop(dialog);
+            focus::pop_modal();
+            match answer {
+                Some(text) => {
+                    if let Some(on_ok) = on_ok {
+                        on_ok(text);
+                    }
+                }
+                None => {
+                    if let Some(on_cancel) = on_cancel {
+                        on_cancel();
+                    }
+                }
+            }
+        }
+    }
+    // Keep other widgets blocked while any dialog is still open (a closure may have opened another)
+    scale::set_mouse_blocked(is_open());
+}
+
+/// Whether a dialog is showing
+#[allow(unused)]
+pub fn is_open() -> bool {
+    DIALOGS.with(|dialogs| !dialogs.borrow().is_empty())
+}
+
+/// Set the panel, text and background dimming colors for dialogs opened after this
+#[allow(unused)]
+pub fn set_colors(panel: impl Into<ThemeColor>, text: impl Into<ThemeColor>, dim: Color) {
+    PANEL_COLOR.with(|color| color.set(panel.into()));
+    TEXT_COLOR.with(|color| color.set(text.into()));
+    DIM_COLOR.with(|color| color.set(dim));
+}
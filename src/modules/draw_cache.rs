@@ -0,0 +1,311 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Keeps a picture of UI that hasn't changed, so screens with lots of labels and panels don't
+measure and draw every letter again 60 times a second
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod draw_cache;
+
+This also needs these modules:
+    pub mod scale;
+    pub mod theme;
+    pub mod ui_manager;
+
+Add with the other use statements:
+    use crate::modules::draw_cache::{Cached, DrawCache};
+
+HOW IT WORKS:
+    The first time, the drawing is done into a texture (a render target) with as many pixels
+    as the area covers on the screen, and the texture is drawn in its place. After that only
+    the texture is drawn, until the cache is marked dirty. It also draws again by itself when
+    the area moves or changes size, the window is resized or the theme changes.
+    Anything that changes every frame (hover effects, a blinking cursor, animations) looks
+    frozen inside a cache, so leave it out. Things drawn on a solid background (a panel, a
+    label with a background color) come out the sharpest.
+
+A GROUP OF WIDGETS:
+    Above the loop section:
+        let mut cache_sidebar = DrawCache::new();
+    Then in the loop:
+        cache_sidebar.draw(Rect::new(0.0, 0.0, 250.0, 768.0), || {
+            draw_rectangle(0.0, 0.0, 250.0, 768.0, DARKGRAY);
+            lbl_title.draw();
+            lbl_help.draw();
+        });
+    After changing something inside it:
+        lbl_title.set_text("Level 2");
+        cache_sidebar.mark_dirty();
+    Only what is drawn inside the area is kept. The closure only runs on the frames the
+    picture is made again (draw returns true on those), so don't check for clicks in it.
+
+ONE WIDGET IN A UiManager:
+    let lbl_title = ui.add(Cached::new(Label::new("Settings", 50.0, 60.0, 40)));
+    lbl_title.borrow_mut().set_text("Options");               // Marks it dirty by itself
+    let text = lbl_title.borrow().get_text().to_string();     // Reading it doesn't
+    Cached works like the widget inside it. Any change made through borrow_mut marks it
+    dirty. While the widget is hovered or focused, or its needs_redraw says it is animating,
+    it is drawn the normal way so buttons still light up and text boxes still blink.
+
+OTHER METHODS:
+    cache_sidebar.mark_dirty();
+    let dirty = cache_sidebar.is_dirty();
+    cache_sidebar.clear();                         // Free the texture, it is made again on the next draw
+    cache_sidebar.with_tint(WHITE.with_alpha(0.5)); // Color the picture is drawn with (fading it, default WHITE)
+    lbl_title.borrow_mut().with_margin(10.0);      // Room kept around the widget for outlines and shadows (default 6)
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale;
+use crate::modules::theme;
+use crate::modules::ui_manager::{UiContext, UiEvent, Widget};
+use std::ops::{Deref, DerefMut};
+
+// Room kept around a Cached widget's rect by default
+const DEFAULT_MARGIN: f32 = 6.0;
+
+pub struct DrawCache {
+    target: Option<RenderTarget>,
+    area: Rect,          // Where the picture was drawn from, in virtual coordinates
+    pixels: (u32, u32),  // Size of the texture
+    theme: u64,          // theme::generation() when it was drawn
+    dirty: bool,
+    tint: Color,         // Multiplies the picture's colors when it is drawn
+}
+
+impl DrawCache {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self {
+            target: None,
+            area: Rect::default(),
+            pixels: (0, 0),
+            theme: 0,
+            dirty: true,
+            tint: WHITE,
+        }
+    }
+
+    // Draw the picture again on the next draw
+    #[allow(unused)]
+    pub fn mark_dirty(&mut self) -> &mut Self {
+        self.dirty = true;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    // Color the picture is drawn with, a see-through white fades it
+    #[allow(unused)]
+    pub fn with_tint(&mut self, tint: Color) -> &mut Self {
+        self.tint = tint;
+        self
+    }
+
+    // Let go of the texture
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        self.target = None;
+        self.dirty = true;
+        self
+    }
+
+    // Draw what the closure draws inside an area, running it only when the picture is out of date.
+    // Returns true on the frames it ran
+    #[allow(unused)]
+    pub fn draw<F: FnOnce()>(&mut self, area: Rect, draw: F) -> bool {
+        let redrawn = self.refresh(area, draw);
+        self.draw_texture();
+        redrawn
+    }
+
+    // Make the picture again if it is out of date, returns true if it was
+    fn refresh<F: FnOnce()>(&mut self, area: Rect, draw: F) -> bool {
+        let pixels = pixel_size(area);
+        if pixels.0 == 0 || pixels.1 == 0 {
+            self.target = None;
+            return false;
+        }
+        let stale = self.dirty || self.area != area || self.pixels != pixels || self.theme != theme::generation();
+        if !stale && self.target.is_some() {
+            return false;
+        }
+        if self.pixels != pixels || self.target.is_none() {
+            let target = render_target(pixels.0, pixels.1);
+            target.texture.set_filter(FilterMode::Linear);
+            self.target = Some(target);
+        }
+        let Some(target) = &self.target else {
+            return false;
+        };
+
+        let mut camera = Camera2D::from_display_rect(area);
+        camera.render_target = Some(target.clone());
+        push_camera_state();
+        set_camera(&camera);
+        scale::begin_render_target(pixels.0 as f32, pixels.1 as f32);
+        clear_background(Color::new(0.0, 0.0, 0.0, 0.0));
+        draw();
+        // The camera goes back first so the window's clip rectangle is worked out with it
+        pop_camera_state();
+        scale::end_render_target();
+
+        self.area = area;
+        self.pixels = pixels;
+        self.theme = theme::generation();
+        self.dirty = false;
+        true
+    }
+
+    fn draw_texture(&self) {
+        let Some(target) = &self.target else {
+            return;
+        };
+        draw_texture_ex(
+            &target.texture,
+            self.area.x,
+            self.area.y,
+            self.tint,
+            DrawTextureParams {
+                dest_size: Some(self.area.size()),
+                // Render targets come out upside down
+                flip_y: true,
+                ..Default::default()
+            },
+        );
+    }
+}
+
+impl Default for DrawCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// A widget that is drawn from a DrawCache while nothing about it changes
+pub struct Cached<W: Widget> {
+    widget: W,
+    cache: DrawCache,
+    margin: f32,
+    live: bool, // Drawn the normal way this frame (hovered, focused or animating)
+}
+
+impl<W: Widget> Cached<W> {
+    #[allow(unused)]
+    pub fn new(widget: W) -> Self {
+        Self {
+            widget,
+            cache: DrawCache::new(),
+            margin: DEFAULT_MARGIN,
+            live: false,
+        }
+    }
+
+    // Room kept around the widget's rect, for outlines, shadows and borders drawn outside it
+    #[allow(unused)]
+    pub fn with_margin(&mut self, margin: f32) -> &mut Self {
+        self.margin = margin.max(0.0);
+        self.cache.mark_dirty();
+        self
+    }
+
+    // For changes the widget makes to itself (changes made through borrow_mut are noticed already)
+    #[allow(unused)]
+    pub fn mark_dirty(&mut self) -> &mut Self {
+        self.cache.mark_dirty();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn into_inner(self) -> W {
+        self.widget
+    }
+}
+
+impl<W: Widget> Deref for Cached<W> {
+    type Target = W;
+
+    fn deref(&self) -> &W {
+        &self.widget
+    }
+}
+
+// Anything that can change the widget goes through here, so it marks the picture out of date
+impl<W: Widget> DerefMut for Cached<W> {
+    fn deref_mut(&mut self) -> &mut W {
+        self.cache.mark_dirty();
+        &mut self.widget
+    }
+}
+
+impl<W: Widget> Widget for Cached<W> {
+    fn rect(&self) -> Rect {
+        self.widget.rect()
+    }
+
+    fn update(&mut self, ctx: &UiContext) {
+        self.live = ctx.hovered || ctx.focused || self.widget.needs_redraw();
+        if self.live {
+            self.widget.update(ctx);
+            // It may look different by the time it settles down
+            self.cache.mark_dirty();
+            return;
+        }
+        let rect = self.widget.rect();
+        let area = Rect::new(rect.x - self.margin, rect.y - self.margin, rect.w + self.margin * 2.0, rect.h + self.margin * 2.0);
+        let widget = &mut self.widget;
+        self.cache.refresh(area, || {
+            widget.update(ctx);
+            widget.draw();
+        });
+    }
+
+    fn draw(&self) {
+        if self.live {
+            self.widget.draw();
+        } else if self.widget.is_visible() {
+            self.cache.draw_texture();
+        }
+    }
+
+    fn handle_event(&mut self, event: &UiEvent) -> bool {
+        self.cache.mark_dirty();
+        self.widget.handle_event(event)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.widget.is_visible()
+    }
+
+    fn focus_id(&self) -> Option<usize> {
+        self.widget.focus_id()
+    }
+
+    fn popup_rect(&self) -> Option<Rect> {
+        self.widget.popup_rect()
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.widget.needs_redraw()
+    }
+
+    fn was_clicked(&self) -> bool {
+        self.widget.was_clicked()
+    }
+
+    fn changed_text(&self) -> Option<String> {
+        self.widget.changed_text()
+    }
+}
+
+// How many pixels an area covers in the window's framebuffer
+fn pixel_size(area: Rect) -> (u32, u32) {
+    let (left, top) = scale::world_to_screen(area.x, area.y);
+    let (right, bottom) = scale::world_to_screen(area.x + area.w, area.y + area.h);
+
+    let dpi = screen_dpi_scale();
+    (((right - left) * dpi).ceil().max(0.0) as u32, ((bottom - top) * dpi).ceil().max(0.0) as u32)
+}
@@ -0,0 +1,450 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Keeps track of which widget has keyboard focus and moves it with Tab / Shift+Tab
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod focus;
+
+This also needs these modules (for FocusManager):
+    pub mod scale;
+    pub mod theme;
+    pub mod virtual_keyboard;
+
+Add with the other use statements:
+    use crate::modules::focus;
+
+HOW IT WORKS:
+    Every TextInput, TextArea and TextButton gets a focus id when it is created and is
+    added to the tab order in the order the widgets were created. Only the focused
+    widget receives typing, so two text boxes can never be active at the same time.
+    Pressing Tab moves focus to the next widget, Shift+Tab to the previous one.
+    A focused button is clicked with Enter or Space.
+
+Usage examples:
+    // Change the tab order (widgets not listed are left out of Tab traversal)
+    focus::set_tab_order(&[txt_user.focus_id(), txt_password.focus_id(), btn_login.focus_id()]);
+
+    // Give a widget focus from code
+    focus::set_focus(txt_user.focus_id());
+
+    // Remove focus from everything
+    focus::clear_focus();
+
+    // Swap the tab order out for a while and put it back afterwards
+    let saved = focus::get_tab_order();
+    focus::set_tab_order(&[btn_ok.focus_id()]);
+    focus::set_tab_order(&saved);
+
+    // Check what is focused
+    if focus::is_focused(txt_user.focus_id()) {
+        // ...
+    }
+
+MODAL LAYERS:
+    Something that sits over everything and must be dealt with first (a dialog, an open
+    menu) takes the keyboard away from the widgets under it:
+        focus::push_modal(&[btn_ok.focus_id(), btn_cancel.focus_id()]);
+    Only the listed widgets can be tabbed to and nothing has focus until one is picked,
+    so typing can't reach a text box underneath. When it closes:
+        focus::pop_modal();     // The tab order and focus from before come back
+    Layers stack, so a dialog opened from a menu item puts back the menu's layer.
+    dialog, context_menu, menu_bar and UiManager already do this.
+        let layers = focus::modal_depth();    // How many are open
+
+KEYBOARD MODE:
+    For players who can't or don't use a mouse. Turn it on before the loop (or from a
+    settings toggle), it needs a FocusManager:
+        focus::set_keyboard_mode(true);
+    With it:
+        - A thick ring in the theme's primary color is drawn around whatever has focus.
+        - The arrow keys move focus to the nearest widget in that direction (Tab and
+          Shift+Tab still go through the tab order). Text boxes keep the arrow keys for
+          moving the cursor, so leave them with Tab.
+        - Enter or Space clicks the focused button, Space ticks a focused checkbox.
+    Pair it with the high contrast theme for low vision players:
+        theme::set_theme(Theme::high_contrast());
+    Your own widgets that use the arrow keys while focused should say so every frame:
+        focus::use_arrows();
+        let on = focus::keyboard_mode();
+        focus::focus_toward(vec2(1.0, 0.0));    // Move focus right from code (a gamepad d-pad)
+
+FOCUS MANAGER:
+    Widgets handle their own clicks, so when widgets overlap (a text box inside a panel
+    that slides over another one) each one under the mouse would take focus for the
+    click. A FocusManager sorts that out and adds the rest of the usual keyboard focus
+    behaviour. Above the loop section:
+        let mut focus_manager = FocusManager::new();
+    And at the very end of the loop, after everything is drawn:
+        focus_manager.update();
+    With it:
+        - A click gives focus to the top widget under the mouse (the one drawn last) and
+          only that one, widgets underneath don't react to the click at all.
+        - Clicking somewhere with no focusable widget removes focus.
+        - Escape removes focus, unless a widget used it this frame (closing a suggestion
+          list, a menu or a dialog, or cancelling a grid edit).
+    Options:
+        focus_manager.with_click_clears(false);     // Clicking empty space keeps focus
+        focus_manager.with_escape_clears(false);    // Escape leaves focus alone
+    Import it with:
+        use crate::modules::focus::{self, FocusManager};
+
+    TextInput, TextArea, TextButton and Form already tell the focus module where they are
+    drawn. Your own focusable widgets can do the same every frame they are drawn:
+        focus::set_area(my_id, Rect::new(x, y, width, height));
+        // Only react to a click if nothing focusable was drawn on top of this last frame
+        if is_mouse_button_pressed(MouseButton::Left) && focus::is_top_at(my_id, mouse_x, mouse_y) { }
+        // And if Escape means something to the widget
+        if is_key_pressed(KeyCode::Escape) { focus::use_escape(); }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale;
+use crate::modules::theme;
+use crate::modules::virtual_keyboard;
+use std::cell::{Cell, RefCell};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+thread_local! {
+    static NEXT_ID: Cell<usize> = const { Cell::new(1) };
+    static FOCUSED: Cell<Option<usize>> = const { Cell::new(None) };
+    static TAB_ORDER: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    // Widgets that can't take focus right now (disabled or hidden)
+    static UNFOCUSABLE: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    // Set once Tab has moved focus this frame, so later widgets don't move it again
+    static TAB_CONSUMED: Cell<bool> = const { Cell::new(false) };
+    // Where each widget was drawn this frame, bottom to top (see FocusManager)
+    static AREAS: RefCell<Vec<(usize, Rect)>> = const { RefCell::new(Vec::new()) };
+    // The same from last frame, what widgets check clicks against
+    static LAST_AREAS: RefCell<Vec<(usize, Rect)>> = const { RefCell::new(Vec::new()) };
+    // Set when a widget used Escape this frame, so FocusManager leaves focus alone
+    static ESCAPE_USED: Cell<bool> = const { Cell::new(false) };
+    // Tab order and focus from before each open modal layer, the last one is on top
+    static MODALS: RefCell<Vec<(Vec<usize>, Option<usize>)>> = const { RefCell::new(Vec::new()) };
+    // Focus ring and arrow key movement (see KEYBOARD MODE)
+    static KEYBOARD_MODE: Cell<bool> = const { Cell::new(false) };
+    // Set when the focused widget used the arrow keys this frame, so focus stays put
+    static ARROWS_USED: Cell<bool> = const { Cell::new(false) };
+}
+
+// How thick the keyboard mode focus ring is, and how far outside the widget it sits
+const RING_WIDTH: f32 = 3.0;
+const RING_GAP: f32 = 4.0;
+
+/// Create a new focus id and add it to the end of the tab order
+pub fn register() -> usize {
+    let id = NEXT_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    });
+    TAB_ORDER.with(|order| order.borrow_mut().push(id));
+    id
+}
+
+/// Remove a focus id (called when a widget is dropped)
+pub fn unregister(id: usize) {
+    TAB_ORDER.with(|order| order.borrow_mut().retain(|&other| other != id));
+    UNFOCUSABLE.with(|list| list.borrow_mut().retain(|&other| other != id));
+    AREAS.with(|areas| areas.borrow_mut().retain(|(other, _)| *other != id));
+    LAST_AREAS.with(|areas| areas.borrow_mut().retain(|(other, _)| *other != id));
+    if is_focused(id) {
+        clear_focus();
+    }
+}
+
+/// Replace the tab order with the given ids
+#[allow(unused)]
+pub fn set_tab_order(ids: &[usize]) {
+    TAB_ORDER.with(|order| *order.borrow_mut() = ids.to_vec());
+}
+
+/// The current tab order (to put it back later after set_tab_order)
+pub fn get_tab_order() -> Vec<usize> {
+    TAB_ORDER.with(|order| order.borrow().clone())
+}
+
+/// Allow or stop a widget from receiving focus (disabled widgets shouldn't be tabbed to)
+pub fn set_focusable(id: usize, focusable: bool) {
+    UNFOCUSABLE.with(|list| {
+        let mut list = list.borrow_mut();
+        let listed = list.contains(&id);
+        if focusable && listed {
+            list.retain(|&other| other != id);
+        } else if !focusable && !listed {
+            list.push(id);
+        }
+    });
+    if !focusable && is_focused(id) {
+        clear_focus();
+    }
+}
+
+/// Whether a widget can receive focus right now (see set_focusable)
+pub fn is_focusable(id: usize) -> bool {
+    UNFOCUSABLE.with(|list| !list.borrow().contains(&id))
+}
+
+/// Give focus to a widget
+pub fn set_focus(id: usize) {
+    FOCUSED.with(|focused| focused.set(Some(id)));
+}
+
+/// Remove focus from every widget
+pub fn clear_focus() {
+    FOCUSED.with(|focused| focused.set(None));
+}
+
+/// Remove focus only if the given widget has it
+pub fn release_focus(id: usize) {
+    if is_focused(id) {
+        clear_focus();
+    }
+}
+
+/// The id of the focused widget, if any
+#[allow(unused)]
+pub fn focused() -> Option<usize> {
+    FOCUSED.with(|focused| focused.get())
+}
+
+/// Check if a widget has focus
+pub fn is_focused(id: usize) -> bool {
+    FOCUSED.with(|focused| focused.get() == Some(id))
+}
+
+/// Move focus to the next (forward) or previous focusable widget in the tab order
+pub fn focus_next(forward: bool) {
+    let order: Vec<usize> = TAB_ORDER.with(|order| order.borrow().clone());
+    let candidates: Vec<usize> = order.into_iter().filter(|&id| is_focusable(id)).collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    let current = focused().and_then(|id| candidates.iter().position(|&other| other == id));
+    let next = match (current, forward) {
+        (Some(index), true) => (index + 1) % candidates.len(),
+        (Some(index), false) => (index + candidates.len() - 1) % candidates.len(),
+        (None, true) => 0,
+        (None, false) => candidates.len() - 1,
+    };
+    set_focus(candidates[next]);
+}
+
+/// Handle Tab / Shift+Tab - widgets call this every update, only the first call in a frame acts
+pub fn handle_tab() {
+    if !is_key_pressed(KeyCode::Tab) {
+        TAB_CONSUMED.with(|consumed| consumed.set(false));
+        return;
+    }
+    if TAB_CONSUMED.with(|consumed| consumed.replace(true)) {
+        return;
+    }
+    // Ctrl+Tab is left for switching tabs (see the tabs module)
+    if is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl) {
+        return;
+    }
+    let shift_down = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+    focus_next(!shift_down);
+}
+
+/// Start a modal layer: only these widgets can be tabbed to and nothing keeps focus
+pub fn push_modal(ids: &[usize]) {
+    MODALS.with(|modals| modals.borrow_mut().push((get_tab_order(), focused())));
+    set_tab_order(ids);
+    clear_focus();
+}
+
+/// End the top modal layer, putting back the tab order and focus from before it
+pub fn pop_modal() {
+    let Some((tab_order, saved_focus)) = MODALS.with(|modals| modals.borrow_mut().pop()) else {
+        return;
+    };
+    set_tab_order(&tab_order);
+    match saved_focus {
+        Some(id) => set_focus(id),
+        None => clear_focus(),
+    }
+}
+
+/// How many modal layers (dialogs, menus, ...) are open
+pub fn modal_depth() -> usize {
+    MODALS.with(|modals| modals.borrow().len())
+}
+
+/// Record where a widget was drawn this frame (drawn later means on top)
+pub fn set_area(id: usize, area: Rect) {
+    AREAS.with(|areas| {
+        let mut areas = areas.borrow_mut();
+        areas.retain(|(other, _)| *other != id);
+        areas.push((id, area));
+    });
+}
+
+/// Whether a click at this point belongs to the widget: false only when another widget
+/// with an area was drawn over the point last frame
+pub fn is_top_at(id: usize, x: f32, y: f32) -> bool {
+    LAST_AREAS.with(|areas| {
+        let areas = areas.borrow();
+        match top_at(&areas, Vec2::new(x, y)) {
+            Some(top) => top == id || !areas.iter().any(|(other, _)| *other == id),
+            None => true,
+        }
+    })
+}
+
+/// Tell FocusManager that Escape meant something to a widget this frame
+pub fn use_escape() {
+    ESCAPE_USED.with(|used| used.set(true));
+}
+
+/// Turn the focus ring and arrow key movement on or off
+#[allow(unused)]
+pub fn set_keyboard_mode(on: bool) {
+    KEYBOARD_MODE.with(|mode| mode.set(on));
+}
+
+/// Whether keyboard mode is on (widgets draw their own focus outline when it's off)
+pub fn keyboard_mode() -> bool {
+    KEYBOARD_MODE.with(Cell::get)
+}
+
+/// Tell FocusManager the focused widget uses the arrow keys itself (text boxes, lists)
+pub fn use_arrows() {
+    ARROWS_USED.with(|used| used.set(true));
+}
+
+/// Move focus to the nearest focusable widget in a direction (like (1.0, 0.0) for right),
+/// using where widgets were drawn. Returns false if there is nothing that way
+#[allow(unused)]
+pub fn focus_toward(direction: Vec2) -> bool {
+    let areas = LAST_AREAS.with(|areas| areas.borrow().clone());
+    move_toward(&areas, direction)
+}
+
+fn move_toward(areas: &[(usize, Rect)], direction: Vec2) -> bool {
+    let order = get_tab_order();
+    let Some(from) = focused().and_then(|id| areas.iter().find(|(other, _)| *other == id)).map(|(_, area)| area.center()) else {
+        // Nothing focused yet, start at the beginning of the tab order
+        focus_next(true);
+        return focused().is_some();
+    };
+    let best = areas
+        .iter()
+        .filter(|(id, _)| Some(*id) != focused() && order.contains(id) && is_focusable(*id))
+        .filter_map(|(id, area)| {
+            let offset = area.center() - from;
+            let along = offset.dot(direction);
+            if along <= 0.0 {
+                return None;
+            }
+            // Straight ahead beats closer but off to the side
+            let across = (offset - direction * along).length();
+            Some((*id, along + across * 2.0))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    match best {
+        Some((id, _)) => {
+            set_focus(id);
+            true
+        }
+        None => false,
+    }
+}
+
+fn top_at(areas: &[(usize, Rect)], point: Vec2) -> Option<usize> {
+    areas.iter().rev().find(|(_, area)| area.contains(point)).map(|(id, _)| *id)
+}
+
+pub struct FocusManager {
+    click_clears: bool,
+    escape_clears: bool,
+    was_blocked: bool, // Mouse blocked at the end of last frame (a dialog or menu was open)
+}
+
+impl FocusManager {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self {
+            click_clears: true,
+            escape_clears: true,
+            was_blocked: false,
+        }
+    }
+
+    // Whether clicking where there is no focusable widget removes focus
+    #[allow(unused)]
+    pub fn with_click_clears(&mut self, clears: bool) -> &mut Self {
+        self.click_clears = clears;
+        self
+    }
+
+    // Whether Escape removes focus (when no widget used it)
+    #[allow(unused)]
+    pub fn with_escape_clears(&mut self, clears: bool) -> &mut Self {
+        self.escape_clears = clears;
+        self
+    }
+
+    // Settle focus for this frame, call once at the end of the loop after everything is drawn
+    #[allow(unused)]
+    pub fn update(&mut self) {
+        let areas = AREAS.with(|areas| std::mem::take(&mut *areas.borrow_mut()));
+        // Clicks that opened, closed or landed on a dialog or menu are left to it
+        let blocked = scale::is_mouse_blocked();
+        if is_mouse_button_pressed(MouseButton::Left) && !blocked && !self.was_blocked {
+            let (mouse_x, mouse_y) = mouse_position();
+            // Tapping the on-screen keyboard keeps typing going where it was
+            if !virtual_keyboard::covers(mouse_x, mouse_y) {
+                match top_at(&areas, Vec2::new(mouse_x, mouse_y)).filter(|&id| is_focusable(id)) {
+                    Some(id) => set_focus(id),
+                    None if self.click_clears => clear_focus(),
+                    None => {}
+                }
+            }
+        }
+        if self.escape_clears && is_key_pressed(KeyCode::Escape) && !ESCAPE_USED.with(Cell::get) {
+            clear_focus();
+        }
+        ESCAPE_USED.with(|used| used.set(false));
+        if keyboard_mode() {
+            self.keyboard(&areas);
+        }
+        ARROWS_USED.with(|used| used.set(false));
+        LAST_AREAS.with(|last| *last.borrow_mut() = areas);
+        self.was_blocked = blocked;
+    }
+
+    // Arrow key movement and the focus ring
+    fn keyboard(&self, areas: &[(usize, Rect)]) {
+        if !ARROWS_USED.with(Cell::get) {
+            let directions = [
+                (KeyCode::Left, vec2(-1.0, 0.0)),
+                (KeyCode::Right, vec2(1.0, 0.0)),
+                (KeyCode::Up, vec2(0.0, -1.0)),
+                (KeyCode::Down, vec2(0.0, 1.0)),
+            ];
+            if let Some((_, direction)) = directions.iter().find(|(key, _)| is_key_pressed(*key)) {
+                move_toward(areas, *direction);
+            }
+        }
+        let ring = focused().and_then(|id| areas.iter().find(|(other, _)| *other == id)).map(|(_, area)| *area);
+        if let Some(area) = ring {
+            draw_rectangle_lines(
+                area.x - RING_GAP,
+                area.y - RING_GAP,
+                area.w + RING_GAP * 2.0,
+                area.h + RING_GAP * 2.0,
+                RING_WIDTH,
+                theme::palette().primary,
+            );
+        }
+    }
+}
+
+impl Default for FocusManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,233 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Backup fonts for characters a widget's own font doesn't have, so emoji, Chinese, Japanese,
+Korean and accented letters typed by players show up instead of empty boxes
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod font_fallback;
+
+This also needs these modules:
+    pub mod assets;
+    pub mod glyph_cache;
+
+In the Cargo.toml file add the following under [dependencies]:
+    fontdue = "0.9"
+
+Add with the other use statements:
+    use crate::modules::font_fallback;
+
+Then before the loop, load the backup fonts in the order they should be tried:
+    font_fallback::load_fallback("assets/fonts/NotoSans-Regular.ttf").await.unwrap();   // Accented letters, Greek, Cyrillic
+    font_fallback::load_fallback("assets/fonts/NotoSansSC-Regular.ttf").await.unwrap(); // Chinese (and most Japanese)
+    font_fallback::load_fallback("assets/fonts/NotoEmoji-Regular.ttf").await.unwrap();  // Emoji
+Label, TextInput, TextArea and TextButton use them from then on.
+
+HOW IT WORKS:
+    Text is split into pieces that each draw with one font. Each character uses the
+    widget's own font if it has the character, otherwise the first backup font in the list
+    that has it. If none do it stays with the widget's font (and shows as a box).
+    macroquad's default font has the basic Latin letters, the accented ones used in western
+    Europe (é, ñ, ü, ...) and €. For your own fonts, characters outside plain ASCII are
+    checked by comparing them with the box the font draws for a missing character.
+    Marks that join onto the character before them (accents typed separately, emoji
+    variation selectors and zero width joiners) stay with that character's font, and are
+    left out if that font doesn't have them.
+    Fonts draw in one color, so emoji need a black and white emoji font (like Noto Emoji)
+    rather than a color one.
+
+    Widgets use this module's measure_text, draw_text and draw_text_ex in place of
+    macroquad's (same arguments), your own drawing code can too:
+        use crate::modules::font_fallback::{draw_text, draw_text_ex, measure_text};
+
+OTHER FUNCTIONS:
+    font_fallback::add_fallback_bytes(include_bytes!("../../assets/fonts/NotoEmoji-Regular.ttf")).unwrap();
+    let count = font_fallback::fallback_count();
+    font_fallback::clear_fallbacks();
+*/
+
+use macroquad::prelude::*;
+use crate::modules::assets;
+use crate::modules::glyph_cache;
+use std::cell::RefCell;
+use std::ops::Range;
+
+// A backup font, with fontdue's copy of it for looking up which characters it has
+struct FallbackFont {
+    font: Font,
+    face: fontdue::Font,
+}
+
+// Which font a piece of text is drawn with
+#[derive(Clone, Copy, PartialEq)]
+enum Source {
+    Own,             // The widget's font
+    Fallback(usize), // A backup font, by its place in the list
+    Skip,            // A joining mark the font before it doesn't have, left out
+}
+
+thread_local! {
+    // Tried in order for characters the widget's font doesn't have
+    static FALLBACKS: RefCell<Vec<FallbackFont>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Load a .ttf or .otf font and add it to the end of the backup list
+#[allow(unused)]
+pub async fn load_fallback(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = assets::load_bytes(path).await?;
+    add_fallback_bytes(&bytes).map_err(|e| format!("Failed to read font {}: {}", path, e))?;
+    Ok(())
+}
+
+/// Add a font to the end of the backup list from its bytes (e.g. include_bytes!)
+#[allow(unused)]
+pub fn add_fallback_bytes(bytes: &[u8]) -> Result<(), String> {
+    let face = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default()).map_err(str::to_string)?;
+    let font = load_ttf_font_from_bytes(bytes).map_err(|e| format!("{:?}", e))?;
+    FALLBACKS.with(|fallbacks| fallbacks.borrow_mut().push(FallbackFont { font, face }));
+    // Characters measured before may come from a different font now
+    glyph_cache::clear();
+    Ok(())
+}
+
+/// Forget every backup font
+#[allow(unused)]
+pub fn clear_fallbacks() {
+    FALLBACKS.with(|fallbacks| fallbacks.borrow_mut().clear());
+    glyph_cache::clear();
+}
+
+#[allow(unused)]
+pub fn fallback_count() -> usize {
+    FALLBACKS.with(|fallbacks| fallbacks.borrow().len())
+}
+
+/// measure_text that measures each character with the font that will draw it
+pub fn measure_text(text: &str, font: Option<&Font>, font_size: u16, font_scale: f32) -> TextDimensions {
+    let runs = runs(text, font);
+    if runs.iter().all(|(_, source)| *source == Source::Own) {
+        return macroquad::text::measure_text(text, font, font_size, font_scale);
+    }
+    let mut sizes = Vec::with_capacity(runs.len());
+    for (range, source) in runs {
+        let fallback_font = match source {
+            Source::Own => None,
+            Source::Fallback(index) => Some(fallback_font(index)),
+            Source::Skip => continue,
+        };
+        sizes.push(macroquad::text::measure_text(&text[range], fallback_font.as_ref().or(font), font_size, font_scale));
+    }
+    combine(&sizes)
+}
+
+/// draw_text with backup fonts
+pub fn draw_text(text: &str, x: f32, y: f32, font_size: f32, color: Color) -> TextDimensions {
+    draw_text_ex(
+        text,
+        x,
+        y,
+        TextParams {
+            font_size: font_size as u16,
+            font_scale: 1.0,
+            color,
+            ..Default::default()
+        },
+    )
+}
+
+/// draw_text_ex with backup fonts (rotation only works for text that needs none of them)
+pub fn draw_text_ex(text: &str, x: f32, y: f32, params: TextParams) -> TextDimensions {
+    let runs = runs(text, params.font);
+    if runs.iter().all(|(_, source)| *source == Source::Own) {
+        return macroquad::text::draw_text_ex(text, x, y, params);
+    }
+    let mut sizes = Vec::with_capacity(runs.len());
+    let mut pen_x = x;
+    for (range, source) in runs {
+        let fallback_font = match source {
+            Source::Own => None,
+            Source::Fallback(index) => Some(fallback_font(index)),
+            Source::Skip => continue,
+        };
+        let size = macroquad::text::draw_text_ex(
+            &text[range],
+            pen_x,
+            y,
+            TextParams {
+                font: fallback_font.as_ref().or(params.font),
+                ..params.clone()
+            },
+        );
+        pen_x += size.width;
+        sizes.push(size);
+    }
+    combine(&sizes)
+}
+
+// Split text into pieces that each draw with one font
+fn runs(text: &str, font: Option<&Font>) -> Vec<(Range<usize>, Source)> {
+    FALLBACKS.with(|fallbacks| {
+        let fallbacks = fallbacks.borrow();
+        if fallbacks.is_empty() {
+            return vec![(0..text.len(), Source::Own)];
+        }
+        let mut runs: Vec<(Range<usize>, Source)> = Vec::new();
+        for (index, c) in text.char_indices() {
+            let end = index + c.len_utf8();
+            let pick = if is_joining_mark(c) {
+                // Stays with whatever the character before it uses, if that font has it
+                match runs.last().map_or(Source::Own, |(_, last)| *last) {
+                    Source::Own if has_glyph(font, c) => Source::Own,
+                    Source::Fallback(index) if fallbacks[index].face.has_glyph(c) => Source::Fallback(index),
+                    _ => Source::Skip,
+                }
+            } else if has_glyph(font, c) {
+                Source::Own
+            } else {
+                fallbacks.iter().position(|fallback| fallback.face.has_glyph(c)).map_or(Source::Own, Source::Fallback)
+            };
+            match runs.last_mut() {
+                Some((range, last)) if *last == pick => range.end = end,
+                _ => runs.push((index..end, pick)),
+            }
+        }
+        runs
+    })
+}
+
+fn fallback_font(index: usize) -> Font {
+    FALLBACKS.with(|fallbacks| fallbacks.borrow()[index].font.clone())
+}
+
+// Whether a widget's own font (None for macroquad's default font) has a character
+fn has_glyph(font: Option<&Font>, c: char) -> bool {
+    match font {
+        // The default font (ProggyClean) has Latin-1 and the euro sign
+        None => matches!(c as u32, 0x00..=0x7F | 0x81..=0xFF | 0x20AC),
+        Some(font) => c.is_ascii() || !looks_missing(font, c),
+    }
+}
+
+// macroquad can't say which characters a font has, but a missing one is drawn as the font's
+// "missing" box, which measures the same as a character no font has (U+10FFFF)
+fn looks_missing(font: &Font, c: char) -> bool {
+    let missing = macroquad::text::measure_text("\u{10FFFF}", Some(font), 32, 1.0);
+    let measured = macroquad::text::measure_text(c.encode_utf8(&mut [0; 4]), Some(font), 32, 1.0);
+    measured.width == missing.width && measured.height == missing.height && measured.offset_y == missing.offset_y
+}
+
+// Characters drawn onto the one before them rather than on their own
+fn is_joining_mark(c: char) -> bool {
+    matches!(c as u32, 0x0300..=0x036F | 0x200B..=0x200F | 0xFE00..=0xFE0F)
+}
+
+// The size of pieces drawn one after another
+fn combine(sizes: &[TextDimensions]) -> TextDimensions {
+    let above = sizes.iter().map(|size| size.offset_y).fold(0.0, f32::max);
+    let below = sizes.iter().map(|size| size.height - size.offset_y).fold(0.0, f32::max);
+    TextDimensions {
+        width: sizes.iter().map(|size| size.width).sum(),
+        height: above + below,
+        offset_y: above,
+    }
+}
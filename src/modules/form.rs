@@ -0,0 +1,594 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A form built from a list of fields (text, password, number, checkbox) that lays itself out,
+checks what was typed and hands back the values ready to send to the database
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod form;
+
+This also needs these modules:
+    pub mod audio;
+    pub mod focus;
+    pub mod text_button;
+    pub mod text_input;
+
+Add with the other use statements:
+    use crate::modules::form::Form;
+
+Then above the loop section:
+    let mut frm_account = Form::new(250.0, 120.0, 300.0, 25.0);
+    frm_account
+        .add_text("username", "Username")
+        .add_password("password", "Password")
+        .add_number("level", "Starting level")
+        .add_checkbox("admin", "Admin")
+        .required("username")
+        .required("password")
+        .with_max_length("username", 32)
+        .with_validator("level", |text| match text.parse::<i32>() {
+            Ok(level) if (1..=100).contains(&level) => Ok(()),
+            _ => Err("Pick a level from 1 to 100".to_string()),
+        })
+        .add_button("Create")
+        .add_button("Login");
+Where the parameters are x, y, width and font size. Each field has a name (the column it
+goes in) and the text shown above it. The fields are stacked under each other with room
+for an error message under each one, and the buttons go in a row at the bottom.
+
+Then in the loop you would use:
+    match frm_account.draw().as_deref() {
+        Some("Create") => {
+            // submit() checks every field, shows the problems and gives None if any are wrong
+            if let Some(values) = frm_account.submit() {
+                let inserted: Vec<serde_json::Map<String, serde_json::Value>> =
+                    client.insert_record("draysTable", &values).await.unwrap();
+            }
+        }
+        Some("Login") => { }
+        _ => {}
+    }
+draw returns the text of the button that was pressed, pressing Enter in a text box is the
+same as pressing the first button.
+
+HOW IT WORKS:
+    submit() gives a serde_json::Map from field name to value: text and password fields
+    are strings, number fields are numbers (null when left empty) and checkboxes are
+    true/false. It works with insert_record and update_records, or turn it into your own
+    struct with serde_json::from_value(serde_json::Value::Object(values)).
+    Required fields can't be empty (or unchecked), number fields have to hold a number, and
+    then each validator is run on the text (checkboxes give "true" or "false"). The first
+    problem for each field is shown under it in the theme's error color until it is edited, and the first
+    field with a problem gets focus. The audio module's "error" sound plays, if registered.
+    Tab moves through the fields and buttons in the order they were added, Space checks
+    or unchecks a focused checkbox.
+
+OTHER METHODS:
+    frm_account.with_prompt("username", "Enter Username"); // Grey text while a box is empty
+    frm_account.with_colors(BLACK, BLUE, RED);            // Label, button and button hover colors (default from the theme)
+    frm_account.set_error("username", "User already exists"); // Show a problem found elsewhere
+    frm_account.clear_errors();
+    let name = frm_account.get_text("username");
+    frm_account.set_text("username", "dray");
+    frm_account.set_checked("admin", true);
+    if frm_account.is_checked("admin") { }
+    frm_account.set_values(&values);                     // Fill the fields from a record's Map
+    frm_account.clear();                                 // Empty every field
+    if let Some(txt_user) = frm_account.input("username") {
+        txt_user.set_suggestions(usernames);             // Anything else a TextInput can do
+    }
+    frm_account.set_position(100.0, 100.0);
+    let height = frm_account.get_height();
+    let ids = frm_account.focus_ids();                   // For focus::set_tab_order
+*/
+
+use macroquad::prelude::*;
+use crate::modules::audio::{self, SoundEvent};
+use crate::modules::focus;
+use crate::modules::text_button::TextButton;
+use crate::modules::text_input::{InputFilter, TextInput};
+use crate::modules::theme::{self, ThemeColor};
+use serde_json::{Map, Value};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Checks a field's text, returns the message to show when it is wrong
+type FieldValidator = Box<dyn Fn(&str) -> Result<(), String>>;
+
+// What kind of value a field holds
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum FieldKind {
+    Text,
+    Password,
+    Number,
+    Checkbox,
+}
+
+enum FieldWidget {
+    Input(Box<TextInput>),
+    Checkbox { checked: bool, focus_id: usize, y: f32 },
+}
+
+struct Field {
+    name: String,
+    label: String,
+    kind: FieldKind,
+    widget: FieldWidget,
+    required: bool,
+    validators: Vec<FieldValidator>,
+    checkbox_error: Option<String>, // Text boxes show their own errors, checkboxes keep theirs here
+    label_y: f32,
+}
+
+pub struct Form {
+    x: f32,
+    y: f32,
+    width: f32,
+    font_size: f32,
+    fields: Vec<Field>,
+    buttons: Vec<TextButton>,
+    label_color: ThemeColor,
+    button_colors: Option<(Color, Color)>, // Normal and hovered, None uses themed buttons
+    height: f32,
+}
+
+impl Form {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, font_size: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            font_size,
+            fields: Vec::new(),
+            buttons: Vec::new(),
+            label_color: ThemeColor::Text,
+            button_colors: None,
+            height: 0.0,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn add_text(&mut self, name: &str, label: &str) -> &mut Self {
+        self.add_field(name, label, FieldKind::Text)
+    }
+
+    // Shows * for each character
+    #[allow(unused)]
+    pub fn add_password(&mut self, name: &str, label: &str) -> &mut Self {
+        self.add_field(name, label, FieldKind::Password)
+    }
+
+    // Only digits, - and . can be typed
+    #[allow(unused)]
+    pub fn add_number(&mut self, name: &str, label: &str) -> &mut Self {
+        self.add_field(name, label, FieldKind::Number)
+    }
+
+    #[allow(unused)]
+    pub fn add_checkbox(&mut self, name: &str, label: &str) -> &mut Self {
+        self.add_field(name, label, FieldKind::Checkbox)
+    }
+
+    // Add a button in the row under the fields
+    #[allow(unused)]
+    pub fn add_button(&mut self, text: &str) -> &mut Self {
+        let button = match self.button_colors {
+            Some((normal, hover)) => TextButton::new(0.0, 0.0, 0.0, 0.0, text, normal, hover, self.font_size as u16),
+            None => TextButton::themed(0.0, 0.0, 0.0, 0.0, text, self.font_size as u16),
+        };
+        self.buttons.push(button);
+        self.layout();
+        self
+    }
+
+    // The field can't be left empty (or a checkbox unchecked)
+    #[allow(unused)]
+    pub fn required(&mut self, name: &str) -> &mut Self {
+        if let Some(field) = self.field_mut(name) {
+            field.required = true;
+        }
+        self
+    }
+
+    // Add a check run on the field's text when the form is submitted
+    #[allow(unused)]
+    pub fn with_validator<F>(&mut self, name: &str, validator: F) -> &mut Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        if let Some(field) = self.field_mut(name) {
+            field.validators.push(Box::new(validator));
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_max_length(&mut self, name: &str, max_length: usize) -> &mut Self {
+        if let Some(input) = self.input(name) {
+            input.set_max_length(max_length);
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_prompt(&mut self, name: &str, prompt: &str) -> &mut Self {
+        if let Some(input) = self.input(name) {
+            input.set_prompt(prompt);
+        }
+        self
+    }
+
+    // Colors for the labels and the buttons (normal and hovered), instead of the theme's
+    #[allow(unused)]
+    pub fn with_colors(&mut self, label: impl Into<ThemeColor>, button: Color, button_hover: Color) -> &mut Self {
+        self.label_color = label.into();
+        self.button_colors = Some((button, button_hover));
+        for existing in &mut self.buttons {
+            existing.with_hover_color(button_hover);
+            existing.normal_color = button;
+        }
+        self
+    }
+
+    // The text box of a field, for anything the form doesn't do itself
+    #[allow(unused)]
+    pub fn input(&mut self, name: &str) -> Option<&mut TextInput> {
+        match &mut self.field_mut(name)?.widget {
+            FieldWidget::Input(input) => Some(input),
+            FieldWidget::Checkbox { .. } => None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn get_text(&self, name: &str) -> String {
+        match self.fields.iter().find(|field| field.name == name).map(|field| &field.widget) {
+            Some(FieldWidget::Input(input)) => input.get_text(),
+            Some(FieldWidget::Checkbox { checked, .. }) => checked.to_string(),
+            None => String::new(),
+        }
+    }
+
+    #[allow(unused)]
+    pub fn set_text(&mut self, name: &str, text: &str) -> &mut Self {
+        if let Some(input) = self.input(name) {
+            input.set_text(text);
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_checked(&self, name: &str) -> bool {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .is_some_and(|field| matches!(field.widget, FieldWidget::Checkbox { checked: true, .. }))
+    }
+
+    #[allow(unused)]
+    pub fn set_checked(&mut self, name: &str, value: bool) -> &mut Self {
+        if let Some(FieldWidget::Checkbox { checked, .. }) = self.field_mut(name).map(|field| &mut field.widget) {
+            *checked = value;
+        }
+        self
+    }
+
+    // Fill the fields from a record (keys without a field are ignored)
+    #[allow(unused)]
+    pub fn set_values(&mut self, values: &Map<String, Value>) -> &mut Self {
+        for field in &mut self.fields {
+            let Some(value) = values.get(&field.name) else {
+                continue;
+            };
+            match &mut field.widget {
+                FieldWidget::Input(input) => {
+                    let text = match value {
+                        Value::String(text) => text.clone(),
+                        Value::Null => String::new(),
+                        other => other.to_string(),
+                    };
+                    input.set_text(text);
+                }
+                FieldWidget::Checkbox { checked, .. } => *checked = value.as_bool().unwrap_or(false),
+            }
+        }
+        self
+    }
+
+    // Empty every field and remove the error messages
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        for field in &mut self.fields {
+            match &mut field.widget {
+                FieldWidget::Input(input) => {
+                    input.set_text("");
+                }
+                FieldWidget::Checkbox { checked, .. } => *checked = false,
+            }
+        }
+        self.clear_errors();
+        self
+    }
+
+    // Show a message under a field, like a problem the database found
+    #[allow(unused)]
+    pub fn set_error(&mut self, name: &str, message: &str) -> &mut Self {
+        if let Some(field) = self.field_mut(name) {
+            field.show_error(Some(message));
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_errors(&mut self) -> &mut Self {
+        for field in &mut self.fields {
+            field.show_error(None);
+        }
+        self
+    }
+
+    // Check every field, returns the values when they are all fine
+    #[allow(unused)]
+    pub fn submit(&mut self) -> Option<Map<String, Value>> {
+        let mut values = Map::new();
+        let mut first_problem = None;
+        for field in &mut self.fields {
+            let problem = field.problem();
+            if problem.is_some() && first_problem.is_none() {
+                first_problem = Some(field.focus_id());
+            }
+            field.show_error(problem.as_deref());
+            values.insert(field.name.clone(), field.value());
+        }
+
+        match first_problem {
+            Some(id) => {
+                focus::set_focus(id);
+                audio::play_event(SoundEvent::Error);
+                None
+            }
+            None => Some(values),
+        }
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self.layout();
+        self
+    }
+
+    // Height of everything, from the first label to the bottom of the buttons
+    #[allow(unused)]
+    pub fn get_height(&self) -> f32 {
+        self.height
+    }
+
+    // Focus ids of the fields then the buttons
+    #[allow(unused)]
+    pub fn focus_ids(&self) -> Vec<usize> {
+        self.fields
+            .iter()
+            .map(Field::focus_id)
+            .chain(self.buttons.iter().map(TextButton::focus_id))
+            .collect()
+    }
+
+    // Draw and handle the form, returns the text of the button pressed this frame
+    #[allow(unused)]
+    pub fn draw(&mut self) -> Option<String> {
+        let mut pressed = None;
+        let label_size = self.font_size * 0.8;
+        let row_height = self.row_height();
+        let palette = theme::palette();
+        let accent = self.button_colors.map_or(palette.primary, |(normal, _)| normal);
+        let box_size = row_height * 0.7;
+        let (mouse_x, mouse_y) = mouse_position();
+
+        for field in &mut self.fields {
+            match &mut field.widget {
+                FieldWidget::Input(input) => {
+                    draw_text(&field.label, self.x, field.label_y, label_size, self.label_color.color());
+                    input.draw();
+                    if input.submitted() {
+                        pressed = self.buttons.first().map(|button| button.get_text().to_string());
+                    }
+                }
+                FieldWidget::Checkbox { checked, focus_id, y } => {
+                    // The box and its label can both be clicked
+                    let box_y = *y + (row_height - box_size) / 2.0;
+                    let label_width = measure_text(&field.label, None, label_size as u16, 1.0).width;
+                    let area = Rect::new(self.x, box_y, box_size + 10.0 + label_width, box_size);
+                    focus::handle_tab();
+                    focus::set_area(*focus_id, area);
+                    let focused = focus::is_focused(*focus_id);
+                    if is_mouse_button_pressed(MouseButton::Left) && area.contains(Vec2::new(mouse_x, mouse_y))
+                        && focus::is_top_at(*focus_id, mouse_x, mouse_y) {
+                        *checked = !*checked;
+                        focus::set_focus(*focus_id);
+                        field.checkbox_error = None;
+                    } else if focused && is_key_pressed(KeyCode::Space) {
+                        *checked = !*checked;
+                        field.checkbox_error = None;
+                    }
+
+                    draw_rectangle(self.x, box_y, box_size, box_size, palette.surface);
+                    let border = if field.checkbox_error.is_some() { palette.error } else if focused { accent } else { palette.border };
+                    draw_rectangle_lines(self.x, box_y, box_size, box_size, 2.0, border);
+                    if *checked {
+                        // A tick
+                        let (left, top) = (self.x, box_y);
+                        draw_line(left + box_size * 0.2, top + box_size * 0.5, left + box_size * 0.42, top + box_size * 0.75, 3.0, accent);
+                        draw_line(left + box_size * 0.42, top + box_size * 0.75, left + box_size * 0.8, top + box_size * 0.25, 3.0, accent);
+                    }
+                    draw_text(&field.label, self.x + box_size + 10.0, box_y + box_size / 2.0 + label_size / 3.0, label_size, self.label_color.color());
+                    if let Some(message) = &field.checkbox_error {
+                        let message_size = self.font_size * 0.6;
+                        draw_text(message, self.x, box_y + box_size + message_size + 2.0, message_size, palette.error);
+                    }
+                }
+            }
+        }
+
+        for button in &self.buttons {
+            if button.click() {
+                pressed = Some(button.get_text().to_string());
+            }
+        }
+        pressed
+    }
+
+    fn add_field(&mut self, name: &str, label: &str, kind: FieldKind) -> &mut Self {
+        let widget = match kind {
+            FieldKind::Checkbox => FieldWidget::Checkbox { checked: false, focus_id: focus::register(), y: 0.0 },
+            _ => {
+                let mut input = TextInput::new(self.x, self.y, self.width, self.row_height(), self.font_size);
+                if kind == FieldKind::Password {
+                    input.set_password(true);
+                }
+                if kind == FieldKind::Number {
+                    input.set_filter(InputFilter::Custom(|c| c.is_ascii_digit() || c == '-' || c == '.'));
+                }
+                FieldWidget::Input(Box::new(input))
+            }
+        };
+        self.fields.push(Field {
+            name: name.to_string(),
+            label: label.to_string(),
+            kind,
+            widget,
+            required: false,
+            validators: Vec::new(),
+            checkbox_error: None,
+            label_y: 0.0,
+        });
+        // Buttons made before this field would be in front of it when tabbing
+        let button_ids: Vec<usize> = self.buttons.iter().map(TextButton::focus_id).collect();
+        if !button_ids.is_empty() {
+            let mut order: Vec<usize> = focus::get_tab_order().into_iter().filter(|id| !button_ids.contains(id)).collect();
+            order.extend(button_ids);
+            focus::set_tab_order(&order);
+        }
+        self.layout();
+        self
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut Field> {
+        self.fields.iter_mut().find(|field| field.name == name)
+    }
+
+    // Height of a text box (and of a checkbox row)
+    fn row_height(&self) -> f32 {
+        self.font_size * 1.6
+    }
+
+    // Stack the fields and put the buttons in a row under them
+    fn layout(&mut self) {
+        let row_height = self.row_height();
+        let label_height = self.font_size;
+        // Room under each field for its error message
+        let error_space = self.font_size * 0.6 + 10.0;
+        let mut y = self.y;
+        for field in &mut self.fields {
+            match &mut field.widget {
+                FieldWidget::Input(input) => {
+                    field.label_y = y + label_height * 0.8;
+                    input.set_position(self.x, y + label_height);
+                    input.set_dimensions(self.width, row_height);
+                    y += label_height + row_height + error_space;
+                }
+                FieldWidget::Checkbox { y: checkbox_y, .. } => {
+                    *checkbox_y = y;
+                    y += row_height + error_space;
+                }
+            }
+        }
+
+        if !self.buttons.is_empty() {
+            let gap = 10.0;
+            let count = self.buttons.len() as f32;
+            let button_width = (self.width - gap * (count - 1.0)) / count;
+            let button_height = self.font_size * 2.0;
+            for (i, button) in self.buttons.iter_mut().enumerate() {
+                button.update_position(self.x + i as f32 * (button_width + gap), y, Some(button_width), Some(button_height));
+            }
+            y += button_height;
+        }
+        self.height = y - self.y;
+    }
+}
+
+impl Field {
+    fn focus_id(&self) -> usize {
+        match &self.widget {
+            FieldWidget::Input(input) => input.focus_id(),
+            FieldWidget::Checkbox { focus_id, .. } => *focus_id,
+        }
+    }
+
+    fn text(&self) -> String {
+        match &self.widget {
+            FieldWidget::Input(input) => input.get_text(),
+            FieldWidget::Checkbox { checked, .. } => checked.to_string(),
+        }
+    }
+
+    // The first thing wrong with the field, if anything
+    fn problem(&self) -> Option<String> {
+        let text = self.text();
+        let empty = match self.kind {
+            FieldKind::Checkbox => text == "false",
+            _ => text.trim().is_empty(),
+        };
+        if empty {
+            // Empty optional fields aren't checked any further
+            return self.required.then(|| match self.kind {
+                FieldKind::Checkbox => format!("{} must be checked", self.label),
+                _ => format!("{} is required", self.label),
+            });
+        }
+        if self.kind == FieldKind::Number && text.trim().parse::<f64>().is_err() {
+            return Some(format!("{} must be a number", self.label));
+        }
+        self.validators.iter().find_map(|validator| validator(&text).err())
+    }
+
+    fn value(&self) -> Value {
+        let text = self.text();
+        match self.kind {
+            FieldKind::Text | FieldKind::Password => Value::String(text),
+            FieldKind::Checkbox => Value::Bool(text == "true"),
+            FieldKind::Number => {
+                let text = text.trim();
+                // Whole numbers stay whole so they fit int columns
+                if let Ok(whole) = text.parse::<i64>() {
+                    Value::from(whole)
+                } else {
+                    text.parse::<f64>().ok().and_then(serde_json::Number::from_f64).map_or(Value::Null, Value::Number)
+                }
+            }
+        }
+    }
+
+    fn show_error(&mut self, message: Option<&str>) {
+        match &mut self.widget {
+            FieldWidget::Input(input) => {
+                input.set_error(message);
+            }
+            FieldWidget::Checkbox { .. } => self.checkbox_error = message.map(str::to_string),
+        }
+    }
+}
+
+impl Drop for Form {
+    fn drop(&mut self) {
+        // Text boxes and buttons unregister themselves, the checkboxes are the form's own
+        for field in &self.fields {
+            if let FieldWidget::Checkbox { focus_id, .. } = field.widget {
+                focus::unregister(focus_id);
+            }
+        }
+    }
+}
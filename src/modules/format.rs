@@ -0,0 +1,161 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Helpers for showing numbers, durations and dates nicely (for labels, tables and the like)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod format;
+
+Add with the other use statements:
+    use crate::modules::format;
+
+Usage examples:
+    format::thousands(1234567)          // "1,234,567"
+    format::ordinal(22)                 // "22nd"
+    format::duration(9060.0)            // "2h 31m"
+    format::duration(45.0)              // "45s"
+    format::relative_time(-180.0)       // "3 min ago"
+    format::relative_time(7200.0)       // "in 2 h"
+
+    // created_at columns from the database ("2026-10-16T09:30:00+00:00")
+    let when = format::time_ago(&record.created_at).unwrap_or_default(); // "5 days ago"
+    let seconds = format::parse_timestamp(&record.created_at);          // Seconds since 1970
+
+    // In a label
+    lbl_out.set_text(format!("Level {} - {} points", new_record.level, format::thousands(score)));
+*/
+
+use macroquad::miniquad::date;
+
+/// Put a comma between every group of three digits
+#[allow(unused)]
+pub fn thousands(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    if value < 0 {
+        grouped.insert(0, '-');
+    }
+    grouped
+}
+
+/// A number with st, nd, rd or th after it
+#[allow(unused)]
+pub fn ordinal(value: i64) -> String {
+    let last_two = value.unsigned_abs() % 100;
+    let suffix = match (last_two % 10, last_two) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", value, suffix)
+}
+
+/// A length of time using its two biggest units, like "2h 31m" or "45s"
+#[allow(unused)]
+pub fn duration(seconds: f64) -> String {
+    let total = seconds.abs().floor() as u64;
+    let units = [(total / 86400, "d"), (total / 3600 % 24, "h"), (total / 60 % 60, "m"), (total % 60, "s")];
+    let parts: Vec<String> = units
+        .iter()
+        .skip_while(|(amount, _)| *amount == 0)
+        .take(2)
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, unit)| format!("{}{}", amount, unit))
+        .collect();
+    let text = if parts.is_empty() { String::from("0s") } else { parts.join(" ") };
+    if seconds < 0.0 { format!("-{}", text) } else { text }
+}
+
+/// How far away a time is in words: negative is the past ("3 min ago"), positive the future ("in 3 min")
+#[allow(unused)]
+pub fn relative_time(seconds_from_now: f64) -> String {
+    let seconds = seconds_from_now.abs();
+    let amount = if seconds < 45.0 {
+        return String::from("just now");
+    } else if seconds < 3600.0 {
+        format!("{} min", (seconds / 60.0).round().max(1.0))
+    } else if seconds < 86400.0 {
+        format!("{} h", (seconds / 3600.0).round())
+    } else if seconds < 172800.0 {
+        return String::from(if seconds_from_now < 0.0 { "yesterday" } else { "tomorrow" });
+    } else if seconds < 86400.0 * 30.0 {
+        format!("{} days", (seconds / 86400.0).round())
+    } else if seconds < 86400.0 * 365.0 {
+        let months = (seconds / (86400.0 * 30.0)).round();
+        format!("{} month{}", months, if months == 1.0 { "" } else { "s" })
+    } else {
+        let years = (seconds / (86400.0 * 365.0)).round();
+        format!("{} year{}", years, if years == 1.0 { "" } else { "s" })
+    };
+    if seconds_from_now < 0.0 { format!("{} ago", amount) } else { format!("in {}", amount) }
+}
+
+/// How long ago a timestamp from the database was, like "3 min ago" (None if it can't be read)
+#[allow(unused)]
+pub fn time_ago(timestamp: &str) -> Option<String> {
+    parse_timestamp(timestamp).map(|time| relative_time(time - date::now()))
+}
+
+/// Read an ISO 8601 timestamp ("2026-10-16T09:30:00.123+00:00", "2026-10-16 09:30:00Z" or
+/// just "2026-10-16") as seconds since 1970 in UTC
+#[allow(unused)]
+pub fn parse_timestamp(timestamp: &str) -> Option<f64> {
+    let timestamp = timestamp.trim();
+    let (date_part, time_part) = match timestamp.find(['T', ' ']) {
+        Some(split) => (&timestamp[..split], &timestamp[split + 1..]),
+        None => (timestamp, ""),
+    };
+
+    let mut date_fields = date_part.split('-');
+    let year: i64 = date_fields.next()?.parse().ok()?;
+    let month: i64 = date_fields.next()?.parse().ok()?;
+    let day: i64 = date_fields.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Split the time from its timezone (Z, +hh:mm or -hh:mm)
+    let (clock, offset_seconds) = match time_part.find(['Z', 'z', '+', '-']) {
+        Some(split) => (&time_part[..split], parse_offset(&time_part[split..])?),
+        None => (time_part, 0),
+    };
+    let mut clock_fields = clock.split(':');
+    let hours: f64 = clock_fields.next().filter(|h| !h.is_empty()).map_or(Some(0.0), |h| h.parse().ok())?;
+    let minutes: f64 = clock_fields.next().map_or(Some(0.0), |m| m.parse().ok())?;
+    let seconds: f64 = clock_fields.next().map_or(Some(0.0), |s| s.parse().ok())?;
+
+    let days = days_from_civil(year, month, day);
+    Some(days as f64 * 86400.0 + hours * 3600.0 + minutes * 60.0 + seconds - offset_seconds as f64)
+}
+
+// "+05:30", "-0800", "+02" or "Z" as seconds ahead of UTC
+fn parse_offset(offset: &str) -> Option<i64> {
+    let sign = match offset.chars().next()? {
+        'Z' | 'z' => return Some(0),
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits: String = offset[1..].chars().filter(char::is_ascii_digit).collect();
+    let hours: i64 = digits.get(..2)?.parse().ok()?;
+    let minutes: i64 = digits.get(2..4).map_or(Some(0), |m| m.parse().ok())?;
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+// Days from 1970-01-01 to a date (Howard Hinnant's days_from_civil)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
@@ -0,0 +1,83 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Caches how wide each character is so text widgets don't call measure_text for every
+character of their text every frame
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod glyph_cache;
+
+Add with the other use statements:
+    use crate::modules::glyph_cache;
+
+HOW IT WORKS:
+    Widths are stored by (font, font size, character) and shared by every widget, so a
+    character only has to be measured once. macroquad fonts can't be compared with each
+    other, so each custom font a widget is given gets its own id from new_font_id(). The
+    default font always uses id 0. Give an id back with forget_font_id when the widget
+    stops using that font, so its widths don't pile up.
+
+Usage examples (TextInput and TextArea already do this):
+    // When a widget is given a font
+    glyph_cache::forget_font_id(self.font_id);   // The font it had before
+    self.font = Some(font);
+    self.font_id = glyph_cache::new_font_id();
+
+    // Measuring text
+    let width = glyph_cache::text_width("Hello", self.font.as_ref(), self.font_id, 25);
+    let width = glyph_cache::char_width('H', self.font.as_ref(), self.font_id, 25);
+
+    // Free the memory if lots of fonts or sizes have been used
+    glyph_cache::clear();
+*/
+
+use macroquad::prelude::*;
+use crate::modules::font_fallback::measure_text;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+thread_local! {
+    static WIDTHS: RefCell<HashMap<(usize, u16, char), f32>> = RefCell::new(HashMap::new());
+    // Id 0 is the default font
+    static NEXT_FONT_ID: Cell<usize> = const { Cell::new(1) };
+}
+
+/// Get a new id for a custom font (call it whenever a widget's font changes)
+pub fn new_font_id() -> usize {
+    NEXT_FONT_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    })
+}
+
+/// Forget the widths measured for a font id that won't be used again (0, the default font, is kept)
+pub fn forget_font_id(id: usize) {
+    if id != 0 {
+        WIDTHS.with(|widths| widths.borrow_mut().retain(|(font_id, _, _), _| *font_id != id));
+    }
+}
+
+/// Width of one character, measured the first time and cached after that
+pub fn char_width(c: char, font: Option<&Font>, font_id: usize, font_size: u16) -> f32 {
+    // Without a font the default font is used, whatever id was passed in
+    let font_id = if font.is_some() { font_id } else { 0 };
+    let key = (font_id, font_size, c);
+    if let Some(width) = WIDTHS.with(|widths| widths.borrow().get(&key).copied()) {
+        return width;
+    }
+    let width = measure_text(c.encode_utf8(&mut [0; 4]), font, font_size, 1.0).width;
+    WIDTHS.with(|widths| widths.borrow_mut().insert(key, width));
+    width
+}
+
+/// Width of some text measured one character at a time
+pub fn text_width(text: &str, font: Option<&Font>, font_id: usize, font_size: u16) -> f32 {
+    text.chars().map(|c| char_width(c, font, font_id, font_size)).sum()
+}
+
+/// Forget every cached width
+#[allow(unused)]
+pub fn clear() {
+    WIDTHS.with(|widths| widths.borrow_mut().clear());
+}
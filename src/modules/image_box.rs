@@ -0,0 +1,257 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Shows an image (avatars, item icons) in a box, loading it in the background so the game keeps drawing
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod image_box;
+
+This also needs these modules:
+    pub mod assets;
+    pub mod scale;
+    pub mod spinner;
+    pub mod tasks;
+
+Add with the other use statements:
+    use crate::modules::image_box::{ImageBox, ImageFit};
+
+Then above the loop section:
+    let mut img_avatar = ImageBox::new(50.0, 50.0, 96.0, 96.0);
+    img_avatar.load("assets/avatar.png");
+Where the parameters are x, y, width and height. On native builds the path is a file,
+on the web it is fetched (relative to index.html, or a full URL). A grey box with a
+spinner shows until the image is ready (tasks::run_pending has to be called each frame).
+
+Then in the loop you would use:
+    img_avatar.draw();
+
+SCALING:
+    img_avatar.with_fit(ImageFit::Fit);      // Whole image shown, keeping its shape (default)
+    img_avatar.with_fit(ImageFit::Fill);     // Fills the box keeping its shape, edges cut off
+    img_avatar.with_fit(ImageFit::Stretch);  // Stretched to exactly the box
+
+OTHER METHODS:
+    img_avatar.set_texture(texture);                   // Show a texture you already have
+    img_avatar.clear();                                // Back to the empty placeholder
+    img_avatar.with_placeholder_color(LIGHTGRAY);      // Box color while loading or empty
+    img_avatar.with_tint(Color::new(1.0, 1.0, 1.0, 0.5)); // Multiply the image's colors (WHITE = unchanged)
+    img_avatar.set_position(10.0, 10.0);
+    img_avatar.set_size(64.0, 64.0);
+    if img_avatar.is_loading() { }
+    if let Some(error) = img_avatar.get_error() {
+        println!("Couldn't load the avatar: {}", error);
+    }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::assets;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::spinner::Spinner;
+use crate::modules::tasks::{self, TaskHandle};
+use crate::modules::theme::{self, ThemeColor};
+
+// How the image is sized to the box
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum ImageFit {
+    Fit,     // As big as possible while still showing the whole image
+    Fill,    // Covers the whole box, cutting off what doesn't fit
+    Stretch, // Exactly the size of the box, ignoring the image's shape
+}
+
+enum ImageState {
+    Empty,
+    Loading(TaskHandle<Result<Texture2D, String>>),
+    Loaded(Texture2D),
+    Failed(String),
+}
+
+pub struct ImageBox {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    fit: ImageFit,
+    state: ImageState,
+    placeholder_color: ThemeColor,
+    tint: Color,
+    spinner: Spinner,
+}
+
+impl ImageBox {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            fit: ImageFit::Fit,
+            state: ImageState::Empty,
+            placeholder_color: ThemeColor::SurfaceAlt,
+            tint: WHITE,
+            spinner: placeholder_spinner(x, y, width, height),
+        }
+    }
+
+    // Start loading an image in the background (replaces the one showing)
+    #[allow(unused)]
+    pub fn load(&mut self, path: &str) -> &mut Self {
+        let path = path.to_string();
+        self.state = ImageState::Loading(tasks::spawn(async move { assets::load_texture(&path).await.map_err(|e| e.to_string()) }));
+        self
+    }
+
+    // Show a texture that is already loaded
+    #[allow(unused)]
+    pub fn set_texture(&mut self, texture: Texture2D) -> &mut Self {
+        self.state = ImageState::Loaded(texture);
+        self
+    }
+
+    // Remove the image, leaving the placeholder
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        self.state = ImageState::Empty;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_fit(&mut self, fit: ImageFit) -> &mut Self {
+        self.fit = fit;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_placeholder_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.placeholder_color = color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_tint(&mut self, tint: Color) -> &mut Self {
+        self.tint = tint;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self.update_spinner();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self.update_spinner();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_loading(&self) -> bool {
+        matches!(self.state, ImageState::Loading(_))
+    }
+
+    #[allow(unused)]
+    pub fn is_loaded(&self) -> bool {
+        matches!(self.state, ImageState::Loaded(_))
+    }
+
+    // Why the last load failed, if it did
+    #[allow(unused)]
+    pub fn get_error(&self) -> Option<&str> {
+        match &self.state {
+            ImageState::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    // The texture showing, once it has loaded
+    #[allow(unused)]
+    pub fn get_texture(&self) -> Option<&Texture2D> {
+        match &self.state {
+            ImageState::Loaded(texture) => Some(texture),
+            _ => None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        // Pick up the texture once the background load finishes
+        if let ImageState::Loading(task) = &self.state {
+            if let Some(result) = task.take() {
+                self.state = match result {
+                    Ok(texture) => ImageState::Loaded(texture),
+                    Err(error) => ImageState::Failed(error),
+                };
+            }
+        }
+
+        match &self.state {
+            ImageState::Loaded(texture) => self.draw_texture(texture),
+            ImageState::Loading(_) => {
+                draw_rectangle(self.x, self.y, self.width, self.height, self.placeholder_color.color());
+                self.spinner.draw();
+            }
+            ImageState::Empty => draw_rectangle(self.x, self.y, self.width, self.height, self.placeholder_color.color()),
+            ImageState::Failed(_) => {
+                // Placeholder with a cross through it
+                draw_rectangle(self.x, self.y, self.width, self.height, self.placeholder_color.color());
+                let inset = self.width.min(self.height) * 0.3;
+                let (left, top) = (self.x + self.width / 2.0 - inset, self.y + self.height / 2.0 - inset);
+                draw_line(left, top, left + inset * 2.0, top + inset * 2.0, 2.0, theme::palette().text_muted);
+                draw_line(left + inset * 2.0, top, left, top + inset * 2.0, 2.0, theme::palette().text_muted);
+            }
+        }
+    }
+
+    fn draw_texture(&self, texture: &Texture2D) {
+        let (texture_width, texture_height) = (texture.width(), texture.height());
+        if texture_width <= 0.0 || texture_height <= 0.0 {
+            return;
+        }
+        let (width, height) = match self.fit {
+            ImageFit::Stretch => (self.width, self.height),
+            ImageFit::Fit => {
+                let scale = (self.width / texture_width).min(self.height / texture_height);
+                (texture_width * scale, texture_height * scale)
+            }
+            ImageFit::Fill => {
+                let scale = (self.width / texture_width).max(self.height / texture_height);
+                (texture_width * scale, texture_height * scale)
+            }
+        };
+        let params = DrawTextureParams {
+            dest_size: Some(Vec2::new(width, height)),
+            ..Default::default()
+        };
+        // Centered in the box, Fill is cut off at the box's edges
+        let clip = self.fit == ImageFit::Fill;
+        if clip {
+            push_clip_rect(Rect::new(self.x, self.y, self.width, self.height));
+        }
+        draw_texture_ex(
+            texture,
+            self.x + (self.width - width) / 2.0,
+            self.y + (self.height - height) / 2.0,
+            self.tint,
+            params,
+        );
+        if clip {
+            pop_clip_rect();
+        }
+    }
+
+    // Keep the loading spinner centered in the box
+    fn update_spinner(&mut self) {
+        self.spinner = placeholder_spinner(self.x, self.y, self.width, self.height);
+    }
+}
+
+// Spinner centered in a box, small enough to fit inside it
+fn placeholder_spinner(x: f32, y: f32, width: f32, height: f32) -> Spinner {
+    Spinner::new(x + width / 2.0, y + height / 2.0, (width.min(height) * 0.2).clamp(4.0, 20.0))
+}
@@ -0,0 +1,326 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Adds IME (input method) composition support so Chinese, Japanese, Korean and other
+composed text can be typed into text boxes
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod ime;
+
+This also needs the scale module (for placing the web input over the text box):
+    pub mod scale;
+
+Add the following to the web-sys features list in Cargo.toml:
+    "Document", "Element", "HtmlElement", "HtmlInputElement", "CssStyleDeclaration",
+    "Node", "Event", "EventTarget", "UiEvent", "KeyboardEvent", "KeyboardEventInit",
+    "CompositionEvent", "DomRect", "TouchEvent", "TouchList", "Touch"
+
+Add with the other use statements:
+    use crate::modules::ime;
+
+HOW IT WORKS:
+    macroquad only reports single key presses, so text that is built up with an input method
+    never reaches get_char_pressed(). On the web a hidden <input> element is placed at the
+    cursor of the active text box and given keyboard focus. The browser tells us the text
+    being composed (the "preedit", shown underlined at the cursor) and the final text once
+    the user confirms it. Ordinary keys like Backspace and the arrows are passed on to the
+    game canvas so everything else keeps working as before.
+    The same hidden input brings up the on-screen keyboard on phones and tablets. Mobile
+    browsers only open the keyboard when an input is focused during a touch, so text boxes
+    register where they are with set_tap_area() and a tap inside one focuses the input
+    straight from the browser's touch event.
+    On native builds this module does nothing: preedit() is always empty and nothing is
+    ever committed, typing goes through get_char_pressed() as usual.
+
+Usage examples (TextInput and TextArea already do this):
+    // Every frame while the box is active, with the cursor position in virtual coordinates
+    ime::enable(txt_input.focus_id(), cursor_x, cursor_y);
+    // When it is not active
+    ime::disable(txt_input.focus_id());
+
+    // Every frame while the box can be tapped (so touch screens get a keyboard)
+    ime::set_tap_area(txt_input.focus_id(), Rect::new(x, y, width, height));
+    // When it is disabled or removed
+    ime::remove_tap_area(txt_input.focus_id());
+
+    // Text to draw at the cursor while the user is still composing
+    let preedit = ime::preedit();
+
+    // Text the user confirmed, insert it at the cursor
+    if let Some(text) = ime::take_committed() {
+        // ...
+    }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale;
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    // Focus id of the text box that currently receives IME input
+    static OWNER: Cell<Option<usize>> = const { Cell::new(None) };
+    // Text being composed but not confirmed yet
+    static PREEDIT: RefCell<String> = const { RefCell::new(String::new()) };
+    // Confirmed text waiting to be inserted by the owner
+    static COMMITTED: RefCell<String> = const { RefCell::new(String::new()) };
+    // Screen rectangles of the text boxes, tapping one opens the on-screen keyboard
+    static TAP_AREAS: RefCell<Vec<(usize, Rect)>> = const { RefCell::new(Vec::new()) };
+
+    // True between compositionstart and compositionend
+    #[cfg(target_arch = "wasm32")]
+    static COMPOSING: Cell<bool> = const { Cell::new(false) };
+    #[cfg(target_arch = "wasm32")]
+    static HIDDEN_INPUT: RefCell<Option<web_sys::HtmlInputElement>> = const { RefCell::new(None) };
+}
+
+/// Route IME input to a text box, x/y is where its cursor is (virtual coordinates)
+pub fn enable(owner: usize, x: f32, y: f32) {
+    if OWNER.with(|current| current.replace(Some(owner))) != Some(owner) {
+        // A different box took over, drop anything half typed for the old one
+        PREEDIT.with(|preedit| preedit.borrow_mut().clear());
+        COMMITTED.with(|committed| committed.borrow_mut().clear());
+    }
+
+    let (x, y) = scale::world_to_screen(x, y);
+
+    #[cfg(target_arch = "wasm32")]
+    web::focus_hidden_input(x, y);
+
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = (x, y);
+}
+
+/// Stop routing IME input to a text box (does nothing if another box owns it)
+pub fn disable(owner: usize) {
+    if OWNER.with(|current| current.get()) != Some(owner) {
+        return;
+    }
+    OWNER.with(|current| current.set(None));
+    PREEDIT.with(|preedit| preedit.borrow_mut().clear());
+    COMMITTED.with(|committed| committed.borrow_mut().clear());
+
+    #[cfg(target_arch = "wasm32")]
+    web::release_hidden_input();
+}
+
+/// Let taps inside a text box (virtual coordinates) open the on-screen keyboard
+pub fn set_tap_area(owner: usize, area: Rect) {
+    let area = {
+        let (left, top) = scale::world_to_screen(area.x, area.y);
+        let (right, bottom) = scale::world_to_screen(area.x + area.w, area.y + area.h);
+        Rect::new(left, top, right - left, bottom - top)
+    };
+
+    TAP_AREAS.with(|areas| {
+        let mut areas = areas.borrow_mut();
+        match areas.iter_mut().find(|(id, _)| *id == owner) {
+            Some((_, existing)) => *existing = area,
+            None => areas.push((owner, area)),
+        }
+    });
+
+    #[cfg(target_arch = "wasm32")]
+    web::install_tap_listener();
+}
+
+/// Stop taps on a text box from opening the on-screen keyboard
+pub fn remove_tap_area(owner: usize) {
+    TAP_AREAS.with(|areas| areas.borrow_mut().retain(|(id, _)| *id != owner));
+}
+
+/// Text the user is composing right now (empty when not composing)
+pub fn preedit() -> String {
+    PREEDIT.with(|preedit| preedit.borrow().clone())
+}
+
+/// Take the text the user confirmed since the last call, if any
+pub fn take_committed() -> Option<String> {
+    let text = COMMITTED.with(|committed| std::mem::take(&mut *committed.borrow_mut()));
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use super::{COMMITTED, COMPOSING, HIDDEN_INPUT, PREEDIT, TAP_AREAS};
+    use macroquad::prelude::vec2;
+    use std::cell::Cell;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+    use web_sys::{CompositionEvent, Event, HtmlElement, HtmlInputElement, KeyboardEvent, KeyboardEventInit};
+
+    // Key code browsers report for keys the input method is handling
+    const IME_KEY_CODE: u32 = 229;
+
+    thread_local! {
+        static TAP_LISTENER_INSTALLED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    // The canvas macroquad draws to and listens for keys on
+    fn canvas() -> Option<web_sys::Element> {
+        web_sys::window()?.document()?.get_element_by_id("glcanvas")
+    }
+
+    // Create the hidden input and its event listeners
+    fn create_hidden_input() -> Option<HtmlInputElement> {
+        let document = web_sys::window()?.document()?;
+        let input: HtmlInputElement = document.create_element("input").ok()?.dyn_into().ok()?;
+        input.set_attribute("autocomplete", "off").ok()?;
+        let style = input.style();
+        for (property, value) in [
+            ("position", "fixed"),
+            ("opacity", "0"),
+            ("width", "1px"),
+            ("height", "1px"),
+            ("border", "none"),
+            ("padding", "0"),
+            ("pointer-events", "none"),
+            // iOS zooms the page into inputs with smaller text
+            ("font-size", "16px"),
+        ] {
+            style.set_property(property, value).ok()?;
+        }
+        document.body()?.append_child(&input).ok()?;
+
+        let on_start = Closure::<dyn FnMut(CompositionEvent)>::new(|_event: CompositionEvent| {
+            COMPOSING.with(|composing| composing.set(true));
+        });
+        input.add_event_listener_with_callback("compositionstart", on_start.as_ref().unchecked_ref()).ok()?;
+        on_start.forget();
+
+        let on_update = Closure::<dyn FnMut(CompositionEvent)>::new(|event: CompositionEvent| {
+            PREEDIT.with(|preedit| *preedit.borrow_mut() = event.data().unwrap_or_default());
+        });
+        input.add_event_listener_with_callback("compositionupdate", on_update.as_ref().unchecked_ref()).ok()?;
+        on_update.forget();
+
+        let target = input.clone();
+        let on_end = Closure::<dyn FnMut(CompositionEvent)>::new(move |event: CompositionEvent| {
+            COMPOSING.with(|composing| composing.set(false));
+            PREEDIT.with(|preedit| preedit.borrow_mut().clear());
+            COMMITTED.with(|committed| committed.borrow_mut().push_str(&event.data().unwrap_or_default()));
+            target.set_value("");
+        });
+        input.add_event_listener_with_callback("compositionend", on_end.as_ref().unchecked_ref()).ok()?;
+        on_end.forget();
+
+        // Plain typing (no composition) lands in the input too, pass it on as committed text
+        let target = input.clone();
+        let on_input = Closure::<dyn FnMut(Event)>::new(move |_event: Event| {
+            if COMPOSING.with(|composing| composing.get()) {
+                return;
+            }
+            COMMITTED.with(|committed| committed.borrow_mut().push_str(&target.value()));
+            target.set_value("");
+        });
+        input.add_event_listener_with_callback("input", on_input.as_ref().unchecked_ref()).ok()?;
+        on_input.forget();
+
+        // Copy key events to the canvas so macroquad still sees Backspace, arrows, Enter, etc.
+        for kind in ["keydown", "keyup"] {
+            let forward = Closure::<dyn FnMut(KeyboardEvent)>::new(|event: KeyboardEvent| {
+                if event.is_composing() || event.key_code() == IME_KEY_CODE {
+                    return;
+                }
+                let Some(canvas) = canvas() else {
+                    return;
+                };
+                let init = KeyboardEventInit::new();
+                init.set_bubbles(true);
+                init.set_cancelable(true);
+                init.set_code(&event.code());
+                init.set_key(&event.key());
+                init.set_ctrl_key(event.ctrl_key());
+                init.set_shift_key(event.shift_key());
+                init.set_alt_key(event.alt_key());
+                init.set_meta_key(event.meta_key());
+                init.set_repeat(event.repeat());
+                if let Ok(copy) = KeyboardEvent::new_with_keyboard_event_init_dict(&event.type_(), &init) {
+                    let _ = canvas.dispatch_event(&copy);
+                    // macroquad blocks the default for keys like space and Tab, do the same here
+                    // so they are not typed twice or move focus out of the page
+                    if copy.default_prevented() {
+                        event.prevent_default();
+                    }
+                }
+                // Shortcuts like ctrl+V are handled by the text boxes, don't let them edit the input too
+                if event.type_() == "keydown" && (event.ctrl_key() || event.meta_key()) {
+                    event.prevent_default();
+                }
+            });
+            input.add_event_listener_with_callback(kind, forward.as_ref().unchecked_ref()).ok()?;
+            forward.forget();
+        }
+
+        Some(input)
+    }
+
+    // Listen for taps on the canvas (once) and focus the hidden input when a text box is tapped
+    pub(super) fn install_tap_listener() {
+        if TAP_LISTENER_INSTALLED.with(|installed| installed.replace(true)) {
+            return;
+        }
+        let Some(canvas) = canvas() else {
+            return;
+        };
+        let on_tap = Closure::<dyn FnMut(web_sys::TouchEvent)>::new(|event: web_sys::TouchEvent| {
+            let (Some(touch), Some(canvas)) = (event.changed_touches().get(0), canvas()) else {
+                return;
+            };
+            let rect = canvas.get_bounding_client_rect();
+            let x = touch.client_x() as f32 - rect.left() as f32;
+            let y = touch.client_y() as f32 - rect.top() as f32;
+            let tapped = TAP_AREAS.with(|areas| areas.borrow().iter().any(|(_, area)| area.contains(vec2(x, y))));
+            if tapped {
+                // This has to happen inside the touch event, or the keyboard won't open
+                focus_hidden_input(x, y);
+            }
+        });
+        let _ = canvas.add_event_listener_with_callback("touchend", on_tap.as_ref().unchecked_ref());
+        on_tap.forget();
+    }
+
+    // Move the hidden input to the cursor (so the IME popup shows up there) and focus it
+    pub(super) fn focus_hidden_input(x: f32, y: f32) {
+        let input = HIDDEN_INPUT.with(|hidden| {
+            let mut hidden = hidden.borrow_mut();
+            if hidden.is_none() {
+                *hidden = create_hidden_input();
+            }
+            hidden.clone()
+        });
+        let Some(input) = input else {
+            return;
+        };
+
+        // Screen coordinates are relative to the canvas, the input is positioned on the page
+        let (left, top) = canvas().map_or((0.0, 0.0), |canvas| {
+            let rect = canvas.get_bounding_client_rect();
+            (rect.left() as f32, rect.top() as f32)
+        });
+        let style = input.style();
+        let _ = style.set_property("left", &format!("{}px", left + x));
+        let _ = style.set_property("top", &format!("{}px", top + y));
+
+        // Clicking the canvas gives it focus back, so check every frame
+        let focused = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element());
+        if focused.as_ref() != Some(input.unchecked_ref::<web_sys::Element>()) {
+            let _ = input.focus();
+        }
+    }
+
+    // Blur the hidden input and give keyboard focus back to the canvas
+    pub(super) fn release_hidden_input() {
+        COMPOSING.with(|composing| composing.set(false));
+        HIDDEN_INPUT.with(|hidden| {
+            if let Some(input) = hidden.borrow().as_ref() {
+                input.set_value("");
+                let _ = input.blur();
+            }
+        });
+        if let Some(canvas) = canvas().and_then(|canvas| canvas.dyn_into::<HtmlElement>().ok()) {
+            let _ = canvas.focus();
+        }
+    }
+}
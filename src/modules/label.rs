@@ -6,6 +6,10 @@ Adds a label object
 
 In your mod.rs file located in the modules folder add the following to the end of the file
         pub mod label;
+
+Add the following to Cargo.toml under [dependencies] (used to wrap text without
+splitting emoji or accented letters):
+    unicode-segmentation = "1.13"
     
 
 Add with the other use statements
@@ -20,6 +24,12 @@ Where the colors are text color and background color respectively.
 
 You can also specify a custom font with:
      lbl_out.with_font(font);
+Characters the font doesn't have (emoji, Chinese, ...) use the backup fonts loaded with
+the font_fallback module.
+
+You can change the space between lines of multi-line text with:
+     lbl_out.with_line_spacing(1.5);
+Where the value is the line height as a multiple of the font size (default 1.2).
 
 You can add rounded corners to the label with:
      lbl_out.with_round(10.0);
@@ -29,23 +39,89 @@ You can add a border to the label with:
      lbl_out.with_border(RED, 2.0);
 Where the first value is the border color and the second is the thickness.
 
+You can change the space between the text and the edge of the background with:
+     lbl_out.with_padding(12.0, 6.0);
+Where the values are the horizontal and vertical padding in pixels (default 5.0, 0.0).
+The text stays where it is and the background grows around it, so together with
+with_colors, with_round and with_border a label works as a status chip on its own:
+     lbl_status.with_colors(WHITE, Some(DARKGREEN)).with_padding(12.0, 6.0).with_round(8.0);
+
 You can set a fixed size for the label with:
      lbl_out.with_fixed_size(200.0, 100.0);
 Where the values are width and height in pixels.
 
-You can also set the text alignment within a fixed-size label with:
+You can also set the text alignment within the label with:
      lbl_out.with_alignment(modules::label::TextAlign::Center);
-Options are modules::label::TextAlign::Left, objects::label::TextAlign::Center, and objects::label::TextAlign::Right.
+Options are modules::label::TextAlign::Left, modules::label::TextAlign::Center, and modules::label::TextAlign::Right.
+Lines are lined up inside the label's box (its fixed width, or the width of its longest line).
+And the vertical alignment within a fixed-size label with:
+     lbl_out.with_vertical_alignment(modules::label::VerticalAlign::Middle);
+Options are modules::label::VerticalAlign::Top, modules::label::VerticalAlign::Middle, and modules::label::VerticalAlign::Bottom.
 
 To access the label's position:
      let x = lbl_out.get_x();
      let y = lbl_out.get_y();
      let position = lbl_out.get_position(); // Returns a Vec2 with both x and y
 
+To get the area the label covers on screen (its background box, including wrapping,
+font, line spacing and padding):
+     let area = lbl_out.measure(); // A Rect with x, y, w, h
+     txt_input.set_position(area.x, area.y + area.h + 10.0); // Put something just below it
+A bound label is measured with the text it had when it was last drawn.
+
 To change the label's position:
      lbl_out.set_position(150.0, 250.0);
 This changes the x and y coordinates of the label.
 
+To keep text readable over busy backgrounds, add an outline and/or a drop shadow:
+     lbl_out.with_outline(BLACK, 2.0);            // Outline color and thickness
+     lbl_out.with_shadow(Color::new(0.0, 0.0, 0.0, 0.6), 3.0, 3.0); // Shadow color and x, y offset
+     lbl_out.clear_outline();
+     lbl_out.clear_shadow();
+
+To wrap long text onto more lines automatically:
+     lbl_out.set_max_width(300.0);   // Lines break at spaces to stay within 300 pixels
+     lbl_out.clear_max_width();      // Back to only breaking at \n
+Words longer than the width are split wherever they need to be (no hyphen is added).
+
+To cut off text that is too long with "..." (e.g. long usernames in a table row):
+     lbl_out.with_truncate(150.0);   // Lines wider than 150 pixels end in an ellipsis
+     lbl_out.clear_truncate();
+     let cut = lbl_out.is_truncated();
+While the text is cut off, hovering over the label shows all of it in a tooltip (this needs
+the tooltip module in mod.rs and tooltip::draw() at the end of the loop). A custom font
+gets the single "…" character, the default font uses "..." since it doesn't have one.
+
+To shrink the text so it always fits in a box (e.g. usernames of any length):
+     lbl_out.with_fit_to_box(200.0, 40.0, 12); // Width, height, smallest font size allowed
+     lbl_out.clear_fit();
+The font starts at the label's font size and gets smaller until the text fits (or the
+smallest size is reached). Use with_fixed_size with the same size to draw the box.
+
+To make part of the text a clickable link (like "Forgot password?"):
+     lbl_out.add_link("Forgot password?", || println!("Reset password"));
+     lbl_out.with_link_color(SKYBLUE); // Default is the theme's primary color
+     lbl_out.clear_links();
+The first place the text appears in the label becomes the link. It is drawn in the link
+color, gets underlined while the mouse is over it and runs the closure when clicked. Links
+are looked up again when the text changes, so they keep working after set_text as long as
+the text is still in the label.
+
+To animate the label (e.g. a "Saved!" message that appears and then goes away):
+     lbl_out.fade_in(0.5);          // Fade from invisible to fully shown over 0.5 seconds
+     lbl_out.fade_out(1.0);         // Fade away over 1 second, then the label is hidden
+     lbl_out.typewriter(30.0);      // Reveal the text 30 letters per second
+     lbl_out.skip_animations();     // Jump every animation to its end
+     let busy = lbl_out.is_animating();
+To run code when an animation finishes:
+     lbl_out.on_animation_done(|animation| {
+         if animation == modules::label::LabelAnimation::FadeIn { println!("Shown"); }
+     });
+     lbl_out.clear_animation_done();
+Call typewriter after set_text, since it starts revealing from the first letter when called.
+This needs the animation module:
+        pub mod animation;
+
 To change the font size:
      lbl_out.set_font_size(24);
 This changes the font size of the label and recalculates its dimensions.
@@ -53,6 +129,26 @@ This changes the font size of the label and recalculates its dimensions.
 To change the label's text:
      lbl_out.set_text("New text content");
 
+To have the text work itself out every time the label is drawn (so it never needs set_text):
+     let level = Rc::new(Cell::new(1));
+     let mut lbl_level = Label::bound({
+         let level = level.clone();
+         move || format!("Level: {}", level.get())
+     }, 50.0, 100.0, 30);
+     level.set(level.get() + 1); // The label shows the new level next frame
+The closure has to own what it reads, so share values with Rc<Cell<...>> or Rc<RefCell<...>>.
+An existing label can be bound or unbound with:
+     lbl_out.bind_text(move || format!("Score: {}", score.get()));
+     lbl_out.unbind_text();
+Calling set_text also removes the binding. The label is only measured again when the text
+the closure returns is different from last time.
+
+To show translated text (see the locale module, which needs to be in mod.rs too):
+     let mut lbl_title = Label::from_key("login.title", 50.0, 50.0, 30);
+     lbl_out.bind_key("login.title"); // Same thing for an existing label
+These are bound labels that look their key up every draw, so locale::set_language
+changes them on the next frame.
+
 To control the visibility of a label:
      lbl_out.set_visible(false); // Hide the label
      lbl_out.set_visible(true);  // Show the label
@@ -72,27 +168,60 @@ Example:
             .with_round(8.0)
             .with_border(RED, 1.5)
             .with_fixed_size(250.0, 120.0)
-            .with_alignment(objects::label::TextAlign::Center)
+            .with_alignment(modules::label::TextAlign::Center)
+            .with_vertical_alignment(modules::label::VerticalAlign::Middle)
             .with_visibility(true); // Explicitly set visibility (default is true)
-Otherwise the default system font will be used.
+Otherwise the theme's font will be used (the default system font unless the theme has one).
 
 Then in the loop you would use:
     lbl_out.draw();
+draw() runs the animations, bound text and links and then draws. To do them apart:
+    lbl_out.update_only();
+    lbl_out.draw_only();
 */
 use macroquad::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use std::cell::RefCell;
+use std::ops::Range;
+use crate::modules::animation::{Easing, Tween};
+// Characters the font doesn't have are drawn with the backup fonts (see font_fallback)
+use crate::modules::font_fallback::{draw_text, draw_text_ex, measure_text};
+use crate::modules::locale;
+use crate::modules::theme::{self, draw_round_rect, draw_round_rect_lines, ThemeColor};
+use crate::modules::tooltip;
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Closure run when a link is clicked
+type LinkCallback = Box<dyn FnMut()>;
+
+// Closure that works out a bound label's text
+type TextSource = Box<dyn Fn() -> String>;
+
+// Closure run when an animation finishes, told which one it was
+type AnimationCallback = Box<dyn FnMut(LabelAnimation)>;
+
+// A clickable part of the label's text
+struct LabelLink {
+    text: String,                 // The text that is the link
+    range: Option<Range<usize>>,  // Where it is in the label's text (None if it isn't there)
+    on_click: RefCell<LinkCallback>,
+}
 
 pub struct Label {
     text: String,
     x: f32,
     y: f32,
-    font_size: u16,
-    foreground: Color,
+    font_size: u16,         // Size the text is drawn at (smaller than base_font_size when fitting a box)
+    base_font_size: u16,    // Size asked for with new/set_font_size
+    foreground: ThemeColor,
     background: Option<Color>,
     line_spacing: f32,
+    padding: Vec2,      // Space between the text and the edge of the background
     font: Option<Font>, // Store the font directly since Font is Clone
+    theme_font_id: usize, // The theme's font when the text was measured (for noticing a theme switch)
     corner_radius: f32, // For rounded corners
     border: bool,       // Whether to draw a border
-    border_color: Color, // Color of the border
+    border_color: ThemeColor, // Color of the border
     border_thickness: f32, // Thickness of the border
     visible: bool,      // Whether the label should be drawn
     
@@ -100,15 +229,34 @@ pub struct Label {
     fixed_width: Option<f32>,
     fixed_height: Option<f32>,
     text_align: TextAlign,
+    vertical_align: VerticalAlign,
+    max_width: Option<f32>, // Wrap lines wider than this
+    truncate_width: Option<f32>, // Cut lines wider than this and add an ellipsis
+    truncated: bool,        // Whether any line was cut when last measured
+    tooltip_id: usize,      // Id for the full text tooltip when truncated
+    outline: Option<(Color, f32)>, // Outline color and thickness around the text
+    fit: Option<(f32, f32, u16)>, // Box width, height and smallest font size for auto-fit
+    shadow: Option<(Color, Vec2)>, // Shadow color and offset
+    links: Vec<LabelLink>,
+    link_color: ThemeColor,
+    text_source: Option<TextSource>, // Works out the text every draw when bound
+    fade: Option<(Tween, LabelAnimation)>, // Alpha tween and whether it is fading in or out
+    alpha: f32,                       // Current fade, multiplied into every color drawn
+    typewriter: Option<(f64, f32)>,   // Time the reveal started and letters per second
+    on_animation_done: Option<AnimationCallback>,
+    hovered_link: Option<usize>,      // Link under the mouse as of the last update
     
     // Cached values for performance
     cached_lines: Vec<String>,
+    cached_line_starts: Vec<usize>, // Byte position in text where each cached line starts
+    cached_kept_lengths: Vec<usize>, // Bytes of each cached line taken from the text (the rest is a truncation ellipsis)
     cached_line_dimensions: Vec<TextDimensions>,
     cached_max_width: f32,
     cached_total_height: f32,
 }
 
-// Enum for text alignment within a fixed-size label
+// Enum for horizontal text alignment within the label
+#[derive(Clone, Copy, PartialEq, Debug)]
 #[allow(unused)]
 pub enum TextAlign {
     Left,
@@ -116,6 +264,24 @@ pub enum TextAlign {
     Right,
 }
 
+// Enum for vertical text alignment within a fixed-height label
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum VerticalAlign {
+    Top,
+    Middle,
+    Bottom,
+}
+
+// Animations a label can run
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum LabelAnimation {
+    FadeIn,
+    FadeOut,
+    Typewriter,
+}
+
 impl Label {
     // Constructor using x and y separately
     pub fn new<T: Into<String>>(text: T, x: f32, y: f32, font_size: u16) -> Self {
@@ -124,19 +290,40 @@ impl Label {
             x,
             y,
             font_size,
-            foreground: BLACK, // Default to black
+            base_font_size: font_size,
+            foreground: ThemeColor::Text, // Default to black
             background: None,  // No background by default
             line_spacing: 1.2,
-            font: None,        // Default to None (use system font)
+            padding: Vec2::new(5.0, 0.0),
+            font: None,        // Default to None (use the theme's font)
+            theme_font_id: theme::font_id(),
             corner_radius: 0.0, // Default to no rounded corners
             border: false,      // Default to no border
-            border_color: BLACK, // Default border color
+            border_color: ThemeColor::Border, // Default border color
             border_thickness: 1.0, // Default border thickness
             visible: true,      // Default to visible
             fixed_width: None, // No fixed width by default
             fixed_height: None, // No fixed height by default
             text_align: TextAlign::Left, // Default to left alignment
+            vertical_align: VerticalAlign::Top, // Default to the top
+            max_width: None, // No wrapping by default
+            truncate_width: None,
+            truncated: false,
+            tooltip_id: tooltip::new_id(),
+            outline: None,
+            fit: None,
+            shadow: None,
+            links: Vec::new(),
+            link_color: ThemeColor::Primary,
+            text_source: None,
+            fade: None,
+            alpha: 1.0,
+            typewriter: None,
+            on_animation_done: None,
+            hovered_link: None,
             cached_lines: Vec::new(),
+            cached_line_starts: Vec::new(),
+            cached_kept_lengths: Vec::new(),
             cached_line_dimensions: Vec::new(),
             cached_max_width: 0.0,
             cached_total_height: 0.0,
@@ -148,10 +335,60 @@ impl Label {
         label
     }
     
-    // Calculate and cache text dimensions
+    // Constructor for a label whose text comes from a closure, run again every draw
+    #[allow(unused)]
+    pub fn bound<F: Fn() -> String + 'static>(source: F, x: f32, y: f32, font_size: u16) -> Self {
+        let mut label = Self::new(source(), x, y, font_size);
+        label.text_source = Some(Box::new(source));
+        label
+    }
+
+    // Constructor for a label showing the translation of a key in the current language
+    #[allow(unused)]
+    pub fn from_key(key: &str, x: f32, y: f32, font_size: u16) -> Self {
+        let key = key.to_string();
+        Self::bound(move || locale::tr(&key), x, y, font_size)
+    }
+
+    // Calculate and cache text dimensions, shrinking the font first if it has to fit a box
     fn calculate_text_dimensions(&mut self) {
-        // Split text into lines and store for later use
-        self.cached_lines = self.text.split('\n').map(String::from).collect();
+        let Some((fit_width, fit_height, min_font_size)) = self.fit else {
+            self.measure_lines();
+            return;
+        };
+
+        // Try the requested size first, then one size smaller at a time until it fits
+        self.font_size = self.base_font_size;
+        loop {
+            self.measure_lines();
+            let widest = self.cached_line_dimensions.iter().map(|d| d.width).fold(0.0, f32::max);
+            let total_height = self.cached_lines.len() as f32 * self.font_size as f32 * self.line_spacing;
+            let fits = widest + self.padding.x * 2.0 <= fit_width && total_height + self.padding.y * 2.0 <= fit_height;
+            if fits || self.font_size <= min_font_size.max(1) {
+                break;
+            }
+            self.font_size -= 1;
+        }
+    }
+
+    // The label's own font, or the theme's when it has none
+    fn current_font(&self) -> Option<Font> {
+        self.font.clone().or_else(theme::font)
+    }
+
+    // Measure each line at the current font size
+    fn measure_lines(&mut self) {
+        // Split text into lines (wrapping them if there is a max width) and store for later use
+        self.cached_lines = match self.max_width {
+            Some(max_width) => self.text.split('\n').flat_map(|line| self.wrap_line(line, max_width)).collect(),
+            None => self.text.split('\n').map(String::from).collect(),
+        };
+        self.find_line_starts();
+        self.cached_kept_lengths = self.cached_lines.iter().map(String::len).collect();
+        self.truncate_lines();
+        for link in &mut self.links {
+            link.range = self.text.find(&link.text).map(|start| start..start + link.text.len());
+        }
         let line_height = self.font_size as f32 * self.line_spacing;
         
         // Clear previous cached values
@@ -159,11 +396,10 @@ impl Label {
         self.cached_max_width = 0.0;
         
         // Calculate dimensions for each line
+        let font = self.current_font();
+        self.theme_font_id = theme::font_id();
         for line in &self.cached_lines {
-            let dimensions = match &self.font {
-                Some(font) => measure_text(line, Some(font), self.font_size, 1.0),
-                None => measure_text(line, None, self.font_size, 1.0),
-            };
+            let dimensions = measure_text(line, font.as_ref(), self.font_size, 1.0);
             self.cached_line_dimensions.push(dimensions);
             
             // Only update max_width if we don't have a fixed width
@@ -178,10 +414,90 @@ impl Label {
         }
     }
 
+    // Work out where each cached line starts in the text (wrapping drops the space it breaks at)
+    fn find_line_starts(&mut self) {
+        self.cached_line_starts.clear();
+        let mut line_start = 0;
+        let mut pieces = self.cached_lines.iter();
+        for source_line in self.text.split('\n') {
+            let mut cursor = 0;
+            while cursor <= source_line.len() {
+                let Some(piece) = pieces.next() else { return };
+                if !source_line[cursor..].starts_with(piece.as_str()) {
+                    cursor += 1; // Skip the space the line was broken at
+                }
+                self.cached_line_starts.push(line_start + cursor);
+                cursor += piece.len();
+                if cursor >= source_line.len() {
+                    break;
+                }
+            }
+            line_start += source_line.len() + 1;
+        }
+    }
+
+    // Cut lines wider than the truncate width, ending them in an ellipsis
+    fn truncate_lines(&mut self) {
+        self.truncated = false;
+        let Some(max_width) = self.truncate_width else { return };
+        // The default font doesn't have the single character version
+        let font = self.current_font();
+        let font_size = self.font_size;
+        let ellipsis = if font.is_some() { "…" } else { "..." };
+        let width_of = |text: &str| measure_text(text, font.as_ref(), font_size, 1.0).width;
+        for (line, kept) in self.cached_lines.iter_mut().zip(self.cached_kept_lengths.iter_mut()) {
+            if width_of(line) <= max_width {
+                continue;
+            }
+            self.truncated = true;
+            // Drop letters off the end until it fits with the ellipsis
+            let mut end = line.len();
+            while end > 0 && width_of(&format!("{}{}", line[..end].trim_end(), ellipsis)) > max_width {
+                end = line[..end].grapheme_indices(true).next_back().map_or(0, |(index, _)| index);
+            }
+            *kept = line[..end].trim_end().len();
+            *line = format!("{}{}", &line[..*kept], ellipsis);
+        }
+    }
+
+    // Break one line into pieces no wider than max_width, at spaces where possible
+    fn wrap_line(&self, line: &str, max_width: f32) -> Vec<String> {
+        let width_of = |text: &str| measure_text(text, self.current_font().as_ref(), self.font_size, 1.0).width;
+        let mut lines = Vec::new();
+        let mut current = String::new();
+
+        for word in line.split(' ') {
+            let candidate = if current.is_empty() { word.to_string() } else { format!("{} {}", current, word) };
+            if width_of(&candidate) <= max_width || current.is_empty() {
+                current = candidate;
+            } else {
+                lines.push(std::mem::replace(&mut current, word.to_string()));
+            }
+
+            // A single word wider than the line gets split wherever it has to be
+            while width_of(&current) > max_width {
+                let mut split_at = 0;
+                for (index, grapheme) in current.grapheme_indices(true) {
+                    if index > 0 && width_of(&current[..index + grapheme.len()]) > max_width {
+                        break;
+                    }
+                    split_at = index + grapheme.len();
+                }
+                if split_at >= current.len() {
+                    break;
+                }
+                let rest = current.split_off(split_at);
+                lines.push(std::mem::replace(&mut current, rest));
+            }
+        }
+        lines.push(current);
+        lines
+    }
+
     // Method to set foreground and background colors
     #[allow(unused)]
-    pub fn with_colors(&mut self, foreground: Color, background: Option<Color>) -> &mut Self {
-        self.foreground = foreground;
+    pub fn with_colors(&mut self, foreground: impl Into<ThemeColor>, background: Option<Color>) -> &mut Self {
+        self.foreground = foreground.into();
         self.background = background;
         self
     }
@@ -195,6 +511,82 @@ impl Label {
         self
     }
 
+    // Method to set the line height as a multiple of the font size
+    #[allow(unused)]
+    pub fn with_line_spacing(&mut self, spacing: f32) -> &mut Self {
+        self.line_spacing = spacing.max(0.1);
+        // Recalculate dimensions since the spacing changes the label's height
+        self.calculate_text_dimensions();
+        self
+    }
+
+    // Getter for the line spacing multiplier
+    #[allow(unused)]
+    pub fn get_line_spacing(&self) -> f32 {
+        self.line_spacing
+    }
+
+    // Method to draw an outline around the text
+    #[allow(unused)]
+    pub fn with_outline(&mut self, color: Color, thickness: f32) -> &mut Self {
+        self.outline = Some((color, thickness));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_outline(&mut self) -> &mut Self {
+        self.outline = None;
+        self
+    }
+
+    // Method to draw a shadow behind the text, offset by x, y pixels
+    #[allow(unused)]
+    pub fn with_shadow(&mut self, color: Color, offset_x: f32, offset_y: f32) -> &mut Self {
+        self.shadow = Some((color, Vec2::new(offset_x, offset_y)));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_shadow(&mut self) -> &mut Self {
+        self.shadow = None;
+        self
+    }
+
+    // Method to make the first place `text` appears a link that runs on_click when clicked
+    #[allow(unused)]
+    pub fn add_link<F: FnMut() + 'static>(&mut self, text: &str, on_click: F) -> &mut Self {
+        self.links.push(LabelLink {
+            text: text.to_string(),
+            range: None,
+            on_click: RefCell::new(Box::new(on_click)),
+        });
+        self.calculate_text_dimensions();
+        self
+    }
+
+    // Method to remove every link
+    #[allow(unused)]
+    pub fn clear_links(&mut self) -> &mut Self {
+        self.links.clear();
+        self
+    }
+
+    // Method to set the color links are drawn in
+    #[allow(unused)]
+    pub fn with_link_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.link_color = color.into();
+        self
+    }
+
+    // Method to set the space between the text and the edge of the background
+    #[allow(unused)]
+    pub fn with_padding(&mut self, horizontal: f32, vertical: f32) -> &mut Self {
+        self.padding = Vec2::new(horizontal.max(0.0), vertical.max(0.0));
+        // Recalculate in case the font has to shrink to fit a box
+        self.calculate_text_dimensions();
+        self
+    }
+
     // Method to set rounded corners
     #[allow(unused)]
     pub fn with_round(&mut self, radius: f32) -> &mut Self {
@@ -204,9 +596,9 @@ impl Label {
 
     // Method to add border with custom color and thickness
     #[allow(unused)]
-    pub fn with_border(&mut self, color: Color, thickness: f32) -> &mut Self {
+    pub fn with_border(&mut self, color: impl Into<ThemeColor>, thickness: f32) -> &mut Self {
         self.border = true;
-        self.border_color = color;
+        self.border_color = color.into();
         self.border_thickness = thickness;
         self
     }
@@ -226,17 +618,80 @@ impl Label {
         self
     }
     
-    // Method to set text alignment (only applies when using fixed width)
+    // Method to set horizontal text alignment within the label's box
     #[allow(unused)]
     pub fn with_alignment(&mut self, alignment: TextAlign) -> &mut Self {
         self.text_align = alignment;
         self
     }
 
+    // Method to set vertical text alignment (only changes anything with a fixed height)
+    #[allow(unused)]
+    pub fn with_vertical_alignment(&mut self, alignment: VerticalAlign) -> &mut Self {
+        self.vertical_align = alignment;
+        self
+    }
+
+    // Method to wrap text so no line is wider than the given width
+    #[allow(unused)]
+    pub fn set_max_width(&mut self, width: f32) -> &mut Self {
+        self.max_width = Some(width.max(1.0));
+        self.calculate_text_dimensions();
+        self
+    }
+
+    // Method to stop wrapping (lines only break at \n)
+    #[allow(unused)]
+    pub fn clear_max_width(&mut self) -> &mut Self {
+        self.max_width = None;
+        self.calculate_text_dimensions();
+        self
+    }
+
+    // Method to cut lines wider than the given width and end them with an ellipsis
+    #[allow(unused)]
+    pub fn with_truncate(&mut self, width: f32) -> &mut Self {
+        self.truncate_width = Some(width.max(1.0));
+        self.calculate_text_dimensions();
+        self
+    }
+
+    // Method to stop cutting lines off
+    #[allow(unused)]
+    pub fn clear_truncate(&mut self) -> &mut Self {
+        self.truncate_width = None;
+        self.calculate_text_dimensions();
+        self
+    }
+
+    // Whether any line is currently cut off
+    #[allow(unused)]
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    // Method to shrink the font (not below min_font_size) until the text fits in width x height
+    #[allow(unused)]
+    pub fn with_fit_to_box(&mut self, width: f32, height: f32, min_font_size: u16) -> &mut Self {
+        self.fit = Some((width, height, min_font_size));
+        self.calculate_text_dimensions();
+        self
+    }
+
+    // Method to stop fitting and go back to the normal font size
+    #[allow(unused)]
+    pub fn clear_fit(&mut self) -> &mut Self {
+        self.fit = None;
+        self.font_size = self.base_font_size;
+        self.calculate_text_dimensions();
+        self
+    }
+
     // Method to set text - now accepts both String and &str
     #[allow(unused)]
     pub fn set_text<T: Into<String>>(&mut self, new_text: T) -> &mut Self {
         self.text = new_text.into();
+        self.text_source = None;
         
         // Only recalculate if we need to (when not using fixed dimensions)
         // Even with fixed dimensions, we still need to recalculate line dimensions
@@ -245,12 +700,165 @@ impl Label {
         
         self
     }
-     // Getter for width (fixed width or max content width)
+
+    // Method to get the text from a closure every draw instead of set_text
+    #[allow(unused)]
+    pub fn bind_text<F: Fn() -> String + 'static>(&mut self, source: F) -> &mut Self {
+        self.text_source = Some(Box::new(source));
+        self.refresh_bound_text();
+        self
+    }
+
+    // Method to show the translation of a key, following language changes
+    #[allow(unused)]
+    pub fn bind_key(&mut self, key: &str) -> &mut Self {
+        let key = key.to_string();
+        self.bind_text(move || locale::tr(&key))
+    }
+
+    // Method to stop the closure from changing the text (it keeps its current text)
+    #[allow(unused)]
+    pub fn unbind_text(&mut self) -> &mut Self {
+        self.text_source = None;
+        self
+    }
+
+    // Whether the text comes from bind_text or bind_key
+    #[allow(unused)]
+    pub fn is_bound(&self) -> bool {
+        self.text_source.is_some()
+    }
+
+    // Run the text closure and re-measure if it gave something new
+    fn refresh_bound_text(&mut self) {
+        if let Some(source) = &self.text_source {
+            let text = source();
+            if text != self.text {
+                self.text = text;
+                self.calculate_text_dimensions();
+            }
+        }
+    }
+
+    // Method to fade the label in from invisible (shows it if it was hidden)
+    #[allow(unused)]
+    pub fn fade_in(&mut self, duration: f32) -> &mut Self {
+        let mut tween = Tween::new(0.0, 1.0, duration, Easing::EaseOut);
+        tween.start();
+        self.fade = Some((tween, LabelAnimation::FadeIn));
+        self.alpha = 0.0;
+        self.visible = true;
+        self
+    }
+
+    // Method to fade the label out, it is hidden once the fade finishes
+    #[allow(unused)]
+    pub fn fade_out(&mut self, duration: f32) -> &mut Self {
+        let mut tween = Tween::new(self.alpha, 0.0, duration, Easing::EaseIn);
+        tween.start();
+        self.fade = Some((tween, LabelAnimation::FadeOut));
+        self
+    }
+
+    // Method to reveal the text a few letters at a time
+    #[allow(unused)]
+    pub fn typewriter(&mut self, letters_per_second: f32) -> &mut Self {
+        self.typewriter = Some((get_time(), letters_per_second.max(0.1)));
+        self.visible = true;
+        self
+    }
+
+    // Method to finish every animation straight away (completion callbacks still run)
+    #[allow(unused)]
+    pub fn skip_animations(&mut self) -> &mut Self {
+        if let Some((tween, _)) = &mut self.fade {
+            tween.stop();
+        }
+        if let Some((start, _)) = &mut self.typewriter {
+            *start = f64::MIN;
+        }
+        self.update_animations();
+        self
+    }
+
+    // Whether a fade or typewriter is still running
+    #[allow(unused)]
+    pub fn is_animating(&self) -> bool {
+        self.fade.is_some() || self.typewriter.is_some()
+    }
+
+    // Method to set a closure that runs when any animation finishes
+    #[allow(unused)]
+    pub fn on_animation_done<F: FnMut(LabelAnimation) + 'static>(&mut self, callback: F) -> &mut Self {
+        self.on_animation_done = Some(Box::new(callback));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_animation_done(&mut self) -> &mut Self {
+        self.on_animation_done = None;
+        self
+    }
+
+    // Move the fade and typewriter along, running the callback for any that finish
+    fn update_animations(&mut self) {
+        let mut finished = Vec::new();
+        if let Some((tween, kind)) = self.fade {
+            self.alpha = tween.value();
+            if tween.is_finished() {
+                self.fade = None;
+                if kind == LabelAnimation::FadeOut {
+                    // Hidden now, so it's back to full alpha for the next set_visible(true)
+                    self.visible = false;
+                    self.alpha = 1.0;
+                }
+                finished.push(kind);
+            }
+        }
+        if self.typewriter.is_some() && self.revealed_letters() >= self.total_letters() {
+            self.typewriter = None;
+            finished.push(LabelAnimation::Typewriter);
+        }
+        if let Some(callback) = &mut self.on_animation_done {
+            for kind in finished {
+                callback(kind);
+            }
+        }
+    }
+
+    // How many letters the typewriter has shown so far (all of them when it isn't running)
+    fn revealed_letters(&self) -> usize {
+        match self.typewriter {
+            Some((start, speed)) => ((get_time() - start).max(0.0) * speed as f64) as usize,
+            None => usize::MAX,
+        }
+    }
+
+    fn total_letters(&self) -> usize {
+        self.cached_lines.iter().map(|line| line.graphemes(true).count()).sum()
+    }
+
+    // Byte length of each cached line that the typewriter has revealed
+    fn revealed_line_ends(&self) -> Vec<usize> {
+        let mut remaining = self.revealed_letters();
+        self.cached_lines.iter().map(|line| {
+            let end = line.grapheme_indices(true).nth(remaining).map_or(line.len(), |(index, _)| index);
+            remaining = remaining.saturating_sub(line.graphemes(true).count());
+            end
+        }).collect()
+    }
+
+    // Color with the current fade applied
+    fn faded(&self, color: Color) -> Color {
+        Color::new(color.r, color.g, color.b, color.a * self.alpha)
+    }
+
+    // Getter for width (fixed width or max content width)
     #[allow(unused)]
     pub fn get_width(&self) -> Option<f32> {
         match self.fixed_width {
             Some(width) => Some(width),
-            None => Some(self.cached_max_width + 10.0) // Same padding as in draw method
+            None => Some(self.cached_max_width + self.padding.x * 2.0) // Same padding as in draw method
         }
     }
     
@@ -259,11 +867,20 @@ impl Label {
     pub fn get_height(&self) -> Option<f32> {
         match self.fixed_height {
             Some(height) => Some(height),
-            None => Some(self.cached_total_height)
+            None => Some(self.cached_total_height + self.padding.y * 2.0)
         }
     }
     
-    // Getter for font size
+    // Area the label covers (its background box), for lining other things up with it
+    #[allow(unused)]
+    pub fn measure(&self) -> Rect {
+        // Determine width and height (using fixed values if set, otherwise use content size)
+        let width = self.fixed_width.unwrap_or(self.cached_max_width + self.padding.x * 2.0);
+        let height = self.fixed_height.unwrap_or(self.cached_total_height + self.padding.y * 2.0);
+        Rect::new(self.x - self.padding.x, self.y - self.font_size as f32 - self.padding.y, width, height)
+    }
+
+    // Getter for font size (the shrunk size when fitting a box)
     #[allow(unused)]
     pub fn get_font_size(&self) -> u16 {
         self.font_size
@@ -311,6 +928,7 @@ impl Label {
     #[allow(unused)]
     pub fn set_font_size(&mut self, font_size: u16) -> &mut Self {
         self.font_size = font_size;
+        self.base_font_size = font_size;
         
         // Recalculate text dimensions since font size affects text measurements
         self.calculate_text_dimensions();
@@ -332,25 +950,55 @@ impl Label {
         self.visible
     }
     
-    // Method to draw the label
-    pub fn draw(&self) {
-        // Only draw if the label is visible
+    // Primary method - both updates and draws the label
+    pub fn draw(&mut self) {
+        self.update_internal();
+        self.draw_internal();
+    }
+
+    // For cases when only updating is needed without drawing (animations, bound text, links)
+    #[allow(unused)]
+    pub fn update_only(&mut self) {
+        self.update_internal();
+    }
+
+    // For cases when only drawing is needed without updating
+    #[allow(unused)]
+    pub fn draw_only(&self) {
+        self.draw_internal();
+    }
+
+    fn update_internal(&mut self) {
+        self.update_animations();
+        self.hovered_link = None;
+        // Only update if the label is visible
         if !self.visible {
             return;
         }
-        
+        self.refresh_bound_text();
+        // Measure again if the theme's font was switched since
+        if self.font.is_none() && self.theme_font_id != theme::font_id() {
+            self.calculate_text_dimensions();
+        }
+
+        // Show all the text in a tooltip when some of it is cut off
+        if self.truncated {
+            tooltip::register(self.tooltip_id, self.measure(), &self.text);
+        }
         let line_height = self.font_size as f32 * self.line_spacing;
-        
-        // Determine width and height (using fixed values if set, otherwise use content size)
-        let width = self.fixed_width.unwrap_or(self.cached_max_width + 10.0);
-        let height = self.fixed_height.unwrap_or(self.cached_total_height);
-        
+        self.hovered_link = self.update_links(&self.line_positions(), line_height);
+    }
+
+    fn draw_internal(&self) {
+        // Only draw if the label is visible
+        if !self.visible {
+            return;
+        }
         // Calculate positions for all elements
-        let bg_x = self.x - 5.0;
-        let bg_y = self.y - self.font_size as f32;
-        
+        let Rect { x: bg_x, y: bg_y, w: width, h: height } = self.measure();
+
         // Draw background first
-        if let Some(bg) = self.background {
+        if let Some(bg) = self.background.map(|bg| self.faded(bg)) {
             // Draw a single background for all lines
             if self.corner_radius > 0.0 {
                 draw_round_rect(
@@ -368,87 +1016,163 @@ impl Label {
         
         // Draw border if enabled
         if self.border {
-            // Get background color for the inner part of the border
-            let bg_color = self.background.unwrap_or(GRAY);
+            let border_color = self.faded(self.border_color.color());
             
             if self.corner_radius > 0.0 {
-                // Draw rounded border with the correct background color
-                draw_round_rect_border(
-                    bg_x, bg_y, width, height,
-                    self.corner_radius,
+                // Draw rounded border just inside the edge, leaving the inside as it is
+                let half = self.border_thickness / 2.0;
+                draw_round_rect_lines(
+                    bg_x + half, bg_y + half, width - self.border_thickness, height - self.border_thickness,
+                    (self.corner_radius - half).max(0.0),
                     self.border_thickness,
-                    self.border_color,
-                    bg_color,
+                    border_color,
                 );
             } else {
                 // Draw regular rectangular border
                 draw_rectangle_border(
                     bg_x, bg_y, width, height,
                     self.border_thickness,
-                    self.border_color,
+                    border_color,
                 );
             }
         }
 
+        let positions = self.line_positions();
+        let hovered_link = self.hovered_link;
+        let revealed_ends = self.revealed_line_ends();
+
         // Draw each line of text
-        for (i, (line, dimensions)) in self.cached_lines.iter().zip(self.cached_line_dimensions.iter()).enumerate() {
-            let y = self.y + i as f32 * line_height;
+        for (i, (full_line, position)) in self.cached_lines.iter().zip(positions.iter()).enumerate() {
+            let (x, y) = (position.x, position.y);
+            // Only the part the typewriter has reached
+            let line = &full_line[..revealed_ends[i]];
+            if line.is_empty() {
+                continue;
+            }
             
-            // Calculate x position based on alignment (if fixed width is set)
-            let x = if let Some(fixed_width) = self.fixed_width {
-                match self.text_align {
-                    TextAlign::Left => self.x,
-                    TextAlign::Center => self.x + (fixed_width / 2.0) - (dimensions.width / 2.0),
-                    TextAlign::Right => self.x + fixed_width - dimensions.width - 10.0, // 10.0 for padding
+            // Shadow first, then the outline, so the text sits on top of both
+            if let Some((color, offset)) = self.shadow {
+                self.draw_line_text(line, x + offset.x, y + offset.y, color);
+            }
+            if let Some((color, thickness)) = self.outline {
+                // Copies of the text around a circle make the outline
+                let steps = ((thickness * 4.0).ceil() as usize).clamp(8, 32);
+                for step in 0..steps {
+                    let angle = step as f32 / steps as f32 * std::f32::consts::TAU;
+                    self.draw_line_text(line, x + angle.cos() * thickness, y + angle.sin() * thickness, color);
                 }
-            } else {
-                self.x
-            };
+            }
+            // The text itself, with links in their own color
+            for (range, link) in self.line_segments(i) {
+                let range = range.start.min(line.len())..range.end.min(line.len());
+                if range.is_empty() {
+                    continue;
+                }
+                let segment_x = x + self.text_width(&line[..range.start]);
+                let color = if link.is_some() { self.link_color.color() } else { self.foreground.color() };
+                self.draw_line_text(&line[range.clone()], segment_x, y, color);
+                if link.is_some() && link == hovered_link {
+                    let underline_y = y + self.font_size as f32 * 0.15;
+                    let underline_width = self.text_width(&line[range]);
+                    draw_line(segment_x, underline_y, segment_x + underline_width, underline_y, (self.font_size as f32 / 16.0).max(1.0), color);
+                }
+            }
+        }
+    }
+
+    // Where each line of text starts
+    fn line_positions(&self) -> Vec<Vec2> {
+        let line_height = self.font_size as f32 * self.line_spacing;
+        let Rect { x: bg_x, w: width, h: height, .. } = self.measure();
+
+        // Move the block of lines down for middle/bottom alignment
+        let content_height = self.cached_lines.len() as f32 * line_height;
+        let inner_height = height - self.padding.y * 2.0;
+        let y_offset = match self.vertical_align {
+            VerticalAlign::Top => 0.0,
+            VerticalAlign::Middle => (inner_height - content_height) / 2.0,
+            VerticalAlign::Bottom => inner_height - content_height,
+        };
+
+        self.cached_line_dimensions.iter().enumerate().map(|(i, dimensions)| {
+            let y = self.y + y_offset + i as f32 * line_height;
             
-            // Draw the text - use draw_text_ex if we have a custom font
-            match &self.font {
-                Some(font) => {
-                    draw_text_ex(
-                        line,
-                        x,
-                        y,
-                        TextParams {
-                            font: Some(font),
-                            font_size: self.font_size,
-                            color: self.foreground,
-                            ..Default::default()
-                        },
-                    );
-                },
-                None => {
-                    // Use the default draw_text function
-                    draw_text(line, x, y, self.font_size as f32, self.foreground);
+            // Calculate x position based on alignment within the box (padding each side)
+            let x = match self.text_align {
+                TextAlign::Left => self.x,
+                TextAlign::Center => bg_x + (width / 2.0) - (dimensions.width / 2.0),
+                TextAlign::Right => bg_x + width - dimensions.width - self.padding.x,
+            };
+            Vec2::new(x, y)
+        }).collect()
+    }
+
+    // Split a cached line into plain and link parts, as (byte range in the line, link index)
+    fn line_segments(&self, line_index: usize) -> Vec<(Range<usize>, Option<usize>)> {
+        let line = &self.cached_lines[line_index];
+        let line_start = self.cached_line_starts.get(line_index).copied().unwrap_or(0);
+        let kept = self.cached_kept_lengths.get(line_index).copied().unwrap_or(line.len());
+        let links: Vec<(Range<usize>, usize)> = self.links.iter().enumerate().filter_map(|(index, link)| Some((link.range.clone()?, index))).collect();
+        split_links(line.len(), kept, line_start, &links)
+    }
+
+    // Find the link under the mouse and run it when clicked, returns its index
+    fn update_links(&self, positions: &[Vec2], line_height: f32) -> Option<usize> {
+        if self.links.is_empty() {
+            return None;
+        }
+        let mouse = Vec2::from(mouse_position());
+        let mut hovered = None;
+        for (i, position) in positions.iter().enumerate() {
+            for (range, link) in self.line_segments(i) {
+                let Some(link) = link else { continue };
+                let line = &self.cached_lines[i];
+                let rect = Rect::new(
+                    position.x + self.text_width(&line[..range.start]),
+                    position.y - self.font_size as f32 * 0.8,
+                    self.text_width(&line[range]),
+                    line_height,
+                );
+                if rect.contains(mouse) {
+                    hovered = Some(link);
                 }
             }
         }
+        if let Some(link) = hovered {
+            if is_mouse_button_pressed(MouseButton::Left) {
+                (self.links[link].on_click.borrow_mut())();
+            }
+        }
+        hovered
     }
-}
 
-// Function to draw a rectangle with rounded corners - optimized version
-fn draw_round_rect(x: f32, y: f32, w: f32, h: f32, radius: f32, color: Color) {
-    // Precompute corner positions
-    let top_left = Vec2::new(x + radius, y + radius);
-    let top_right = Vec2::new(x + w - radius, y + radius);
-    let bottom_left = Vec2::new(x + radius, y + h - radius);
-    let bottom_right = Vec2::new(x + w - radius, y + h - radius);
-    
-    // Draw center rectangle
-    draw_rectangle(x + radius, y, w - 2.0 * radius, h, color);
-    
-    // Draw the side rectangles
-    draw_rectangle(x, y + radius, radius, h - 2.0 * radius, color);
-    draw_rectangle(x + w - radius, y + radius, radius, h - 2.0 * radius, color);
-    
-    // Draw the four corner circles (could be batched in a real engine)
-    draw_circle(top_left.x, top_left.y, radius, color);     // Top-left
-    draw_circle(top_right.x, top_right.y, radius, color);   // Top-right
-    draw_circle(bottom_left.x, bottom_left.y, radius, color);  // Bottom-left
-    draw_circle(bottom_right.x, bottom_right.y, radius, color); // Bottom-right
+    fn text_width(&self, text: &str) -> f32 {
+        measure_text(text, self.current_font().as_ref(), self.font_size, 1.0).width
+    }
+
+    // Draw one line of text - use draw_text_ex if we have a custom font
+    fn draw_line_text(&self, line: &str, x: f32, y: f32, color: Color) {
+        let color = self.faded(color);
+        match &self.current_font() {
+            Some(font) => {
+                draw_text_ex(
+                    line,
+                    x,
+                    y,
+                    TextParams {
+                        font: Some(font),
+                        font_size: self.font_size,
+                        color,
+                        ..Default::default()
+                    },
+                );
+            },
+            None => {
+                // Use the default draw_text function
+                draw_text(line, x, y, self.font_size as f32, color);
+            }
+        }
+    }
 }
 
 // New border drawing method using filled rectangles instead of lines
@@ -464,23 +1188,51 @@ fn draw_rectangle_border(x: f32, y: f32, w: f32, h: f32, thickness: f32, color:
     draw_rectangle(x + w - thickness, y + thickness, thickness, h - (thickness * 2.0), color);
 }
 
-// New function to draw rounded rectangle borders with consistent thickness
-fn draw_round_rect_border(x: f32, y: f32, w: f32, h: f32, radius: f32, thickness: f32, color: Color, bg_color: Color) {
-    if radius <= 0.0 {
-        // Use our new rectangle border function for non-rounded corners
-        draw_rectangle_border(x, y, w, h, thickness, color);
-        return;
+// Split a line into plain and link parts, from links given as (byte range in the whole text,
+// link index). Links only cover the first kept bytes, so none reach into a truncation ellipsis
+fn split_links(line_len: usize, kept: usize, line_start: usize, links: &[(Range<usize>, usize)]) -> Vec<(Range<usize>, Option<usize>)> {
+    let kept = kept.min(line_len);
+    let mut line_links: Vec<(Range<usize>, usize)> = links.iter().filter_map(|(range, index)| {
+        // Part of the link that is on this line, before any ellipsis
+        let start = range.start.max(line_start).saturating_sub(line_start);
+        let end = range.end.saturating_sub(line_start).min(kept);
+        (start < end).then_some((start..end, *index))
+    }).collect();
+    line_links.sort_by_key(|(range, _)| range.start);
+    let mut segments = Vec::new();
+    let mut cursor = 0;
+    for (range, index) in line_links {
+        if range.start < cursor {
+            continue; // Overlaps a link already on this line
+        }
+        if range.start > cursor {
+            segments.push((cursor..range.start, None));
+        }
+        cursor = range.end;
+        segments.push((range, Some(index)));
+    }
+    if cursor < line_len || segments.is_empty() {
+        segments.push((cursor..line_len, None));
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_links;
+
+    // "Read the rules here" truncated to "Read the…", with links on "rules" (cut off) and "Read the r" (cut in half)
+    #[test]
+    fn links_stop_at_truncation() {
+        let line = "Read the\u{2026}";
+        let kept = "Read the".len();
+        let links = [(9..14, 0), (0..10, 1)];
+        let segments = split_links(line.len(), kept, 0, &links);
+        assert_eq!(segments, vec![(0..8, Some(1)), (8..line.len(), None)]);
+        // Every piece can be sliced out of the line, which is what drawing does
+        for (range, _) in segments {
+            let _ = &line[..range.start];
+            let _ = &line[range];
+        }
     }
-    
-    // Draw outer rounded rectangle
-    draw_round_rect(x, y, w, h, radius, color);
-    
-    // Draw inner rounded rectangle with background color
-    let inner_x = x + thickness;
-    let inner_y = y + thickness;
-    let inner_w = w - (thickness * 2.0);
-    let inner_h = h - (thickness * 2.0);
-    let inner_radius = (radius - thickness).max(0.0);
-    
-    draw_round_rect(inner_x, inner_y, inner_w, inner_h, inner_radius, bg_color);
 }
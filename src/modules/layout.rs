@@ -0,0 +1,676 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Rows, columns, grids and anchors for working out where widgets go, instead of typing in the
+pixel position of everything
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod layout;
+
+This also needs the scale module (anchors are worked out against the virtual resolution):
+    pub mod scale;
+The widgets it can place itself (see PLACING WIDGETS) need their own modules:
+    pub mod data_grid;
+    pub mod form;
+    pub mod label;
+    pub mod spinner;
+    pub mod text_area;
+    pub mod text_button;
+    pub mod text_input;
+
+Add with the other use statements:
+    use crate::modules::layout::{self, Align, Anchor, Breakpoint, BreakpointWatcher, Column, GridLayout, Justify, Place, Row, Size};
+
+Then above the loop section, lay out an area and hand the pieces to the widgets:
+    // A 400 x 300 box in the middle of the screen
+    let area = layout::anchored(Anchor::Center, 400.0, 300.0, 0.0, 0.0);
+    let mut col_login = Column::new();
+    col_login
+        .with_padding(16.0)                 // Space inside the edges of the area
+        .with_spacing(12.0)                 // Space between each piece
+        .add(Size::Fixed(40.0))             // Username box
+        .add(Size::Fixed(40.0))             // Password box
+        .add(Size::Flex(1.0))               // Whatever height is left over
+        .add(Size::Fixed(60.0));            // Row of buttons
+    let rects = col_login.layout(area);
+    txt_username.set_position(rects[0].x, rects[0].y);
+    txt_password.set_position(rects[1].x, rects[1].y);
+
+    // Rows work the same way from left to right, and can go inside a piece of a column
+    let mut row_buttons = Row::new();
+    row_buttons.with_spacing(10.0).add(Size::Flex(1.0)).add(Size::Flex(1.0));
+    let buttons = row_buttons.layout(rects[3]);
+    btn_login.update_position(buttons[0].x, buttons[0].y, Some(buttons[0].w), Some(buttons[0].h));
+    btn_create.update_position(buttons[1].x, buttons[1].y, Some(buttons[1].w), Some(buttons[1].h));
+The rectangles come back in the order the pieces were added.
+
+HOW IT WORKS:
+    Fixed pieces get their size first, then the space that is left (after the padding and
+    spacing) is shared between the Flex pieces by their weight, so Flex(2.0) gets twice as
+    much as Flex(1.0). Percent(0.25) is a quarter of the area (inside the padding).
+    Across the row or column every piece fills the area unless it was added with its own
+    cross size (add_sized), which is then lined up with with_align. When there are no Flex
+    pieces to take up the spare room, with_justify decides where the pieces sit.
+
+    Anchors pin a box to an edge, corner or the middle of the virtual resolution (the same
+    Anchor as scale::anchored_position), with margins moving it in from the edges:
+        let rect_save = layout::anchored(Anchor::BottomRight, 200.0, 60.0, 20.0, 20.0);
+    The edges are those of the safe area, so on phones the box stays clear of the notch and the
+    home bar (layout::safe_screen() is that area, layout::screen() the whole virtual resolution).
+    anchored_in does the same inside another rectangle (like a piece of a column):
+        let rect_close = layout::anchored_in(rects[0], Anchor::Right, 30.0, 30.0, 5.0, 0.0);
+
+GRIDS:
+    A GridLayout has a number of equal columns and fills its cells left to right, top to
+    bottom. A cell can span several columns and rows, later cells flow around it:
+        let mut grd_dashboard = GridLayout::new(3);        // 3 columns
+        grd_dashboard
+            .with_gutter(12.0, 12.0)                       // Space between columns and between rows
+            .with_row_height(120.0)                        // Leave out to share the height between the rows
+            .add_span(2, 2)                                // Chart, 2 columns wide and 2 rows tall
+            .add()                                         // Score
+            .add()                                         // Level
+            .add_span(3, 1);                               // Log along the bottom
+        let cells = grd_dashboard.layout(layout::inset(layout::screen(), 20.0));
+    Like a row or column, the rectangles come back in the order the cells were added.
+
+PLACING WIDGETS:
+    Instead of copying each rectangle into a widget, place() moves and sizes the widgets
+    straight into the cells or pieces, in order:
+        grd_dashboard.place(area, &mut [&mut chr_scores, &mut lbl_score, &mut lbl_level, &mut grd_log]);
+        col_login.place(area, &mut [&mut txt_username, &mut txt_password]);
+    TextButton, TextInput, TextArea, DataGrid, Label (wrapped to the width), Form (moved only,
+    it keeps its own width and height) and Spinner (centered) can be placed. Anything else can
+    too by adding the Place trait to it:
+        impl Place for MyWidget {
+            fn place(&mut self, rect: Rect) {
+                self.rect = rect;
+            }
+        }
+    Widgets past the number of cells are left where they are.
+
+BREAKPOINTS:
+    The window (or the canvas on the web) is sorted into Compact (phone sized, or taller than
+    it is wide), Medium or Wide by its size in logical pixels, so a scene can move things
+    around to suit it. Keep a BreakpointWatcher above the loop and lay things out again
+    whenever it changes (it also says so on the first frame):
+        let mut breakpoints = BreakpointWatcher::new();
+        loop {
+            if let Some(breakpoint) = breakpoints.changed() {
+                let mut col_menu = if breakpoint == Breakpoint::Compact { ... } else { ... };
+                col_menu.place(layout::screen(), &mut [&mut btn_play, &mut btn_quit]);
+            }
+        }
+    Or just pick a value for the current breakpoint:
+        let columns = layout::responsive(1, 2, 3);   // Compact, Medium, Wide
+        if layout::breakpoint() == Breakpoint::Compact { }
+        layout::set_breakpoints(600.0, 1100.0);      // Compact below 600 wide, Wide from 1100 (default)
+        let aspect = layout::window_aspect();        // Width / height of the window
+
+OTHER METHODS:
+    col_login.add_sized(Size::Fixed(40.0), 200.0);  // 200 wide instead of the whole width
+    col_login.with_align(Align::Center);            // Start, Center, End or Stretch (default)
+    col_login.with_justify(Justify::Center);        // Start (default), Center, End or SpaceBetween
+    col_login.clear();                              // Take out every piece to add them again
+    let area = layout::screen();                    // The part of the virtual resolution that shows, as a Rect
+    let inner = layout::inset(area, 20.0);          // A rectangle shrunk on every side
+    grd_dashboard.with_padding(10.0);               // Space inside the edges of the area (grids too)
+    let rows = grd_dashboard.row_count();           // How many rows the cells fill
+*/
+
+use macroquad::prelude::*;
+pub use crate::modules::scale::Anchor;
+use std::cell::Cell;
+use crate::modules::data_grid::DataGrid;
+use crate::modules::form::Form;
+use crate::modules::label::Label;
+use crate::modules::spinner::Spinner;
+use crate::modules::text_area::TextArea;
+use crate::modules::text_button::TextButton;
+use crate::modules::text_input::TextInput;
+
+thread_local! {
+    // Window widths (logical pixels) where Compact ends and Wide starts
+    static BREAKPOINTS: Cell<(f32, f32)> = const { Cell::new((600.0, 1100.0)) };
+}
+
+// How much room a piece takes along the row or column
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum Size {
+    Fixed(f32),   // Exactly this many pixels
+    Percent(f32), // Part of the area (0.5 is half)
+    Flex(f32),    // A share of the space left over, by weight
+}
+
+// Where pieces with their own cross size sit across the row or column
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum Align {
+    Start,  // Top of a row, left of a column
+    Center,
+    End,    // Bottom of a row, right of a column
+    Stretch,
+}
+
+// Where the pieces sit along the row or column when they don't fill it
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum Justify {
+    Start,
+    Center,
+    End,
+    SpaceBetween, // First and last piece at the edges, the rest spread evenly between
+}
+
+#[derive(Clone, Copy)]
+struct Piece {
+    size: Size,
+    cross: Option<f32>, // Own width in a column / height in a row, None fills the area
+}
+
+// Pieces laid out one after another, left to right (Row) or top to bottom (Column)
+pub struct Stack<const VERTICAL: bool> {
+    pieces: Vec<Piece>,
+    spacing: f32,
+    padding: f32,
+    align: Align,
+    justify: Justify,
+}
+
+// Pieces side by side, left to right
+#[allow(unused)]
+pub type Row = Stack<false>;
+// Pieces stacked, top to bottom
+#[allow(unused)]
+pub type Column = Stack<true>;
+
+impl<const VERTICAL: bool> Stack<VERTICAL> {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self {
+            pieces: Vec::new(),
+            spacing: 0.0,
+            padding: 0.0,
+            align: Align::Stretch,
+            justify: Justify::Start,
+        }
+    }
+
+    // Add a piece that fills the area across the row or column
+    #[allow(unused)]
+    pub fn add(&mut self, size: Size) -> &mut Self {
+        self.pieces.push(Piece { size, cross: None });
+        self
+    }
+
+    // Add a piece with its own height (in a row) or width (in a column)
+    #[allow(unused)]
+    pub fn add_sized(&mut self, size: Size, cross: f32) -> &mut Self {
+        self.pieces.push(Piece { size, cross: Some(cross.max(0.0)) });
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_spacing(&mut self, spacing: f32) -> &mut Self {
+        self.spacing = spacing.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_padding(&mut self, padding: f32) -> &mut Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_align(&mut self, align: Align) -> &mut Self {
+        self.align = align;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_justify(&mut self, justify: Justify) -> &mut Self {
+        self.justify = justify;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        self.pieces.clear();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.pieces.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    // Split an area into one rectangle per piece, in the order they were added
+    #[allow(unused)]
+    pub fn layout(&self, area: Rect) -> Vec<Rect> {
+        let inner = inset(area, self.padding);
+        // Work along "main" (x for a row, y for a column) and across "cross"
+        let (main_start, main_length, cross_start, cross_length) =
+            if VERTICAL { (inner.y, inner.h, inner.x, inner.w) } else { (inner.x, inner.w, inner.y, inner.h) };
+
+        let gaps = self.spacing * self.pieces.len().saturating_sub(1) as f32;
+        let mut used = gaps;
+        let mut flex_total = 0.0;
+        for piece in &self.pieces {
+            match piece.size {
+                Size::Fixed(length) => used += length.max(0.0),
+                Size::Percent(part) => used += main_length * part.max(0.0),
+                Size::Flex(weight) => flex_total += weight.max(0.0),
+            }
+        }
+        let spare = (main_length - used).max(0.0);
+        let lengths: Vec<f32> = self
+            .pieces
+            .iter()
+            .map(|piece| match piece.size {
+                Size::Fixed(length) => length.max(0.0),
+                Size::Percent(part) => main_length * part.max(0.0),
+                Size::Flex(weight) if flex_total > 0.0 => spare * weight.max(0.0) / flex_total,
+                Size::Flex(_) => 0.0,
+            })
+            .collect();
+
+        // Room still free after the flex pieces took their share decides where justify puts things
+        let free = if flex_total > 0.0 { 0.0 } else { spare };
+        let (mut position, gap) = match self.justify {
+            Justify::Start => (main_start, self.spacing),
+            Justify::Center => (main_start + free / 2.0, self.spacing),
+            Justify::End => (main_start + free, self.spacing),
+            Justify::SpaceBetween if self.pieces.len() > 1 => {
+                (main_start, self.spacing + free / (self.pieces.len() - 1) as f32)
+            }
+            Justify::SpaceBetween => (main_start, self.spacing),
+        };
+
+        let mut rects = Vec::with_capacity(self.pieces.len());
+        for (piece, length) in self.pieces.iter().zip(lengths) {
+            let (across, size) = match (piece.cross, self.align) {
+                (None, _) | (Some(_), Align::Stretch) => (cross_start, cross_length),
+                (Some(size), Align::Start) => (cross_start, size),
+                (Some(size), Align::Center) => (cross_start + (cross_length - size) / 2.0, size),
+                (Some(size), Align::End) => (cross_start + cross_length - size, size),
+            };
+            rects.push(if VERTICAL {
+                Rect::new(across, position, size, length)
+            } else {
+                Rect::new(position, across, length, size)
+            });
+            position += length + gap;
+        }
+        rects
+    }
+
+    // Lay out an area and move the widgets into the pieces, in order
+    #[allow(unused)]
+    pub fn place(&self, area: Rect, widgets: &mut [&mut dyn Place]) {
+        for (widget, rect) in widgets.iter_mut().zip(self.layout(area)) {
+            widget.place(rect);
+        }
+    }
+}
+
+impl<const VERTICAL: bool> Default for Stack<VERTICAL> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct GridCell {
+    columns: usize,
+    rows: usize,
+}
+
+// Cells in equal columns, filled left to right then top to bottom
+pub struct GridLayout {
+    columns: usize,
+    cells: Vec<GridCell>,
+    gutter_x: f32,            // Space between columns
+    gutter_y: f32,            // Space between rows
+    padding: f32,
+    row_height: Option<f32>,  // None shares the area's height between the rows
+}
+
+impl GridLayout {
+    #[allow(unused)]
+    pub fn new(columns: usize) -> Self {
+        Self {
+            columns: columns.max(1),
+            cells: Vec::new(),
+            gutter_x: 0.0,
+            gutter_y: 0.0,
+            padding: 0.0,
+            row_height: None,
+        }
+    }
+
+    // Add a cell one column wide and one row tall
+    #[allow(unused)]
+    pub fn add(&mut self) -> &mut Self {
+        self.add_span(1, 1)
+    }
+
+    // Add a cell covering several columns and rows (more columns than the grid has are cut down)
+    #[allow(unused)]
+    pub fn add_span(&mut self, columns: usize, rows: usize) -> &mut Self {
+        self.cells.push(GridCell { columns: columns.clamp(1, self.columns), rows: rows.max(1) });
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_gutter(&mut self, between_columns: f32, between_rows: f32) -> &mut Self {
+        self.gutter_x = between_columns.max(0.0);
+        self.gutter_y = between_rows.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_padding(&mut self, padding: f32) -> &mut Self {
+        self.padding = padding.max(0.0);
+        self
+    }
+
+    // Give every row the same height instead of sharing out the area
+    #[allow(unused)]
+    pub fn with_row_height(&mut self, height: f32) -> &mut Self {
+        self.row_height = Some(height.max(0.0));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        self.cells.clear();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // How many rows the cells fill
+    #[allow(unused)]
+    pub fn row_count(&self) -> usize {
+        self.positions().iter().zip(&self.cells).map(|(&(row, _), cell)| row + cell.rows).max().unwrap_or(0)
+    }
+
+    // Split an area into one rectangle per cell, in the order they were added
+    #[allow(unused)]
+    pub fn layout(&self, area: Rect) -> Vec<Rect> {
+        let inner = inset(area, self.padding);
+        let positions = self.positions();
+        let rows = self.row_count();
+        let column_width = ((inner.w - self.gutter_x * (self.columns - 1) as f32) / self.columns as f32).max(0.0);
+        let row_height = self.row_height.unwrap_or_else(|| {
+            if rows == 0 {
+                0.0
+            } else {
+                ((inner.h - self.gutter_y * (rows - 1) as f32) / rows as f32).max(0.0)
+            }
+        });
+
+        positions
+            .iter()
+            .zip(&self.cells)
+            .map(|(&(row, column), cell)| {
+                Rect::new(
+                    inner.x + column as f32 * (column_width + self.gutter_x),
+                    inner.y + row as f32 * (row_height + self.gutter_y),
+                    column_width * cell.columns as f32 + self.gutter_x * (cell.columns - 1) as f32,
+                    row_height * cell.rows as f32 + self.gutter_y * (cell.rows - 1) as f32,
+                )
+            })
+            .collect()
+    }
+
+    // Lay out an area and move the widgets into the cells, in order
+    #[allow(unused)]
+    pub fn place(&self, area: Rect, widgets: &mut [&mut dyn Place]) {
+        for (widget, rect) in widgets.iter_mut().zip(self.layout(area)) {
+            widget.place(rect);
+        }
+    }
+
+    // Row and column of each cell's top-left corner, flowing around cells that span rows above
+    fn positions(&self) -> Vec<(usize, usize)> {
+        let mut taken: Vec<Vec<bool>> = Vec::new();
+        let mut positions = Vec::with_capacity(self.cells.len());
+        // Cells are never put before the one added ahead of them
+        let (mut row, mut column) = (0, 0);
+        for cell in &self.cells {
+            loop {
+                if column + cell.columns > self.columns {
+                    row += 1;
+                    column = 0;
+                    continue;
+                }
+                let fits = (row..row + cell.rows).all(|r| {
+                    (column..column + cell.columns).all(|c| !taken.get(r).is_some_and(|cells| cells[c]))
+                });
+                if fits {
+                    break;
+                }
+                column += 1;
+            }
+            while taken.len() < row + cell.rows {
+                taken.push(vec![false; self.columns]);
+            }
+            for cells in &mut taken[row..row + cell.rows] {
+                cells[column..column + cell.columns].fill(true);
+            }
+            positions.push((row, column));
+            column += cell.columns;
+        }
+        positions
+    }
+}
+
+// Something a layout can move and size into a rectangle
+pub trait Place {
+    fn place(&mut self, rect: Rect);
+}
+
+impl Place for TextButton {
+    fn place(&mut self, rect: Rect) {
+        self.update_position(rect.x, rect.y, Some(rect.w), Some(rect.h));
+    }
+}
+
+impl Place for TextInput {
+    fn place(&mut self, rect: Rect) {
+        self.set_position(rect.x, rect.y).set_dimensions(rect.w, rect.h);
+    }
+}
+
+impl Place for TextArea {
+    fn place(&mut self, rect: Rect) {
+        self.set_position(rect.x, rect.y).set_dimensions(rect.w, rect.h);
+    }
+}
+
+impl Place for DataGrid {
+    fn place(&mut self, rect: Rect) {
+        self.set_position(rect.x, rect.y).set_size(rect.w, rect.h);
+    }
+}
+
+// Wrapped to the width, with the top-left of its text box in the corner
+impl Place for Label {
+    fn place(&mut self, rect: Rect) {
+        self.set_max_width(rect.w);
+        // Label positions are the text's baseline, so move by where the box is now
+        let size = self.measure();
+        let (x, y) = (self.get_x(), self.get_y());
+        self.set_position(rect.x + (x - size.x), rect.y + (y - size.y));
+    }
+}
+
+// Forms keep the width and height they worked out for themselves
+impl Place for Form {
+    fn place(&mut self, rect: Rect) {
+        self.set_position(rect.x, rect.y);
+    }
+}
+
+// Centered in the rectangle
+impl Place for Spinner {
+    fn place(&mut self, rect: Rect) {
+        self.set_position(rect.x + rect.w / 2.0, rect.y + rect.h / 2.0);
+    }
+}
+
+// Rough size of the window, for rearranging widgets to fit it
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[allow(unused)]
+pub enum Breakpoint {
+    Compact, // Phone sized, or taller than it is wide
+    Medium,
+    Wide,
+}
+
+// Tells a scene when the window moves into a different breakpoint
+pub struct BreakpointWatcher {
+    last: Option<Breakpoint>,
+}
+
+impl BreakpointWatcher {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self { last: None }
+    }
+
+    // Some(breakpoint) on the first call and whenever the breakpoint is different from last time
+    #[allow(unused)]
+    pub fn changed(&mut self) -> Option<Breakpoint> {
+        let current = breakpoint();
+        if self.last == Some(current) {
+            return None;
+        }
+        self.last = Some(current);
+        Some(current)
+    }
+
+    // The breakpoint as of the last call to changed()
+    #[allow(unused)]
+    pub fn current(&self) -> Option<Breakpoint> {
+        self.last
+    }
+}
+
+impl Default for BreakpointWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Size of the window in logical pixels (high DPI screens report more real pixels)
+fn window_size() -> (f32, f32) {
+    let dpi = screen_dpi_scale().max(1.0);
+    (screen_width() / dpi, screen_height() / dpi)
+}
+
+/// Width divided by height of the window (below 1.0 is portrait)
+#[allow(unused)]
+pub fn window_aspect() -> f32 {
+    let (width, height) = window_size();
+    width / height.max(1.0)
+}
+
+/// Which breakpoint the window is in right now
+#[allow(unused)]
+pub fn breakpoint() -> Breakpoint {
+    let (compact_below, wide_from) = BREAKPOINTS.with(Cell::get);
+    let (width, _) = window_size();
+    // A portrait window is laid out like a phone whatever its size
+    if width < compact_below || window_aspect() < 0.8 {
+        Breakpoint::Compact
+    } else if width >= wide_from {
+        Breakpoint::Wide
+    } else {
+        Breakpoint::Medium
+    }
+}
+
+/// Change the window widths (logical pixels) where Compact ends and Wide starts
+#[allow(unused)]
+pub fn set_breakpoints(compact_below: f32, wide_from: f32) {
+    BREAKPOINTS.with(|breakpoints| breakpoints.set((compact_below, wide_from.max(compact_below))));
+}
+
+/// Pick the value for the current breakpoint
+#[allow(unused)]
+pub fn responsive<T>(compact: T, medium: T, wide: T) -> T {
+    match breakpoint() {
+        Breakpoint::Compact => compact,
+        Breakpoint::Medium => medium,
+        Breakpoint::Wide => wide,
+    }
+}
+
+/// The part of the virtual resolution that shows, as a rectangle (the window when the scale feature is off)
+#[allow(unused)]
+pub fn screen() -> Rect {
+    crate::modules::scale::visible_area()
+}
+
+/// The part of screen() not hidden by a notch, the home bar or browser bars
+#[allow(unused)]
+pub fn safe_screen() -> Rect {
+    crate::modules::scale::safe_area()
+}
+
+/// A box of the given size pinned to an anchor of the safe area, margins move it inward
+#[allow(unused)]
+pub fn anchored(anchor: Anchor, width: f32, height: f32, margin_x: f32, margin_y: f32) -> Rect {
+    anchored_in(safe_screen(), anchor, width, height, margin_x, margin_y)
+}
+
+/// A box of the given size pinned to an anchor inside another rectangle, margins move it inward
+#[allow(unused)]
+pub fn anchored_in(area: Rect, anchor: Anchor, width: f32, height: f32, margin_x: f32, margin_y: f32) -> Rect {
+    // 0.0 = start edge, 0.5 = middle, 1.0 = end edge
+    let (along_x, along_y) = match anchor {
+        Anchor::TopLeft => (0.0, 0.0),
+        Anchor::Top => (0.5, 0.0),
+        Anchor::TopRight => (1.0, 0.0),
+        Anchor::Left => (0.0, 0.5),
+        Anchor::Center => (0.5, 0.5),
+        Anchor::Right => (1.0, 0.5),
+        Anchor::BottomLeft => (0.0, 1.0),
+        Anchor::Bottom => (0.5, 1.0),
+        Anchor::BottomRight => (1.0, 1.0),
+    };
+    // Margins push inward from an end edge
+    let margin_x = if along_x == 1.0 { -margin_x } else { margin_x };
+    let margin_y = if along_y == 1.0 { -margin_y } else { margin_y };
+    Rect::new(
+        area.x + (area.w - width) * along_x + margin_x,
+        area.y + (area.h - height) * along_y + margin_y,
+        width,
+        height,
+    )
+}
+
+/// A rectangle shrunk by the same amount on every side (never smaller than nothing)
+#[allow(unused)]
+pub fn inset(rect: Rect, amount: f32) -> Rect {
+    let amount_x = amount.min(rect.w / 2.0);
+    let amount_y = amount.min(rect.h / 2.0);
+    Rect::new(rect.x + amount_x, rect.y + amount_y, rect.w - amount_x * 2.0, rect.h - amount_y * 2.0)
+}
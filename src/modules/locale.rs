@@ -0,0 +1,154 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Translations for UI text, so the app can be shown in more than one language
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod locale;
+
+This also needs the assets module (to load the files):
+    pub mod assets;
+
+Add with the other use statements:
+    use crate::modules::locale;
+
+HOW IT WORKS:
+    Each language is a JSON file of keys and the text for that key. Objects inside the
+    file are joined to the key with dots, so both of these give the key "login.title":
+        { "login.title": "Log in" }
+        { "login": { "title": "Log in" } }
+    tr looks the key up in the current language, then in the fallback language ("en" by
+    default), and if it still isn't found gives back the key itself so missing text is
+    easy to spot. Labels made with Label::from_key look their text up every draw, so
+    calling set_language changes them all on the next frame.
+
+Usage examples:
+    // Load the languages before the main loop (assets/lang/en.json, assets/lang/fr.json)
+    locale::load_language("en", "assets/lang/en.json").await.unwrap();
+    locale::load_language("fr", "assets/lang/fr.json").await.unwrap();
+    locale::set_language("en");
+
+    // Labels that follow the language
+    let mut lbl_title = Label::from_key("login.title", 50.0, 50.0, 30);
+
+    // Text for anything else
+    btn_login.set_text(&locale::tr("login.button"));
+
+    // Fill in {name} style placeholders: "welcome": "Welcome back, {name}!"
+    let text = locale::tr_with("welcome", &[("name", &new_record.username)]);
+
+    // Switch language while running
+    locale::set_language("fr");
+
+    // Add a language from a string instead of a file
+    locale::add_language("de", r#"{ "login": { "title": "Anmelden" } }"#).unwrap();
+*/
+
+use crate::modules::assets;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    // Every loaded language: language code -> (key -> text)
+    static LANGUAGES: RefCell<HashMap<String, HashMap<String, String>>> = RefCell::new(HashMap::new());
+    static CURRENT: RefCell<String> = RefCell::new(String::from("en"));
+    static FALLBACK: RefCell<String> = RefCell::new(String::from("en"));
+}
+
+/// Load a language from a JSON file
+#[allow(unused)]
+pub async fn load_language(code: &str, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let bytes = assets::load_bytes(path).await?;
+    let json = String::from_utf8(bytes).map_err(|e| format!("{} is not valid UTF-8: {}", path, e))?;
+    add_language(code, &json)
+}
+
+/// Add a language from a JSON string (replaces the language if it was already added)
+#[allow(unused)]
+pub fn add_language(code: &str, json: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let value: Value = serde_json::from_str(json)?;
+    let mut table = HashMap::new();
+    flatten("", &value, &mut table);
+    LANGUAGES.with(|languages| languages.borrow_mut().insert(code.to_string(), table));
+    Ok(())
+}
+
+/// Switch the language text is shown in
+#[allow(unused)]
+pub fn set_language(code: &str) {
+    CURRENT.with(|current| *current.borrow_mut() = code.to_string());
+}
+
+/// Code of the language text is shown in
+#[allow(unused)]
+pub fn get_language() -> String {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+/// Set the language used for keys the current language doesn't have
+#[allow(unused)]
+pub fn set_fallback_language(code: &str) {
+    FALLBACK.with(|fallback| *fallback.borrow_mut() = code.to_string());
+}
+
+/// Codes of every loaded language, sorted
+#[allow(unused)]
+pub fn available_languages() -> Vec<String> {
+    let mut codes: Vec<String> = LANGUAGES.with(|languages| languages.borrow().keys().cloned().collect());
+    codes.sort();
+    codes
+}
+
+/// Whether the current or fallback language has text for a key
+#[allow(unused)]
+pub fn has_key(key: &str) -> bool {
+    lookup(key).is_some()
+}
+
+/// Text for a key in the current language (the key itself if there isn't any)
+pub fn tr(key: &str) -> String {
+    lookup(key).unwrap_or_else(|| key.to_string())
+}
+
+/// Text for a key with each {name} placeholder replaced by its value
+#[allow(unused)]
+pub fn tr_with(key: &str, values: &[(&str, &str)]) -> String {
+    let mut text = tr(key);
+    for (name, value) in values {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+    text
+}
+
+// Look in the current language, then the fallback
+fn lookup(key: &str) -> Option<String> {
+    let current = get_language();
+    let fallback = FALLBACK.with(|fallback| fallback.borrow().clone());
+    LANGUAGES.with(|languages| {
+        let languages = languages.borrow();
+        [current, fallback]
+            .iter()
+            .find_map(|code| languages.get(code).and_then(|table| table.get(key)).cloned())
+    })
+}
+
+// Turn nested objects into dotted keys ({"a": {"b": "x"}} becomes "a.b" -> "x")
+fn flatten(prefix: &str, value: &Value, table: &mut HashMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let full_key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten(&full_key, value, table);
+            }
+        }
+        Value::String(text) => {
+            table.insert(prefix.to_string(), text.clone());
+        }
+        Value::Null => {}
+        // Numbers, true/false and lists are kept as their JSON text
+        other => {
+            table.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
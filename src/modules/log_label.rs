@@ -0,0 +1,318 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Adds a scrolling log label for status messages, sync progress, chat and the like
+
+In your mod.rs file located in the modules folder add the following to the end of the file
+        pub mod log_label;
+
+This also needs these modules:
+        pub mod scale;   // For clipping
+        pub mod theme;
+and the unicode-segmentation crate in Cargo.toml under [dependencies]:
+        unicode-segmentation = "1.13"
+
+Add with the other use statements
+    use crate::modules::log_label::LogLabel;
+
+Then to use this you would put the following above the loop:
+    let mut log_status = LogLabel::new(50.0, 500.0, 400.0, 150.0, 18);
+Where the parameters are x, y, width, height, font size
+
+New lines go at the bottom. Once there are more than the max line count the oldest lines
+are dropped, so it never grows forever. The mouse wheel or dragging inside the box scrolls
+back through older lines; while scrolled to the bottom it follows new lines as they come in.
+Long lines wrap to fit the width.
+
+CUSTOMIZATION:
+    log_status.with_colors(WHITE, Some(Color::new(0.0, 0.0, 0.0, 0.6))); // Text (default from the theme) and background
+    log_status.with_font(my_font.clone());
+    log_status.set_max_lines(500);    // Default 200
+    log_status.set_line_spacing(1.3); // Default 1.2
+
+ADDING LINES:
+    log_status.push_line("Connected");
+    log_status.push_line(format!("Synced {} records", count));
+    log_status.push_colored_line("Sync failed", RED);
+    log_status.clear();
+    let count = log_status.get_line_count();
+
+Then in the main loop you would use:
+    log_status.draw();
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::theme::ThemeColor;
+use unicode_segmentation::UnicodeSegmentation;
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+const PADDING: f32 = 5.0;
+
+pub struct LogLabel {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    font_size: u16,
+    font: Option<Font>,
+    line_spacing: f32,
+    text_color: ThemeColor,
+    background: Option<Color>,
+    max_lines: usize,
+    lines: Vec<(String, ThemeColor)>,     // Lines as pushed, with their color
+    wrapped: Vec<(String, ThemeColor)>,   // Lines after wrapping to the width (what is drawn)
+    scroll_offset: f32,                   // Pixels scrolled down from the top
+    follow: bool,                         // Stay scrolled to the bottom as lines are added
+    drag_start: Option<(f32, f32)>,       // Mouse y and scroll offset when a drag started
+}
+
+impl LogLabel {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32, font_size: u16) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            font_size,
+            font: None,
+            line_spacing: 1.2,
+            text_color: ThemeColor::Text,
+            background: None,
+            max_lines: 200,
+            lines: Vec::new(),
+            wrapped: Vec::new(),
+            scroll_offset: 0.0,
+            follow: true,
+            drag_start: None,
+        }
+    }
+
+    // Method to set the text color and background color
+    #[allow(unused)]
+    pub fn with_colors(&mut self, text_color: impl Into<ThemeColor>, background: Option<Color>) -> &mut Self {
+        self.text_color = text_color.into();
+        self.background = background;
+        self
+    }
+
+    // Method to set a custom font
+    #[allow(unused)]
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        self.font = Some(font);
+        self.rewrap();
+        self
+    }
+
+    // Keep at most this many lines (older ones are dropped)
+    #[allow(unused)]
+    pub fn set_max_lines(&mut self, max_lines: usize) -> &mut Self {
+        self.max_lines = max_lines.max(1);
+        self.trim();
+        self.rewrap();
+        self
+    }
+
+    // Space between lines as a multiple of the font size
+    #[allow(unused)]
+    pub fn set_line_spacing(&mut self, spacing: f32) -> &mut Self {
+        self.line_spacing = spacing;
+        self.clamp_scroll();
+        self
+    }
+
+    // Add a line at the bottom in the normal text color
+    #[allow(unused)]
+    pub fn push_line<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        let color = self.text_color;
+        self.push_colored_line(text, color)
+    }
+
+    // Add a line at the bottom in its own color
+    #[allow(unused)]
+    pub fn push_colored_line<T: Into<String>>(&mut self, text: T, color: impl Into<ThemeColor>) -> &mut Self {
+        let text = text.into();
+        let color = color.into();
+        // A line with \n in it counts as several lines
+        for line in text.split('\n') {
+            self.lines.push((line.to_string(), color));
+            let pieces = self.wrap_line(line);
+            self.wrapped.extend(pieces.into_iter().map(|piece| (piece, color)));
+        }
+        if self.lines.len() > self.max_lines {
+            self.trim();
+            self.rewrap();
+        }
+        self.clamp_scroll();
+        self
+    }
+
+    // Remove every line
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        self.lines.clear();
+        self.wrapped.clear();
+        self.scroll_offset = 0.0;
+        self.follow = true;
+        self
+    }
+
+    // Number of lines pushed (before wrapping)
+    #[allow(unused)]
+    pub fn get_line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    // Change the size of the box (lines are wrapped again to the new width)
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self.rewrap();
+        self
+    }
+
+    // Handles scrolling and draws the log
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        self.update_scrolling();
+
+        if let Some(background) = self.background {
+            draw_rectangle(self.x, self.y, self.width, self.height, background);
+        }
+
+        let line_height = self.line_height();
+        push_clip_rect(Rect::new(self.x, self.y, self.width, self.height));
+        // Only the lines that can be seen are drawn
+        let first = (self.scroll_offset / line_height).floor().max(0.0) as usize;
+        let visible = (self.height / line_height).ceil() as usize + 1;
+        for (index, (line, color)) in self.wrapped.iter().enumerate().skip(first).take(visible) {
+            let top = self.y + PADDING + index as f32 * line_height - self.scroll_offset;
+            draw_text_ex(
+                line,
+                self.x + PADDING,
+                top + self.font_size as f32 * 0.8,
+                TextParams {
+                    font: self.font.as_ref(),
+                    font_size: self.font_size,
+                    color: color.color(),
+                    ..Default::default()
+                },
+            );
+        }
+        pop_clip_rect();
+    }
+
+    fn update_scrolling(&mut self) {
+        let (mouse_x, mouse_y) = mouse_position();
+        let hovered = Rect::new(self.x, self.y, self.width, self.height).contains(Vec2::new(mouse_x, mouse_y));
+
+        let (_, wheel_y) = mouse_wheel();
+        if hovered && wheel_y != 0.0 {
+            self.scroll_offset -= wheel_y.signum() * self.line_height() * 3.0;
+            self.after_user_scroll();
+        }
+
+        // Dragging scrolls like a phone (content follows the finger)
+        if hovered && is_mouse_button_pressed(MouseButton::Left) {
+            self.drag_start = Some((mouse_y, self.scroll_offset));
+        }
+        if !is_mouse_button_down(MouseButton::Left) {
+            self.drag_start = None;
+        }
+        if let Some((start_y, start_offset)) = self.drag_start {
+            self.scroll_offset = start_offset - (mouse_y - start_y);
+            self.after_user_scroll();
+        }
+    }
+
+    // Keep following new lines only while scrolled to the bottom
+    fn after_user_scroll(&mut self) {
+        self.clamp_scroll_only();
+        self.follow = self.scroll_offset >= self.max_scroll() - 0.5;
+    }
+
+    fn line_height(&self) -> f32 {
+        self.font_size as f32 * self.line_spacing
+    }
+
+    fn max_scroll(&self) -> f32 {
+        let content_height = self.wrapped.len() as f32 * self.line_height() + PADDING * 2.0;
+        (content_height - self.height).max(0.0)
+    }
+
+    fn clamp_scroll_only(&mut self) {
+        self.scroll_offset = self.scroll_offset.clamp(0.0, self.max_scroll());
+    }
+
+    // Keep the scroll in range, jumping to the bottom while following
+    fn clamp_scroll(&mut self) {
+        if self.follow {
+            self.scroll_offset = self.max_scroll();
+        }
+        self.clamp_scroll_only();
+    }
+
+    // Drop the oldest lines past the max line count
+    fn trim(&mut self) {
+        if self.lines.len() > self.max_lines {
+            let extra = self.lines.len() - self.max_lines;
+            self.lines.drain(..extra);
+        }
+    }
+
+    // Wrap every line again (after the width, font or line list changes)
+    fn rewrap(&mut self) {
+        let mut wrapped = Vec::new();
+        for (line, color) in &self.lines {
+            wrapped.extend(self.wrap_line(line).into_iter().map(|piece| (piece, *color)));
+        }
+        self.wrapped = wrapped;
+        self.clamp_scroll();
+    }
+
+    // Break a line into pieces that fit the width, at spaces where possible
+    fn wrap_line(&self, line: &str) -> Vec<String> {
+        let max_width = (self.width - PADDING * 2.0).max(1.0);
+        let width_of = |text: &str| measure_text(text, self.font.as_ref(), self.font_size, 1.0).width;
+        let mut pieces = Vec::new();
+        let mut current = String::new();
+
+        for word in line.split(' ') {
+            let candidate = if current.is_empty() { word.to_string() } else { format!("{} {}", current, word) };
+            if width_of(&candidate) <= max_width || current.is_empty() {
+                current = candidate;
+            } else {
+                pieces.push(std::mem::replace(&mut current, word.to_string()));
+            }
+
+            // A word wider than the box is split wherever it has to be
+            while width_of(&current) > max_width {
+                // Keep as many graphemes as fit (at least one so it always moves on)
+                let mut split_at = 0;
+                for (index, grapheme) in current.grapheme_indices(true) {
+                    let end = index + grapheme.len();
+                    if split_at > 0 && width_of(&current[..end]) > max_width {
+                        break;
+                    }
+                    split_at = end;
+                }
+                if split_at >= current.len() {
+                    break;
+                }
+                let rest = current.split_off(split_at);
+                pieces.push(std::mem::replace(&mut current, rest));
+            }
+        }
+        pieces.push(current);
+        pieces
+    }
+}
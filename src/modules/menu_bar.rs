@@ -0,0 +1,597 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A menu bar along the top (File, Edit, ...) with drop-down menus, separators and keyboard shortcuts,
+for tool-style apps like a table editor
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod menu_bar;
+
+This also needs these modules:
+    pub mod focus;
+    pub mod scale;
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::menu_bar::MenuBar;
+
+Then above the loop section:
+    let mut mnu_main = MenuBar::new(0.0, 0.0, 1024.0);
+    mnu_main
+        .add_menu("File")
+        .add_item("Reload", "F5", || println!("Reload"))
+        .add_item("Save", "Ctrl+S", || println!("Save"))
+        .add_separator()
+        .add_item("Quit", "Ctrl+Q", || std::process::exit(0))
+        .add_menu("Edit")
+        .add_item("Delete row", "Delete", || println!("Delete"));
+Where the parameters are x, y and width of the bar. Items go into the menu added last.
+The shortcut is shown on the right of the item and pressing it runs the item even while
+the menu is closed ("" for no shortcut). Shortcuts are written like "Ctrl+S",
+"Ctrl+Shift+Z", "Alt+F4", "F5", "Delete" or "Enter".
+The closures have to own what they use, share values with Rc<Cell<...>> or
+Rc<RefCell<...>> to change things in main.
+
+Then in the loop, after the widgets it should cover (and before tooltip::draw / dialog::draw):
+    if let Some(item) = mnu_main.draw() {
+        // item is "File/Save" etc. when an item was chosen, if you'd rather check here than in a closure
+    }
+
+USING IT:
+    Click a menu to open it, then click an item. While one menu is open, moving the mouse
+    onto another opens that one instead. Up / Down / Left / Right and Enter work too.
+    Clicking somewhere else or pressing Escape closes it. While a menu is open the widgets
+    under it don't get the mouse or the keyboard.
+
+OTHER METHODS:
+    mnu_main.set_item_enabled("Edit", "Delete row", false); // Greyed out and can't be chosen
+    mnu_main.with_height(32.0);                              // Default 28
+    mnu_main.with_font_size(22);                             // Default 20
+    mnu_main.with_colors(LIGHTGRAY, WHITE, SKYBLUE, BLACK);  // Bar, menus, highlight, text (default from the theme)
+    mnu_main.set_width(1280.0);
+    if mnu_main.is_open() { }
+    mnu_main.close();
+*/
+
+use macroquad::prelude::*;
+use crate::modules::focus;
+use crate::modules::scale;
+use crate::modules::theme::{self, ThemeColor};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Space on each side of a menu title and around the items in a menu
+const PADDING: f32 = 12.0;
+// Height of a separator line's row
+const SEPARATOR_HEIGHT: f32 = 9.0;
+
+// Closure run when a menu item is chosen
+type MenuCallback = Box<dyn FnMut()>;
+
+// Colors and text size shared by the bar and its menus
+#[derive(Clone, Copy)]
+pub(crate) struct MenuStyle {
+    pub font_size: u16,
+    pub menu_color: ThemeColor,
+    pub highlight_color: ThemeColor,
+    pub text_color: ThemeColor,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        Self {
+            font_size: 20,
+            menu_color: ThemeColor::Surface,
+            highlight_color: ThemeColor::Selection,
+            text_color: ThemeColor::Text,
+        }
+    }
+}
+
+// A key with modifiers, read from text like "Ctrl+Shift+S"
+#[derive(Clone, Copy, PartialEq)]
+struct Shortcut {
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+    key: KeyCode,
+}
+
+impl Shortcut {
+    fn parse(text: &str) -> Option<Self> {
+        let mut shortcut = Shortcut { ctrl: false, shift: false, alt: false, key: KeyCode::Unknown };
+        for part in text.split('+').map(str::trim) {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" => shortcut.ctrl = true,
+                "shift" => shortcut.shift = true,
+                "alt" => shortcut.alt = true,
+                _ => shortcut.key = key_from_name(part)?,
+            }
+        }
+        (shortcut.key != KeyCode::Unknown).then_some(shortcut)
+    }
+
+    fn pressed(&self) -> bool {
+        let ctrl = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+        let shift = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        let alt = is_key_down(KeyCode::LeftAlt) || is_key_down(KeyCode::RightAlt);
+        ctrl == self.ctrl && shift == self.shift && alt == self.alt && is_key_pressed(self.key)
+    }
+}
+
+enum MenuEntry {
+    Item {
+        label: String,
+        shortcut_text: String,
+        shortcut: Option<Shortcut>,
+        callback: MenuCallback,
+        enabled: bool,
+    },
+    Separator,
+}
+
+// One drop-down list of items (used by MenuBar, and on its own by ContextMenu)
+pub struct Menu {
+    title: String,
+    entries: Vec<MenuEntry>,
+    highlighted: Option<usize>,
+}
+
+impl Menu {
+    #[allow(unused)]
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            entries: Vec::new(),
+            highlighted: None,
+        }
+    }
+
+    // Add an item with its shortcut ("" for none) and what it does
+    #[allow(unused)]
+    pub fn add_item<F: FnMut() + 'static>(&mut self, label: &str, shortcut: &str, callback: F) -> &mut Self {
+        self.entries.push(MenuEntry::Item {
+            label: label.to_string(),
+            shortcut_text: shortcut.to_string(),
+            shortcut: Shortcut::parse(shortcut),
+            callback: Box::new(callback),
+            enabled: true,
+        });
+        self
+    }
+
+    #[allow(unused)]
+    pub fn add_separator(&mut self) -> &mut Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_item_enabled(&mut self, label: &str, enabled: bool) -> &mut Self {
+        for entry in &mut self.entries {
+            if let MenuEntry::Item { label: item_label, enabled: item_enabled, .. } = entry {
+                if item_label == label {
+                    *item_enabled = enabled;
+                }
+            }
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    // Forget the keyboard highlight (when the menu opens)
+    pub(crate) fn reset(&mut self) {
+        self.highlighted = None;
+    }
+
+    // Where the menu goes when opened at a point, moved to stay inside the view
+    pub(crate) fn rect(&self, origin: Vec2, style: &MenuStyle) -> Rect {
+        let item_height = item_height(style);
+        let mut width: f32 = 120.0;
+        let mut height = PADDING / 2.0;
+        for entry in &self.entries {
+            match entry {
+                MenuEntry::Item { label, shortcut_text, .. } => {
+                    let label_width = measure_text(label, None, style.font_size, 1.0).width;
+                    let shortcut_width = if shortcut_text.is_empty() {
+                        0.0
+                    } else {
+                        measure_text(shortcut_text, None, style.font_size, 1.0).width + PADDING * 2.0
+                    };
+                    width = width.max(label_width + shortcut_width + PADDING * 2.0);
+                    height += item_height;
+                }
+                MenuEntry::Separator => height += SEPARATOR_HEIGHT,
+            }
+        }
+        height += PADDING / 2.0;
+
+        let view = scale::visible_area();
+        let x = origin.x.min(view.right() - width).max(view.x);
+        let y = if origin.y + height > view.bottom() { (origin.y - height).max(view.y) } else { origin.y };
+        Rect::new(x, y, width, height)
+    }
+
+    // Draw the menu opened at a point and handle the mouse and arrow keys,
+    // returns the index of the item chosen (its closure hasn't run yet, see activate)
+    pub(crate) fn update(&mut self, origin: Vec2, style: &MenuStyle) -> Option<usize> {
+        let rect = self.rect(origin, style);
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+
+        // Arrow keys move the highlight over the items that can be chosen
+        let choosable: Vec<usize> = (0..self.entries.len()).filter(|&index| self.is_choosable(index)).collect();
+        if !choosable.is_empty() {
+            let current = self.highlighted.and_then(|index| choosable.iter().position(|&other| other == index));
+            if is_key_pressed(KeyCode::Down) {
+                self.highlighted = Some(choosable[current.map_or(0, |position| (position + 1) % choosable.len())]);
+            }
+            if is_key_pressed(KeyCode::Up) {
+                let last = choosable.len() - 1;
+                self.highlighted = Some(choosable[current.map_or(last, |position| (position + last) % choosable.len())]);
+            }
+        }
+
+        let palette = theme::palette();
+        draw_rectangle(rect.x + 3.0, rect.y + 3.0, rect.w, rect.h, palette.shadow);
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, style.menu_color.color());
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, palette.border);
+
+        let item_height = item_height(style);
+        let mut chosen = None;
+        let mut y = rect.y + PADDING / 2.0;
+        for index in 0..self.entries.len() {
+            let MenuEntry::Item { label, shortcut_text, enabled, .. } = &self.entries[index] else {
+                let middle = (y + SEPARATOR_HEIGHT / 2.0).floor();
+                draw_line(rect.x + PADDING / 2.0, middle, rect.x + rect.w - PADDING / 2.0, middle, 1.0, palette.border.with_alpha(0.4));
+                y += SEPARATOR_HEIGHT;
+                continue;
+            };
+            let row = Rect::new(rect.x, y, rect.w, item_height);
+            if *enabled && row.contains(mouse) && mouse_delta_position() != Vec2::ZERO {
+                self.highlighted = Some(index);
+            }
+            if *enabled && row.contains(mouse) && is_mouse_button_pressed(MouseButton::Left) {
+                chosen = Some(index);
+            }
+            if self.highlighted == Some(index) {
+                draw_rectangle(row.x + 2.0, row.y, row.w - 4.0, row.h, style.highlight_color.color());
+            }
+
+            let text_color = style.text_color.color();
+            let color = if *enabled { text_color } else { text_color.with_alpha(0.4) };
+            let size = measure_text(label, None, style.font_size, 1.0);
+            let baseline = y + (item_height - size.height) / 2.0 + size.offset_y;
+            draw_text(label, rect.x + PADDING, baseline, style.font_size as f32, color);
+            if !shortcut_text.is_empty() {
+                let shortcut_width = measure_text(shortcut_text, None, style.font_size, 1.0).width;
+                let shortcut_color = Color::new(color.r, color.g, color.b, color.a * 0.6);
+                draw_text(shortcut_text, rect.x + rect.w - PADDING - shortcut_width, baseline, style.font_size as f32, shortcut_color);
+            }
+            y += item_height;
+        }
+
+        if is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::KpEnter) {
+            chosen = chosen.or(self.highlighted.filter(|&index| self.is_choosable(index)));
+        }
+        chosen
+    }
+
+    // Run an item's closure, returns its label
+    pub(crate) fn activate(&mut self, index: usize) -> Option<String> {
+        match self.entries.get_mut(index) {
+            Some(MenuEntry::Item { label, callback, enabled: true, .. }) => {
+                callback();
+                Some(label.clone())
+            }
+            _ => None,
+        }
+    }
+
+    // Index of an enabled item whose shortcut was pressed this frame
+    pub(crate) fn shortcut_pressed(&self) -> Option<usize> {
+        self.entries.iter().position(|entry| match entry {
+            MenuEntry::Item { shortcut: Some(shortcut), enabled: true, .. } => shortcut.pressed(),
+            _ => false,
+        })
+    }
+
+    fn is_choosable(&self, index: usize) -> bool {
+        matches!(self.entries.get(index), Some(MenuEntry::Item { enabled: true, .. }))
+    }
+}
+
+pub struct MenuBar {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    bar_color: ThemeColor,
+    style: MenuStyle,
+    menus: Vec<Menu>,
+    open: Option<usize>, // Index of the menu showing its items
+}
+
+impl MenuBar {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height: 28.0,
+            bar_color: ThemeColor::SurfaceAlt,
+            style: MenuStyle::default(),
+            menus: Vec::new(),
+            open: None,
+        }
+    }
+
+    // Add a menu to the bar, items added after this go into it
+    #[allow(unused)]
+    pub fn add_menu(&mut self, title: &str) -> &mut Self {
+        self.menus.push(Menu::new(title));
+        self
+    }
+
+    // Add an item to the last menu, with its shortcut ("" for none) and what it does
+    #[allow(unused)]
+    pub fn add_item<F: FnMut() + 'static>(&mut self, label: &str, shortcut: &str, callback: F) -> &mut Self {
+        if let Some(menu) = self.menus.last_mut() {
+            menu.add_item(label, shortcut, callback);
+        }
+        self
+    }
+
+    // Add a line between items in the last menu
+    #[allow(unused)]
+    pub fn add_separator(&mut self) -> &mut Self {
+        if let Some(menu) = self.menus.last_mut() {
+            menu.add_separator();
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_item_enabled(&mut self, menu: &str, label: &str, enabled: bool) -> &mut Self {
+        for found in self.menus.iter_mut().filter(|found| found.title == menu) {
+            found.set_item_enabled(label, enabled);
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_height(&mut self, height: f32) -> &mut Self {
+        self.height = height;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.style.font_size = font_size;
+        self
+    }
+
+    // Bar, menu, highlight and text colors
+    #[allow(unused)]
+    pub fn with_colors(
+        &mut self,
+        bar: impl Into<ThemeColor>,
+        menu: impl Into<ThemeColor>,
+        highlight: impl Into<ThemeColor>,
+        text: impl Into<ThemeColor>,
+    ) -> &mut Self {
+        self.bar_color = bar.into();
+        self.style.menu_color = menu.into();
+        self.style.highlight_color = highlight.into();
+        self.style.text_color = text.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_width(&mut self, width: f32) -> &mut Self {
+        self.width = width;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    #[allow(unused)]
+    pub fn close(&mut self) -> &mut Self {
+        if self.open.take().is_some() {
+            scale::set_mouse_blocked(false);
+            focus::pop_modal();
+        }
+        self
+    }
+
+    // Draw the bar (and the open menu) and handle input,
+    // returns "Menu/Item" on the frame an item is chosen (after its closure has run)
+    #[allow(unused)]
+    pub fn draw(&mut self) -> Option<String> {
+        // The open menu blocks the mouse for everything else, but not for itself
+        if self.open.is_some() {
+            scale::set_mouse_blocked(false);
+        }
+        let chosen = self.update();
+
+        draw_rectangle(self.x, self.y, self.width, self.height, self.bar_color.color());
+        let (mouse_x, mouse_y) = mouse_position();
+        for (index, title_rect) in self.title_rects().into_iter().enumerate() {
+            let hovered = title_rect.contains(Vec2::new(mouse_x, mouse_y));
+            if self.open == Some(index) || hovered {
+                draw_rectangle(title_rect.x, title_rect.y, title_rect.w, title_rect.h, self.style.highlight_color.color());
+            }
+            let title = &self.menus[index].title;
+            let size = measure_text(title, None, self.style.font_size, 1.0);
+            draw_text(
+                title,
+                title_rect.x + PADDING,
+                title_rect.y + (title_rect.h - size.height) / 2.0 + size.offset_y,
+                self.style.font_size as f32,
+                self.style.text_color.color(),
+            );
+        }
+
+        let mut result = None;
+        if let Some(index) = self.open {
+            let origin = self.menu_origin(index);
+            if let Some(item) = self.menus[index].update(origin, &self.style) {
+                result = Some((index, item));
+            }
+        }
+        let result = result.or(chosen);
+
+        if self.open.is_some() && result.is_none() {
+            scale::set_mouse_blocked(true);
+            return None;
+        }
+        // Close before running the item, so a dialog it opens can block the mouse itself
+        self.close();
+        let (menu, item) = result?;
+        let label = self.menus[menu].activate(item)?;
+        Some(format!("{}/{}", self.menus[menu].title, label))
+    }
+
+    // Open and close menus, returns a (menu, item) chosen by its shortcut
+    fn update(&mut self) -> Option<(usize, usize)> {
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+        let title_rects = self.title_rects();
+        let hovered_title = title_rects.iter().position(|rect| rect.contains(mouse));
+
+        if let Some(open) = self.open {
+            if is_key_pressed(KeyCode::Escape) {
+                focus::use_escape();
+                self.close();
+                return None;
+            }
+            let count = self.menus.len();
+            let mut next = hovered_title.unwrap_or(open);
+            if is_key_pressed(KeyCode::Right) {
+                next = (open + 1) % count;
+            }
+            if is_key_pressed(KeyCode::Left) {
+                next = (open + count - 1) % count;
+            }
+            if next != open {
+                self.open_menu(next);
+            }
+            if is_mouse_button_pressed(MouseButton::Left) {
+                let open = self.open.unwrap_or(open);
+                let menu_rect = self.menus[open].rect(self.menu_origin(open), &self.style);
+                if hovered_title == Some(open) {
+                    // Clicking the open menu's title closes it
+                    self.close();
+                } else if !menu_rect.contains(mouse) {
+                    self.close();
+                }
+            }
+            return None;
+        }
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            if let Some(index) = hovered_title {
+                self.open_menu(index);
+                return None;
+            }
+        }
+        // Shortcuts, unless something else (like a dialog) has taken over the mouse
+        if scale::is_mouse_blocked() {
+            return None;
+        }
+        self.menus
+            .iter()
+            .enumerate()
+            .find_map(|(menu, found)| found.shortcut_pressed().map(|item| (menu, item)))
+    }
+
+    fn open_menu(&mut self, index: usize) {
+        self.menus[index].reset();
+        // Moving between menus keeps the one modal layer
+        if self.open.is_none() {
+            focus::push_modal(&[]);
+        }
+        self.open = Some(index);
+    }
+
+    // Where each menu title sits on the bar, left to right
+    fn title_rects(&self) -> Vec<Rect> {
+        let mut x = self.x;
+        self.menus
+            .iter()
+            .map(|menu| {
+                let width = measure_text(&menu.title, None, self.style.font_size, 1.0).width + PADDING * 2.0;
+                let rect = Rect::new(x, self.y, width, self.height);
+                x += width;
+                rect
+            })
+            .collect()
+    }
+
+    // Top-left corner of a menu's drop-down, just under its title
+    fn menu_origin(&self, index: usize) -> Vec2 {
+        let title = self.title_rects()[index];
+        Vec2::new(title.x, title.y + title.h)
+    }
+}
+
+// Height of an item's row
+fn item_height(style: &MenuStyle) -> f32 {
+    style.font_size as f32 * 1.5
+}
+
+// KeyCode for a key name in a shortcut ("S", "5", "F5", "Delete", ...)
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    let upper = name.to_uppercase();
+    let letters = [
+        KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D, KeyCode::E, KeyCode::F, KeyCode::G, KeyCode::H, KeyCode::I,
+        KeyCode::J, KeyCode::K, KeyCode::L, KeyCode::M, KeyCode::N, KeyCode::O, KeyCode::P, KeyCode::Q, KeyCode::R,
+        KeyCode::S, KeyCode::T, KeyCode::U, KeyCode::V, KeyCode::W, KeyCode::X, KeyCode::Y, KeyCode::Z,
+    ];
+    let digits = [
+        KeyCode::Key0, KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4,
+        KeyCode::Key5, KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
+    ];
+    let function_keys = [
+        KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+        KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12,
+    ];
+    let mut chars = upper.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if c.is_ascii_uppercase() {
+            return Some(letters[(c as u8 - b'A') as usize]);
+        }
+        if c.is_ascii_digit() {
+            return Some(digits[(c as u8 - b'0') as usize]);
+        }
+    }
+    if let Some(number) = upper.strip_prefix('F').and_then(|number| number.parse::<usize>().ok()) {
+        return function_keys.get(number.checked_sub(1)?).copied();
+    }
+    match upper.as_str() {
+        "DELETE" | "DEL" => Some(KeyCode::Delete),
+        "ENTER" | "RETURN" => Some(KeyCode::Enter),
+        "ESCAPE" | "ESC" => Some(KeyCode::Escape),
+        "BACKSPACE" => Some(KeyCode::Backspace),
+        "INSERT" => Some(KeyCode::Insert),
+        "HOME" => Some(KeyCode::Home),
+        "END" => Some(KeyCode::End),
+        "PAGEUP" => Some(KeyCode::PageUp),
+        "PAGEDOWN" => Some(KeyCode::PageDown),
+        "SPACE" => Some(KeyCode::Space),
+        "TAB" => Some(KeyCode::Tab),
+        "UP" => Some(KeyCode::Up),
+        "DOWN" => Some(KeyCode::Down),
+        "LEFT" => Some(KeyCode::Left),
+        "RIGHT" => Some(KeyCode::Right),
+        _ => None,
+    }
+}
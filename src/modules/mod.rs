@@ -16,5 +16,46 @@ use crate::modules::grid::draw_grid;
 pub mod database;
 pub mod text_button;
 pub mod text_input;
+pub mod text_area;
 pub mod scale;
-pub mod label;
\ No newline at end of file
+pub mod label;
+pub mod clipboard;
+pub mod focus;
+pub mod ime;
+pub mod glyph_cache;
+pub mod assets;
+pub mod tooltip;
+pub mod audio;
+pub mod button_group;
+pub mod animation;
+pub mod tasks;
+pub mod log_label;
+pub mod locale;
+pub mod format;
+pub mod spinner;
+pub mod dialog;
+pub mod data_grid;
+pub mod tabs;
+pub mod panel;
+pub mod image_box;
+pub mod number_spinner;
+pub mod menu_bar;
+pub mod context_menu;
+pub mod virtual_keyboard;
+pub mod chart;
+pub mod search_bar;
+pub mod avatar;
+pub mod form;
+pub mod split_pane;
+pub mod badge;
+pub mod wizard;
+pub mod card;
+pub mod accordion;
+pub mod ui_manager;
+pub mod theme;
+pub mod layout;
+pub mod draw_cache;
+pub mod font_fallback;
+pub mod settings;
+pub mod ui_loader;
+pub mod screenshot;
\ No newline at end of file
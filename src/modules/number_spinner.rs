@@ -0,0 +1,215 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A box for whole numbers with + and - buttons beside it (good for editing columns like level)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod number_spinner;
+
+This also needs these modules:
+    pub mod focus;
+    pub mod text_button;
+    pub mod text_input;
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::number_spinner::NumberSpinner;
+
+Then above the loop section:
+    let mut num_level = NumberSpinner::new(250.0, 320.0, 160.0, 40.0, 25.0);
+    num_level.with_range(1, 100).with_step(1).set_value(new_record.level as i64);
+Where the parameters are x, y, width (buttons included), height and font size
+
+Then in the loop you would use:
+    if num_level.draw() {
+        // The value changed this frame
+        new_record.level = num_level.get_value() as i32;
+    }
+
+HOW IT CHANGES:
+    Click (or hold) + and -, scroll the mouse wheel over it, press Up / Down while it has
+    focus, or type a number and press Enter (or click away). Typing only accepts digits
+    and a minus sign, and anything outside the range is pulled back into it. Text that
+    isn't a number puts the old value back.
+
+OTHER METHODS:
+    num_level.with_range(0, 10);     // Smallest and biggest value (default no limit)
+    num_level.with_step(5);          // How much + and - change it (default 1)
+    num_level.set_value(3);
+    let value = num_level.get_value();
+    num_level.set_position(100.0, 100.0);
+    num_level.set_enabled(false);
+    let id = num_level.focus_id();   // For focus::set_tab_order (the buttons aren't in the tab order)
+*/
+
+use macroquad::prelude::*;
+use crate::modules::focus;
+use crate::modules::text_button::TextButton;
+use crate::modules::text_input::{InputFilter, TextInput};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+pub struct NumberSpinner {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    value: i64,
+    min: i64,
+    max: i64,
+    step: i64,
+    input: TextInput,
+    up_button: TextButton,
+    down_button: TextButton,
+    was_active: bool, // Whether the text box had focus last frame (losing it keeps what was typed)
+}
+
+impl NumberSpinner {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32, font_size: f32) -> Self {
+        let mut input = TextInput::new(x, y, (width - height).max(height), height, font_size);
+        input.set_filter(InputFilter::Custom(|c| c.is_ascii_digit() || c == '-'));
+        input.set_text("0");
+        let up_button = TextButton::themed(0.0, 0.0, height / 2.0, height / 2.0, "+", (height * 0.45) as u16);
+        let down_button = TextButton::themed(0.0, 0.0, height / 2.0, height / 2.0, "-", (height * 0.45) as u16);
+
+        // Tab goes to the text box only, the buttons are for the mouse
+        let button_ids = [up_button.focus_id(), down_button.focus_id()];
+        let order: Vec<usize> = focus::get_tab_order().into_iter().filter(|id| !button_ids.contains(id)).collect();
+        focus::set_tab_order(&order);
+
+        let mut spinner = Self {
+            x,
+            y,
+            width,
+            height,
+            value: 0,
+            min: i64::MIN,
+            max: i64::MAX,
+            step: 1,
+            input,
+            up_button,
+            down_button,
+            was_active: false,
+        };
+        spinner.layout();
+        spinner
+    }
+
+    // Smallest and biggest allowed values (the current value is pulled into the range)
+    #[allow(unused)]
+    pub fn with_range(&mut self, min: i64, max: i64) -> &mut Self {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self.set_value(self.value)
+    }
+
+    // How much the buttons, wheel and arrow keys change the value
+    #[allow(unused)]
+    pub fn with_step(&mut self, step: i64) -> &mut Self {
+        self.step = step.max(1);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_value(&self) -> i64 {
+        self.value
+    }
+
+    #[allow(unused)]
+    pub fn set_value(&mut self, value: i64) -> &mut Self {
+        self.value = value.clamp(self.min, self.max);
+        self.input.set_text(self.value.to_string());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self.layout();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.input.set_enabled(enabled);
+        self.up_button.set_enabled(enabled);
+        self.down_button.set_enabled(enabled);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_enabled(&self) -> bool {
+        self.input.is_enabled()
+    }
+
+    // Focus id of the text box
+    #[allow(unused)]
+    pub fn focus_id(&self) -> usize {
+        self.input.focus_id()
+    }
+
+    // Draw and handle input, returns true on the frame the value changes
+    #[allow(unused)]
+    pub fn draw(&mut self) -> bool {
+        let old_value = self.value;
+        self.input.draw();
+
+        // Typed numbers count once Enter is pressed or the box loses focus
+        let active = self.input.is_active();
+        if self.input.submitted() || (self.was_active && !active) {
+            self.commit_text();
+        }
+        self.was_active = active;
+
+        if self.up_button.click_repeating(0.4, 10.0) {
+            self.nudge(1);
+        }
+        if self.down_button.click_repeating(0.4, 10.0) {
+            self.nudge(-1);
+        }
+
+        if self.is_enabled() {
+            if active && is_key_pressed(KeyCode::Up) {
+                self.nudge(1);
+            }
+            if active && is_key_pressed(KeyCode::Down) {
+                self.nudge(-1);
+            }
+            let (mouse_x, mouse_y) = mouse_position();
+            let hovered = Rect::new(self.x, self.y, self.width, self.height).contains(Vec2::new(mouse_x, mouse_y));
+            let (_, wheel_y) = mouse_wheel();
+            if hovered && wheel_y != 0.0 {
+                self.nudge(wheel_y.signum() as i64);
+            }
+        }
+
+        self.value != old_value
+    }
+
+    // Move the value up (1) or down (-1) by the step
+    fn nudge(&mut self, direction: i64) {
+        // Use what is typed so far if it is a number, so + after typing 5 gives 6
+        self.commit_text();
+        self.set_value(self.value.saturating_add(direction * self.step));
+    }
+
+    // Turn the typed text into the value, or put the old value back if it isn't a number
+    fn commit_text(&mut self) {
+        match self.input.get_text().trim().parse::<i64>() {
+            Ok(value) => self.set_value(value),
+            Err(_) => self.set_value(self.value),
+        };
+    }
+
+    // Text box on the left, + above - on the right
+    fn layout(&mut self) {
+        let button_size = self.height / 2.0;
+        let button_x = self.x + self.width - button_size;
+        self.input.set_position(self.x, self.y);
+        self.input.set_dimensions((self.width - button_size).max(button_size), self.height);
+        self.up_button.update_position(button_x, self.y, None, None);
+        self.down_button.update_position(button_x, self.y + button_size, None, None);
+    }
+}
@@ -0,0 +1,316 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A floating window with a title bar that can be dragged around, collapsed and closed
+(handy for tool windows like a database inspector)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod panel;
+
+This also needs these modules:
+    pub mod scale;   // For clipping the contents
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::panel::Panel;
+
+Then above the loop section:
+    let mut pnl_inspector = Panel::new("Database", 600.0, 80.0, 380.0, 300.0);
+Where the parameters are the title, x, y, width and height (the title bar is included in the height)
+
+Then in the loop, draw what goes inside the panel between begin() and end():
+    if pnl_inspector.begin() {
+        let area = pnl_inspector.content_rect();
+        grd_users.set_position(area.x, area.y);
+        grd_users.set_size(area.w, area.h);
+        grd_users.draw();
+        pnl_inspector.end();
+    }
+begin() draws the frame and title bar and returns false while the panel is closed or
+collapsed (then skip the contents and don't call end). Anything drawn between begin()
+and end() is cut off at the edges of the panel. Draw panels after the widgets they
+cover so they show on top.
+
+CUSTOMIZATION:
+    pnl_inspector.with_closable(true);          // X button in the title bar (default on)
+    pnl_inspector.with_collapsible(true);       // - button that rolls it up to the title bar (default on)
+    pnl_inspector.with_draggable(false);        // Stop it being moved (default on)
+    pnl_inspector.with_title_height(32.0);      // Default 28
+    pnl_inspector.with_colors(DARKBLUE, WHITE, LIGHTGRAY); // Title bar, title text and body (default from the theme)
+    pnl_inspector.set_title("Users");
+    pnl_inspector.set_position(10.0, 10.0);
+    pnl_inspector.set_size(400.0, 320.0);
+
+OPENING AND CLOSING:
+    pnl_inspector.set_open(true);               // Show it again after the X was pressed
+    if pnl_inspector.is_open() { }
+    pnl_inspector.set_collapsed(false);
+    if pnl_inspector.is_collapsed() { }
+    let position = pnl_inspector.get_position();
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale::{self, pop_clip_rect, push_clip_rect};
+use crate::modules::theme::{self, ThemeColor};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Size of the close and collapse buttons, as a fraction of the title bar height
+const BUTTON_SCALE: f32 = 0.6;
+
+pub struct Panel {
+    title: String,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    title_height: f32,
+    title_color: ThemeColor,
+    title_text_color: ThemeColor,
+    body_color: ThemeColor,
+    closable: bool,
+    collapsible: bool,
+    draggable: bool,
+    open: bool,
+    collapsed: bool,
+    drag_offset: Option<Vec2>, // Mouse position relative to the panel while it is being dragged
+    clipping: bool,            // True between begin() and end()
+}
+
+impl Panel {
+    #[allow(unused)]
+    pub fn new(title: &str, x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            title: title.to_string(),
+            x,
+            y,
+            width,
+            height,
+            title_height: 28.0,
+            title_color: ThemeColor::Primary,
+            title_text_color: ThemeColor::OnPrimary,
+            body_color: ThemeColor::Surface,
+            closable: true,
+            collapsible: true,
+            draggable: true,
+            open: true,
+            collapsed: false,
+            drag_offset: None,
+            clipping: false,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn with_closable(&mut self, closable: bool) -> &mut Self {
+        self.closable = closable;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_collapsible(&mut self, collapsible: bool) -> &mut Self {
+        self.collapsible = collapsible;
+        if !collapsible {
+            self.collapsed = false;
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_draggable(&mut self, draggable: bool) -> &mut Self {
+        self.draggable = draggable;
+        self.drag_offset = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_title_height(&mut self, height: f32) -> &mut Self {
+        self.title_height = height;
+        self
+    }
+
+    // Title bar color, title text color and body color
+    #[allow(unused)]
+    pub fn with_colors(&mut self, title: impl Into<ThemeColor>, title_text: impl Into<ThemeColor>, body: impl Into<ThemeColor>) -> &mut Self {
+        self.title_color = title.into();
+        self.title_text_color = title_text.into();
+        self.body_color = body.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_title(&mut self, title: &str) -> &mut Self {
+        self.title = title.to_string();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_position(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    #[allow(unused)]
+    pub fn set_open(&mut self, open: bool) -> &mut Self {
+        self.open = open;
+        self.drag_offset = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    #[allow(unused)]
+    pub fn set_collapsed(&mut self, collapsed: bool) -> &mut Self {
+        self.collapsed = collapsed && self.collapsible;
+        self
+    }
+
+    // The area under the title bar where the contents go
+    #[allow(unused)]
+    pub fn content_rect(&self) -> Rect {
+        Rect::new(self.x, self.y + self.title_height, self.width, (self.height - self.title_height).max(0.0))
+    }
+
+    // Handle dragging and the title bar buttons, draw the panel, and start clipping to its contents.
+    // Returns false (and doesn't clip) while the panel is closed or collapsed
+    #[allow(unused)]
+    pub fn begin(&mut self) -> bool {
+        if !self.open {
+            return false;
+        }
+        self.update_input();
+        if !self.open {
+            return false;
+        }
+
+        let body_height = if self.collapsed { 0.0 } else { self.height - self.title_height };
+        let total_height = self.title_height + body_height.max(0.0);
+        // Shadow, body and title bar
+        let palette = theme::palette();
+        draw_rectangle(self.x + 4.0, self.y + 4.0, self.width, total_height, palette.shadow);
+        draw_rectangle(self.x, self.y, self.width, total_height, self.body_color.color());
+        draw_rectangle(self.x, self.y, self.width, self.title_height, self.title_color.color());
+        draw_rectangle_lines(self.x, self.y, self.width, total_height, 1.0, palette.border);
+
+        // Title, cut off before the buttons
+        let buttons_left = self.button_rects().iter().flatten().map(|rect| rect.x).fold(self.x + self.width, f32::min);
+        let size = measure_text(&self.title, None, (self.title_height * 0.65) as u16, 1.0);
+        push_clip_rect(Rect::new(self.x, self.y, buttons_left - self.x, self.title_height));
+        draw_text(
+            &self.title,
+            self.x + 8.0,
+            self.y + (self.title_height - size.height) / 2.0 + size.offset_y,
+            self.title_height * 0.65,
+            self.title_text_color.color(),
+        );
+        pop_clip_rect();
+
+        let title_text_color = self.title_text_color.color();
+        let [close_button, collapse_button] = self.button_rects();
+        if let Some(rect) = close_button {
+            self.draw_button(rect);
+            let inset = rect.w * 0.28;
+            draw_line(rect.x + inset, rect.y + inset, rect.x + rect.w - inset, rect.y + rect.h - inset, 2.0, title_text_color);
+            draw_line(rect.x + rect.w - inset, rect.y + inset, rect.x + inset, rect.y + rect.h - inset, 2.0, title_text_color);
+        }
+        if let Some(rect) = collapse_button {
+            self.draw_button(rect);
+            let inset = rect.w * 0.25;
+            let middle = rect.y + rect.h / 2.0;
+            draw_line(rect.x + inset, middle, rect.x + rect.w - inset, middle, 2.0, title_text_color);
+            // + when collapsed (click to expand)
+            if self.collapsed {
+                let center = rect.x + rect.w / 2.0;
+                draw_line(center, rect.y + inset, center, rect.y + rect.h - inset, 2.0, title_text_color);
+            }
+        }
+
+        if self.collapsed {
+            return false;
+        }
+        push_clip_rect(self.content_rect());
+        self.clipping = true;
+        true
+    }
+
+    // Stop clipping to the panel (only needed when begin() returned true)
+    #[allow(unused)]
+    pub fn end(&mut self) {
+        if self.clipping {
+            pop_clip_rect();
+            self.clipping = false;
+        }
+    }
+
+    fn update_input(&mut self) {
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let [close_button, collapse_button] = self.button_rects();
+            let title_bar = Rect::new(self.x, self.y, self.width, self.title_height);
+            if close_button.is_some_and(|rect| rect.contains(mouse)) {
+                self.open = false;
+                self.drag_offset = None;
+                return;
+            } else if collapse_button.is_some_and(|rect| rect.contains(mouse)) {
+                self.collapsed = !self.collapsed;
+            } else if self.draggable && title_bar.contains(mouse) {
+                self.drag_offset = Some(mouse - Vec2::new(self.x, self.y));
+            }
+        }
+
+        if let Some(offset) = self.drag_offset {
+            if is_mouse_button_down(MouseButton::Left) {
+                // Keep at least part of the title bar on screen so it can be grabbed again
+                let view = scale::visible_area();
+                let position = mouse - offset;
+                self.x = position.x.clamp(view.x + 40.0 - self.width, view.right() - 40.0);
+                self.y = position.y.clamp(view.y, (view.bottom() - self.title_height).max(view.y));
+            } else {
+                self.drag_offset = None;
+            }
+        }
+    }
+
+    // Close and collapse buttons (right to left), None when turned off
+    fn button_rects(&self) -> [Option<Rect>; 2] {
+        let size = self.title_height * BUTTON_SCALE;
+        let gap = (self.title_height - size) / 2.0;
+        let y = self.y + gap;
+        let close_x = self.x + self.width - gap - size;
+        let collapse_x = if self.closable { close_x - gap - size } else { close_x };
+        [
+            self.closable.then(|| Rect::new(close_x, y, size, size)),
+            self.collapsible.then(|| Rect::new(collapse_x, y, size, size)),
+        ]
+    }
+
+    // Background of a title bar button, lighter while hovered
+    fn draw_button(&self, rect: Rect) {
+        let (mouse_x, mouse_y) = mouse_position();
+        if rect.contains(Vec2::new(mouse_x, mouse_y)) {
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, Color::new(1.0, 1.0, 1.0, 0.25));
+        }
+    }
+}
@@ -11,6 +11,9 @@ In the Cargo.toml file add the following:
     [features]
     scale = []
     default = ["scale"]
+Screenshots (see 11.) also need this under [dependencies]:
+    image = { version = "0.24", default-features = false, features = ["png"] }
+and on the web "Blob", "BlobPropertyBag", "Url" and "HtmlAnchorElement" in the web-sys features list.
 
 Then in your main.rs file add the following to the top of the file:
     
@@ -28,6 +31,147 @@ Usage examples:
         // Draw your game objects...
     }
 
+2. Clipping drawing to a rectangle (in virtual coordinates):
+    push_clip_rect(Rect::new(100.0, 100.0, 300.0, 40.0));
+    // Anything drawn here is cut off at the edges of the rectangle
+    pop_clip_rect();
+Clip rectangles can be nested, the inner one is limited to the outer one.
+
+3. Pinning things to an edge or corner of the virtual resolution:
+    let pos = anchored_position(Anchor::BottomRight, 200.0, 60.0, 20.0, 20.0);
+    // Top-left corner for a 200x60 box, 20 pixels in from the bottom right corner
+Offsets move inward from the anchored edges (for Center they move right/down). They are
+measured from the safe area (see 10.), so nothing pinned ends up under a phone's notch.
+
+4. Drawing into a render target of a given size in pixels (draw_cache does this), so clip
+   rectangles are worked out for it instead of the window:
+    begin_render_target(512.0, 256.0);
+    // ...set a camera with the render target and draw...
+    end_render_target();
+
+5. Where the mouse and fingers are in virtual coordinates (every widget uses these for
+   hit tests, with or without the scale feature):
+    let (mouse_x, mouse_y) = mouse_position_world();
+    for touch in touches_world() {
+        // touch.position, touch.phase (Started, Moved, Ended, ...), touch.id
+    }
+    let (x, y) = screen_to_world(100.0, 50.0);   // Any window point, and world_to_screen back
+   A first finger also moves the mouse and presses its left button (macroquad does this
+   unless simulate_mouse_with_touch(false) is called), so widgets work on touch screens.
+   Without the scale feature virtual coordinates are just window coordinates.
+
+6. Stopping widgets from seeing the mouse (the dialog module does this while a dialog is open):
+    set_mouse_blocked(true);
+    // mouse_position_world now gives (-1.0, -1.0), off the top-left corner, so nothing is hovered
+    set_mouse_blocked(false);
+
+7. Choosing how the virtual resolution fills a window of a different shape:
+    set_scale_mode(ScaleMode::IntegerScale);   // Before use_virtual_resolution, once is enough
+    let mode = scale_mode();
+   The modes are:
+    ScaleMode::Fit           Everything shows, as big as fits, with bars on two sides (default)
+    ScaleMode::Fill          Fills the window, cutting off the edges that don't fit
+    ScaleMode::Stretch       Fills the window exactly, squashing or stretching things
+    ScaleMode::IntegerScale  Like Fit but only whole number sizes (2x, 3x, ...), sharp pixel art
+   Fit and IntegerScale show whatever is drawn outside the virtual resolution in the bars.
+   To cover the bars with a color instead, call draw_bars at the very end of the loop:
+    set_bar_color(BLACK);                      // Default BLACK
+    draw_bars();
+    let area = screen_area();                  // Where the virtual resolution is in the window
+   The mouse and every other function here follow the mode.
+
+8. Several virtual resolutions in one frame (a scrolling game view with a fixed UI on top, or a
+   minimap in a corner), each with its own part of the window and camera:
+    Above the loop section:
+        let mut view_game = Viewport::new(320.0, 180.0);
+        view_game.with_mode(ScaleMode::IntegerScale);
+        let mut view_ui = Viewport::new(1024.0, 768.0);
+        let mut view_map = Viewport::new(200.0, 200.0);
+        view_map.with_area(Rect::new(0.75, 0.0, 0.25, 0.25));   // Fractions of the window
+    Then in the loop:
+        clear_background(BLACK);
+        view_game.set_position(player_x - 160.0, player_y - 90.0);   // World point at its top-left
+        view_game.apply();
+        // ...draw the level and the player in the game's coordinates...
+        view_ui.draw(|| {
+            // ...draw and update the UI widgets in 1024x768...
+        });                                        // Goes back to view_game afterwards
+        view_map.clear(DARKGRAY);                  // Makes it active and fills its part of the window
+   Whichever one was applied last is what mouse_position_world, clip rectangles, draw_bars and
+   the rest of this module work with, so update widgets while their viewport is active.
+   use_virtual_resolution goes back to the whole window. A viewport can also be asked about
+   the mouse while another one is active:
+    let (x, y) = view_game.mouse_position();
+    let over_map = view_map.contains_mouse();
+   Other methods: set_zoom(2.0) / get_zoom(), get_position(), set_resolution(w, h),
+   get_resolution(), screen_area(), is_active()
+
+9. Fullscreen and window resizes (both are watched by use_virtual_resolution, so call it at the
+   top of the loop even when drawing with Viewports):
+    toggle_fullscreen();                       // Alt+Enter does this too
+    set_fullscreen(true);
+    let full = is_fullscreen();
+    set_fullscreen_key(false);                 // Turn Alt+Enter off
+    if let Some((width, height)) = resized() {
+        // The window or browser canvas changed size since last frame (window coordinates)
+    }
+   Web builds can only go fullscreen from a key press or click, which Alt+Enter and buttons are.
+
+10. The safe area, the part of the window not covered by a notch, rounded corners, the home bar
+    or browser bars (anchored_position and layout::anchored keep inside it):
+    let area = safe_area();                    // In virtual coordinates
+    let shown = visible_area();                // All of the virtual resolution that shows, notch or not
+    let insets = safe_area_insets();           // How far in from each window edge, in window coordinates
+    set_safe_area_insets(Some(Insets { top: 40.0, ..Default::default() }));   // Your own, None goes back
+   Web builds ask the browser (its env(safe-area-inset-...) values), which only gives them when
+   index.html's viewport meta tag has viewport-fit=cover. Native builds can't ask the phone, so
+   they have no insets until you give them. For a phone app set them for the devices you
+   build for, e.g. for an iPhone with a notch held upright (in window coordinates):
+    set_safe_area_insets(Some(Insets { top: 47.0, bottom: 34.0, ..Default::default() }));
+
+11. Saving a picture of the virtual resolution (the game, not the bars) as a PNG, to share a
+    leaderboard or a high score. Call it after everything is drawn, just before next_frame:
+    if btn_share.click() {
+        share_requested = true;
+    }
+    // ...the rest of the drawing...
+    if share_requested {
+        match screenshot() {                   // Saved as screenshot-<time>.png, web builds download it
+            Ok(file_name) => println!("Saved {}", file_name),
+            Err(e) => println!("{}", e),
+        }
+        share_requested = false;
+    }
+    next_frame().await;
+    save_screenshot("highscore.png")?;         // Your own file name (path on native)
+    let picture = screenshot_image();          // Just the Image, to draw or upload yourself
+    save_image_png(&picture, "later.png")?;    // Save an Image taken earlier
+   The picture has as many pixels as the virtual resolution covers in the window, so it is as
+   sharp as what is on screen. With a Viewport active only its part is taken.
+   Native builds don't open a file dialog: the file goes in the folder the game runs from.
+   The screenshot module asks the player for the file name in a text box instead.
+
+12. Split screen, the window divided into regions that each have their own Viewport (two
+    players, or an editor next to a preview):
+    Above the loop section:
+        let mut split = SplitScreen::columns(2, 640.0, 720.0);   // Side by side, each 640x720
+        split.with_background(DARKGRAY);                         // Clear each region first (optional)
+    Then in the loop:
+        clear_background(BLACK);
+        split.viewport_mut(0).set_position(player1_x - 320.0, player1_y - 360.0);
+        split.viewport_mut(1).set_position(player2_x - 320.0, player2_y - 360.0);
+        split.draw(|index| {
+            // ...draw the world (and this player's widgets) in the region's coordinates...
+        });
+        split.draw_dividers(WHITE, 4.0);
+   SplitScreen::rows(2, ...) stacks them, SplitScreen::grid(2, 2, ...) makes four (left to right,
+   then down), and SplitScreen::from_areas(&[Rect::new(0.0, 0.0, 0.7, 1.0), Rect::new(0.7, 0.0,
+   0.3, 1.0)], ...) takes any areas (fractions of the window). While a region is drawn, widgets
+   in it only see the mouse when it is over that region. Outside of draw:
+    let region = split.mouse_region();         // Some(index) of the region the mouse is over
+    let (x, y) = split.mouse_position(1);      // The mouse in region 1's coordinates
+    let count = split.len();
+
 Benefits:
 - Your game will maintain the correct aspect ratio on any screen size
 - All game coordinates stay consistent regardless of the physical screen resolution
@@ -36,7 +180,7 @@ Benefits:
 */
 
 use macroquad::prelude::*;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 
 // Static variable to store the camera using RefCell for interior mutability
 thread_local! {
@@ -47,80 +191,881 @@ thread_local! {
     });
     
     // We'll store the current virtual resolution here - made pub so other modules can access it
-    pub static VIRTUAL_RESOLUTION: RefCell<(f32, f32)> = RefCell::new((1024.0, 768.0));
+    pub static VIRTUAL_RESOLUTION: RefCell<(f32, f32)> = const { RefCell::new((1024.0, 768.0)) };
+
+    // Stack of active clip rectangles in virtual coordinates
+    static CLIP_STACK: RefCell<Vec<Rect>> = const { RefCell::new(Vec::new()) };
+
+    // When true the mouse is reported off screen so widgets ignore it
+    static MOUSE_BLOCKED: Cell<bool> = const { Cell::new(false) };
+
+    // Pixel size of the render target being drawn into (None for the window)
+    static TARGET_SIZE: Cell<Option<Vec2>> = const { Cell::new(None) };
+    // Clip stacks and target sizes put aside by begin_render_target
+    static SAVED_TARGETS: RefCell<Vec<(Vec<Rect>, Option<Vec2>)>> = const { RefCell::new(Vec::new()) };
+
+    static SCALE_MODE: Cell<ScaleMode> = const { Cell::new(ScaleMode::Fit) };
+    static BAR_COLOR: Cell<Color> = const { Cell::new(BLACK) };
+
+    // Window size last frame, and the new size on a frame it changed
+    static WINDOW_SIZE: Cell<Option<(f32, f32)>> = const { Cell::new(None) };
+    static RESIZED: Cell<Option<(f32, f32)>> = const { Cell::new(None) };
+    static FULLSCREEN: Cell<bool> = const { Cell::new(false) };
+    // Whether Alt+Enter toggles fullscreen
+    static FULLSCREEN_KEY: Cell<bool> = const { Cell::new(true) };
+    // Safe area insets set with set_safe_area_insets, used instead of the platform's
+    static SAFE_AREA_INSETS: Cell<Option<Insets>> = const { Cell::new(None) };
+
+    // How the current virtual resolution is shown (use_virtual_resolution or a Viewport)
+    static ACTIVE_VIEW: Cell<View> = const { Cell::new(View::full_window(ScaleMode::Fit)) };
+}
+
+// How the virtual resolution is fitted to the window (see 7. above)
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum ScaleMode {
+    Fit,
+    Fill,
+    Stretch,
+    IntegerScale,
+}
+
+// Where a virtual resolution is shown in the window
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct View {
+    mode: ScaleMode,
+    area: Rect,     // Part of the window it is fitted into, as fractions of the window's size
+    position: Vec2, // World point shown at the virtual resolution's top-left corner
+    zoom: f32,
+}
+
+// A view's area covering the whole window
+const WHOLE_WINDOW: Rect = Rect { x: 0.0, y: 0.0, w: 1.0, h: 1.0 };
+
+impl View {
+    const fn full_window(mode: ScaleMode) -> Self {
+        Self {
+            mode,
+            area: WHOLE_WINDOW,
+            position: Vec2::ZERO,
+            zoom: 1.0,
+        }
+    }
 }
 
 /// Sets the camera to the virtual resolution and adjusts the scale
 pub fn use_virtual_resolution(virtual_width: f32, virtual_height: f32) {
+    watch_window();
+    activate(vec2(virtual_width, virtual_height), View::full_window(scale_mode()));
+}
+
+// Switch to a virtual resolution shown in a view, and set the camera for it
+fn activate(size: Vec2, view: View) {
     // Store the virtual resolution for other functions to use
     VIRTUAL_RESOLUTION.with(|res| {
-        *res.borrow_mut() = (virtual_width, virtual_height);
+        *res.borrow_mut() = (size.x, size.y);
     });
-    
-    let screen_aspect = screen_width() / screen_height();
-    let virtual_aspect = virtual_width / virtual_height;
-
-    let (cam_width, cam_height) = if screen_aspect > virtual_aspect {
-        // Screen is wider — match height
-        let height = virtual_height;
-        let width = height * screen_aspect;
-        (width, height)
-    } else {
-        // Screen is taller — match width
-        let width = virtual_width;
-        let height = width / screen_aspect;
-        (width, height)
-    };
+    ACTIVE_VIEW.with(|active| active.set(view));
+
+    // The camera shows the whole window, which covers more or less than the virtual resolution
+    let visible = visible_world();
 
     CAMERA.with(|camera| {
         let mut camera = camera.borrow_mut();
 
         *camera = Camera2D {
-            zoom: vec2(2.0 / cam_width, 2.0 / cam_height),
-            target: vec2(virtual_width / 2.0, virtual_height / 2.0),
+            zoom: vec2(2.0 / visible.w, 2.0 / visible.h),
+            target: visible.center(),
             ..Default::default()
         };
 
         // Apply the camera settings to the game screen
         set_camera(&*camera);
     });
+    // Drawing stays inside the view's part of the window
+    apply_scissor(CLIP_STACK.with(|stack| stack.borrow().last().copied()));
 }
 
 /// Function to get the mouse position in world coordinates based on the current camera state
 pub fn mouse_position_world() -> (f32, f32) {
+    if MOUSE_BLOCKED.with(Cell::get) {
+        return (-1.0, -1.0);
+    }
     let (mouse_x, mouse_y) = ::macroquad::input::mouse_position();  // Get the raw mouse position
+    clamp_to_virtual(screen_to_world(mouse_x, mouse_y))
+}
 
-    VIRTUAL_RESOLUTION.with(|res| {
-        let (virtual_width, virtual_height) = *res.borrow();
-        
-        // Get screen dimensions
-        let screen_width = screen_width();
-        let screen_height = screen_height();
+/// Every finger on the screen, with positions in world coordinates like mouse_position_world
+/// (none while the mouse is blocked)
+#[allow(unused)]
+pub fn touches_world() -> Vec<Touch> {
+    if MOUSE_BLOCKED.with(Cell::get) {
+        return Vec::new();
+    }
+    touches()
+        .into_iter()
+        .map(|touch| {
+            let (x, y) = clamp_to_virtual(screen_to_world(touch.position.x, touch.position.y));
+            Touch { position: vec2(x, y), ..touch }
+        })
+        .collect()
+}
 
-        // Calculate the scale factor between screen and virtual resolution
-        let screen_aspect = screen_width / screen_height;
-        let virtual_aspect = virtual_width / virtual_height;
-        
-        let scale_factor = if screen_aspect > virtual_aspect {
-            // Screen is wider than virtual - height is matched
-            screen_height / virtual_height
-        } else {
-            // Screen is taller than virtual - width is matched
-            screen_width / virtual_width
+/// Convert a point in screen (window) coordinates to virtual coordinates - the opposite of world_to_screen
+#[allow(unused)]
+pub fn screen_to_world(x: f32, y: f32) -> (f32, f32) {
+    let (scale, offset) = screen_transform();
+    ((x - offset.x) / scale.x, (y - offset.y) / scale.y)
+}
+
+// Keep a point inside the virtual resolution (bars and cut off edges count as its edge)
+fn clamp_to_virtual(point: (f32, f32)) -> (f32, f32) {
+    let (size, view) = active();
+    clamp_to_view(size, view, point)
+}
+
+// Keep a point inside what a view's virtual resolution shows
+fn clamp_to_view(size: Vec2, view: View, (x, y): (f32, f32)) -> (f32, f32) {
+    // Without scaling the world is the window
+    if cfg!(not(feature = "scale")) {
+        return (x.clamp(0.0, screen_width()), y.clamp(0.0, screen_height()));
+    }
+    let shown = Rect::new(view.position.x, view.position.y, size.x / view.zoom, size.y / view.zoom);
+    (x.clamp(shown.x, shown.right()), y.clamp(shown.y, shown.bottom()))
+}
+
+/// Hide the mouse from every widget (it is reported just off the top-left corner)
+pub fn set_mouse_blocked(blocked: bool) {
+    MOUSE_BLOCKED.with(|flag| flag.set(blocked));
+}
+
+/// Whether the mouse is currently hidden from widgets
+#[allow(unused)]
+pub fn is_mouse_blocked() -> bool {
+    MOUSE_BLOCKED.with(Cell::get)
+}
+
+/// Convert a point in virtual coordinates to screen (window) coordinates - the opposite of mouse_position_world
+#[allow(unused)]
+pub fn world_to_screen(x: f32, y: f32) -> (f32, f32) {
+    let (scale, offset) = screen_transform();
+    (x * scale.x + offset.x, y * scale.y + offset.y)
+}
+
+/// The window's new size (in window coordinates) on the frame it changed, None on other frames
+#[allow(unused)]
+pub fn resized() -> Option<(f32, f32)> {
+    RESIZED.with(Cell::get)
+}
+
+/// Go between fullscreen and a window
+#[allow(unused)]
+pub fn toggle_fullscreen() {
+    set_fullscreen(!is_fullscreen());
+}
+
+#[allow(unused)]
+pub fn set_fullscreen(fullscreen: bool) {
+    FULLSCREEN.with(|current| current.set(fullscreen));
+    macroquad::window::set_fullscreen(fullscreen);
+}
+
+#[allow(unused)]
+pub fn is_fullscreen() -> bool {
+    // The browser can leave fullscreen by itself (Escape), so ask it
+    #[cfg(target_arch = "wasm32")]
+    if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        return document.fullscreen_element().is_some();
+    }
+    FULLSCREEN.with(Cell::get)
+}
+
+/// Whether Alt+Enter toggles fullscreen (default true)
+#[allow(unused)]
+pub fn set_fullscreen_key(enabled: bool) {
+    FULLSCREEN_KEY.with(|key| key.set(enabled));
+}
+
+// How far the safe area is in from each edge of the window, in window coordinates
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+#[allow(unused)]
+pub struct Insets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+/// How far in from each window edge things are hidden (notches, the home bar, browser bars)
+#[allow(unused)]
+pub fn safe_area_insets() -> Insets {
+    SAFE_AREA_INSETS.with(Cell::get).unwrap_or_else(platform_insets)
+}
+
+/// Use these insets instead of the platform's, or None to go back to the platform's
+#[allow(unused)]
+pub fn set_safe_area_insets(insets: Option<Insets>) {
+    SAFE_AREA_INSETS.with(|current| current.set(insets));
+}
+
+/// The part of the virtual resolution the active view shows (moved by its position, shrunk by its zoom),
+/// in virtual coordinates
+#[allow(unused)]
+pub fn visible_area() -> Rect {
+    // Without scaling the world is the window
+    if cfg!(not(feature = "scale")) {
+        return Rect::new(0.0, 0.0, screen_width(), screen_height());
+    }
+    let (size, view) = active();
+    Rect::new(view.position.x, view.position.y, size.x / view.zoom, size.y / view.zoom)
+}
+
+/// The part of the virtual resolution that shows and isn't under a notch or bar, in virtual coordinates
+#[allow(unused)]
+pub fn safe_area() -> Rect {
+    let insets = safe_area_insets();
+    let (left, top) = screen_to_world(insets.left, insets.top);
+    let (right, bottom) = screen_to_world(screen_width() - insets.right, screen_height() - insets.bottom);
+    let shown = visible_area();
+    Rect::new(left, top, right - left, bottom - top).intersect(shown).unwrap_or(shown)
+}
+
+// The browser's env(safe-area-inset-...) values, read from a hidden element padded by them
+#[cfg(target_arch = "wasm32")]
+fn platform_insets() -> Insets {
+    const PROBE_ID: &str = "safe-area-probe";
+    let read = || -> Option<Insets> {
+        let window = web_sys::window()?;
+        let document = window.document()?;
+        let probe = match document.get_element_by_id(PROBE_ID) {
+            Some(probe) => probe,
+            None => {
+                let probe = document.create_element("div").ok()?;
+                probe.set_id(PROBE_ID);
+                probe
+                    .set_attribute(
+                        "style",
+                        "position: fixed; visibility: hidden; pointer-events: none; padding: env(safe-area-inset-top) env(safe-area-inset-right) env(safe-area-inset-bottom) env(safe-area-inset-left);",
+                    )
+                    .ok()?;
+                document.body()?.append_child(&probe).ok()?;
+                probe
+            }
+        };
+        let style = window.get_computed_style(&probe).ok()??;
+        // CSS pixels, which are window coordinates on the web
+        let pixels = |side: &str| {
+            style
+                .get_property_value(side)
+                .ok()
+                .and_then(|value| value.trim_end_matches("px").trim().parse().ok())
+                .unwrap_or(0.0)
+        };
+        Some(Insets {
+            top: pixels("padding-top"),
+            right: pixels("padding-right"),
+            bottom: pixels("padding-bottom"),
+            left: pixels("padding-left"),
+        })
+    };
+    read().unwrap_or_default()
+}
+
+// Phones can't be asked through macroquad and their sizes differ, so native builds have none
+// unless set_safe_area_insets gives them
+#[cfg(not(target_arch = "wasm32"))]
+fn platform_insets() -> Insets {
+    Insets::default()
+}
+
+/// Save a PNG of what has been drawn in the virtual resolution this frame (call after everything
+/// is drawn) as screenshot-<time>.png, returns the file name. Web builds download it
+#[allow(unused)]
+pub fn screenshot() -> Result<String, String> {
+    let file_name = screenshot_file_name();
+    save_screenshot(&file_name)?;
+    Ok(file_name)
+}
+
+/// The file name screenshot() saves to, screenshot-<seconds since 1970>.png
+#[allow(unused)]
+pub fn screenshot_file_name() -> String {
+    format!("screenshot-{}.png", macroquad::miniquad::date::now() as u64)
+}
+
+/// Save a PNG of what has been drawn in the virtual resolution this frame (call after everything
+/// is drawn). Web builds download it, named after the end of the path
+#[allow(unused)]
+pub fn save_screenshot(path: &str) -> Result<(), String> {
+    save_image_png(&screenshot_image(), path)
+}
+
+/// Save an Image (like one from screenshot_image) as a PNG, web builds download it
+#[allow(unused)]
+pub fn save_image_png(picture: &Image, path: &str) -> Result<(), String> {
+    let png = encode_png(picture)?;
+    #[cfg(not(target_arch = "wasm32"))]
+    return std::fs::write(path, &png).map_err(|e| format!("Couldn't save {}: {}", path, e));
+    #[cfg(target_arch = "wasm32")]
+    {
+        let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        download(&png, file_name).ok_or_else(|| format!("Couldn't download {}", file_name))
+    }
+}
+
+/// What has been drawn in the virtual resolution this frame, in the window's real pixels
+#[allow(unused)]
+pub fn screenshot_image() -> Image {
+    let (_, view) = active();
+    let window = Rect::new(0.0, 0.0, screen_width(), screen_height());
+    let shown = screen_area().intersect(window_region(view.area)).and_then(|shown| shown.intersect(window)).unwrap_or_default();
+    let screen = get_screen_data();
+    let dpi = screen_dpi_scale();
+    let pixel_x = |x: f32| ((x * dpi).round().max(0.0) as u16).min(screen.width);
+    let pixel_y = |y: f32| ((y * dpi).round().max(0.0) as u16).min(screen.height);
+    let (left, top, right, bottom) = (pixel_x(shown.x), pixel_y(shown.y), pixel_x(shown.right()), pixel_y(shown.bottom()));
+    let (width, height) = (right - left, bottom - top);
+    let mut bytes = Vec::with_capacity(width as usize * height as usize * 4);
+    // The screen comes bottom row first
+    for y in top..bottom {
+        let start = ((screen.height - 1 - y) as usize * screen.width as usize + left as usize) * 4;
+        bytes.extend_from_slice(&screen.bytes[start..start + width as usize * 4]);
+    }
+    // See-through backgrounds come out solid, like on screen
+    for pixel in bytes.chunks_exact_mut(4) {
+        pixel[3] = 255;
+    }
+    Image { bytes, width, height }
+}
+
+fn encode_png(picture: &Image) -> Result<Vec<u8>, String> {
+    use image::ImageEncoder;
+    let mut png = Vec::new();
+    image::codecs::png::PngEncoder::new(&mut png)
+        .write_image(&picture.bytes, picture.width as u32, picture.height as u32, image::ColorType::Rgba8)
+        .map_err(|e| e.to_string())?;
+    Ok(png)
+}
+
+// Have the browser download a file through a link to it
+#[cfg(target_arch = "wasm32")]
+fn download(bytes: &[u8], file_name: &str) -> Option<()> {
+    use wasm_bindgen::JsCast;
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("image/png");
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options).ok()?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).ok()?;
+    let link: web_sys::HtmlAnchorElement = web_sys::window()?.document()?.create_element("a").ok()?.dyn_into().ok()?;
+    link.set_href(&url);
+    link.set_download(file_name);
+    link.click();
+    web_sys::Url::revoke_object_url(&url).ok()
+}
+
+// Once a frame: Alt+Enter, and whether the window changed size since last frame
+fn watch_window() {
+    let alt = is_key_down(KeyCode::LeftAlt) || is_key_down(KeyCode::RightAlt);
+    if FULLSCREEN_KEY.with(Cell::get) && alt && (is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::KpEnter)) {
+        toggle_fullscreen();
+    }
+    let size = (screen_width(), screen_height());
+    let last = WINDOW_SIZE.with(|window| window.replace(Some(size)));
+    RESIZED.with(|resized| resized.set(last.filter(|last| *last != size).map(|_| size)));
+}
+
+/// Choose how the virtual resolution fills the window, used from the next use_virtual_resolution
+#[allow(unused)]
+pub fn set_scale_mode(mode: ScaleMode) {
+    SCALE_MODE.with(|current| current.set(mode));
+}
+
+#[allow(unused)]
+pub fn scale_mode() -> ScaleMode {
+    SCALE_MODE.with(Cell::get)
+}
+
+/// Color draw_bars covers the bars with
+#[allow(unused)]
+pub fn set_bar_color(color: Color) {
+    BAR_COLOR.with(|bar| bar.set(color));
+}
+
+/// Where the virtual resolution is drawn in the window, in window coordinates
+#[allow(unused)]
+pub fn screen_area() -> Rect {
+    let (size, view) = active();
+    view_screen_area(size, view)
+}
+
+/// Cover everything outside the virtual resolution with the bar color, call at the end of the loop
+/// (with a Viewport active only its part of the window gets bars)
+#[allow(unused)]
+pub fn draw_bars() {
+    let (_, view) = active();
+    let region = window_region(view.area);
+    let area = screen_area();
+    let color = BAR_COLOR.with(Cell::get);
+    // Bars can't be clipped away by a clip rectangle left open
+    let clip = CLIP_STACK.with(|stack| stack.borrow().last().copied());
+    apply_scissor(None);
+    // Left and right the full height, then top and bottom between them
+    let (left, right) = ((area.x - region.x).max(0.0), (region.right() - area.right()).max(0.0));
+    let (top, bottom) = ((area.y - region.y).max(0.0), (region.bottom() - area.bottom()).max(0.0));
+    let (between, width) = (region.x + left, (region.w - left - right).max(0.0));
+    draw_window_rect(Rect::new(region.x, region.y, left, region.h), color);
+    draw_window_rect(Rect::new(region.right() - right, region.y, right, region.h), color);
+    draw_window_rect(Rect::new(between, region.y, width, top), color);
+    draw_window_rect(Rect::new(between, region.bottom() - bottom, width, bottom), color);
+    apply_scissor(clip);
+}
+
+// Draw a rectangle given in window coordinates with the current camera
+fn draw_window_rect(rect: Rect, color: Color) {
+    let (left, top) = screen_to_world(rect.x, rect.y);
+    let (right, bottom) = screen_to_world(rect.right(), rect.bottom());
+    draw_rectangle(left, top, right - left, bottom - top, color);
+}
+
+// The current virtual resolution and how it is shown
+fn active() -> (Vec2, View) {
+    let (virtual_width, virtual_height) = VIRTUAL_RESOLUTION.with(|res| *res.borrow());
+    (vec2(virtual_width, virtual_height), ACTIVE_VIEW.with(Cell::get))
+}
+
+// Scale and offset that turn virtual coordinates into window coordinates for the scale mode
+fn screen_transform() -> (Vec2, Vec2) {
+    let (size, view) = active();
+    view_transform(size, view)
+}
+
+// Scale and offset that turn a view's world coordinates into window coordinates
+fn view_transform(size: Vec2, view: View) -> (Vec2, Vec2) {
+    // Without scaling virtual coordinates are window coordinates
+    if cfg!(not(feature = "scale")) {
+        return (Vec2::ONE, Vec2::ZERO);
+    }
+    let region = window_region(view.area);
+    let fit = (region.w / size.x).min(region.h / size.y);
+    let scale = match view.mode {
+        ScaleMode::Fit => Vec2::splat(fit),
+        ScaleMode::Fill => Vec2::splat((region.w / size.x).max(region.h / size.y)),
+        ScaleMode::Stretch => vec2(region.w / size.x, region.h / size.y),
+        ScaleMode::IntegerScale => {
+            // Whole numbers of real pixels (a window smaller than the resolution gets Fit)
+            let pixels = (fit * screen_dpi_scale()).floor();
+            Vec2::splat(if pixels >= 1.0 { pixels / screen_dpi_scale() } else { fit })
+        }
+    };
+    // Centered, on a whole real pixel so integer scaling stays sharp
+    let dpi = screen_dpi_scale();
+    let offset = ((region.point() + (region.size() - size * scale) / 2.0) * dpi).round() / dpi;
+    // Zooming keeps the virtual resolution where it is and shows less of the world in it
+    let scale = scale * view.zoom;
+    (scale, offset - view.position * scale)
+}
+
+// Where a view's virtual resolution is drawn, in window coordinates
+fn view_screen_area(size: Vec2, view: View) -> Rect {
+    let (scale, offset) = view_transform(size, view);
+    let top_left = view.position * scale + offset;
+    Rect::new(top_left.x, top_left.y, size.x / view.zoom * scale.x, size.y / view.zoom * scale.y)
+}
+
+// A view's part of the window in window coordinates
+fn window_region(area: Rect) -> Rect {
+    let (width, height) = (screen_width(), screen_height());
+    Rect::new(area.x * width, area.y * height, area.w * width, area.h * height)
+}
+
+// The part of the virtual coordinates the window shows (bigger than the virtual resolution
+// when there are bars, smaller when Fill cuts some off)
+fn visible_world() -> Rect {
+    let (scale, offset) = screen_transform();
+    Rect::new(-offset.x / scale.x, -offset.y / scale.y, screen_width() / scale.x, screen_height() / scale.y)
+}
+
+// A virtual resolution with its own part of the window, scroll position and zoom (see 8. above)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Viewport {
+    size: Vec2,
+    view: View,
+}
+
+impl Viewport {
+    // Fills the whole window with the scale mode from set_scale_mode until changed
+    #[allow(unused)]
+    pub fn new(virtual_width: f32, virtual_height: f32) -> Self {
+        Self {
+            size: vec2(virtual_width, virtual_height),
+            view: View::full_window(scale_mode()),
+        }
+    }
+
+    #[allow(unused)]
+    pub fn with_mode(&mut self, mode: ScaleMode) -> &mut Self {
+        self.view.mode = mode;
+        self
+    }
+
+    // Part of the window it is fitted into, as fractions of the window (0.0 to 1.0) so it
+    // follows resizes. Rect::new(0.5, 0.0, 0.5, 1.0) is the right half
+    #[allow(unused)]
+    pub fn with_area(&mut self, area: Rect) -> &mut Self {
+        self.view.area = area;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_resolution(&mut self, virtual_width: f32, virtual_height: f32) -> &mut Self {
+        self.size = vec2(virtual_width, virtual_height);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_resolution(&self) -> (f32, f32) {
+        (self.size.x, self.size.y)
+    }
+
+    // World point shown at the top-left corner, move it to scroll (default 0, 0)
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.view.position = vec2(x, y);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_position(&self) -> Vec2 {
+        self.view.position
+    }
+
+    // 2.0 shows half as much of the world twice as big, around the top-left corner (default 1.0)
+    #[allow(unused)]
+    pub fn set_zoom(&mut self, zoom: f32) -> &mut Self {
+        self.view.zoom = zoom.max(0.01);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_zoom(&self) -> f32 {
+        self.view.zoom
+    }
+
+    // Draw with this viewport from now on, like use_virtual_resolution
+    #[allow(unused)]
+    pub fn apply(&self) {
+        activate(self.size, self.view);
+    }
+
+    // Draw with this viewport while the closure runs, then go back to the one before it
+    #[allow(unused)]
+    pub fn draw<F: FnOnce()>(&self, draw: F) {
+        let (size, view) = active();
+        self.apply();
+        draw();
+        activate(size, view);
+    }
+
+    // Make this viewport active and fill its part of the window (clear_background would clear
+    // the others too)
+    #[allow(unused)]
+    pub fn clear(&self, color: Color) {
+        self.apply();
+        let clip = CLIP_STACK.with(|stack| stack.borrow().last().copied());
+        apply_scissor(None);
+        draw_window_rect(window_region(self.view.area), color);
+        apply_scissor(clip);
+    }
+
+    #[allow(unused)]
+    pub fn is_active(&self) -> bool {
+        active() == (self.size, self.view)
+    }
+
+    // The mouse in this viewport's world coordinates, whichever viewport is active
+    // ((-1.0, -1.0) while the mouse is blocked)
+    #[allow(unused)]
+    pub fn mouse_position(&self) -> (f32, f32) {
+        if MOUSE_BLOCKED.with(Cell::get) {
+            return (-1.0, -1.0);
+        }
+        let (scale, offset) = view_transform(self.size, self.view);
+        let mouse = (Vec2::from(::macroquad::input::mouse_position()) - offset) / scale;
+        clamp_to_view(self.size, self.view, (mouse.x, mouse.y))
+    }
+
+    // Whether the mouse is over what this viewport shows (not its bars)
+    #[allow(unused)]
+    pub fn contains_mouse(&self) -> bool {
+        let shown = self.screen_area().intersect(window_region(self.view.area));
+        !MOUSE_BLOCKED.with(Cell::get) && shown.is_some_and(|shown| shown.contains(::macroquad::input::mouse_position().into()))
+    }
+
+    // Where its virtual resolution is drawn in the window, in window coordinates
+    #[allow(unused)]
+    pub fn screen_area(&self) -> Rect {
+        view_screen_area(self.size, self.view)
+    }
+}
+
+// The window split into regions that each have their own Viewport (see 12. above)
+#[derive(Clone, PartialEq, Debug)]
+pub struct SplitScreen {
+    viewports: Vec<Viewport>,
+    background: Option<Color>, // Each region is cleared to this before it is drawn
+}
+
+impl SplitScreen {
+    // Side by side, left to right
+    #[allow(unused)]
+    pub fn columns(count: usize, virtual_width: f32, virtual_height: f32) -> Self {
+        Self::grid(count, 1, virtual_width, virtual_height)
+    }
+
+    // One above the other, top to bottom
+    #[allow(unused)]
+    pub fn rows(count: usize, virtual_width: f32, virtual_height: f32) -> Self {
+        Self::grid(1, count, virtual_width, virtual_height)
+    }
+
+    // Equal regions numbered left to right, then top to bottom
+    #[allow(unused)]
+    pub fn grid(columns: usize, rows: usize, virtual_width: f32, virtual_height: f32) -> Self {
+        let (columns, rows) = (columns.max(1), rows.max(1));
+        let (width, height) = (1.0 / columns as f32, 1.0 / rows as f32);
+        let areas: Vec<Rect> = (0..columns * rows)
+            .map(|index| Rect::new((index % columns) as f32 * width, (index / columns) as f32 * height, width, height))
+            .collect();
+        Self::from_areas(&areas, virtual_width, virtual_height)
+    }
+
+    // Regions of any size, as fractions of the window (0.0 to 1.0)
+    #[allow(unused)]
+    pub fn from_areas(areas: &[Rect], virtual_width: f32, virtual_height: f32) -> Self {
+        let viewports = areas
+            .iter()
+            .map(|area| {
+                let mut viewport = Viewport::new(virtual_width, virtual_height);
+                viewport.with_area(*area);
+                viewport
+            })
+            .collect();
+        Self { viewports, background: None }
+    }
+
+    #[allow(unused)]
+    pub fn with_background(&mut self, color: Color) -> &mut Self {
+        self.background = Some(color);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.viewports.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.viewports.is_empty()
+    }
+
+    // A region's Viewport, numbered from 0 (panics past the last one, like a Vec)
+    #[allow(unused)]
+    pub fn viewport(&self, index: usize) -> &Viewport {
+        &self.viewports[index]
+    }
+
+    // Change a region's camera (position, zoom), resolution or scale mode
+    #[allow(unused)]
+    pub fn viewport_mut(&mut self, index: usize) -> &mut Viewport {
+        &mut self.viewports[index]
+    }
+
+    // Draw each region in turn with its own Viewport, the closure gets the region's number.
+    // Goes back to the viewport that was active before afterwards
+    #[allow(unused)]
+    pub fn draw<F: FnMut(usize)>(&self, mut draw: F) {
+        let (size, view) = active();
+        let blocked = is_mouse_blocked();
+        let over = self.mouse_region();
+        for (index, viewport) in self.viewports.iter().enumerate() {
+            // Widgets in a region only see the mouse while it is over that region
+            set_mouse_blocked(blocked || over != Some(index));
+            match self.background {
+                Some(color) => viewport.clear(color),
+                None => viewport.apply(),
+            }
+            draw(index);
+        }
+        set_mouse_blocked(blocked);
+        activate(size, view);
+    }
+
+    // The region the mouse is over (the last one given when they overlap)
+    #[allow(unused)]
+    pub fn mouse_region(&self) -> Option<usize> {
+        self.viewports.iter().rposition(Viewport::contains_mouse)
+    }
+
+    // The mouse in a region's coordinates, wherever it is
+    #[allow(unused)]
+    pub fn mouse_position(&self, index: usize) -> (f32, f32) {
+        self.viewports[index].mouse_position()
+    }
+
+    // Lines along the edges where regions meet
+    #[allow(unused)]
+    pub fn draw_dividers(&self, color: Color, thickness: f32) {
+        let (size, view) = active();
+        // Draw in window coordinates for a moment
+        activate(vec2(screen_width(), screen_height()), View::full_window(ScaleMode::Stretch));
+        apply_scissor(None);
+        for viewport in &self.viewports {
+            let region = window_region(viewport.view.area);
+            // Each region draws its left and top edges, unless they are the window's
+            if region.x > 0.0 {
+                draw_rectangle(region.x - thickness / 2.0, region.y, thickness, region.h, color);
+            }
+            if region.y > 0.0 {
+                draw_rectangle(region.x, region.y - thickness / 2.0, region.w, thickness, color);
+            }
+        }
+        activate(size, view);
+    }
+}
+
+// Where on the screen something is pinned
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+/// Top-left position for a box of the given size pinned to an anchor of the virtual resolution's safe area
+#[allow(unused)]
+pub fn anchored_position(anchor: Anchor, width: f32, height: f32, offset_x: f32, offset_y: f32) -> Vec2 {
+    // Without scaling this is the window's safe area, as the widgets work in window pixels
+    let area = safe_area();
+
+    // 0.0 = start edge, 0.5 = middle, 1.0 = end edge
+    let (along_x, along_y) = match anchor {
+        Anchor::TopLeft => (0.0, 0.0),
+        Anchor::Top => (0.5, 0.0),
+        Anchor::TopRight => (1.0, 0.0),
+        Anchor::Left => (0.0, 0.5),
+        Anchor::Center => (0.5, 0.5),
+        Anchor::Right => (1.0, 0.5),
+        Anchor::BottomLeft => (0.0, 1.0),
+        Anchor::Bottom => (0.5, 1.0),
+        Anchor::BottomRight => (1.0, 1.0),
+    };
+    // Offsets push inward from an end edge
+    let offset_x = if along_x == 1.0 { -offset_x } else { offset_x };
+    let offset_y = if along_y == 1.0 { -offset_y } else { offset_y };
+    vec2(
+        area.x + (area.w - width) * along_x + offset_x,
+        area.y + (area.h - height) * along_y + offset_y,
+    )
+}
+
+/// Limit drawing to a rectangle given in virtual coordinates until pop_clip_rect() is called
+pub fn push_clip_rect(rect: Rect) {
+    let clip = CLIP_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        // Nested clips can only shrink the visible area
+        let clip = match stack.last() {
+            Some(outer) => rect.intersect(*outer).unwrap_or(Rect::new(rect.x, rect.y, 0.0, 0.0)),
+            None => rect,
         };
+        stack.push(clip);
+        clip
+    });
+    apply_scissor(Some(clip));
+}
 
-        // Calculate the offset (to center content)
-        let offset_x = (screen_width - virtual_width * scale_factor) / 2.0;
-        let offset_y = (screen_height - virtual_height * scale_factor) / 2.0;
+/// Remove the most recent clip rectangle, restoring the one before it (if any)
+pub fn pop_clip_rect() {
+    let clip = CLIP_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        stack.pop();
+        stack.last().copied()
+    });
+    apply_scissor(clip);
+}
 
-        // Convert screen coordinates to virtual coordinates
-        let virtual_x = (mouse_x - offset_x) / scale_factor;
-        let virtual_y = (mouse_y - offset_y) / scale_factor;
+/// Start drawing into a render target of this many pixels instead of the window (draw_cache
+/// does this). Clip rectangles start over inside it, the window's come back with end_render_target
+pub fn begin_render_target(width: f32, height: f32) {
+    let clips = CLIP_STACK.with(|stack| std::mem::take(&mut *stack.borrow_mut()));
+    let size = TARGET_SIZE.with(|size| size.replace(Some(vec2(width, height))));
+    SAVED_TARGETS.with(|saved| saved.borrow_mut().push((clips, size)));
+    apply_scissor(None);
+}
 
-        // Clamp coordinates to the virtual resolution
-        let virtual_x = virtual_x.clamp(0.0, virtual_width);
-        let virtual_y = virtual_y.clamp(0.0, virtual_height);
+/// Go back to drawing where things were drawn before begin_render_target
+pub fn end_render_target() {
+    let Some((clips, size)) = SAVED_TARGETS.with(|saved| saved.borrow_mut().pop()) else {
+        return;
+    };
+    let clip = clips.last().copied();
+    CLIP_STACK.with(|stack| *stack.borrow_mut() = clips);
+    TARGET_SIZE.with(|target| target.set(size));
+    apply_scissor(clip);
+}
 
-        (virtual_x, virtual_y)
-    })
+// Convert a virtual rectangle to framebuffer pixels and hand it to the GPU scissor test
+fn apply_scissor(rect: Option<Rect>) {
+    let gl = unsafe { get_internal_gl() };
+    // The window's framebuffer, or the render target being drawn into
+    let dpi = screen_dpi_scale();
+    let target = TARGET_SIZE.with(Cell::get);
+    let (pixels_wide, pixels_high) = match target {
+        Some(size) => (size.x, size.y),
+        None => (screen_width() * dpi, screen_height() * dpi),
+    };
+    let scissor = rect.map(|rect| {
+        // Works with whatever camera is active, not just the virtual resolution one
+        let projection = gl.quad_gl.get_projection_matrix();
+        let to_pixels = |x: f32, y: f32| {
+            let point = projection.transform_point3(vec3(x, y, 0.0));
+            vec2(
+                (point.x / 2.0 + 0.5) * pixels_wide,
+                (0.5 - point.y / 2.0) * pixels_high,
+            )
+        };
+        let top_left = to_pixels(rect.x, rect.y);
+        let bottom_right = to_pixels(rect.x + rect.w, rect.y + rect.h);
+        (
+            top_left.x.min(bottom_right.x) as i32,
+            top_left.y.min(bottom_right.y) as i32,
+            (bottom_right.x - top_left.x).abs() as i32,
+            (bottom_right.y - top_left.y).abs() as i32,
+        )
+    });
+    // On the window a Viewport only draws inside its own part of it
+    let area = ACTIVE_VIEW.with(Cell::get).area;
+    let region = (cfg!(feature = "scale") && target.is_none() && area != WHOLE_WINDOW).then_some((
+        (area.x * pixels_wide) as i32,
+        (area.y * pixels_high) as i32,
+        (area.w * pixels_wide) as i32,
+        (area.h * pixels_high) as i32,
+    ));
+    let scissor = match (scissor, region) {
+        (Some(clip), Some(region)) => {
+            let (left, top) = (clip.0.max(region.0), clip.1.max(region.1));
+            let right = (clip.0 + clip.2).min(region.0 + region.2);
+            let bottom = (clip.1 + clip.3).min(region.1 + region.3);
+            Some((left, top, (right - left).max(0), (bottom - top).max(0)))
+        }
+        (clip, region) => clip.or(region),
+    };
+    gl.quad_gl.scissor(scissor);
 }
@@ -0,0 +1,63 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Lets the player save a screenshot of the game (a leaderboard, a high score) where they want it
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod screenshot;
+
+This also needs these modules:
+    pub mod dialog;
+    pub mod scale;
+
+Add with the other use statements:
+    use crate::modules::screenshot;
+
+HOW IT WORKS:
+    The picture is taken straight away with scale::screenshot_image, so call it after
+    everything is drawn, just before next_frame. Native builds then show a Dialog::prompt
+    text box asking for the file name (".png" is added if it is left off). This is not a
+    file dialog: the name is a path from the folder the game runs from. Web builds
+    download the file without asking. Anything that goes wrong is shown with Dialog::alert.
+    To save without asking, use scale::screenshot() or scale::save_screenshot(path) instead.
+
+Usage example:
+    if btn_share.click() {
+        share_requested = true;
+    }
+    // ...the rest of the drawing...
+    if share_requested {
+        screenshot::save_as();
+        share_requested = false;
+    }
+    dialog::draw();
+    next_frame().await;
+*/
+
+use crate::modules::dialog::Dialog;
+use crate::modules::scale;
+
+/// Take a screenshot and ask for its file name in a dialog (web builds download it)
+#[allow(unused)]
+pub fn save_as() {
+    let picture = scale::screenshot_image();
+    let file_name = scale::screenshot_file_name();
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let save = move |name: String| {
+            let name = name.trim();
+            if name.is_empty() {
+                return;
+            }
+            let path = if name.to_lowercase().ends_with(".png") { name.to_string() } else { format!("{}.png", name) };
+            if let Err(e) = scale::save_image_png(&picture, &path) {
+                Dialog::alert(e);
+            }
+        };
+        Dialog::prompt("Save the screenshot as:", &file_name, save, || {});
+    }
+    #[cfg(target_arch = "wasm32")]
+    if let Err(e) = scale::save_image_png(&picture, &file_name) {
+        Dialog::alert(e);
+    }
+}
@@ -0,0 +1,220 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A search box that looks records up in the database as you type, waiting until typing
+pauses so it doesn't send a request for every letter
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod search_bar;
+
+This also needs these modules:
+    pub mod database;
+    pub mod spinner;
+    pub mod tasks;
+    pub mod text_input;
+
+Add with the other use statements:
+    use crate::modules::search_bar::SearchBar;
+
+Then above the loop section (the client is shared with Rc so searches can run in the background):
+    let client = Rc::new(create_database_client());
+    let mut sch_users: SearchBar<DatabaseTable> = SearchBar::new(250.0, 50.0, 300.0, 40.0, 25.0, client.clone(), "draysTable", "username");
+    let mut found: Vec<DatabaseTable> = Vec::new();
+Where the parameters are x, y, width, height, font size, the client, the table, and the
+column to search. Records are found when the column contains the typed text anywhere,
+ignoring upper/lower case (a PostgREST ilike filter sent with fetch_table_with_query).
+
+Then in the loop you would use:
+    if let Some(result) = sch_users.draw() {
+        match result {
+            Ok(records) => found = records,     // New results, show them in a list or DataGrid
+            Err(e) => println!("Search failed: {}", e),
+        }
+    }
+Don't forget tasks::run_pending(); at the end of the loop, the searches run through it.
+
+HOW IT WORKS:
+    After the text changes, the search waits until nothing has been typed for a moment
+    (0.3 seconds by default) and then starts a request in the background. Pressing Enter
+    searches straight away. A small spinner shows in the box while a search is running.
+    If the text changes again before the results come back, the old results are thrown
+    away so what's shown always matches the box. Clearing the box gives an empty list
+    without asking the database.
+
+OTHER METHODS:
+    sch_users.with_debounce(0.5);         // Seconds to wait after typing stops
+    sch_users.with_limit(20);             // Most records to get back (default 50)
+    sch_users.with_min_chars(2);          // Don't search until this many letters are typed (default 1)
+    sch_users.with_order("level.desc");   // PostgREST order for the results (default "id")
+    sch_users.set_prompt("Search players");
+    sch_users.set_text("dray");           // Also starts a search
+    let text = sch_users.get_text();
+    if sch_users.is_searching() { }
+    sch_users.set_position(10.0, 10.0);
+    let id = sch_users.focus_id();
+*/
+
+use macroquad::prelude::*;
+use crate::modules::database::{percent_encode, DatabaseClient};
+use crate::modules::spinner::Spinner;
+use crate::modules::tasks::{self, TaskHandle};
+use crate::modules::text_input::TextInput;
+use serde::de::DeserializeOwned;
+use std::rc::Rc;
+
+pub struct SearchBar<T> {
+    input: TextInput,
+    spinner: Spinner,
+    client: Rc<DatabaseClient>,
+    table: String,
+    column: String,
+    order: String,
+    limit: usize,
+    min_chars: usize,
+    debounce: f64,
+    searched_text: String,               // Text the results (or running search) are for
+    changed_at: Option<f64>,             // When the text last changed, while a search is waiting to start
+    task: Option<TaskHandle<Result<Vec<T>, String>>>,
+}
+
+impl<T: DeserializeOwned + 'static> SearchBar<T> {
+    #[allow(unused)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32, font_size: f32, client: Rc<DatabaseClient>, table: &str, column: &str) -> Self {
+        let mut input = TextInput::new(x, y, width, height, font_size);
+        input.set_prompt("Search");
+        Self {
+            input,
+            spinner: Self::box_spinner(x, y, width, height),
+            client,
+            table: table.to_string(),
+            column: column.to_string(),
+            order: String::from("id"),
+            limit: 50,
+            min_chars: 1,
+            debounce: 0.3,
+            searched_text: String::new(),
+            changed_at: None,
+            task: None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn with_debounce(&mut self, seconds: f64) -> &mut Self {
+        self.debounce = seconds.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = limit.max(1);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_min_chars(&mut self, count: usize) -> &mut Self {
+        self.min_chars = count;
+        self
+    }
+
+    // PostgREST order for the results, like "username" or "level.desc"
+    #[allow(unused)]
+    pub fn with_order(&mut self, order: &str) -> &mut Self {
+        self.order = order.to_string();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_prompt(&mut self, prompt: &str) -> &mut Self {
+        self.input.set_prompt(prompt);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_text(&self) -> String {
+        self.input.get_text()
+    }
+
+    // Change the text from code (searches after the usual wait)
+    #[allow(unused)]
+    pub fn set_text(&mut self, text: &str) -> &mut Self {
+        self.input.set_text(text);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.input.set_position(x, y);
+        let (width, height) = self.input.get_dimensions();
+        self.spinner = Self::box_spinner(x, y, width, height);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn focus_id(&self) -> usize {
+        self.input.focus_id()
+    }
+
+    // Whether a search is waiting to start or running
+    #[allow(unused)]
+    pub fn is_searching(&self) -> bool {
+        self.changed_at.is_some() || self.task.as_ref().is_some_and(|task| !task.is_done())
+    }
+
+    // Draw the box and run searches, returns the results on the frame they arrive
+    #[allow(unused)]
+    pub fn draw(&mut self) -> Option<Result<Vec<T>, String>> {
+        self.input.draw();
+        let text = self.input.get_text();
+        if text != self.searched_text {
+            // Results for the old text are no use any more
+            self.searched_text = text.clone();
+            self.changed_at = Some(get_time());
+            self.task = None;
+        }
+
+        let mut result = None;
+        let due = self.changed_at.is_some_and(|changed| get_time() - changed >= self.debounce);
+        if due || (self.input.submitted() && self.task.is_none()) {
+            self.changed_at = None;
+            let query = text.trim();
+            if query.chars().count() < self.min_chars.max(1) {
+                // Nothing to look for, so there's nothing found
+                result = Some(Ok(Vec::new()));
+            } else {
+                self.start_search(query);
+            }
+        }
+
+        if let Some(task) = &self.task {
+            if !task.is_done() {
+                self.spinner.draw();
+            }
+            if let Some(found) = task.take() {
+                result = Some(found);
+            }
+        }
+        result
+    }
+
+    fn start_search(&mut self, text: &str) {
+        let client = self.client.clone();
+        let table = self.table.clone();
+        let query = format!(
+            "select=*&{}=ilike.*{}*&order={}&limit={}",
+            self.column,
+            percent_encode(text),
+            self.order,
+            self.limit
+        );
+        self.task = Some(tasks::spawn(async move {
+            client.fetch_table_with_query::<T>(&table, &query).await.map_err(|e| e.to_string())
+        }));
+    }
+
+    // Small spinner at the right end of the box
+    fn box_spinner(x: f32, y: f32, width: f32, height: f32) -> Spinner {
+        let radius = height * 0.22;
+        Spinner::new(x + width - height / 2.0, y + height / 2.0, radius)
+    }
+}
@@ -0,0 +1,140 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Remembers small settings (mute, a player's name, ...) between runs of the game
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod settings;
+
+Web builds also need "Storage" in the web-sys features list in Cargo.toml.
+
+Add with the other use statements:
+    use crate::modules::settings;
+
+HOW IT WORKS:
+    Settings are text values stored by name. They are read the first time one is asked
+    for and saved every time one changes. Native builds keep them in settings.json next
+    to where the program runs, web builds keep them in the browser's localStorage, so they
+    stay after the page is reloaded.
+    The audio module keeps its mute setting here.
+
+Usage examples:
+    settings::set("player_name", "Dray");
+    let name = settings::get("player_name").unwrap_or_default();
+
+    settings::set_bool("show_fps", true);
+    let show_fps = settings::get_bool("show_fps").unwrap_or(false);
+
+    settings::remove("player_name");
+
+    // Change the file they are saved in (native only), before any are used
+    settings::set_file("saves/settings.json");
+*/
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+thread_local! {
+    // Every setting, None until they have been read
+    static VALUES: RefCell<Option<BTreeMap<String, String>>> = const { RefCell::new(None) };
+    #[cfg(not(target_arch = "wasm32"))]
+    static FILE: RefCell<String> = RefCell::new("settings.json".to_string());
+}
+
+// The localStorage entry they are all kept in on the web
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "settings";
+
+/// A setting's value, if it has been set
+#[allow(unused)]
+pub fn get(key: &str) -> Option<String> {
+    with_values(|values| values.get(key).cloned())
+}
+
+/// Change a setting and save
+#[allow(unused)]
+pub fn set<T: Into<String>>(key: &str, value: T) {
+    let value = value.into();
+    let changed = with_values(|values| values.insert(key.to_string(), value.clone()) != Some(value));
+    if changed {
+        save();
+    }
+}
+
+/// Forget a setting and save
+#[allow(unused)]
+pub fn remove(key: &str) {
+    if with_values(|values| values.remove(key).is_some()) {
+        save();
+    }
+}
+
+/// A setting stored with set_bool (None if it isn't set or isn't true/false)
+#[allow(unused)]
+pub fn get_bool(key: &str) -> Option<bool> {
+    get(key).and_then(|value| value.parse().ok())
+}
+
+#[allow(unused)]
+pub fn set_bool(key: &str, value: bool) {
+    set(key, value.to_string());
+}
+
+/// Change the file settings are saved in (native only, the web always uses localStorage)
+#[allow(unused)]
+pub fn set_file(path: &str) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        FILE.with(|file| *file.borrow_mut() = path.to_string());
+        // Read again from the new file next time
+        VALUES.with(|values| *values.borrow_mut() = None);
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = path;
+}
+
+// Run code with the settings, reading them in first if they haven't been yet
+fn with_values<R>(f: impl FnOnce(&mut BTreeMap<String, String>) -> R) -> R {
+    VALUES.with(|values| {
+        let mut values = values.borrow_mut();
+        let values = values.get_or_insert_with(|| read().and_then(|text| serde_json::from_str(&text).ok()).unwrap_or_default());
+        f(values)
+    })
+}
+
+fn save() {
+    let Some(text) = VALUES.with(|values| values.borrow().as_ref().and_then(|values| serde_json::to_string_pretty(values).ok())) else {
+        return;
+    };
+    if let Err(e) = write(&text) {
+        println!("Couldn't save settings: {}", e);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn read() -> Option<String> {
+    std::fs::read_to_string(FILE.with(|file| file.borrow().clone())).ok()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn write(text: &str) -> Result<(), String> {
+    std::fs::write(FILE.with(|file| file.borrow().clone()), text).map_err(|e| e.to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn read() -> Option<String> {
+    local_storage()?.get_item(STORAGE_KEY).ok().flatten()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn write(text: &str) -> Result<(), String> {
+    local_storage()
+        .ok_or("localStorage isn't available")?
+        .set_item(STORAGE_KEY, text)
+        .map_err(|_| "localStorage is full or blocked".to_string())
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
@@ -0,0 +1,203 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+An animated loading spinner to show while waiting on the database (or anything else slow)
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod spinner;
+
+This also needs these modules:
+    pub mod tasks;   // For draw_while
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::spinner::{Spinner, SpinnerStyle};
+
+Then to use this you would put the following above the loop:
+    let spn_loading = Spinner::new(512.0, 384.0, 20.0);
+Where the parameters are the x and y of the center and the radius
+
+CUSTOMIZATION:
+    spn_loading.with_style(SpinnerStyle::Arc);   // Dots (default) or Arc
+    spn_loading.with_color(WHITE);               // Default the theme's text_muted
+    spn_loading.with_speed(2.0);                 // Turns per second (default 1.5)
+    spn_loading.with_text("Loading...");         // Shown under the spinner
+    spn_loading.clear_text();
+    spn_loading.set_position(100.0, 100.0);
+    spn_loading.set_visible(false);
+
+Then in the loop you would use:
+    spn_loading.draw();
+
+Or, to only show it while a background task is running (see the tasks module):
+    let loader = tasks::spawn(async move { client.fetch_table::<DatabaseTable>("draysTable").await.map_err(|e| e.to_string()) });
+
+    // In the loop
+    spn_loading.draw_while(&loader);
+*/
+
+use macroquad::prelude::*;
+use crate::modules::tasks::TaskHandle;
+use crate::modules::theme::ThemeColor;
+
+// How the spinner looks
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum SpinnerStyle {
+    Dots, // A ring of dots with a bright one going around
+    Arc,  // A spinning arc that grows and shrinks
+}
+
+pub struct Spinner {
+    x: f32,
+    y: f32,
+    radius: f32,
+    color: ThemeColor,
+    style: SpinnerStyle,
+    speed: f32, // Turns per second
+    text: Option<String>,
+    font_size: u16,
+    visible: bool,
+}
+
+impl Spinner {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, radius: f32) -> Self {
+        Self {
+            x,
+            y,
+            radius,
+            color: ThemeColor::TextMuted,
+            style: SpinnerStyle::Dots,
+            speed: 1.5,
+            text: None,
+            font_size: 20,
+            visible: true,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn with_style(&mut self, style: SpinnerStyle) -> &mut Self {
+        self.style = style;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.color = color.into();
+        self
+    }
+
+    // How many times it goes around each second
+    #[allow(unused)]
+    pub fn with_speed(&mut self, turns_per_second: f32) -> &mut Self {
+        self.speed = turns_per_second;
+        self
+    }
+
+    // Text drawn centered under the spinner
+    #[allow(unused)]
+    pub fn with_text<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_text(&mut self) -> &mut Self {
+        self.text = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    // Draw the spinner (nothing if hidden)
+    #[allow(unused)]
+    pub fn draw(&self) {
+        if !self.visible {
+            return;
+        }
+        let turn = (get_time() * self.speed as f64).fract() as f32;
+        match self.style {
+            SpinnerStyle::Dots => self.draw_dots(turn),
+            SpinnerStyle::Arc => self.draw_spinning_arc(turn),
+        }
+        if let Some(text) = &self.text {
+            let size = measure_text(text, None, self.font_size, 1.0);
+            draw_text(
+                text,
+                self.x - size.width / 2.0,
+                self.y + self.radius + 8.0 + size.offset_y,
+                self.font_size as f32,
+                self.color.color(),
+            );
+        }
+    }
+
+    // Draw the spinner only while a task is still running, returns whether it was drawn
+    #[allow(unused)]
+    pub fn draw_while<T>(&self, task: &TaskHandle<T>) -> bool {
+        if task.is_done() {
+            return false;
+        }
+        self.draw();
+        self.visible
+    }
+
+    fn draw_dots(&self, turn: f32) {
+        let color = self.color.color();
+        let dots = 8;
+        for i in 0..dots {
+            let angle = i as f32 / dots as f32 * std::f32::consts::TAU;
+            // Dots fade out behind the bright one
+            let behind = (turn - i as f32 / dots as f32).rem_euclid(1.0);
+            let alpha = color.a * (1.0 - behind * 0.8);
+            draw_circle(
+                self.x + angle.cos() * self.radius,
+                self.y + angle.sin() * self.radius,
+                self.radius * 0.22,
+                color.with_alpha(alpha),
+            );
+        }
+    }
+
+    fn draw_spinning_arc(&self, turn: f32) {
+        // The arc's length swings between a quarter and most of the circle
+        let sweep = 90.0 + 180.0 * (0.5 - 0.5 * (turn * std::f32::consts::TAU).cos());
+        let thickness = (self.radius * 0.25).max(2.0);
+        let color = self.color.color();
+        // Faint full ring behind the arc
+        draw_arc(
+            self.x,
+            self.y,
+            48,
+            self.radius - thickness,
+            0.0,
+            thickness,
+            360.0,
+            color.with_alpha(color.a * 0.2),
+        );
+        draw_arc(self.x, self.y, 48, self.radius - thickness, turn * 360.0, thickness, sweep, color);
+    }
+}
@@ -0,0 +1,324 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Splits an area into two panes with a divider that can be dragged to resize them
+(for tool layouts like "table list | record details")
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod split_pane;
+
+This also needs the scale module (for clipping the panes):
+    pub mod scale;
+
+Add with the other use statements:
+    use crate::modules::split_pane::{SplitPane, SplitDirection};
+
+Then above the loop section:
+    let mut spl_main = SplitPane::new(0.0, 0.0, 1024.0, 768.0, SplitDirection::Horizontal);
+    spl_main.with_ratio(0.3).with_min_sizes(150.0, 300.0);
+Where the parameters are x, y, width, height and which way it splits. Horizontal puts the
+panes side by side (first on the left), Vertical stacks them (first on top).
+
+Then in the loop, draw the split first and then each pane between begin and end:
+    spl_main.draw();
+    if spl_main.begin_first() {
+        let area = spl_main.first_rect();
+        lst_tables.set_position(area.x, area.y);
+        lst_tables.draw();
+        spl_main.end();
+    }
+    if spl_main.begin_second() {
+        let area = spl_main.second_rect();
+        grd_records.set_position(area.x, area.y);
+        grd_records.set_size(area.w, area.h);
+        grd_records.draw();
+        spl_main.end();
+    }
+Anything drawn between begin and end is cut off at the edges of that pane. The begin
+methods return false when the pane has no room at all (then skip it and don't call end).
+
+NESTING:
+    // Split the right pane again into record details over a log
+    spl_details.set_bounds(spl_main.second_rect());
+    spl_details.draw();
+
+HOW IT WORKS:
+    Drag the divider to move it, the panes can't be made smaller than their minimum sizes.
+    Double-click the divider to put it back where it started. The divider keeps its place
+    as a fraction of the whole, so the panes grow and shrink together when set_bounds or
+    set_size changes the size.
+
+OTHER METHODS:
+    spl_main.with_divider(6.0, GRAY, DARKGRAY);   // Thickness, color and color while hovered or dragged (colors default from the theme)
+    spl_main.with_ratio(0.5);                     // Where the divider is (0.0 to 1.0), also what double-click resets to
+    let ratio = spl_main.get_ratio();             // Save it to restore the layout next time
+    spl_main.set_ratio(ratio);                    // Move the divider without changing the double-click spot
+    spl_main.set_position(10.0, 10.0);
+    spl_main.set_size(800.0, 600.0);
+    if spl_main.is_dragging() { }
+    if spl_main.draw() {
+        // The divider was moved this frame
+    }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::theme::ThemeColor;
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Longest gap between clicks (in seconds) that still counts as a double click
+const DOUBLE_CLICK_TIME: f64 = 0.4;
+
+// Which way the area is split
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum SplitDirection {
+    Horizontal, // Side by side, first pane on the left
+    Vertical,   // Stacked, first pane on top
+}
+
+pub struct SplitPane {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    direction: SplitDirection,
+    ratio: f32,         // Where the divider is, as a fraction of the space the panes share
+    default_ratio: f32, // What double-clicking the divider goes back to
+    min_first: f32,
+    min_second: f32,
+    divider_thickness: f32,
+    divider_color: ThemeColor,
+    divider_active_color: ThemeColor,
+    drag_offset: Option<f32>, // Mouse distance from the divider's start while dragging
+    last_click: f64,          // When the divider was last pressed, for double clicks
+    clipping: bool,           // True between a begin and end()
+}
+
+impl SplitPane {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32, direction: SplitDirection) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            direction,
+            ratio: 0.5,
+            default_ratio: 0.5,
+            min_first: 50.0,
+            min_second: 50.0,
+            divider_thickness: 6.0,
+            divider_color: ThemeColor::Border,
+            divider_active_color: ThemeColor::Primary,
+            drag_offset: None,
+            last_click: -1.0,
+            clipping: false,
+        }
+    }
+
+    // Where the divider starts, and goes back to on double-click
+    #[allow(unused)]
+    pub fn with_ratio(&mut self, ratio: f32) -> &mut Self {
+        self.default_ratio = ratio.clamp(0.0, 1.0);
+        self.set_ratio(ratio)
+    }
+
+    // Smallest the first and second panes can be made by dragging
+    #[allow(unused)]
+    pub fn with_min_sizes(&mut self, first: f32, second: f32) -> &mut Self {
+        self.min_first = first.max(0.0);
+        self.min_second = second.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_divider(&mut self, thickness: f32, color: impl Into<ThemeColor>, active_color: impl Into<ThemeColor>) -> &mut Self {
+        self.divider_thickness = thickness.max(0.0);
+        self.divider_color = color.into();
+        self.divider_active_color = active_color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    #[allow(unused)]
+    pub fn set_ratio(&mut self, ratio: f32) -> &mut Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width.max(0.0);
+        self.height = height.max(0.0);
+        self
+    }
+
+    // Move and resize to fill an area (like a pane of another SplitPane)
+    #[allow(unused)]
+    pub fn set_bounds(&mut self, area: Rect) -> &mut Self {
+        self.set_position(area.x, area.y);
+        self.set_size(area.w, area.h)
+    }
+
+    #[allow(unused)]
+    pub fn is_dragging(&self) -> bool {
+        self.drag_offset.is_some()
+    }
+
+    // The first pane (left or top)
+    #[allow(unused)]
+    pub fn first_rect(&self) -> Rect {
+        let first = self.first_size();
+        match self.direction {
+            SplitDirection::Horizontal => Rect::new(self.x, self.y, first, self.height),
+            SplitDirection::Vertical => Rect::new(self.x, self.y, self.width, first),
+        }
+    }
+
+    // The second pane (right or bottom)
+    #[allow(unused)]
+    pub fn second_rect(&self) -> Rect {
+        let start = self.first_size() + self.divider_thickness;
+        let size = (self.length() - start).max(0.0);
+        match self.direction {
+            SplitDirection::Horizontal => Rect::new(self.x + start, self.y, size, self.height),
+            SplitDirection::Vertical => Rect::new(self.x, self.y + start, self.width, size),
+        }
+    }
+
+    // Handle dragging and draw the divider, returns true on frames the divider moved
+    #[allow(unused)]
+    pub fn draw(&mut self) -> bool {
+        let old_ratio = self.ratio;
+        let (mouse_x, mouse_y) = mouse_position();
+        let divider = self.divider_rect();
+        // A few extra pixels either side so a thin divider is easy to grab
+        let grab = match self.direction {
+            SplitDirection::Horizontal => Rect::new(divider.x - 3.0, divider.y, divider.w + 6.0, divider.h),
+            SplitDirection::Vertical => Rect::new(divider.x, divider.y - 3.0, divider.w, divider.h + 6.0),
+        };
+        let hovered = grab.contains(Vec2::new(mouse_x, mouse_y));
+        let along = match self.direction {
+            SplitDirection::Horizontal => mouse_x - self.x,
+            SplitDirection::Vertical => mouse_y - self.y,
+        };
+
+        if hovered && is_mouse_button_pressed(MouseButton::Left) {
+            let now = get_time();
+            if now - self.last_click <= DOUBLE_CLICK_TIME {
+                self.ratio = self.default_ratio;
+                self.last_click = -1.0;
+            } else {
+                self.drag_offset = Some(along - self.first_size());
+                self.last_click = now;
+            }
+        }
+        if let Some(offset) = self.drag_offset {
+            if is_mouse_button_down(MouseButton::Left) {
+                let space = self.space();
+                if space > 0.0 {
+                    self.ratio = self.clamp_first(along - offset) / space;
+                }
+            } else {
+                self.drag_offset = None;
+            }
+        }
+
+        let divider = self.divider_rect();
+        let color = if hovered || self.drag_offset.is_some() { self.divider_active_color.color() } else { self.divider_color.color() };
+        draw_rectangle(divider.x, divider.y, divider.w, divider.h, color);
+        // Grip dots in the middle of the divider
+        let (center_x, center_y) = (divider.x + divider.w / 2.0, divider.y + divider.h / 2.0);
+        let dot = (self.divider_thickness * 0.25).max(1.0);
+        for step in [-1.0, 0.0, 1.0] {
+            let (dot_x, dot_y) = match self.direction {
+                SplitDirection::Horizontal => (center_x, center_y + step * dot * 4.0),
+                SplitDirection::Vertical => (center_x + step * dot * 4.0, center_y),
+            };
+            draw_circle(dot_x, dot_y, dot, self.divider_active_color.color());
+        }
+        self.ratio != old_ratio
+    }
+
+    // Start clipping to the first pane, false when it has no room
+    #[allow(unused)]
+    pub fn begin_first(&mut self) -> bool {
+        self.begin(self.first_rect())
+    }
+
+    // Start clipping to the second pane, false when it has no room
+    #[allow(unused)]
+    pub fn begin_second(&mut self) -> bool {
+        self.begin(self.second_rect())
+    }
+
+    // Stop clipping to the pane (only needed when begin returned true)
+    #[allow(unused)]
+    pub fn end(&mut self) {
+        if self.clipping {
+            pop_clip_rect();
+            self.clipping = false;
+        }
+    }
+
+    fn begin(&mut self, area: Rect) -> bool {
+        // Left open from a pane whose end() was skipped
+        self.end();
+        if area.w <= 0.0 || area.h <= 0.0 {
+            return false;
+        }
+        push_clip_rect(area);
+        self.clipping = true;
+        true
+    }
+
+    // Size of the whole area in the direction it is split
+    fn length(&self) -> f32 {
+        match self.direction {
+            SplitDirection::Horizontal => self.width,
+            SplitDirection::Vertical => self.height,
+        }
+    }
+
+    // Room the two panes share
+    fn space(&self) -> f32 {
+        (self.length() - self.divider_thickness).max(0.0)
+    }
+
+    // Size of the first pane
+    fn first_size(&self) -> f32 {
+        self.clamp_first(self.space() * self.ratio)
+    }
+
+    // Keep a first pane size where both panes are at least their minimum (when there's room)
+    fn clamp_first(&self, size: f32) -> f32 {
+        let space = self.space();
+        if self.min_first + self.min_second > space {
+            // Not enough room for both, share it out by their minimums
+            let total = self.min_first + self.min_second;
+            return if total > 0.0 { space * self.min_first / total } else { size.clamp(0.0, space) };
+        }
+        size.clamp(self.min_first, space - self.min_second)
+    }
+
+    fn divider_rect(&self) -> Rect {
+        let first = self.first_size();
+        match self.direction {
+            SplitDirection::Horizontal => Rect::new(self.x + first, self.y, self.divider_thickness, self.height),
+            SplitDirection::Vertical => Rect::new(self.x, self.y + first, self.width, self.divider_thickness),
+        }
+    }
+}
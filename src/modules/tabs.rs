@@ -0,0 +1,365 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A row of tabs across the top of an area, for switching between screens (Login | Leaderboard | Settings)
+without keeping a bool for each screen
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod tabs;
+
+This also needs these modules:
+    pub mod focus;
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::tabs::Tabs;
+
+Then above the loop section:
+    let mut tab_screens = Tabs::new(50.0, 50.0, 924.0, 668.0);
+    tab_screens
+        .add_tab("Login", &[txtuser.focus_id(), txtpassword.focus_id(), btn_login.focus_id()])
+        .add_tab("Leaderboard", &[])
+        .add_tab("Settings", &[btn_sound.focus_id()]);
+Where the parameters are x, y, width and height of the whole area (the tab bar is along the top).
+The list given with each tab is the focus ids of the widgets on that page. Widgets on the
+other pages can't be tabbed to, and lose focus when their page is switched away from.
+
+Then in the loop you would use:
+    tab_screens.draw();
+    match tab_screens.selected() {
+        0 => { /* draw and handle the login widgets */ }
+        1 => { /* the leaderboard */ }
+        _ => { /* settings */ }
+    }
+Only draw the widgets of the page that is showing, the others then don't react to the mouse.
+draw() returns Some(index) on the frame the page changes, e.g. to reload the leaderboard:
+    if tab_screens.draw() == Some(1) {
+        // Leaderboard was just opened
+    }
+
+SWITCHING:
+    Click a tab, or Ctrl+Tab / Ctrl+Shift+Tab for the next / previous one,
+    or Ctrl+1 to Ctrl+9 to jump straight to a tab.
+    tab_screens.set_selected(2);           // From code
+
+CUSTOMIZATION:
+    tab_screens.with_colors(DARKGRAY, GRAY, WHITE, BLACK); // Bar, tab, selected tab (and page), text (default from the theme)
+    tab_screens.with_bar_height(50.0);     // Default 40
+    tab_screens.with_font_size(24);        // Default 22
+    tab_screens.set_tab_enabled(1, false); // Greyed out and can't be switched to
+    tab_screens.set_title(0, "Sign in");
+    tab_screens.set_position(0.0, 0.0);
+    tab_screens.set_size(1024.0, 768.0);
+
+To lay out the page's widgets, content_rect() is the area under the tab bar:
+    let page = tab_screens.content_rect();
+    txtuser.set_position(page.x + 20.0, page.y + 20.0);
+*/
+
+use macroquad::prelude::*;
+use crate::modules::focus;
+use crate::modules::theme::{self, ThemeColor};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Space between a tab's title and its sides
+const TAB_PADDING: f32 = 20.0;
+
+struct Tab {
+    title: String,
+    widgets: Vec<usize>, // Focus ids of the widgets on this page
+    enabled: bool,
+}
+
+pub struct Tabs {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    bar_height: f32,
+    font_size: u16,
+    tabs: Vec<Tab>,
+    selected: usize,
+    bar_color: ThemeColor,
+    tab_color: ThemeColor,
+    selected_color: ThemeColor,
+    text_color: ThemeColor,
+    focus_synced: bool, // False when the pages' widgets need their focusability updated
+}
+
+impl Tabs {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            bar_height: 40.0,
+            font_size: 22,
+            tabs: Vec::new(),
+            selected: 0,
+            bar_color: ThemeColor::Background,
+            tab_color: ThemeColor::SurfaceAlt,
+            selected_color: ThemeColor::Surface,
+            text_color: ThemeColor::Text,
+            focus_synced: false,
+        }
+    }
+
+    // Add a page with its title and the focus ids of the widgets on it
+    #[allow(unused)]
+    pub fn add_tab(&mut self, title: &str, widgets: &[usize]) -> &mut Self {
+        self.tabs.push(Tab {
+            title: title.to_string(),
+            widgets: widgets.to_vec(),
+            enabled: true,
+        });
+        self.focus_synced = false;
+        self
+    }
+
+    // Index of the page showing
+    #[allow(unused)]
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    #[allow(unused)]
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected == index
+    }
+
+    // Switch pages from code (ignored if out of range or disabled)
+    #[allow(unused)]
+    pub fn set_selected(&mut self, index: usize) -> &mut Self {
+        if self.tabs.get(index).is_some_and(|tab| tab.enabled) {
+            self.selected = index;
+            self.focus_synced = false;
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_tab_enabled(&mut self, index: usize, enabled: bool) -> &mut Self {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            tab.enabled = enabled;
+        }
+        // Don't stay on a page that was just disabled
+        if !enabled && index == self.selected {
+            if let Some(other) = (0..self.tabs.len()).find(|&other| self.tabs[other].enabled) {
+                self.selected = other;
+                self.focus_synced = false;
+            }
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_title(&self, index: usize) -> Option<&str> {
+        self.tabs.get(index).map(|tab| tab.title.as_str())
+    }
+
+    #[allow(unused)]
+    pub fn set_title(&mut self, index: usize, title: &str) -> &mut Self {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            tab.title = title.to_string();
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    // Bar color, unselected tab color, selected tab (and page) color and text color
+    #[allow(unused)]
+    pub fn with_colors(
+        &mut self,
+        bar: impl Into<ThemeColor>,
+        tab: impl Into<ThemeColor>,
+        selected: impl Into<ThemeColor>,
+        text: impl Into<ThemeColor>,
+    ) -> &mut Self {
+        self.bar_color = bar.into();
+        self.tab_color = tab.into();
+        self.selected_color = selected.into();
+        self.text_color = text.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_bar_height(&mut self, height: f32) -> &mut Self {
+        self.bar_height = height;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    // The area under the tab bar where the page's widgets go
+    #[allow(unused)]
+    pub fn content_rect(&self) -> Rect {
+        Rect::new(self.x, self.y + self.bar_height, self.width, (self.height - self.bar_height).max(0.0))
+    }
+
+    // Draw the tab bar and page background and handle switching, returns Some(index) when the page changes
+    #[allow(unused)]
+    pub fn draw(&mut self) -> Option<usize> {
+        let previous = self.selected;
+        self.update_input();
+        if !self.focus_synced {
+            self.sync_focus();
+        }
+
+        let content = self.content_rect();
+        let border_color = theme::palette().border;
+        let selected_color = self.selected_color.color();
+        let tab_color = self.tab_color.color();
+        let text_color = self.text_color.color();
+        draw_rectangle(self.x, self.y, self.width, self.bar_height, self.bar_color.color());
+        draw_rectangle(content.x, content.y, content.w, content.h, selected_color);
+        draw_rectangle_lines(content.x, content.y, content.w, content.h, 1.0, border_color);
+
+        let (mouse_x, mouse_y) = mouse_position();
+        for (index, rect) in self.tab_rects().into_iter().enumerate() {
+            let tab = &self.tabs[index];
+            let selected = index == self.selected;
+            let hovered = tab.enabled && rect.contains(Vec2::new(mouse_x, mouse_y));
+            let background = if selected {
+                selected_color
+            } else if hovered {
+                Color::new(
+                    (tab_color.r + 0.08).min(1.0),
+                    (tab_color.g + 0.08).min(1.0),
+                    (tab_color.b + 0.08).min(1.0),
+                    tab_color.a,
+                )
+            } else {
+                tab_color
+            };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, background);
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, border_color);
+            // The selected tab joins onto its page
+            if selected {
+                draw_line(rect.x + 1.0, rect.y + rect.h, rect.x + rect.w - 1.0, rect.y + rect.h, 2.0, selected_color);
+            }
+
+            let text_color = if tab.enabled { text_color } else { text_color.with_alpha(text_color.a * 0.4) };
+            let size = measure_text(&tab.title, None, self.font_size, 1.0);
+            draw_text(
+                &tab.title,
+                rect.x + (rect.w - size.width) / 2.0,
+                rect.y + (rect.h - size.height) / 2.0 + size.offset_y,
+                self.font_size as f32,
+                text_color,
+            );
+        }
+
+        (self.selected != previous).then_some(self.selected)
+    }
+
+    // Mouse clicks on the tabs and the Ctrl shortcuts
+    fn update_input(&mut self) {
+        if self.tabs.is_empty() {
+            return;
+        }
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let (mouse_x, mouse_y) = mouse_position();
+            let clicked = self.tab_rects().iter().position(|rect| rect.contains(Vec2::new(mouse_x, mouse_y)));
+            if let Some(index) = clicked {
+                self.set_selected(index);
+            }
+        }
+
+        if !(is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl)) {
+            return;
+        }
+        if is_key_pressed(KeyCode::Tab) || is_key_pressed(KeyCode::PageDown) || is_key_pressed(KeyCode::PageUp) {
+            let shift_down = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+            let forward = if is_key_pressed(KeyCode::Tab) { !shift_down } else { is_key_pressed(KeyCode::PageDown) };
+            self.step(forward);
+        }
+        let number_keys = [
+            KeyCode::Key1,
+            KeyCode::Key2,
+            KeyCode::Key3,
+            KeyCode::Key4,
+            KeyCode::Key5,
+            KeyCode::Key6,
+            KeyCode::Key7,
+            KeyCode::Key8,
+            KeyCode::Key9,
+        ];
+        if let Some(index) = number_keys.iter().position(|&key| is_key_pressed(key)) {
+            self.set_selected(index);
+        }
+    }
+
+    // Move to the next (or previous) enabled tab, wrapping around
+    fn step(&mut self, forward: bool) {
+        let count = self.tabs.len();
+        for offset in 1..count {
+            let index = if forward { (self.selected + offset) % count } else { (self.selected + count - offset) % count };
+            if self.tabs[index].enabled {
+                self.set_selected(index);
+                return;
+            }
+        }
+    }
+
+    // Where each tab is drawn, left to right and sized to its title
+    fn tab_rects(&self) -> Vec<Rect> {
+        let mut x = self.x;
+        self.tabs
+            .iter()
+            .map(|tab| {
+                let width = measure_text(&tab.title, None, self.font_size, 1.0).width + TAB_PADDING * 2.0;
+                let rect = Rect::new(x, self.y + 4.0, width, self.bar_height - 4.0);
+                x += width;
+                rect
+            })
+            .collect()
+    }
+
+    // Only the showing page's widgets can take focus
+    fn sync_focus(&mut self) {
+        for (index, tab) in self.tabs.iter().enumerate() {
+            if index != self.selected {
+                for &id in &tab.widgets {
+                    focus::set_focusable(id, false);
+                }
+            }
+        }
+        // Done after hiding the others so a widget on two pages stays focusable
+        if let Some(tab) = self.tabs.get(self.selected) {
+            for &id in &tab.widgets {
+                focus::set_focusable(id, true);
+            }
+        }
+        self.focus_synced = true;
+    }
+}
@@ -0,0 +1,132 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Runs async work (like database calls) in the background while the game keeps drawing
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod tasks;
+
+Add with the other use statements:
+    use crate::modules::tasks;
+
+Then in the loop, once per frame (just before next_frame is a good spot):
+    tasks::run_pending();
+
+HOW IT WORKS:
+    tasks::spawn takes a future and returns a TaskHandle straight away instead of waiting
+    for it. Every call to run_pending moves each task along as far as it can go without
+    waiting, so the loop keeps running (and drawing) while the task is in progress. When
+    the task finishes its result is kept in the handle until you take it.
+    Futures have to own everything they use (use async move and clone what you need).
+    Work that blocks (like a native HTTP request with ureq, which the database module does)
+    would freeze the game while it runs, so on native builds it goes on its own thread with
+    run_on_thread. The future waits for the thread without blocking, so buttons keep showing
+    their busy spinner and the loop keeps drawing. Web requests never block.
+
+Usage examples:
+    // Start loading records without freezing the game
+    let client = Rc::new(create_database_client());
+    let loader = {
+        let client = client.clone();
+        tasks::spawn(async move { client.fetch_table::<DatabaseTable>("draysTable").await.map_err(|e| e.to_string()) })
+    };
+
+    // In the loop
+    if let Some(result) = loader.take() {
+        match result {
+            Ok(records) => println!("Loaded {} records", records.len()),
+            Err(e) => println!("Couldn't load: {}", e),
+        }
+    }
+    if !loader.is_done() {
+        draw_text("Loading...", 20.0, 20.0, 20.0, WHITE);
+    }
+    tasks::run_pending();
+    next_frame().await;
+
+    // Native only: blocking work on its own thread, awaited like any other future
+    let text = tasks::run_on_thread(|| std::fs::read_to_string("save.json")).await;
+*/
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{self, TryRecvError};
+
+type Task = Pin<Box<dyn Future<Output = ()>>>;
+
+thread_local! {
+    static TASKS: RefCell<Vec<Task>> = const { RefCell::new(Vec::new()) };
+}
+
+// Lets you check on a spawned task and take its result once it's finished
+pub struct TaskHandle<T> {
+    result: Rc<RefCell<Option<T>>>,
+    done: Rc<Cell<bool>>,
+}
+
+impl<T> TaskHandle<T> {
+    // Whether the task has finished (stays true after the result is taken)
+    #[allow(unused)]
+    pub fn is_done(&self) -> bool {
+        self.done.get()
+    }
+
+    // Take the result if the task has finished (only returns it once)
+    pub fn take(&self) -> Option<T> {
+        self.result.borrow_mut().take()
+    }
+}
+
+/// Start a task in the background, it runs a bit every time run_pending is called
+pub fn spawn<T: 'static>(future: impl Future<Output = T> + 'static) -> TaskHandle<T> {
+    let result = Rc::new(RefCell::new(None));
+    let done = Rc::new(Cell::new(false));
+    let task_result = result.clone();
+    let task_done = done.clone();
+    let task: Task = Box::pin(async move {
+        let value = future.await;
+        *task_result.borrow_mut() = Some(value);
+        task_done.set(true);
+    });
+    TASKS.with(|tasks| tasks.borrow_mut().push(task));
+    TaskHandle { result, done }
+}
+
+/// Move every task along, call once per frame
+pub fn run_pending() {
+    // Take the list out so tasks can spawn more tasks while they run
+    let mut running = TASKS.with(|tasks| std::mem::take(&mut *tasks.borrow_mut()));
+    let mut context = Context::from_waker(Waker::noop());
+    running.retain_mut(|task| task.as_mut().poll(&mut context) == Poll::Pending);
+    TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        running.append(&mut tasks);
+        *tasks = running;
+    });
+}
+
+/// Number of tasks that haven't finished yet
+#[allow(unused)]
+pub fn pending_count() -> usize {
+    TASKS.with(|tasks| tasks.borrow().len())
+}
+
+/// Run blocking work on its own thread (native only, the web has no threads). The future is
+/// ready once the work is done, and never blocks while it waits
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run_on_thread<T: Send + 'static>(work: impl FnOnce() -> T + Send + 'static) -> impl Future<Output = T> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(work());
+    });
+    // Checked again every time it is polled (each run_pending, or each frame when awaited in main)
+    std::future::poll_fn(move |_| match receiver.try_recv() {
+        Ok(value) => Poll::Ready(value),
+        Err(TryRecvError::Empty) => Poll::Pending,
+        Err(TryRecvError::Disconnected) => panic!("A background thread stopped without finishing its work"),
+    })
+}
@@ -0,0 +1,1362 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Adds a multi-line text area object (the multi-line sibling of TextInput)
+
+In your mod.rs file located in the modules folder add the following to the end of the file
+        pub mod text_area;
+
+Add the following to Cargo.toml under [dependencies] (the cursor moves over whole
+characters, so emoji and accented letters are never split):
+    unicode-segmentation = "1.13"
+
+This also needs the clipboard and scale modules:
+        pub mod clipboard;
+        pub mod scale;
+
+Add with the other use statements
+    use crate::modules::text_area::TextArea;
+
+Then to use this you would put the following above the loop:
+    let mut txt_notes = TextArea::new(100.0, 100.0, 400.0, 200.0, 25.0);
+Where the parameters are x, y, width, height, font size
+
+Text wraps at word boundaries to fit the width of the box. Enter adds a new line,
+up/down move between lines and the mouse wheel scrolls when there are more lines than fit.
+Home/End jump to the start/end of the current line (ctrl+Home/End for the whole text) and
+ctrl/alt+Left/Right jump a word at a time. Input-method (Chinese, Japanese, Korean...)
+text can be typed on the web, and tapping the box on a touch screen opens the on-screen
+keyboard, through the ime module (pub mod ime; in mod.rs).
+Keys tapped on a VirtualKeyboard go into the focused box as well (pub mod virtual_keyboard;).
+Tab moves focus to the next widget (see the focus module), so this also needs:
+        pub mod focus;
+
+APPEARANCE CUSTOMIZATION:
+    // Set colors (text, border, background, cursor), by default they come from the theme
+    txt_notes.with_colors(WHITE, BLUE, DARKGRAY, RED);
+
+    // Set custom font (load it with assets::load_font("assets/my_font.ttf").await)
+    txt_notes.with_font(my_font.clone());
+    txt_notes.set_font(my_font.clone()); // Same thing, for changing it later
+    txt_notes.clear_font();              // Back to the theme's font
+
+    // Space between lines as a multiple of the font size (default 1.2)
+    txt_notes.set_line_spacing(1.5);
+
+    // Prompt text shown when the box is empty
+    txt_notes.set_prompt("Write a message...");
+    txt_notes.set_prompt_color(DARKGRAY);
+
+    // Enable or disable the text area
+    txt_notes.set_enabled(false);
+
+    // Grow (and shrink) with the text instead of scrolling, between a min and max height.
+    // Past the max height it scrolls as usual.
+    txt_notes.set_auto_grow(60.0, 300.0);
+    txt_notes.clear_auto_grow(); // Back to a fixed height
+    // Move things below the box when its size changes
+    txt_notes.set_on_resize(|width, height| println!("Now {} x {}", width, height));
+
+TEXT MANIPULATION:
+    let current_text = txt_notes.get_text();
+    txt_notes.set_text("First line\nSecond line");
+    if txt_notes.changed() { }              // True for one frame after the user changes the text
+    let lines = txt_notes.get_line_count(); // Number of lines after wrapping
+
+    // Limit the number of characters (e.g. to match a text column limit)
+    txt_notes.set_max_length(500);
+
+    // Selection (including double click for a word, triple click for everything)
+    // and clipboard work the same way as TextInput
+    txt_notes.select_all();
+    txt_notes.set_selection(0, 5);
+    let range = txt_notes.get_selection();
+    txt_notes.replace_selection("New text");
+    txt_notes.copy_selection();
+    txt_notes.cut_selection();
+    txt_notes.paste();
+
+Then in the main loop you would use:
+    // Update and draw the text area in one step
+    txt_notes.draw();
+*/
+use macroquad::prelude::*;
+use crate::modules::clipboard;
+use crate::modules::focus;
+// Characters the font doesn't have are drawn with the backup fonts (see font_fallback)
+use crate::modules::font_fallback::draw_text_ex;
+use crate::modules::glyph_cache;
+use crate::modules::ime;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::theme::{self, ThemeColor};
+use crate::modules::virtual_keyboard::{self, VirtualKey};
+use unicode_segmentation::UnicodeSegmentation;
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Space between the border and the text
+const PADDING: f32 = 5.0;
+
+// Callback given the new width and height after the text area resizes itself
+type ResizeCallback = Box<dyn FnMut(f32, f32)>;
+// Longest gap between clicks (in seconds) that still counts as a double/triple click
+const MULTI_CLICK_TIME: f64 = 0.4;
+
+pub struct TextArea {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    text: String,
+    active: bool,
+    cursor_index: usize,
+    cursor_timer: f32,
+    cursor_visible: bool,
+    font_size: f32,
+    line_spacing: f32,      // Line height as a multiple of the font size
+    text_color: ThemeColor,
+    border_color: ThemeColor,
+    background_color: ThemeColor,
+    cursor_color: ThemeColor,
+    font: Option<Font>,
+    font_id: usize,         // Identifies the font in the shared glyph width cache
+    prompt: Option<String>,
+    prompt_color: ThemeColor,
+    key_repeat_delay: f32,  // Initial delay before key starts repeating (in seconds)
+    key_repeat_rate: f32,   // How often the key repeats after initial delay (in seconds)
+    key_repeat_timer: f32,
+    last_key: Option<KeyCode>,
+    enabled: bool,
+    disabled_color: ThemeColor,
+    max_length: Option<usize>, // Maximum number of characters allowed (None = unlimited)
+    selection_anchor: Option<usize>, // Fixed end of the selection, the cursor is the moving end
+    selection_color: ThemeColor,
+    dragging: bool,
+    last_click_time: f64,
+    click_count: u32,       // Clicks in the current double/triple click sequence
+    paste_pending: bool,
+    changed: bool,          // True for the update in which the user changed the text
+    scroll_offset: f32,     // How far the text is scrolled up (in pixels)
+    preferred_x: Option<f32>, // Column to aim for when moving up/down through shorter lines
+
+    focus_id: usize,        // Id in the focus module's tab order
+    auto_grow: Option<(f32, f32)>, // Min and max height when growing with the text
+    on_resize: Option<ResizeCallback>, // Called when auto-grow changes the height
+
+    // Cached wrapped lines as byte ranges into the text
+    cached_lines: Vec<(usize, usize)>,
+}
+
+impl TextArea {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32, font_size: f32) -> Self {
+        let mut text_area = Self {
+            x,
+            y,
+            width,
+            height,
+            text: String::new(),
+            active: false,
+            cursor_index: 0,
+            cursor_timer: 0.0,
+            cursor_visible: true,
+            font_size,
+            line_spacing: 1.2,
+            text_color: ThemeColor::Text,
+            border_color: ThemeColor::Border,
+            background_color: ThemeColor::Surface,
+            cursor_color: ThemeColor::Text,
+            font: None,
+            font_id: 0,
+            prompt: None,
+            prompt_color: ThemeColor::TextMuted,
+            key_repeat_delay: 0.4,
+            key_repeat_rate: 0.05,
+            key_repeat_timer: 0.0,
+            last_key: None,
+            enabled: true,
+            disabled_color: ThemeColor::Custom(Color::new(0.7, 0.7, 0.7, 0.5)),
+            max_length: None,
+            selection_anchor: None,
+            selection_color: ThemeColor::Selection,
+            dragging: false,
+            last_click_time: 0.0,
+            click_count: 0,
+            paste_pending: false,
+            changed: false,
+            scroll_offset: 0.0,
+            preferred_x: None,
+            focus_id: focus::register(),
+            auto_grow: None,
+            on_resize: None,
+            cached_lines: Vec::new(),
+        };
+        text_area.rewrap();
+        text_area
+    }
+
+    // Position and dimension getters/setters
+    #[allow(unused)]
+    pub fn get_x(&self) -> f32 {
+        self.x
+    }
+
+    #[allow(unused)]
+    pub fn set_x(&mut self, x: f32) -> &mut Self {
+        self.x = x;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_y(&self) -> f32 {
+        self.y
+    }
+
+    #[allow(unused)]
+    pub fn set_y(&mut self, y: f32) -> &mut Self {
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_width(&self) -> f32 {
+        self.width
+    }
+
+    #[allow(unused)]
+    pub fn set_width(&mut self, width: f32) -> &mut Self {
+        self.width = width;
+        self.rewrap();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_height(&self) -> f32 {
+        self.height
+    }
+
+    #[allow(unused)]
+    pub fn set_height(&mut self, height: f32) -> &mut Self {
+        self.height = height;
+        self
+    }
+
+    // Grow with the text between min_height and max_height instead of using a fixed height
+    #[allow(unused)]
+    pub fn set_auto_grow(&mut self, min_height: f32, max_height: f32) -> &mut Self {
+        self.auto_grow = Some((min_height, max_height.max(min_height)));
+        self.fit_height();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_auto_grow(&mut self) -> &mut Self {
+        self.auto_grow = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_auto_grow(&self) -> bool {
+        self.auto_grow.is_some()
+    }
+
+    // Called with the new (width, height) whenever auto-grow resizes the text area
+    #[allow(unused)]
+    pub fn set_on_resize<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: FnMut(f32, f32) + 'static,
+    {
+        self.on_resize = Some(Box::new(callback));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_position(&self) -> (f32, f32) {
+        (self.x, self.y)
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_dimensions(&self) -> (f32, f32) {
+        (self.width, self.height)
+    }
+
+    #[allow(unused)]
+    pub fn set_dimensions(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self.rewrap();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_colors(&mut self, text_color: impl Into<ThemeColor>, border_color: impl Into<ThemeColor>, background_color: impl Into<ThemeColor>, cursor_color: impl Into<ThemeColor>) -> &mut Self {
+        self.text_color = text_color.into();
+        self.border_color = border_color.into();
+        self.background_color = background_color.into();
+        self.cursor_color = cursor_color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font(&mut self, font: Font) -> &mut Self {
+        glyph_cache::forget_font_id(self.font_id);
+        self.font_id = glyph_cache::new_font_id();
+        self.font = Some(font);
+        self.rewrap();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_font(&self) -> Option<&Font> {
+        self.font.as_ref()
+    }
+
+    #[allow(unused)]
+    pub fn set_font(&mut self, font: Font) -> &mut Self {
+        glyph_cache::forget_font_id(self.font_id);
+        self.font_id = glyph_cache::new_font_id();
+        self.font = Some(font);
+        self.rewrap();
+        self
+    }
+
+    // Go back to the theme's font
+    #[allow(unused)]
+    pub fn clear_font(&mut self) -> &mut Self {
+        self.font = None;
+        glyph_cache::forget_font_id(self.font_id);
+        self.font_id = 0;
+        self.rewrap();
+        self
+    }
+
+    // Text getters/setters
+    #[allow(unused)]
+    pub fn get_text(&self) -> String {
+        self.text.clone()
+    }
+
+    // True if the user changed the text (typing, pasting, cutting) during the last update
+    #[allow(unused)]
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    #[allow(unused)]
+    pub fn set_text<T: Into<String>>(&mut self, text: T) -> &mut Self {
+        self.text = text.into();
+        if let Some(max) = self.max_length {
+            if let Some((byte_index, _)) = self.text.grapheme_indices(true).nth(max) {
+                self.text.truncate(byte_index);
+            }
+        }
+        self.selection_anchor = None;
+        // Back to the start of the grapheme it lands in, never inside an emoji or accented letter
+        let cursor = self.cursor_index.min(self.text.len());
+        self.cursor_index = self.text.grapheme_indices(true).map(|(index, _)| index).take_while(|&index| index <= cursor).last().unwrap_or(0);
+        if cursor == self.text.len() {
+            self.cursor_index = cursor;
+        }
+        self.rewrap();
+        self.scroll_to_cursor();
+        self
+    }
+
+    // Number of lines after word wrapping
+    #[allow(unused)]
+    pub fn get_line_count(&self) -> usize {
+        self.cached_lines.len()
+    }
+
+    // Active state getters/setters
+    #[allow(unused)]
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    // Activating takes keyboard focus away from any other widget
+    #[allow(unused)]
+    pub fn set_active(&mut self, active: bool) -> &mut Self {
+        if active && self.enabled {
+            focus::set_focus(self.focus_id);
+            self.active = true;
+        } else {
+            focus::release_focus(self.focus_id);
+            self.active = false;
+        }
+        self
+    }
+
+    // Id used by the focus module (for focus::set_tab_order and friends)
+    #[allow(unused)]
+    pub fn focus_id(&self) -> usize {
+        self.focus_id
+    }
+
+    // Cursor index getters/setters
+    #[allow(unused)]
+    pub fn get_cursor_index(&self) -> usize {
+        self.cursor_index
+    }
+
+    #[allow(unused)]
+    pub fn set_cursor_index(&mut self, index: usize) -> &mut Self {
+        if index <= self.text.len() && self.text.is_char_boundary(index) {
+            self.cursor_index = index;
+            self.selection_anchor = None;
+            self.preferred_x = None;
+            self.scroll_to_cursor();
+        }
+        self
+    }
+
+    // Font size and line spacing getters/setters
+    #[allow(unused)]
+    pub fn get_font_size(&self) -> f32 {
+        self.font_size
+    }
+
+    #[allow(unused)]
+    pub fn set_font_size(&mut self, size: f32) -> &mut Self {
+        self.font_size = size;
+        self.rewrap();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_line_spacing(&self) -> f32 {
+        self.line_spacing
+    }
+
+    #[allow(unused)]
+    pub fn set_line_spacing(&mut self, spacing: f32) -> &mut Self {
+        self.line_spacing = spacing;
+        self.fit_height();
+        self
+    }
+
+    // Color getters/setters
+    #[allow(unused)]
+    pub fn get_text_color(&self) -> Color {
+        self.text_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_text_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.text_color = color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_border_color(&self) -> Color {
+        self.border_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_border_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.border_color = color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_background_color(&self) -> Color {
+        self.background_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_background_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.background_color = color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_cursor_color(&self) -> Color {
+        self.cursor_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_cursor_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.cursor_color = color.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_selection_color(&self) -> Color {
+        self.selection_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_selection_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.selection_color = color.into();
+        self
+    }
+
+    // Prompt text getters/setters
+    #[allow(unused)]
+    pub fn get_prompt(&self) -> Option<&String> {
+        self.prompt.as_ref()
+    }
+
+    #[allow(unused)]
+    pub fn set_prompt<T: Into<String>>(&mut self, prompt: T) -> &mut Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_prompt_color(&self) -> Color {
+        self.prompt_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_prompt_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.prompt_color = color.into();
+        self
+    }
+
+    // Key repeat settings
+    #[allow(unused)]
+    pub fn with_key_repeat_settings(&mut self, delay: f32, rate: f32) -> &mut Self {
+        self.key_repeat_delay = delay;
+        self.key_repeat_rate = rate;
+        self
+    }
+
+    // Enable/disable functionality
+    #[allow(unused)]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[allow(unused)]
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        // Disabled boxes can't be tabbed to
+        focus::set_focusable(self.focus_id, enabled);
+        if !enabled {
+            self.active = false;
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_disabled_color(&self) -> Color {
+        self.disabled_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_disabled_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.disabled_color = color.into();
+        self
+    }
+
+    // Maximum length getters/setters (measured in characters, not bytes)
+    #[allow(unused)]
+    pub fn get_max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    #[allow(unused)]
+    pub fn set_max_length(&mut self, max_length: usize) -> &mut Self {
+        self.max_length = Some(max_length);
+        let text = self.text.clone();
+        self.set_text(text);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_max_length(&mut self) -> &mut Self {
+        self.max_length = None;
+        self
+    }
+
+    // Selection methods
+    #[allow(unused)]
+    pub fn has_selection(&self) -> bool {
+        self.selection_range().is_some()
+    }
+
+    #[allow(unused)]
+    pub fn get_selected_text(&self) -> String {
+        match self.selection_range() {
+            Some((start, end)) => self.text[start..end].to_string(),
+            None => String::new(),
+        }
+    }
+
+    #[allow(unused)]
+    pub fn select_all(&mut self) -> &mut Self {
+        self.selection_anchor = Some(0);
+        self.cursor_index = self.text.len();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_selection(&mut self) -> &mut Self {
+        self.selection_anchor = None;
+        self
+    }
+
+    // Selected byte range as (start, end), or None when nothing is selected
+    #[allow(unused)]
+    pub fn get_selection(&self) -> Option<(usize, usize)> {
+        self.selection_range()
+    }
+
+    // Select a byte range (the cursor goes to the end), ignored if it isn't on character boundaries
+    #[allow(unused)]
+    pub fn set_selection(&mut self, start: usize, end: usize) -> &mut Self {
+        let (start, end) = (start.min(end), start.max(end));
+        if end <= self.text.len() && self.text.is_char_boundary(start) && self.text.is_char_boundary(end) {
+            self.selection_anchor = Some(start);
+            self.cursor_index = end;
+        }
+        self
+    }
+
+    // Replace the selected text (or insert at the cursor when nothing is selected)
+    #[allow(unused)]
+    pub fn replace_selection(&mut self, text: &str) -> &mut Self {
+        self.delete_selection();
+        self.insert_at_cursor(text);
+        self.preferred_x = None;
+        self.scroll_to_cursor();
+        self
+    }
+
+    // Clipboard methods
+    #[allow(unused)]
+    pub fn copy_selection(&self) {
+        if self.has_selection() {
+            clipboard::set_text(&self.get_selected_text());
+        }
+    }
+
+    #[allow(unused)]
+    pub fn cut_selection(&mut self) -> &mut Self {
+        if self.enabled && self.has_selection() {
+            clipboard::set_text(&self.get_selected_text());
+            self.delete_selection();
+        }
+        self
+    }
+
+    // Pastes at the cursor once the clipboard text arrives (the next update on the web)
+    #[allow(unused)]
+    pub fn paste(&mut self) -> &mut Self {
+        if self.enabled {
+            clipboard::request_text();
+            self.paste_pending = true;
+        }
+        self
+    }
+
+    // Primary method - both updates and draws the text area
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        self.update_internal();
+        self.draw_internal();
+    }
+
+    // The box's own font, or the theme's when it has none
+    fn current_font(&self) -> Option<Font> {
+        self.font.clone().or_else(theme::font)
+    }
+
+    fn current_font_id(&self) -> usize {
+        if self.font.is_some() { self.font_id } else { theme::font_id() }
+    }
+
+    // For cases when only drawing is needed without updating
+    #[allow(unused)]
+    pub fn draw_only(&self) {
+        self.draw_internal();
+    }
+
+    // For cases when only updating is needed without drawing
+    #[allow(unused)]
+    pub fn update_only(&mut self) {
+        self.update_internal();
+    }
+
+    fn line_height(&self) -> f32 {
+        self.font_size * self.line_spacing
+    }
+
+    fn char_width(&self, c: char) -> f32 {
+        glyph_cache::char_width(c, self.current_font().as_ref(), self.current_font_id(), self.font_size as u16)
+    }
+
+    // Width of a piece of text measured one character at a time (matches how the cursor is placed)
+    fn text_width(&self, text: &str) -> f32 {
+        text.chars().map(|c| self.char_width(c)).sum()
+    }
+
+    // Split the text into lines that fit the box, breaking at spaces where possible
+    fn rewrap(&mut self) {
+        let max_width = (self.width - PADDING * 2.0).max(1.0);
+        let mut lines = Vec::new();
+        let mut paragraph_start = 0;
+
+        for paragraph in self.text.split('\n') {
+            let paragraph_end = paragraph_start + paragraph.len();
+            let mut line_start = paragraph_start;
+            let mut line_width = 0.0;
+            let mut last_break = None; // Byte index just after the last space on this line
+
+            // Step a grapheme cluster at a time so emoji and accented letters never split across lines
+            for (offset, g) in paragraph.grapheme_indices(true) {
+                let index = paragraph_start + offset;
+                let width = self.text_width(g);
+                if line_width + width > max_width && index > line_start {
+                    // Break after the last space, or mid-word if the word is wider than the box
+                    let break_at = match last_break {
+                        Some(at) if at > line_start => at,
+                        _ => index,
+                    };
+                    lines.push((line_start, break_at));
+                    line_start = break_at;
+                    line_width = self.text_width(&self.text[line_start..index]);
+                    last_break = None;
+                }
+                line_width += width;
+                if g == " " {
+                    last_break = Some(index + 1);
+                }
+            }
+
+            lines.push((line_start, paragraph_end));
+            paragraph_start = paragraph_end + 1; // Skip the '\n'
+        }
+
+        self.cached_lines = lines;
+        self.fit_height();
+    }
+
+    // With auto-grow on, resize to fit the wrapped lines and tell the on_resize callback
+    fn fit_height(&mut self) {
+        let Some((min_height, max_height)) = self.auto_grow else {
+            return;
+        };
+        let content_height = self.cached_lines.len() as f32 * self.line_height() + PADDING * 2.0;
+        let height = content_height.clamp(min_height, max_height);
+        if (height - self.height).abs() > f32::EPSILON {
+            self.height = height;
+            self.clamp_scroll();
+            if let Some(callback) = self.on_resize.as_mut() {
+                callback(self.width, height);
+            }
+        }
+    }
+
+    // Line the cursor is on (a cursor at a wrap point belongs to the next line)
+    fn line_of(&self, index: usize) -> usize {
+        self.cached_lines
+            .iter()
+            .rposition(|&(start, _)| start <= index)
+            .unwrap_or(0)
+    }
+
+    // Byte index in a line closest to an x offset from the left edge of the text
+    fn index_in_line(&self, line: usize, x: f32) -> usize {
+        let (start, end) = self.cached_lines[line];
+        let mut offset = 0.0;
+        for (i, g) in self.text[start..end].grapheme_indices(true) {
+            let width = self.text_width(g);
+            if offset + width / 2.0 > x {
+                return start + i;
+            }
+            offset += width;
+        }
+        end
+    }
+
+    // Byte index of the character closest to a point on screen
+    fn index_at_point(&self, x: f32, y: f32) -> usize {
+        let line = ((y - self.y - PADDING + self.scroll_offset) / self.line_height()).floor().max(0.0) as usize;
+        let line = line.min(self.cached_lines.len() - 1);
+        self.index_in_line(line, x - self.x - PADDING)
+    }
+
+    // Scroll just enough to keep the cursor's line inside the box
+    fn scroll_to_cursor(&mut self) {
+        let line_height = self.line_height();
+        let visible_height = (self.height - PADDING * 2.0).max(line_height);
+        let line_top = self.line_of(self.cursor_index) as f32 * line_height;
+
+        if line_top < self.scroll_offset {
+            self.scroll_offset = line_top;
+        } else if line_top + line_height > self.scroll_offset + visible_height {
+            self.scroll_offset = line_top + line_height - visible_height;
+        }
+        self.clamp_scroll();
+    }
+
+    fn clamp_scroll(&mut self) {
+        let visible_height = (self.height - PADDING * 2.0).max(0.0);
+        let content_height = self.cached_lines.len() as f32 * self.line_height();
+        self.scroll_offset = self.scroll_offset.clamp(0.0, (content_height - visible_height).max(0.0));
+    }
+
+    // Selected byte range as (start, end), or None when nothing is selected
+    fn selection_range(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?;
+        if anchor == self.cursor_index {
+            None
+        } else {
+            Some((anchor.min(self.cursor_index), anchor.max(self.cursor_index)))
+        }
+    }
+
+    // Remove the selected text, returns true if anything was removed
+    fn delete_selection(&mut self) -> bool {
+        match self.selection_range() {
+            Some((start, end)) => {
+                self.text.replace_range(start..end, "");
+                self.cursor_index = start;
+                self.selection_anchor = None;
+                self.changed = true;
+                self.rewrap();
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Insert text at the cursor, keeping only as many characters as the max length allows
+    // (counted in graphemes, so an emoji or an accented letter is never cut in half)
+    fn insert_at_cursor(&mut self, text: &str) {
+        let remaining = match self.max_length {
+            Some(max) => max.saturating_sub(self.text.graphemes(true).count()),
+            None => usize::MAX,
+        };
+        let allowed: String = text.graphemes(true).take(remaining).collect();
+        self.text.insert_str(self.cursor_index, &allowed);
+        self.cursor_index += allowed.len();
+        self.changed |= !allowed.is_empty();
+        self.rewrap();
+    }
+
+    // Insert pasted text over the selection, keeping line breaks
+    fn insert_pasted(&mut self, text: &str) {
+        let cleaned: String = text
+            .replace("\r\n", "\n")
+            .chars()
+            .map(|c| if c == '\t' { ' ' } else { c })
+            .filter(|&c| c == '\n' || !c.is_control())
+            .collect();
+        self.delete_selection();
+        self.insert_at_cursor(&cleaned);
+    }
+
+    // Byte index of the character before the cursor (a whole grapheme cluster, so emoji stay intact)
+    fn prev_char_index(&self) -> usize {
+        self.text[..self.cursor_index]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    // Byte index of the character after the cursor
+    fn next_char_index(&self) -> usize {
+        self.text[self.cursor_index..]
+            .graphemes(true)
+            .next()
+            .map_or(self.cursor_index, |g| self.cursor_index + g.len())
+    }
+
+    // Byte range of the word (or run of spaces/punctuation) around an index
+    fn word_range_at(&self, index: usize) -> (usize, usize) {
+        // Use the character after the index, or the one before it at the end of the text
+        let Some(target) = self.text[index..].chars().next().or_else(|| self.text[..index].chars().next_back()) else {
+            return (index, index);
+        };
+        let same_kind = |c: char| {
+            is_word_char(c) == is_word_char(target) && c.is_whitespace() == target.is_whitespace() && c != '\n'
+        };
+        // Whole characters (letters with accents, emoji) are judged by their first code point
+        let start = self.text[..index]
+            .grapheme_indices(true)
+            .rev()
+            .take_while(|&(_, g)| g.chars().next().is_some_and(same_kind))
+            .last()
+            .map_or(index, |(i, _)| i);
+        let end = self.text[index..]
+            .grapheme_indices(true)
+            .find(|&(_, g)| !g.chars().next().is_some_and(same_kind))
+            .map_or(self.text.len(), |(i, _)| index + i);
+        (start, end)
+    }
+
+    // Byte index of the start of the word before the cursor (skips spaces/punctuation first)
+    fn prev_word_index(&self) -> usize {
+        let mut index = self.cursor_index;
+        let mut in_word = false;
+        for (i, g) in self.text[..self.cursor_index].grapheme_indices(true).rev() {
+            if g.chars().next().is_some_and(is_word_char) {
+                in_word = true;
+            } else if in_word {
+                break;
+            }
+            index = i;
+        }
+        index
+    }
+
+    // Byte index of the end of the word after the cursor (skips spaces/punctuation first)
+    fn next_word_index(&self) -> usize {
+        let mut index = self.cursor_index;
+        let mut in_word = false;
+        for (i, g) in self.text[self.cursor_index..].grapheme_indices(true) {
+            if g.chars().next().is_some_and(is_word_char) {
+                in_word = true;
+            } else if in_word {
+                break;
+            }
+            index = self.cursor_index + i + g.len();
+        }
+        index
+    }
+
+    fn delete_backward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        if self.cursor_index > 0 {
+            let prev = self.prev_char_index();
+            self.text.replace_range(prev..self.cursor_index, "");
+            self.cursor_index = prev;
+            self.changed = true;
+            self.rewrap();
+        }
+    }
+
+    fn delete_forward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        if self.cursor_index < self.text.len() {
+            let next = self.next_char_index();
+            self.text.replace_range(self.cursor_index..next, "");
+            self.changed = true;
+            self.rewrap();
+        }
+    }
+
+    // Start or drop the selection before the cursor moves
+    fn begin_move(&mut self, extend: bool) {
+        if extend {
+            self.selection_anchor.get_or_insert(self.cursor_index);
+        } else {
+            self.selection_anchor = None;
+        }
+    }
+
+    fn move_left(&mut self, extend: bool, by_word: bool) {
+        self.preferred_x = None;
+        match self.selection_range() {
+            _ if by_word => {
+                self.begin_move(extend);
+                self.cursor_index = self.prev_word_index();
+            }
+            Some((start, _)) if !extend => {
+                self.cursor_index = start;
+                self.selection_anchor = None;
+            }
+            _ => {
+                self.begin_move(extend);
+                self.cursor_index = self.prev_char_index();
+            }
+        }
+    }
+
+    fn move_right(&mut self, extend: bool, by_word: bool) {
+        self.preferred_x = None;
+        match self.selection_range() {
+            _ if by_word => {
+                self.begin_move(extend);
+                self.cursor_index = self.next_word_index();
+            }
+            Some((_, end)) if !extend => {
+                self.cursor_index = end;
+                self.selection_anchor = None;
+            }
+            _ => {
+                self.begin_move(extend);
+                self.cursor_index = self.next_char_index();
+            }
+        }
+    }
+
+    // Move the cursor to the start (Home) or end (End) of its line, or of the whole text
+    fn move_line_edge(&mut self, to_end: bool, whole_text: bool, extend: bool) {
+        self.preferred_x = None;
+        self.begin_move(extend);
+        if whole_text {
+            self.cursor_index = if to_end { self.text.len() } else { 0 };
+            return;
+        }
+        let line = self.line_of(self.cursor_index);
+        let (start, end) = self.cached_lines[line];
+        self.cursor_index = if !to_end {
+            start
+        } else if self.cached_lines.get(line + 1).is_some_and(|&(next, _)| next == end) {
+            // A wrapped line, its end is the start of the next line so stop one character short
+            self.text[..end].grapheme_indices(true).next_back().map_or(start, |(index, _)| index.max(start))
+        } else {
+            end
+        };
+    }
+
+    // Move the cursor up (-1) or down (+1) a line, keeping roughly the same column
+    fn move_vertical(&mut self, direction: i32, extend: bool) {
+        self.begin_move(extend);
+        let line = self.line_of(self.cursor_index);
+        let (start, _) = self.cached_lines[line];
+        let x = *self.preferred_x.get_or_insert(self.text_width(&self.text[start..self.cursor_index]));
+
+        let target = line as i32 + direction;
+        if target < 0 {
+            self.cursor_index = 0;
+        } else if target as usize >= self.cached_lines.len() {
+            self.cursor_index = self.text.len();
+        } else {
+            self.cursor_index = self.index_in_line(target as usize, x);
+        }
+    }
+
+    // Apply a repeatable key (used for the first press and for key repeat)
+    fn apply_key(&mut self, key: KeyCode, shift_down: bool, word_down: bool) {
+        match key {
+            KeyCode::Left => self.move_left(shift_down, word_down),
+            KeyCode::Right => self.move_right(shift_down, word_down),
+            KeyCode::Home => self.move_line_edge(false, word_down, shift_down),
+            KeyCode::End => self.move_line_edge(true, word_down, shift_down),
+            KeyCode::Up => self.move_vertical(-1, shift_down),
+            KeyCode::Down => self.move_vertical(1, shift_down),
+            KeyCode::Delete => self.delete_forward(),
+            KeyCode::Backspace => self.delete_backward(),
+            KeyCode::Enter | KeyCode::KpEnter => {
+                self.delete_selection();
+                self.insert_at_cursor("\n");
+            }
+            _ => {}
+        }
+    }
+
+    fn update_internal(&mut self) {
+        self.changed = false;
+        // Skip all interaction if disabled
+        if !self.enabled {
+            self.active = false;
+            self.cursor_visible = false;
+            ime::disable(self.focus_id);
+            ime::remove_tap_area(self.focus_id);
+            return;
+        }
+
+        // Tapping the box on a touch screen opens the on-screen keyboard (see the ime module)
+        ime::set_tap_area(self.focus_id, Rect::new(self.x, self.y, self.width, self.height));
+        focus::set_area(self.focus_id, Rect::new(self.x, self.y, self.width, self.height));
+
+        // Tab / Shift+Tab may move focus here or away from here
+        focus::handle_tab();
+        self.active = focus::is_focused(self.focus_id);
+
+        let cursor_before = (self.cursor_index, self.text.len());
+        let shift_down = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        // Ctrl on Windows/Linux, Cmd on macOS
+        let command_down = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl)
+            || is_key_down(KeyCode::LeftSuper) || is_key_down(KeyCode::RightSuper);
+        // Ctrl on Windows/Linux, Alt (Option) on macOS jumps by word
+        let word_down = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl)
+            || is_key_down(KeyCode::LeftAlt) || is_key_down(KeyCode::RightAlt);
+
+        let (mx, my) = mouse_position();
+        let hovered = mx >= self.x && mx <= self.x + self.width && my >= self.y && my <= self.y + self.height
+            && focus::is_top_at(self.focus_id, mx, my);
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let was_active = self.active;
+            // Tapping the on-screen keyboard keeps typing going into this box
+            let on_keyboard = !hovered && virtual_keyboard::covers(mx, my);
+            if hovered {
+                focus::set_focus(self.focus_id);
+            } else if !on_keyboard {
+                focus::release_focus(self.focus_id);
+            }
+            self.active = hovered || (was_active && on_keyboard);
+
+            if hovered {
+                // Count quick repeated clicks, a double click selects a word and a triple click everything
+                let now = get_time();
+                if was_active && now - self.last_click_time <= MULTI_CLICK_TIME {
+                    self.click_count += 1;
+                } else {
+                    self.click_count = 1;
+                }
+                self.last_click_time = now;
+
+                // Clicking places the cursor, shift+click extends the existing selection
+                let click_index = self.index_at_point(mx, my);
+                if shift_down && was_active {
+                    self.selection_anchor.get_or_insert(self.cursor_index);
+                    self.cursor_index = click_index;
+                } else if self.click_count == 2 {
+                    let (start, end) = self.word_range_at(click_index);
+                    self.selection_anchor = Some(start);
+                    self.cursor_index = end;
+                } else if self.click_count >= 3 {
+                    self.select_all();
+                } else {
+                    self.selection_anchor = Some(click_index);
+                    self.cursor_index = click_index;
+                }
+                self.preferred_x = None;
+                // Dragging after a double/triple click would throw away the word selection
+                self.dragging = self.click_count == 1 || shift_down;
+            } else if !self.active {
+                self.selection_anchor = None;
+            }
+        }
+
+        // Dragging with the mouse held down moves the cursor end of the selection
+        if self.dragging {
+            if is_mouse_button_down(MouseButton::Left) {
+                self.cursor_index = self.index_at_point(mx, my);
+            } else {
+                self.dragging = false;
+            }
+        }
+
+        // Mouse wheel scrolls three lines per notch
+        let (_, wheel_y) = mouse_wheel();
+        if hovered && wheel_y != 0.0 {
+            self.scroll_offset -= wheel_y.signum() * self.line_height() * 3.0;
+            self.clamp_scroll();
+        }
+
+        if self.active {
+            // The arrow keys move the cursor, not focus (keyboard mode)
+            focus::use_arrows();
+            if command_down && is_key_pressed(KeyCode::A) {
+                self.select_all();
+            }
+            if command_down && is_key_pressed(KeyCode::C) {
+                self.copy_selection();
+            }
+            if command_down && is_key_pressed(KeyCode::X) {
+                self.cut_selection();
+            }
+            if command_down && is_key_pressed(KeyCode::V) {
+                self.paste();
+            }
+            if self.paste_pending {
+                if let Some(pasted) = clipboard::take_text() {
+                    self.insert_pasted(&pasted);
+                    self.paste_pending = false;
+                }
+            }
+
+            // Handle typing (typed text replaces the selection)
+            while let Some(c) = get_char_pressed() {
+                if !c.is_control() && !command_down {
+                    self.delete_selection();
+                    self.insert_at_cursor(c.encode_utf8(&mut [0; 4]));
+                    self.preferred_x = None;
+                }
+            }
+
+            // Text confirmed through an input method (see the ime module)
+            if let Some(committed) = ime::take_committed() {
+                self.delete_selection();
+                self.insert_at_cursor(&committed);
+                self.preferred_x = None;
+            }
+
+            // Keys tapped on the on-screen keyboard (see the virtual_keyboard module)
+            for key in virtual_keyboard::take_keys(self.focus_id) {
+                match key {
+                    VirtualKey::Char(c) => {
+                        self.delete_selection();
+                        self.insert_at_cursor(c.encode_utf8(&mut [0; 4]));
+                        self.preferred_x = None;
+                    }
+                    VirtualKey::Backspace => self.apply_key(KeyCode::Backspace, false, false),
+                    VirtualKey::Enter => self.apply_key(KeyCode::Enter, false, false),
+                }
+            }
+
+            // Handle initial key presses
+            let repeatable_keys = [
+                KeyCode::Left, KeyCode::Right, KeyCode::Up, KeyCode::Down,
+                KeyCode::Home, KeyCode::End, KeyCode::Delete, KeyCode::Backspace, KeyCode::Enter, KeyCode::KpEnter,
+            ];
+            if let Some(&key) = repeatable_keys.iter().find(|&&key| is_key_pressed(key)) {
+                self.apply_key(key, shift_down, word_down);
+                self.last_key = Some(key);
+                self.key_repeat_timer = 0.0;
+            } else if let Some(key) = self.last_key {
+                // Handle key repeat functionality
+                if is_key_down(key) {
+                    self.key_repeat_timer += get_frame_time();
+                    if self.key_repeat_timer >= self.key_repeat_delay {
+                        self.key_repeat_timer -= self.key_repeat_rate;
+                        self.apply_key(key, shift_down, word_down);
+                    }
+                } else {
+                    self.last_key = None;
+                    self.key_repeat_timer = 0.0;
+                }
+            }
+
+            self.cursor_timer += get_frame_time();
+            if self.cursor_timer >= 0.5 {
+                self.cursor_visible = !self.cursor_visible;
+                self.cursor_timer = 0.0;
+            }
+        } else {
+            self.cursor_visible = false;
+        }
+
+        // Only follow the cursor when it moved, so wheel scrolling isn't undone
+        if (self.cursor_index, self.text.len()) != cursor_before {
+            self.scroll_to_cursor();
+        }
+
+        // Keep the input method's popup next to the cursor
+        if self.active {
+            let line = self.line_of(self.cursor_index);
+            let (start, _) = self.cached_lines[line];
+            let cursor_x = self.x + PADDING + self.text_width(&self.text[start..self.cursor_index]);
+            let cursor_y = self.y + PADDING + (line + 1) as f32 * self.line_height() - self.scroll_offset;
+            ime::enable(self.focus_id, cursor_x, cursor_y);
+        } else {
+            ime::disable(self.focus_id);
+        }
+    }
+
+    fn draw_internal(&self) {
+        let line_height = self.line_height();
+        let text_x = self.x + PADDING;
+        // Baseline of the first line
+        let first_baseline = self.y + PADDING + self.font_size * 0.8 - self.scroll_offset;
+
+        // Draw the background (or disabled color when disabled)
+        let background = if self.enabled { self.background_color.color() } else { self.disabled_color.color() };
+        draw_rectangle(self.x, self.y, self.width, self.height, background);
+
+        let text_color = if self.enabled { self.text_color.color() } else { theme::palette().text_muted };
+        let prompt_color = if self.enabled { self.prompt_color.color() } else { theme::palette().text_muted };
+
+        push_clip_rect(Rect::new(self.x, self.y, self.width, self.height));
+
+        if self.text.is_empty() {
+            if let Some(prompt) = &self.prompt {
+                draw_text_ex(
+                    prompt,
+                    text_x,
+                    first_baseline,
+                    TextParams {
+                        font: self.current_font().as_ref(),
+                        font_size: self.font_size as u16,
+                        color: prompt_color,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        let selection = if self.enabled && self.active { self.selection_range() } else { None };
+        for (i, &(start, end)) in self.cached_lines.iter().enumerate() {
+            let baseline = first_baseline + i as f32 * line_height;
+            // Skip lines scrolled out of view
+            if baseline + line_height < self.y || baseline - line_height > self.y + self.height {
+                continue;
+            }
+
+            // Highlight the part of the selection on this line
+            if let Some((sel_start, sel_end)) = selection {
+                let from = sel_start.max(start);
+                let to = sel_end.min(end);
+                if from < to || (from == to && sel_start <= end && sel_end > end) {
+                    let start_x = text_x + self.text_width(&self.text[start..from]);
+                    let end_x = text_x + self.text_width(&self.text[start..to]);
+                    // Show selected line breaks as a small block
+                    let width = (end_x - start_x).max(if sel_end > end { self.font_size * 0.3 } else { 0.0 });
+                    draw_rectangle(start_x, baseline - self.font_size * 0.8, width, self.font_size, self.selection_color.color());
+                }
+            }
+
+            draw_text_ex(
+                &self.text[start..end],
+                text_x,
+                baseline,
+                TextParams {
+                    font: self.current_font().as_ref(),
+                    font_size: self.font_size as u16,
+                    color: text_color,
+                    ..Default::default()
+                },
+            );
+        }
+
+        // Text still being composed with an input method is drawn underlined at the cursor
+        let preedit = if self.enabled && self.active { ime::preedit() } else { String::new() };
+        if !preedit.is_empty() {
+            let line = self.line_of(self.cursor_index);
+            let (start, _) = self.cached_lines[line];
+            let preedit_x = text_x + self.text_width(&self.text[start..self.cursor_index]);
+            let preedit_width = self.text_width(&preedit);
+            let baseline = first_baseline + line as f32 * line_height;
+            draw_rectangle(preedit_x, baseline - self.font_size * 0.8, preedit_width, line_height, self.background_color.color());
+            draw_text_ex(
+                &preedit,
+                preedit_x,
+                baseline,
+                TextParams {
+                    font: self.current_font().as_ref(),
+                    font_size: self.font_size as u16,
+                    color: text_color,
+                    ..Default::default()
+                },
+            );
+            draw_line(preedit_x, baseline + 3.0, preedit_x + preedit_width, baseline + 3.0, 1.0, text_color);
+        }
+
+        // Only show cursor if enabled and active
+        if self.enabled && self.active && self.cursor_visible {
+            let line = self.line_of(self.cursor_index);
+            let (start, _) = self.cached_lines[line];
+            let cursor_x = text_x + self.text_width(&self.text[start..self.cursor_index]) + self.text_width(&preedit) + 2.0;
+            let baseline = first_baseline + line as f32 * line_height;
+            draw_line(cursor_x, baseline - self.font_size * 0.7, cursor_x, baseline + 2.0, 1.0, self.cursor_color.color());
+        }
+
+        pop_clip_rect();
+
+        // Draw the border
+        let border_color = if self.enabled { self.border_color.color() } else { theme::palette().text_muted };
+        draw_rectangle_lines(self.x, self.y, self.width, self.height, 2.0, border_color);
+    }
+}
+
+impl Drop for TextArea {
+    fn drop(&mut self) {
+        focus::unregister(self.focus_id);
+        ime::disable(self.focus_id);
+        ime::remove_tap_area(self.focus_id);
+        glyph_cache::forget_font_id(self.font_id);
+    }
+}
+
+// Letters, numbers and underscores make up words for ctrl+arrow movement
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -25,13 +25,139 @@ Then above the loop section to use you would go:
         30
     );
 
+Or make a button that takes its colors from the theme (see the theme module), following it
+when the theme is switched:
+    let btn_themed = TextButton::themed(100.0, 200.0, 200.0, 60.0, "Click Me", 30);
+It also starts with the theme's font and corner radius. Calling with_hover_color,
+with_text_color or with_hover_text_color on it stops it following the theme (writing the
+color fields directly does nothing while it follows the theme).
+
 You can customize the text colors with:
     btn_text.with_text_color(WHITE);        // Sets the normal text color
     btn_text.with_hover_text_color(YELLOW); // Sets the text color when hovering
 
 You can also specify a custom font with:
     btn_text.with_font(my_font.clone());
-Otherwise the default system font will be used.
+    btn_text.clear_font();          // Back to the default font
+Otherwise the default system font will be used. Load fonts with the assets module:
+    let my_font = assets::load_font("assets/love.ttf").await.unwrap();
+Characters the font doesn't have (emoji, Chinese, ...) use the font_fallback module's
+backup fonts when it has any loaded.
+
+To change the font size (the text stays centered):
+    btn_text.set_font_size(24);
+    let size = btn_text.get_font_size();
+
+You can change how the button looks while hovered and while held down:
+    btn_text.with_hover_color(SKYBLUE);     // Background while the mouse is over it
+    btn_text.with_pressed_color(DARKBLUE);  // Background while the mouse button is held on it
+    btn_text.with_brightness_shift(0.15);   // Or just lighten on hover and darken when held
+Without a pressed color the hover color is darkened a little while held.
+
+You can disable a button so it greys out and click() always returns false:
+    btn_text.set_enabled(false);
+    btn_text.set_enabled(true);
+    btn_text.set_disabled_color(LIGHTGRAY); // Background while disabled (defaults to a greyed normal color)
+    if btn_text.is_enabled() { }
+
+You can show an image on the button beside, above or instead of the text:
+    let icon = load_texture("assets/save.png").await.unwrap();
+    btn_text.with_icon(icon.clone(), IconLayout::Left);  // Icon then text
+    btn_text.with_icon(icon.clone(), IconLayout::Top);   // Icon above the text
+    btn_text.with_icon(icon, IconLayout::Only);          // Just the icon (toolbar buttons)
+    btn_text.with_icon_size(24.0, 24.0);                 // Otherwise it's shrunk to fit the button
+    btn_text.clear_icon();
+(IconLayout comes from use crate::modules::text_button::{TextButton, IconLayout};)
+
+You can make the button latch on and off (click once for on, again for off):
+    btn_text.with_toggle(true);
+    btn_text.with_toggled_color(DARKGREEN); // Background while on (defaults to the pressed color)
+    if btn_text.click() {
+        let show_password = btn_text.is_toggled();
+    }
+    btn_text.set_toggled(false);            // Change it from code (doesn't count as a click)
+
+You can react to the button being held down:
+    // True once, when the button has been held for 1 second (a long press)
+    btn_text.click();
+    if btn_text.held_for(1.0) { }
+
+    // Or use click_repeating instead of click: true on the first click, then after
+    // 0.5 seconds of holding it repeats 10 times a second until released
+    if btn_level_up.click_repeating(0.5, 10.0) {
+        level += 1;
+    }
+
+You can have the button size itself to fit its text, so changing the text never overflows:
+    let btn_auto = TextButton::new_auto_sized(100.0, 200.0, "Save", BLUE, GREEN, 30);
+    btn_text.with_auto_size(15.0);                    // Padding each side of the text
+    btn_text.with_width_limits(Some(120.0), Some(400.0)); // Optional min/max width
+    btn_text.clear_auto_size();                        // Back to a fixed width
+While auto-sized, the width given to update_position is ignored.
+
+You can show a tooltip after the mouse rests on the button (needs pub mod tooltip; in
+mod.rs, and tooltip::draw(); at the end of the loop):
+    btn_text.set_tooltip("Saves your level to the database");
+    btn_text.clear_tooltip();
+
+You can play a sound when the button is clicked (needs pub mod audio;, pub mod assets; and
+pub mod settings; in mod.rs, see the audio module for turning sound on):
+    let click = audio::load_sound("assets/click.wav").await.unwrap();
+    btn_text.on_click_sound(click);
+    btn_text.clear_click_sound();
+    audio::set_click_sound(Some(click)); // Or one sound for every button without its own
+
+You can animate the button when it is pressed (needs pub mod animation; in mod.rs):
+    btn_text.with_press_animation(PressEffect::Shrink(0.9), 0.15, Easing::EaseOut);  // Shrink to 90% and spring back
+    btn_text.with_press_animation(PressEffect::Flash(WHITE), 0.25, Easing::Linear); // Flash white and fade back
+    btn_text.clear_press_animation();
+(use crate::modules::text_button::PressEffect; and use crate::modules::animation::Easing;)
+
+You can require a double click, e.g. for actions that can't be undone:
+    btn_delete.click();
+    if btn_delete.double_clicked() {
+        // Delete the account
+    }
+    btn_delete.set_double_click_interval(0.5); // Seconds allowed between the two clicks (default 0.4)
+
+You can use an image as the background instead of a flat color. It is drawn nine-slice:
+the corners stay the same size and the edges and middle stretch, so one image works for
+any size of button:
+    let art = load_texture("assets/button.png").await.unwrap();
+    btn_text.with_nine_slice(art.clone(), 12.0);                 // 12 pixel corners on every side
+    btn_text.with_nine_slice_margins(art, 12.0, 8.0, 12.0, 16.0); // left, top, right, bottom
+    btn_text.clear_nine_slice();
+The image is darkened a little on hover and when pressed, and greyed out when disabled.
+
+You can tie the button to async work like a database save (needs pub mod tasks; in mod.rs
+and tasks::run_pending(); in the loop). While it runs the button is disabled and shows a
+spinner with the busy text, then action_result says how it went:
+    btn_save.with_busy_text("Saving...");
+    // Run the same action on every click
+    btn_save.bind_async_action(move || {
+        let client = client.clone(); // Futures must own what they use (e.g. an Rc)
+        async move { client.fetch_json("...").await.map(|_| ()).map_err(|e| e.to_string()) }
+    });
+    // Or start one yourself when clicked
+    if btn_save.click() {
+        btn_save.run_async(async move { Ok(()) });
+    }
+    // Then in the loop
+    if let Some(result) = btn_save.action_result() {
+        match result {
+            Ok(()) => lbl_out.set_text("Saved"),
+            Err(e) => lbl_out.set_text(format!("Save failed: {}", e)),
+        };
+    }
+When the action finishes the audio module's "success" or "error" sound plays, if registered.
+
+You can pin the button to an edge or corner of the screen so it stays there when the
+window or virtual resolution changes (use crate::modules::scale::Anchor;):
+    btn_text.with_anchor(Anchor::BottomRight, 20.0, 20.0); // 20 pixels in from the bottom right
+    btn_text.clear_anchor();
+    let pos = btn_text.get_screen_position();              // Where it is drawn right now
+    let area = btn_text.get_rect();                        // Where it is drawn, with its size
+    if btn_text.was_clicked() { }                          // What the last click() returned
 
 You can add rounded corners to the button with:
     btn_text.with_round(10.0);
@@ -40,6 +166,10 @@ Where the value is the corner radius in pixels.
 You can add a border to the button with:
     btn_text.with_border(RED, 2.0);
 Where the first value is the border color and the second is the thickness.
+Or change them one at a time:
+    btn_text.with_border_color(DARKGRAY);
+    btn_text.with_border_width(3.0);
+Rounded buttons and borders have softened edges so the corners don't look jagged.
 
 To access the button's position:
     let x = btn_text.get_x();
@@ -57,14 +187,70 @@ Then in the loop you would use:
 if btn_text.click() {
 
 }
+click() updates and draws the button in one go. To do them apart (a UiManager does):
+    let clicked = btn_text.update_only(); // Same as click() without drawing
+    btn_text.draw_only();                 // Draws the button the way the last update left it
+
+Buttons take part in Tab focus traversal (this needs pub mod focus; in mod.rs).
+A focused button shows an outline and is clicked with Enter or Space:
+    btn_text.with_focus_color(YELLOW); // Color of the focus outline
+    let id = btn_text.focus_id();      // For focus::set_tab_order
+In keyboard mode (focus::set_keyboard_mode) the focus module draws its ring in place of the outline.
 
 Note: For buttons with transparent backgrounds (set normal_color with alpha=0), 
 only the text area is clickable, not the entire button area.
 */
 use macroquad::prelude::*;
-#[cfg(feature = "scale")]
+use macroquad::audio::Sound;
+use crate::modules::animation::{self, Easing, Tween};
+use crate::modules::audio::{self, SoundEvent};
+use crate::modules::focus;
+// Characters the font doesn't have are drawn with the backup fonts (see font_fallback)
+use crate::modules::font_fallback::{draw_text_ex, measure_text};
+use crate::modules::scale::{anchored_position, Anchor};
+use crate::modules::tasks::{self, TaskHandle};
+use crate::modules::theme::{self, draw_round_rect, draw_round_rect_lines, ThemeColor};
+use crate::modules::tooltip;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
 use crate::modules::scale::mouse_position_world as mouse_position;
 
+// Where a button's icon goes relative to its text
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum IconLayout {
+    Left, // Icon to the left of the text
+    Only, // Icon with no text
+    Top,  // Icon above the text
+}
+
+// What happens to a button when it is pressed (see with_press_animation)
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum PressEffect {
+    Shrink(f32),  // Shrink to this scale (e.g. 0.9) then grow back
+    Flash(Color), // Flash this color then fade back to the normal color
+}
+
+// Future returned by an async action, Err holds a message to show the player
+type ActionFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+// Makes a new future each time the button is clicked
+type AsyncAction = Box<dyn FnMut() -> ActionFuture>;
+
+// Space between the icon and the text
+const ICON_GAP: f32 = 8.0;
+
+// What the button's last update found, for drawing it
+#[derive(Clone, Copy, Default)]
+struct ButtonState {
+    busy: bool,    // An async action is running
+    enabled: bool, // Enabled and not busy
+    hovered: bool,
+    pressed: bool, // Held down by the mouse or Space
+    focused: bool,
+}
+
 // Custom struct for ButtonText
 pub struct TextButton {
     x: f32,              // Now private
@@ -75,9 +261,11 @@ pub struct TextButton {
     pub enabled: bool,
     pub normal_color: Color,
     pub hover_color: Color,
-    off_color: Color,
+    off_color: Option<Color>, // Background while disabled (None fades the normal color)
     pub text_color: Color,
     pub hover_text_color: Color, // Added hover text color
+    pressed_color: Option<Color>, // Background while held (None darkens the hover color)
+    brightness_shift: Option<f32>, // Lighten on hover / darken when held instead of the set colors
     pub font_size: u16,
     pub font: Option<Font>, // Store the font directly since Font is Clone
     pub corner_radius: f32, // For rounded corners
@@ -87,27 +275,54 @@ pub struct TextButton {
     
     // Cached values for performance
     cached_text_width: f32,
+    cached_text_height: f32,
+    cached_text_offset_y: f32, // Distance from the top of the text to its baseline
     cached_text_position: Vec2,
     cached_rect: Rect,
+    cached_icon_rect: Rect,
     pub visible: bool,
+    focus_id: usize,         // Id in the focus module's tab order
+    focus_color: ThemeColor, // Color of the outline drawn while focused
+    themed: bool,            // Colors come from the theme instead of the color fields
+    icon: Option<Texture2D>, // Image drawn with (or instead of) the text
+    icon_layout: IconLayout,
+    icon_size: Option<Vec2>, // None shrinks the texture to fit the button
+    toggle_mode: bool,       // Whether clicks latch the button on/off
+    toggled: Cell<bool>,     // Current on/off state in toggle mode (a Cell since click() borrows immutably)
+    toggled_color: Option<Color>, // Background while on (None uses the pressed color)
+    holding: Cell<bool>,     // Whether a press that started on this button is still held
+    held_time: Cell<f32>,    // How long the current press has been held
+    prev_held_time: Cell<f32>, // held_time on the frame before, to find when a time is crossed
+    auto_padding: Option<f32>, // Space each side of the content when auto-sized (None keeps the width fixed)
+    min_width: Option<f32>,  // Limits on the auto-sized width
+    max_width: Option<f32>,
+    tooltip: Option<String>, // Text shown by the tooltip module while hovered
+    click_sound: Option<Sound>, // Played when clicked (None uses audio's shared click sound)
+    press_effect: Option<PressEffect>, // Animation played when pressed
+    press_tween: Cell<Tween>, // Strength of the press effect, 1.0 when pressed fading to 0.0
+    double_click_interval: f64, // Most seconds between two clicks that count as a double click
+    last_click_time: Cell<Option<f64>>, // When the last single click happened
+    double_clicked: Cell<bool>, // Whether this frame's click completed a double click
+    was_clicked: Cell<bool>, // What the last click() returned
+    state: Cell<ButtonState>, // What the last update found, for draw_only
+    nine_slice: Option<(Texture2D, [f32; 4])>, // Background image and its left, top, right, bottom margins
+    async_action: RefCell<Option<AsyncAction>>, // Started each time the button is clicked
+    running_action: RefCell<Option<TaskHandle<Result<(), String>>>>, // The action while it runs
+    action_result: RefCell<Option<Result<(), String>>>, // How the last action went, until taken
+    busy_text: String,       // Shown with a spinner while an action runs
+    anchor: Option<(Anchor, Vec2)>, // Screen edge/corner the button is pinned to, and its offset
 }
 
 impl TextButton {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(x: f32, y: f32, width: f32, height: f32, text: impl Into<String>, normal_color: Color, hover_color: Color, font_size: u16) -> Self {
         let enabled = true;
-        let off_color = lerp_color(normal_color, GRAY, 0.5);
         let text_string = text.into();
         let text_color = WHITE; // Default text color
         
-        // Pre-calculate and cache values
-        let cached_text_width = measure_text(&text_string, None, font_size, 1.0).width;
-        let cached_text_position = Vec2::new(
-            x + (width / 2.0) - (cached_text_width / 2.0),
-            y + (height / 2.0),
-        );
         let cached_rect = Rect::new(x, y, width, height);
         
-        Self {
+        let mut button = Self {
             x,
             y,
             width,
@@ -116,39 +331,316 @@ impl TextButton {
             enabled,
             normal_color,
             hover_color,
-            off_color,
+            off_color: None,
             text_color,
             hover_text_color: text_color, // Default hover text color to regular text color
+            pressed_color: None,
+            brightness_shift: None,
             font_size,
             font: None, // Default to None (use system font)
             corner_radius: 0.0, // Default to no rounded corners
             border: false, // Default to no border
             border_color: BLACK, // Default border color
             border_thickness: 1.0, // Default border thickness
-            cached_text_width,
-            cached_text_position,
+            cached_text_width: 0.0,
+            cached_text_height: 0.0,
+            cached_text_offset_y: 0.0,
+            cached_text_position: Vec2::new(x, y),
             cached_rect,
+            cached_icon_rect: Rect::new(0.0, 0.0, 0.0, 0.0),
             visible: true,
+            focus_id: focus::register(),
+            focus_color: ThemeColor::Text, // Default focus outline color
+            themed: false,
+            icon: None,
+            icon_layout: IconLayout::Left,
+            icon_size: None,
+            toggle_mode: false,
+            toggled: Cell::new(false),
+            toggled_color: None,
+            holding: Cell::new(false),
+            held_time: Cell::new(0.0),
+            prev_held_time: Cell::new(0.0),
+            auto_padding: None,
+            min_width: None,
+            max_width: None,
+            tooltip: None,
+            click_sound: None,
+            press_effect: None,
+            press_tween: Cell::new(Tween::new(1.0, 0.0, 0.15, Easing::EaseOut)),
+            double_click_interval: 0.4,
+            last_click_time: Cell::new(None),
+            double_clicked: Cell::new(false),
+            was_clicked: Cell::new(false),
+            state: Cell::new(ButtonState::default()),
+            nine_slice: None,
+            async_action: RefCell::new(None),
+            running_action: RefCell::new(None),
+            action_result: RefCell::new(None),
+            busy_text: "Working...".to_string(),
+            anchor: None,
+        };
+        // Pre-calculate and cache the text size and position
+        button.measure_label();
+        button
+    }
+
+    // Create a button colored by the active theme, that changes with it
+    #[allow(unused)]
+    pub fn themed(x: f32, y: f32, width: f32, height: f32, text: impl Into<String>, font_size: u16) -> Self {
+        let palette = theme::palette();
+        let mut button = Self::new(x, y, width, height, text, palette.primary, shade_color(palette.primary, 0.15), font_size);
+        button.themed = true;
+        button.corner_radius = theme::corner_radius();
+        if let Some(font) = theme::font() {
+            button.with_font(font);
         }
+        button
+    }
+
+    // Create a button that sizes itself to its text (with 15 pixels of padding each side)
+    #[allow(unused)]
+    pub fn new_auto_sized(x: f32, y: f32, text: impl Into<String>, normal_color: Color, hover_color: Color, font_size: u16) -> Self {
+        let padding = 15.0;
+        let height = font_size as f32 + padding * 2.0;
+        let mut button = Self::new(x, y, 0.0, height, text, normal_color, hover_color, font_size);
+        button.with_auto_size(padding);
+        button
     }
 
     // Method to set custom font - taking Font by value since it implements Clone
     #[allow(unused)]
     pub fn with_font(&mut self, font: Font) -> &mut Self {
-        self.font = Some(font.clone());
-        
-        // Update cached text width with the new font
-        self.cached_text_width = measure_text(&self.text, Some(&font), self.font_size, 1.0).width;
-        
-        // Update text position based on new measurement
-        self.cached_text_position = Vec2::new(
-            self.x + (self.width / 2.0) - (self.cached_text_width / 2.0),
-            self.y + (self.height / 2.0),
-        );
-        
+        self.font = Some(font);
+        self.measure_label();
+        self
+    }
+
+    // Method to go back to the default font
+    #[allow(unused)]
+    pub fn clear_font(&mut self) -> &mut Self {
+        self.font = None;
+        self.measure_label();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_font_size(&self) -> u16 {
+        self.font_size
+    }
+
+    // Method to change the font size (re-centers the text)
+    #[allow(unused)]
+    pub fn set_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self.measure_label();
+        self
+    }
+
+    // Method to show an image on the button
+    #[allow(unused)]
+    pub fn with_icon(&mut self, texture: Texture2D, layout: IconLayout) -> &mut Self {
+        self.icon = Some(texture);
+        self.icon_layout = layout;
+        self.update_layout();
+        self
+    }
+
+    // Method to draw the icon at a fixed size
+    #[allow(unused)]
+    pub fn with_icon_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.icon_size = Some(Vec2::new(width, height));
+        self.update_layout();
+        self
+    }
+
+    // Method to remove the icon
+    #[allow(unused)]
+    pub fn clear_icon(&mut self) -> &mut Self {
+        self.icon = None;
+        self.update_layout();
+        self
+    }
+
+    // Method to turn toggle mode on or off
+    #[allow(unused)]
+    pub fn with_toggle(&mut self, toggle_mode: bool) -> &mut Self {
+        self.toggle_mode = toggle_mode;
+        if !toggle_mode {
+            self.toggled.set(false);
+        }
+        self
+    }
+
+    // Method to set the background color while toggled on
+    #[allow(unused)]
+    pub fn with_toggled_color(&mut self, color: Color) -> &mut Self {
+        self.toggled_color = Some(color);
+        self
+    }
+
+    // Whether a toggle button is currently on
+    #[allow(unused)]
+    pub fn is_toggled(&self) -> bool {
+        self.toggled.get()
+    }
+
+    // Turn a toggle button on or off from code
+    #[allow(unused)]
+    pub fn set_toggled(&mut self, toggled: bool) -> &mut Self {
+        self.toggled.set(self.toggle_mode && toggled);
+        self
+    }
+
+    // Method to make the button size its width to its text (and icon) plus padding
+    #[allow(unused)]
+    pub fn with_auto_size(&mut self, padding: f32) -> &mut Self {
+        self.auto_padding = Some(padding);
+        self.update_layout();
         self
     }
 
+    // Method to limit how narrow or wide an auto-sized button can get
+    #[allow(unused)]
+    pub fn with_width_limits(&mut self, min: Option<f32>, max: Option<f32>) -> &mut Self {
+        self.min_width = min;
+        self.max_width = max;
+        self.update_layout();
+        self
+    }
+
+    // Method to go back to a fixed width (keeps the current width)
+    #[allow(unused)]
+    pub fn clear_auto_size(&mut self) -> &mut Self {
+        self.auto_padding = None;
+        self
+    }
+
+    // Method to show a tooltip when the mouse rests on the button
+    #[allow(unused)]
+    pub fn set_tooltip(&mut self, text: &str) -> &mut Self {
+        self.tooltip = Some(text.to_string());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_tooltip(&mut self) -> &mut Self {
+        self.tooltip = None;
+        self
+    }
+
+    // Method to play a sound whenever the button is clicked
+    #[allow(unused)]
+    pub fn on_click_sound(&mut self, sound: Sound) -> &mut Self {
+        self.click_sound = Some(sound);
+        self
+    }
+
+    // Method to go back to the shared click sound from the audio module
+    #[allow(unused)]
+    pub fn clear_click_sound(&mut self) -> &mut Self {
+        self.click_sound = None;
+        self
+    }
+
+    // Method to animate the button when pressed, over `duration` seconds
+    #[allow(unused)]
+    pub fn with_press_animation(&mut self, effect: PressEffect, duration: f32, easing: Easing) -> &mut Self {
+        self.press_effect = Some(effect);
+        self.press_tween.set(Tween::new(1.0, 0.0, duration, easing));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_press_animation(&mut self) -> &mut Self {
+        self.press_effect = None;
+        self
+    }
+
+    // Method to draw the background from an image with the same size corners on every side
+    #[allow(unused)]
+    pub fn with_nine_slice(&mut self, texture: Texture2D, margin: f32) -> &mut Self {
+        self.with_nine_slice_margins(texture, margin, margin, margin, margin)
+    }
+
+    // Method to draw the background from an image, margins are in pixels of the image
+    #[allow(unused)]
+    pub fn with_nine_slice_margins(&mut self, texture: Texture2D, left: f32, top: f32, right: f32, bottom: f32) -> &mut Self {
+        self.nine_slice = Some((texture, [left, top, right, bottom]));
+        self
+    }
+
+    // Method to go back to a flat colored background
+    #[allow(unused)]
+    pub fn clear_nine_slice(&mut self) -> &mut Self {
+        self.nine_slice = None;
+        self
+    }
+
+    // Method to run an async action every time the button is clicked (needs tasks::run_pending() in the loop)
+    #[allow(unused)]
+    pub fn bind_async_action<F, Fut>(&mut self, mut action: F) -> &mut Self
+    where
+        F: FnMut() -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        *self.async_action.get_mut() = Some(Box::new(move || Box::pin(action()) as ActionFuture));
+        self
+    }
+
+    // Method to stop running an action on click
+    #[allow(unused)]
+    pub fn clear_async_action(&mut self) -> &mut Self {
+        *self.async_action.get_mut() = None;
+        self
+    }
+
+    // Run a one-off async task with the button showing it is busy (ignored if one is already running)
+    #[allow(unused)]
+    pub fn run_async(&mut self, future: impl Future<Output = Result<(), String>> + 'static) -> &mut Self {
+        if self.running_action.get_mut().is_none() {
+            *self.running_action.get_mut() = Some(tasks::spawn(future));
+        }
+        self
+    }
+
+    // Method to set the text shown while an action runs
+    #[allow(unused)]
+    pub fn with_busy_text(&mut self, text: &str) -> &mut Self {
+        self.busy_text = text.to_string();
+        self
+    }
+
+    // Whether an async action is running
+    #[allow(unused)]
+    pub fn is_busy(&self) -> bool {
+        self.running_action.borrow().is_some()
+    }
+
+    // How the last async action went (Some once, on the frame after it finishes)
+    #[allow(unused)]
+    pub fn action_result(&self) -> Option<Result<(), String>> {
+        self.action_result.borrow_mut().take()
+    }
+
+    // Check on the running action, returns whether it is still running
+    fn update_async_action(&self) -> bool {
+        let mut running = self.running_action.borrow_mut();
+        if let Some(result) = running.as_ref().and_then(TaskHandle::take) {
+            audio::play_event(if result.is_ok() { SoundEvent::Success } else { SoundEvent::Error });
+            *self.action_result.borrow_mut() = Some(result);
+            *running = None;
+        }
+        running.is_some()
+    }
+
+    // Start the bound action if there is one
+    fn start_async_action(&self) {
+        if let Some(action) = self.async_action.borrow_mut().as_mut() {
+            *self.running_action.borrow_mut() = Some(tasks::spawn(action()));
+        }
+    }
+
     // Method to set rounded corners
     #[allow(unused)]
     pub fn with_round(&mut self, radius: f32) -> &mut Self {
@@ -165,9 +657,80 @@ impl TextButton {
         self
     }
     
+    // Method to set the background color while hovered
+    #[allow(unused)]
+    pub fn with_hover_color(&mut self, color: Color) -> &mut Self {
+        self.stop_theming();
+        self.hover_color = color;
+        self
+    }
+
+    // Method to set the background color while the button is held down
+    #[allow(unused)]
+    pub fn with_pressed_color(&mut self, color: Color) -> &mut Self {
+        self.pressed_color = Some(color);
+        self
+    }
+
+    // Method to lighten the normal color on hover and darken it when held (0.0 - 1.0)
+    #[allow(unused)]
+    pub fn with_brightness_shift(&mut self, amount: f32) -> &mut Self {
+        self.brightness_shift = Some(amount.clamp(0.0, 1.0));
+        self
+    }
+
+    // Method to go back to using the hover/pressed colors instead of a brightness shift
+    #[allow(unused)]
+    pub fn clear_brightness_shift(&mut self) -> &mut Self {
+        self.brightness_shift = None;
+        self
+    }
+
+    // Enable/disable the button (disabled buttons are greyed out, can't be clicked or tabbed to)
+    #[allow(unused)]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    #[allow(unused)]
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        focus::set_focusable(self.focus_id, self.visible && enabled);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_disabled_color(&self) -> Color {
+        self.off_color.unwrap_or_else(|| lerp_color(self.current_colors().0, GRAY, 0.5))
+    }
+
+    // Method to set the background color used while disabled
+    #[allow(unused)]
+    pub fn set_disabled_color(&mut self, color: Color) -> &mut Self {
+        self.off_color = Some(color);
+        self
+    }
+
+    // Method to set just the border color (turns the border on)
+    #[allow(unused)]
+    pub fn with_border_color(&mut self, color: Color) -> &mut Self {
+        self.border = true;
+        self.border_color = color;
+        self
+    }
+
+    // Method to set just the border width (0 turns the border off)
+    #[allow(unused)]
+    pub fn with_border_width(&mut self, thickness: f32) -> &mut Self {
+        self.border = thickness > 0.0;
+        self.border_thickness = thickness;
+        self
+    }
+
     // Method to set hover text color
     #[allow(unused)]
     pub fn with_hover_text_color(&mut self, color: Color) -> &mut Self {
+        self.stop_theming();
         self.hover_text_color = color;
         self
     }
@@ -175,6 +738,7 @@ impl TextButton {
     // Method to set text color
     #[allow(unused)]
     pub fn with_text_color(&mut self, color: Color) -> &mut Self {
+        self.stop_theming();
         self.text_color = color;
         if self.hover_text_color == WHITE { // Only update if it wasn't explicitly set
             self.hover_text_color = color;
@@ -182,6 +746,56 @@ impl TextButton {
         self
     }
     
+    // Method to set the color of the outline shown while the button has keyboard focus
+    #[allow(unused)]
+    pub fn with_focus_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.focus_color = color.into();
+        self
+    }
+
+    // Id used by the focus module (for focus::set_tab_order and friends)
+    #[allow(unused)]
+    pub fn focus_id(&self) -> usize {
+        self.focus_id
+    }
+
+    // Method to pin the button to an edge or corner of the screen, offsets move it inward
+    #[allow(unused)]
+    pub fn with_anchor(&mut self, anchor: Anchor, offset_x: f32, offset_y: f32) -> &mut Self {
+        self.anchor = Some((anchor, Vec2::new(offset_x, offset_y)));
+        self
+    }
+
+    // Method to go back to the position set with new/update_position
+    #[allow(unused)]
+    pub fn clear_anchor(&mut self) -> &mut Self {
+        self.anchor = None;
+        self
+    }
+
+    // Where the button is actually drawn this frame (differs from get_position when anchored)
+    #[allow(unused)]
+    pub fn get_screen_position(&self) -> Vec2 {
+        self.get_position() + self.anchor_shift()
+    }
+
+    // The area the button covers on screen this frame (for drawing things on top of it)
+    #[allow(unused)]
+    pub fn get_rect(&self) -> Rect {
+        let position = self.get_screen_position();
+        Rect::new(position.x, position.y, self.width, self.height)
+    }
+
+    // How far the anchored spot is from the button's own x/y
+    fn anchor_shift(&self) -> Vec2 {
+        match self.anchor {
+            Some((anchor, offset)) => {
+                anchored_position(anchor, self.width, self.height, offset.x, offset.y) - self.get_position()
+            }
+            None => Vec2::ZERO,
+        }
+    }
+
     // Getter for x position
     #[allow(unused)]
     pub fn get_x(&self) -> f32 {
@@ -211,17 +825,8 @@ impl TextButton {
     pub fn set_text<T: Into<String>>(&mut self, text: T) -> &mut Self {
         self.text = text.into();
         
-        // Update cached text measurements
-        self.cached_text_width = match &self.font {
-            Some(font) => measure_text(&self.text, Some(font), self.font_size, 1.0).width,
-            None => measure_text(&self.text, None, self.font_size, 1.0).width,
-        };
-        
-        // Update text position
-        self.cached_text_position = Vec2::new(
-            self.x + (self.width / 2.0) - (self.cached_text_width / 2.0),
-            self.y + (self.height / 2.0),
-        );
+        // Update cached text measurements and position
+        self.measure_label();
         
         self
     }
@@ -244,216 +849,491 @@ impl TextButton {
         self.cached_rect = Rect::new(self.x, self.y, self.width, self.height);
         
         // Update text position
-        self.cached_text_position = Vec2::new(
-            self.x + (self.width / 2.0) - (self.cached_text_width / 2.0),
-            self.y + (self.height / 2.0),
-        );
+        self.update_layout();
         
         self
     }
 
+    // Background, hover, text and hover text colors (from the theme for themed buttons)
+    fn current_colors(&self) -> (Color, Color, Color, Color) {
+        if self.themed {
+            let palette = theme::palette();
+            (palette.primary, shade_color(palette.primary, 0.15), palette.on_primary, palette.on_primary)
+        } else {
+            (self.normal_color, self.hover_color, self.text_color, self.hover_text_color)
+        }
+    }
+
+    // Keep the theme's current colors but stop following it
+    fn stop_theming(&mut self) {
+        if self.themed {
+            (self.normal_color, self.hover_color, self.text_color, self.hover_text_color) = self.current_colors();
+            self.themed = false;
+        }
+    }
+
+    // Measure the text with the current font and size, then re-center it
+    fn measure_label(&mut self) {
+        let size = measure_text(&self.text, self.font.as_ref(), self.font_size, 1.0);
+        self.cached_text_width = size.width;
+        self.cached_text_height = size.height;
+        self.cached_text_offset_y = size.offset_y;
+        self.update_layout();
+    }
+
+    // Work out where the text and icon go inside the button
+    fn update_layout(&mut self) {
+        // Shrink the texture to fit the space it has, keeping its shape
+        let icon_size = self.icon.as_ref().map(|icon| {
+            self.icon_size.unwrap_or_else(|| {
+                let texture_size = icon.size();
+                let max_height = match self.icon_layout {
+                    IconLayout::Top => self.height - self.font_size as f32 - ICON_GAP * 2.0,
+                    _ => self.height - ICON_GAP,
+                }
+                .max(1.0);
+                texture_size * (max_height / texture_size.y).min(1.0)
+            })
+        });
+
+        // Auto-sized buttons grow or shrink to fit their content
+        if let Some(padding) = self.auto_padding {
+            let content_width = match (icon_size, self.icon_layout) {
+                (None, _) => self.cached_text_width,
+                (Some(icon), IconLayout::Left) => icon.x + ICON_GAP + self.cached_text_width,
+                (Some(icon), IconLayout::Only) => icon.x,
+                (Some(icon), IconLayout::Top) => icon.x.max(self.cached_text_width),
+            };
+            let mut width = content_width + padding * 2.0;
+            if let Some(max) = self.max_width {
+                width = width.min(max);
+            }
+            if let Some(min) = self.min_width {
+                width = width.max(min);
+            }
+            self.width = width;
+            self.cached_rect = Rect::new(self.x, self.y, self.width, self.height);
+        }
+
+        let center_x = self.x + self.width / 2.0;
+        let center_y = self.y + self.height / 2.0;
+        // Text is drawn from its baseline, so this puts the middle of the text on center_y
+        let baseline_y = center_y - self.cached_text_height / 2.0 + self.cached_text_offset_y;
+        let Some(icon_size) = icon_size else {
+            self.cached_text_position = Vec2::new(center_x - self.cached_text_width / 2.0, baseline_y);
+            self.cached_icon_rect = Rect::new(0.0, 0.0, 0.0, 0.0);
+            return;
+        };
+
+        match self.icon_layout {
+            IconLayout::Left => {
+                let content_width = icon_size.x + ICON_GAP + self.cached_text_width;
+                let icon_x = center_x - content_width / 2.0;
+                self.cached_icon_rect = Rect::new(icon_x, center_y - icon_size.y / 2.0, icon_size.x, icon_size.y);
+                self.cached_text_position = Vec2::new(icon_x + icon_size.x + ICON_GAP, baseline_y);
+            }
+            IconLayout::Only => {
+                self.cached_icon_rect = Rect::new(center_x - icon_size.x / 2.0, center_y - icon_size.y / 2.0, icon_size.x, icon_size.y);
+                self.cached_text_position = Vec2::new(center_x, center_y);
+            }
+            IconLayout::Top => {
+                let icon_y = center_y - (icon_size.y + ICON_GAP + self.cached_text_height) / 2.0;
+                self.cached_icon_rect = Rect::new(center_x - icon_size.x / 2.0, icon_y, icon_size.x, icon_size.y);
+                self.cached_text_position = Vec2::new(
+                    center_x - self.cached_text_width / 2.0,
+                    icon_y + icon_size.y + ICON_GAP + self.cached_text_offset_y,
+                );
+            }
+        }
+    }
+
+    // True on the frame the second click of a double click happens (call after click)
+    #[allow(unused)]
+    pub fn double_clicked(&self) -> bool {
+        self.double_clicked.get()
+    }
+
+    // What the last click() returned, for buttons something else clicks (like a UiManager)
+    #[allow(unused)]
+    pub fn was_clicked(&self) -> bool {
+        self.was_clicked.get()
+    }
+
+    // Set the most seconds allowed between the two clicks of a double click
+    #[allow(unused)]
+    pub fn set_double_click_interval(&mut self, seconds: f64) -> &mut Self {
+        self.double_click_interval = seconds.max(0.0);
+        self
+    }
+
+    // True on the frame a press on this button passes the given number of seconds (call after click)
+    #[allow(unused)]
+    pub fn held_for(&self, seconds: f32) -> bool {
+        self.holding.get() && self.prev_held_time.get() < seconds && self.held_time.get() >= seconds
+    }
+
+    // Same as click, but keeps returning true while held: first after `delay` seconds, then `rate` times a second
+    #[allow(unused)]
+    pub fn click_repeating(&self, delay: f32, rate: f32) -> bool {
+        let clicked = self.click();
+        if clicked || !self.holding.get() || rate <= 0.0 {
+            return clicked;
+        }
+        // Number of repeats that should have happened after a given hold time
+        let repeats = |held: f32| {
+            if held < delay {
+                0
+            } else {
+                ((held - delay) * rate).floor() as u32 + 1
+            }
+        };
+        repeats(self.held_time.get()) > repeats(self.prev_held_time.get())
+    }
+
+    // Both updates and draws the button, returns whether it was clicked this frame
     pub fn click(&self) -> bool {
+        let clicked = self.update_internal();
+        self.draw_internal();
+        clicked
+    }
+
+    // For cases when only updating is needed without drawing (returns what click() would)
+    #[allow(unused)]
+    pub fn update_only(&self) -> bool {
+        self.update_internal()
+    }
+
+    // For cases when only drawing is needed without updating
+    #[allow(unused)]
+    pub fn draw_only(&self) {
+        self.draw_internal();
+    }
+
+    // Handle the mouse and keyboard for this frame, returns whether the button was clicked
+    fn update_internal(&self) -> bool {
+        // Collect the result of a finished async action; a running one disables the button
+        let busy = self.update_async_action();
+        let enabled = self.enabled && !busy;
+
+        // Hidden or disabled buttons are skipped by Tab
+        focus::set_focusable(self.focus_id, self.visible && enabled);
         if !self.visible {
+            self.state.set(ButtonState::default());
             return false; // If not visible, don't process clicks
         }
+        focus::handle_tab();
+        focus::set_area(self.focus_id, self.get_rect());
+        let focused = focus::is_focused(self.focus_id);
         // Get mouse position
         let (mouse_x, mouse_y) = mouse_position();
-        let mouse_pos = Vec2::new(mouse_x, mouse_y);
+        // Anchored buttons are drawn and hit-tested shifted to their pinned spot
+        let shift = self.anchor_shift();
+        let mouse_pos = Vec2::new(mouse_x, mouse_y) - shift;
 
         // Check if the background is transparent (alpha is 0)
-        let is_background_transparent = self.normal_color.a == 0.0;
+        let (normal_color, ..) = self.current_colors();
+        let is_background_transparent = normal_color.a == 0.0 && self.nine_slice.is_none();
         
         // Determine is_hovered based on background transparency
         let is_hovered = if is_background_transparent {
             // If transparent, only detect clicks on the text area
-            let text_height = self.font_size as f32; // Approximate text height
             let text_rect = Rect::new(
                 self.cached_text_position.x,
-                self.cached_text_position.y - text_height,
+                self.cached_text_position.y - self.cached_text_offset_y,
                 self.cached_text_width,
-                text_height
+                self.cached_text_height
             );
-            text_rect.contains(mouse_pos)
+            let on_text = self.icon_layout != IconLayout::Only && text_rect.contains(mouse_pos);
+            on_text || (self.icon.is_some() && self.cached_icon_rect.contains(mouse_pos))
         } else {
             // Otherwise use the full button area
             self.cached_rect.contains(mouse_pos)
+        } && focus::is_top_at(self.focus_id, mouse_x, mouse_y);
+
+        if let Some(text) = self.tooltip.as_ref().filter(|_| is_hovered) {
+            tooltip::hover(self.focus_id, text);
+        }
+
+        // Held down while the mouse button is down over it (or Space is held while focused)
+        let is_pressed = enabled
+            && ((is_hovered && is_mouse_button_down(MouseButton::Left)) || (focused && is_key_down(KeyCode::Space)));
+
+        // Check if the button was clicked (or activated from the keyboard)
+        let mouse_clicked = is_hovered && enabled && is_mouse_button_pressed(MouseButton::Left);
+        let key_clicked = focused && enabled
+            && (is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::KpEnter) || is_key_pressed(KeyCode::Space));
+        let clicked = mouse_clicked || key_clicked;
+
+        // Start the press animation, then find how strong it is this frame (0.0 when not animating)
+        if clicked && self.press_effect.is_some() {
+            let mut tween = self.press_tween.get();
+            tween.start();
+            self.press_tween.set(tween);
+        }
+        self.state.set(ButtonState { busy, enabled, hovered: is_hovered, pressed: is_pressed, focused });
+
+        if mouse_clicked {
+            focus::set_focus(self.focus_id);
+        }
+
+        // Keep track of how long the press has been held (for held_for and click_repeating)
+        let still_held = enabled
+            && ((is_hovered && is_mouse_button_down(MouseButton::Left))
+                || (focused && (is_key_down(KeyCode::Enter) || is_key_down(KeyCode::KpEnter) || is_key_down(KeyCode::Space))));
+        if clicked {
+            self.holding.set(true);
+            self.held_time.set(0.0);
+            self.prev_held_time.set(0.0);
+        } else if self.holding.get() && still_held {
+            self.prev_held_time.set(self.held_time.get());
+            self.held_time.set(self.held_time.get() + get_frame_time());
+        } else {
+            self.holding.set(false);
+            self.held_time.set(0.0);
+            self.prev_held_time.set(0.0);
+        }
+        if clicked {
+            audio::play_click(self.click_sound.as_ref());
+            self.start_async_action();
+        }
+
+        // A click soon after the last one is a double click (a third click starts over)
+        let now = get_time();
+        let double = clicked
+            && self.last_click_time.get().is_some_and(|last| now - last <= self.double_click_interval);
+        self.double_clicked.set(double);
+        if clicked {
+            self.last_click_time.set(if double { None } else { Some(now) });
+        }
+        if clicked && self.toggle_mode {
+            self.toggled.set(!self.toggled.get());
+        }
+        self.was_clicked.set(clicked);
+        clicked
+    }
+
+    // Draw the button the way the last update left it
+    fn draw_internal(&self) {
+        if !self.visible {
+            return;
+        }
+        let ButtonState { busy, enabled, hovered: is_hovered, pressed: is_pressed, focused } = self.state.get();
+        let shift = self.anchor_shift();
+        let (normal_color, hover_color, text_color, hover_text_color) = self.current_colors();
+        let press_strength = if self.press_effect.is_some() { self.press_tween.get().value() } else { 0.0 };
+        let scale = match self.press_effect {
+            Some(PressEffect::Shrink(amount)) => animation::lerp(1.0, amount, press_strength),
+            _ => 1.0,
         };
+        // Scale things around the middle of the button
+        let base_center = self.cached_rect.center();
+        let center = base_center + shift;
+        let scaled = |point: Vec2| center + (point - base_center) * scale;
+        let top_left = scaled(Vec2::new(self.x, self.y));
+        let (width, height) = (self.width * scale, self.height * scale);
 
-        // Draw the text button (change color on hover)
-        let button_color = if self.enabled {
-            if is_hovered {
-                self.hover_color
+        // Draw the text button (change color on hover and while held)
+        let button_color = if !enabled {
+            self.get_disabled_color()
+        } else if let Some(shift) = self.brightness_shift {
+            if is_pressed || self.toggled.get() {
+                shade_color(normal_color, -shift)
+            } else if is_hovered {
+                shade_color(normal_color, shift)
             } else {
-                self.normal_color
+                normal_color
             }
+        } else if is_pressed {
+            self.pressed_color.unwrap_or_else(|| shade_color(hover_color, -0.2))
+        } else if self.toggled.get() {
+            self.toggled_color
+                .or(self.pressed_color)
+                .unwrap_or_else(|| shade_color(hover_color, -0.2))
+        } else if is_hovered {
+            hover_color
         } else {
-            self.off_color
+            normal_color
+        };
+        let button_color = match self.press_effect {
+            Some(PressEffect::Flash(flash)) => animation::lerp_color(button_color, flash, press_strength),
+            _ => button_color,
         };
 
-        // Draw the button with or without rounded corners
-        if self.corner_radius > 0.0 {
-            draw_round_rect(self.x, self.y, self.width, self.height, self.corner_radius, button_color);
+        // Draw the button from its image, or with or without rounded corners
+        if let Some((texture, margins)) = &self.nine_slice {
+            // The image shows the button's state by shading instead of the colors
+            let tint = if !enabled {
+                Color::new(0.6, 0.6, 0.6, 0.6)
+            } else if is_pressed || self.toggled.get() {
+                shade_color(WHITE, -0.25)
+            } else if is_hovered {
+                shade_color(WHITE, -0.1)
+            } else {
+                WHITE
+            };
+            let tint = match self.press_effect {
+                Some(PressEffect::Flash(flash)) => animation::lerp_color(tint, flash, press_strength),
+                _ => tint,
+            };
+            draw_nine_slice(texture, Rect::new(top_left.x, top_left.y, width, height), *margins, tint);
+
+            if self.border {
+                draw_rectangle_lines(top_left.x, top_left.y, width, height, 
+                                    self.border_thickness, self.border_color);
+            }
+        } else if self.corner_radius > 0.0 {
+            draw_round_rect(top_left.x, top_left.y, width, height, self.corner_radius * scale, button_color);
             
             // Draw rounded border if enabled
             if self.border {
-                draw_round_rect_lines(self.x, self.y, self.width, self.height, 
-                                     self.corner_radius, self.border_thickness, self.border_color);
+                draw_round_rect_lines(top_left.x, top_left.y, width, height, 
+                                     self.corner_radius * scale, self.border_thickness, self.border_color);
             }
         } else {
-            draw_rectangle(self.x, self.y, self.width, self.height, button_color);
+            draw_rectangle(top_left.x, top_left.y, width, height, button_color);
             
             // Draw regular border if enabled
             if self.border {
-                draw_rectangle_lines(self.x, self.y, self.width, self.height, 
+                draw_rectangle_lines(top_left.x, top_left.y, width, height, 
                                     self.border_thickness, self.border_color);
             }
         }
 
         // Draw the text with the appropriate font using cached position
-        let current_text_color = if self.enabled {
-            if is_hovered {
-                self.hover_text_color
+        let current_text_color = if enabled {
+            if is_hovered || is_pressed {
+                hover_text_color
             } else {
-                self.text_color
+                text_color
             }
         } else {
             // Use a dimmed text color for disabled state
-            Color::new(self.text_color.r, self.text_color.g, self.text_color.b, 0.5)
+            Color::new(text_color.r, text_color.g, text_color.b, 0.5)
         };
         
-        match &self.font {
-            Some(font) => {
-                draw_text_ex(
-                    &self.text,
-                    self.cached_text_position.x,
-                    self.cached_text_position.y,
-                    TextParams {
-                        font: Some(font),
-                        font_size: self.font_size,
-                        color: current_text_color,
-                        ..Default::default()
-                    },
-                );
-            },
-            None => {
-                // Use the default draw_text function
-                draw_text(
-                    &self.text,
-                    self.cached_text_position.x,
-                    self.cached_text_position.y,
-                    self.font_size.into(),
-                    current_text_color,
-                );
-            }
+        if let Some(icon) = &self.icon {
+            // Fade the icon like the text while disabled
+            let tint = if enabled { WHITE } else { Color::new(1.0, 1.0, 1.0, 0.5) };
+            let icon_position = scaled(self.cached_icon_rect.point());
+            draw_texture_ex(
+                icon,
+                icon_position.x,
+                icon_position.y,
+                tint,
+                DrawTextureParams {
+                    dest_size: Some(self.cached_icon_rect.size() * scale),
+                    ..Default::default()
+                },
+            );
         }
 
-        // After drawing, check if the button was clicked
-        is_hovered && self.enabled && is_mouse_button_pressed(MouseButton::Left)
+        let show_text = self.icon.is_none() || self.icon_layout != IconLayout::Only;
+        if busy {
+            // Show the busy text with a spinner in front of it instead of the label
+            let busy_size = measure_text(&self.busy_text, self.font.as_ref(), self.font_size, scale);
+            let spinner_radius = self.font_size as f32 * 0.3 * scale;
+            let content_width = spinner_radius * 2.0 + ICON_GAP + busy_size.width;
+            let left = center.x - content_width / 2.0;
+            draw_spinner(left + spinner_radius, center.y, spinner_radius, current_text_color);
+            draw_text_ex(
+                &self.busy_text,
+                left + spinner_radius * 2.0 + ICON_GAP,
+                center.y - busy_size.height / 2.0 + busy_size.offset_y,
+                TextParams {
+                    font: self.font.as_ref(),
+                    font_size: self.font_size,
+                    font_scale: scale,
+                    color: current_text_color,
+                    ..Default::default()
+                },
+            );
+        } else if show_text {
+            // Without a font this is the same as draw_text with the default font
+            let text_position = scaled(self.cached_text_position);
+            draw_text_ex(
+                &self.text,
+                text_position.x,
+                text_position.y,
+                TextParams {
+                    font: self.font.as_ref(),
+                    font_size: self.font_size,
+                    font_scale: scale,
+                    color: current_text_color,
+                    ..Default::default()
+                },
+            );
+        }
+
+        // Draw an outline around the button while it has keyboard focus (keyboard mode draws its own ring)
+        if focused && !focus::keyboard_mode() {
+            draw_rectangle_lines(self.x + shift.x - 3.0, self.y + shift.y - 3.0, self.width + 6.0, self.height + 6.0, 2.0, self.focus_color.color());
+        }
     }
 }
 
-// Function to draw a rectangle with rounded corners - optimized version
-fn draw_round_rect(x: f32, y: f32, w: f32, h: f32, radius: f32, color: Color) {
-    // Precompute corner positions
-    let top_left = Vec2::new(x + radius, y + radius);
-    let top_right = Vec2::new(x + w - radius, y + radius);
-    let bottom_left = Vec2::new(x + radius, y + h - radius);
-    let bottom_right = Vec2::new(x + w - radius, y + h - radius);
-    
-    // Draw center rectangle
-    draw_rectangle(x + radius, y, w - 2.0 * radius, h, color);
-    
-    // Draw the side rectangles
-    draw_rectangle(x, y + radius, radius, h - 2.0 * radius, color);
-    draw_rectangle(x + w - radius, y + radius, radius, h - 2.0 * radius, color);
-    
-    // Draw the four corner circles (could be batched in a real engine)
-    draw_circle(top_left.x, top_left.y, radius, color);     // Top-left
-    draw_circle(top_right.x, top_right.y, radius, color);   // Top-right
-    draw_circle(bottom_left.x, bottom_left.y, radius, color);  // Bottom-left
-    draw_circle(bottom_right.x, bottom_right.y, radius, color); // Bottom-right
+impl Drop for TextButton {
+    fn drop(&mut self) {
+        focus::unregister(self.focus_id);
+    }
 }
 
-// Function to draw rounded rectangle borders - optimized version
-fn draw_round_rect_lines(x: f32, y: f32, w: f32, h: f32, radius: f32, thickness: f32, color: Color) {
-    // Precompute corner positions
-    let top_left = Vec2::new(x + radius, y + radius);
-    let top_right = Vec2::new(x + w - radius, y + radius);
-    let bottom_left = Vec2::new(x + radius, y + h - radius);
-    let bottom_right = Vec2::new(x + w - radius, y + h - radius);
-    
-    // Draw the horizontal and vertical lines
-    draw_line(top_left.x, y, top_right.x, y, thickness, color);          // Top
-    draw_line(bottom_left.x, y + h, bottom_right.x, y + h, thickness, color); // Bottom
-    draw_line(x, top_left.y, x, bottom_left.y, thickness, color);           // Left
-    draw_line(x + w, top_right.y, x + w, bottom_right.y, thickness, color);  // Right
-    
-    // Draw the four corner arcs with fewer segments for better performance
-    let segments = 8; // Reduced from 16 - still looks good but fewer draw calls
-    let step = std::f32::consts::PI / 2.0 / segments as f32;
-    
-    // Pre-calculate sin/cos values for angle offsets to avoid redundant calculations
-    let angles: Vec<(f32, f32)> = (0..=segments)
-        .map(|i| {
-            let angle = i as f32 * step;
-            (angle.cos(), angle.sin())
-        })
-        .collect();
-    
-    // Draw arcs for each corner
-    // Top-left corner: PI to PI*3/2
-    for i in 0..segments {
-        let (cos1, sin1) = angles[i];
-        let (cos2, sin2) = angles[i+1];
-        draw_line(
-            top_left.x - radius * cos1,
-            top_left.y - radius * sin1,
-            top_left.x - radius * cos2,
-            top_left.y - radius * sin2,
-            thickness,
-            color
-        );
-    }
-    
-    // Top-right corner: PI*3/2 to PI*2
-    for i in 0..segments {
-        let (cos1, sin1) = angles[i];
-        let (cos2, sin2) = angles[i+1];
-        draw_line(
-            top_right.x + radius * sin1,
-            top_right.y - radius * cos1,
-            top_right.x + radius * sin2,
-            top_right.y - radius * cos2,
-            thickness,
-            color
-        );
-    }
-    
-    // Bottom-left corner: PI/2 to PI
-    for i in 0..segments {
-        let (cos1, sin1) = angles[i];
-        let (cos2, sin2) = angles[i+1];
-        draw_line(
-            bottom_left.x - radius * sin1,
-            bottom_left.y + radius * cos1,
-            bottom_left.x - radius * sin2,
-            bottom_left.y + radius * cos2,
-            thickness, 
-            color
-        );
+// Draw a texture into a rectangle with fixed size corners and stretched edges and middle
+fn draw_nine_slice(texture: &Texture2D, dest: Rect, margins: [f32; 4], tint: Color) {
+    let [left, top, right, bottom] = margins;
+    let texture_size = texture.size();
+
+    // Shrink the corners if the button is smaller than them
+    let fit_x = (dest.w / (left + right)).min(1.0);
+    let fit_y = (dest.h / (top + bottom)).min(1.0);
+    let (dest_left, dest_right) = (left * fit_x, right * fit_x);
+    let (dest_top, dest_bottom) = (top * fit_y, bottom * fit_y);
+
+    // Column and row edges in the texture and on screen
+    let source_xs = [0.0, left, texture_size.x - right, texture_size.x];
+    let source_ys = [0.0, top, texture_size.y - bottom, texture_size.y];
+    let dest_xs = [dest.x, dest.x + dest_left, dest.x + dest.w - dest_right, dest.x + dest.w];
+    let dest_ys = [dest.y, dest.y + dest_top, dest.y + dest.h - dest_bottom, dest.y + dest.h];
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let dest_size = Vec2::new(dest_xs[column + 1] - dest_xs[column], dest_ys[row + 1] - dest_ys[row]);
+            if dest_size.x <= 0.0 || dest_size.y <= 0.0 {
+                continue;
+            }
+            draw_texture_ex(
+                texture,
+                dest_xs[column],
+                dest_ys[row],
+                tint,
+                DrawTextureParams {
+                    dest_size: Some(dest_size),
+                    source: Some(Rect::new(
+                        source_xs[column],
+                        source_ys[row],
+                        source_xs[column + 1] - source_xs[column],
+                        source_ys[row + 1] - source_ys[row],
+                    )),
+                    ..Default::default()
+                },
+            );
+        }
     }
-    
-    // Bottom-right corner: 0 to PI/2
-    for i in 0..segments {
-        let (cos1, sin1) = angles[i];
-        let (cos2, sin2) = angles[i+1];
-        draw_line(
-            bottom_right.x + radius * cos1,
-            bottom_right.y + radius * sin1,
-            bottom_right.x + radius * cos2,
-            bottom_right.y + radius * sin2,
-            thickness,
-            color
+}
+
+// Draw a ring of dots that goes around over time
+fn draw_spinner(x: f32, y: f32, radius: f32, color: Color) {
+    let dots = 8;
+    let turn = (get_time() * 1.5).fract() as f32; // How far around the bright dot is
+    for i in 0..dots {
+        let angle = i as f32 / dots as f32 * std::f32::consts::TAU;
+        // Dots fade out behind the bright one
+        let behind = (turn - i as f32 / dots as f32).rem_euclid(1.0);
+        let alpha = color.a * (1.0 - behind * 0.8);
+        draw_circle(
+            x + angle.cos() * radius,
+            y + angle.sin() * radius,
+            radius * 0.22,
+            Color::new(color.r, color.g, color.b, alpha),
         );
     }
 }
@@ -461,3 +1341,15 @@ fn draw_round_rect_lines(x: f32, y: f32, w: f32, h: f32, radius: f32, thickness:
 fn lerp_color(c1: Color, c2: Color, factor: f32) -> Color {
     Color::new(c1.r * (1.0 - factor) + c2.r * factor, c1.g * (1.0 - factor) + c2.g * factor, c1.b * (1.0 - factor) + c2.b * factor, 1.0)
 }
+
+// Lighten (positive amount) or darken (negative amount) a color, keeping its alpha
+fn shade_color(color: Color, amount: f32) -> Color {
+    let target = if amount >= 0.0 { 1.0 } else { 0.0 };
+    let factor = amount.abs();
+    Color::new(
+        color.r + (target - color.r) * factor,
+        color.g + (target - color.g) * factor,
+        color.b + (target - color.b) * factor,
+        color.a,
+    )
+}
@@ -6,6 +6,10 @@ Adds a text input object
 In your mod.rs file located in the modules folder add the following to the end of the file
         pub mod text_input;
 
+Add the following to Cargo.toml under [dependencies]:
+    unicode-segmentation = "1.13"
+(the cursor, Backspace/Delete and clicks move over whole characters, so emoji and
+accented letters are never split)
 
 Add with the other use statements
     use crate::modules::text_input::TextInput;
@@ -17,7 +21,8 @@ Where the parameters are x, y, width, height, font size
 You can customize the text box using various methods:
 
 APPEARANCE CUSTOMIZATION:
-    // Set colors (text, border, background, cursor)
+    // Set colors (text, border, background, cursor), by default they come from the theme
+    // (see the theme module, a ThemeColor like ThemeColor::Primary works here too)
     txt_input.with_colors(WHITE, BLUE, DARKGRAY, RED);
     
     // Set individual colors
@@ -25,9 +30,18 @@ APPEARANCE CUSTOMIZATION:
           .set_border_color(BLUE)
           .set_background_color(DARKGRAY)
           .set_cursor_color(RED);
+
+    // Cursor look: blink speed (0.0 = always on), line width and shape
+    use crate::modules::text_input::CursorStyle;
+    txt_input.set_cursor_blink_interval(0.6);
+    txt_input.set_cursor_width(2.0);
+    txt_input.set_cursor_style(CursorStyle::Block); // Line (default), Block or Underline
     
-    // Set custom font
+    // Set custom font (load it with assets::load_font("assets/my_font.ttf").await)
     txt_input.with_font(my_font.clone());
+    txt_input.set_font(my_font.clone()); // Same thing, for changing it later
+    txt_input.clear_font();              // Back to the theme's font
+    // Typed characters the font doesn't have use the backup fonts from font_fallback
     
     // Change position and dimensions
     txt_input.set_position(150.0, 150.0);
@@ -37,6 +51,15 @@ APPEARANCE CUSTOMIZATION:
     txt_input.set_prompt("Enter your name...");
     txt_input.set_prompt_color(DARKGRAY);
 
+    // Space between the border and the text, border thickness and rounded corners
+    txt_input.set_padding(8.0);          // Default 5.0
+    txt_input.set_border_thickness(1.0); // Default 2.0, 0.0 for no border
+    txt_input.set_corner_radius(10.0);   // Default from the theme (0.0 for square corners)
+
+    // Line the text up with the left (default), middle or right of the box
+    // (long text still scrolls, alignment only matters while it fits)
+    txt_input.with_alignment(modules::label::TextAlign::Right);
+
     // Enable or disable the text input
     txt_input.set_enabled(false); // Disable the text input (becomes read-only)
     txt_input.set_enabled(true);  // Enable the text input
@@ -53,24 +76,192 @@ TEXT MANIPULATION:
     if txt_input.is_active() {
         // Do something when textbox is active
     }
+
+    // Only one widget can be active at a time - Tab / Shift+Tab move between them.
+    // This needs the focus module (pub mod focus; in mod.rs), see it for changing the tab order.
+    txt_input.set_active(true);
     
     // Set cursor position
     txt_input.set_cursor_index(5);
 
-    // Customize key repeat behavior (for arrow keys, backspace, delete)
+    // Keyboard navigation while typing: Home/End jump to the start/end of the text,
+    // ctrl/alt+Left/Right jump a word at a time (hold shift to select as you go)
+
+    // Selection (shift+arrows, mouse drag, double click for a word, triple click or
+    // ctrl/cmd+A for everything)
+    txt_input.select_all();
+    if txt_input.has_selection() {
+        let selected = txt_input.get_selected_text();
+    }
+    txt_input.clear_selection();
+    txt_input.set_selection(0, 5);              // Select a byte range
+    let range = txt_input.get_selection();      // Some((start, end)) when something is selected
+    txt_input.replace_selection("Goodbye");     // Swap the selection (or insert at the cursor)
+    // Typing, Backspace, Delete and paste all replace the selection as one edit
+    txt_input.set_selection_color(Color::new(0.2, 0.4, 1.0, 0.35));
+
+    // Clipboard: ctrl/cmd+C, ctrl/cmd+X and ctrl/cmd+V work while the box is active.
+    // This needs the clipboard module (pub mod clipboard; in mod.rs).
+    txt_input.copy_selection();
+    txt_input.cut_selection();
+    txt_input.paste();
+
+INPUT FILTERS AND VALIDATION:
+    // Only let certain characters be typed or pasted
+    use crate::modules::text_input::InputFilter;
+    txt_input.set_filter(InputFilter::Numeric);                   // 0-9 only
+    txt_input.set_filter(InputFilter::Alphanumeric);              // Letters and numbers
+    txt_input.set_filter(InputFilter::Charset("abc123_".to_string())); // Only these characters
+    txt_input.set_filter(InputFilter::Custom(|c| c != ' '));      // Any rule you like
+    txt_input.clear_filter();
+
+    // Check the whole text whenever it changes, the border turns red while it is invalid
+    txt_input.set_validator(|text| {
+        match text.parse::<i32>() {
+            Ok(age) if age <= 120 => Ok(()),
+            _ => Err("Enter an age from 0 to 120".to_string()),
+        }
+    });
+    if txt_input.is_valid() {
+        // Safe to save
+    }
+    let problem = txt_input.get_validation_error(); // Some("Enter an age...") when invalid
+
+    // Show a problem found elsewhere (e.g. by the database) - the border turns red and the
+    // message is drawn under the box until clear_error() is called or the user edits the text
+    txt_input.set_error(Some("Username taken"));
+    txt_input.clear_error();
+    txt_input.set_error_color(ORANGE); // Color for the border and message (default the theme's error color)
+    // Validator messages are drawn under the box the same way
+
+MASKED INPUT:
+    // Format structured values as they are typed: # is a digit, A is a letter,
+    // * is any character and everything else is a separator that is added automatically
+    txt_input.set_mask("####-##-##");      // Date, typing 20261016 shows 2026-10-16
+    txt_input.set_mask("(###) ###-####");  // Phone number
+    let raw = txt_input.get_text();           // "20261016" - separators are left out
+    let shown = txt_input.get_formatted_text(); // "2026-10-16"
+    txt_input.set_text("20261016");        // Set the raw value, it is formatted for you
+    txt_input.clear_mask();
+    // Validators and callbacks are given the raw value as well
+
+PASSWORD INPUT:
+    // Show a * for each character instead of the text (get_text still gives the real text)
+    txt_input.set_password(true);
+    // Copy and cut do nothing while this is on, so the password can't be copied out
+
+CALLBACKS:
+    // Run code when the user changes the text (not when set_text is called)
+    txt_input.set_on_change(|text| println!("Now: {}", text));
+
+    // Run code when Enter is pressed while the box is active
+    txt_input.set_on_submit(|text| println!("Submitted: {}", text));
+
+    // Or check for Enter in the loop - true for one frame after Enter is pressed while active
+    if txt_input.submitted() {
+        // Log in, send the message, etc.
+    }
+    if txt_input.changed() { }                // True for one frame after the user changes the text
+    txt_input.set_deactivate_on_submit(true); // Leave the box after Enter is pressed
+
+HISTORY:
+    // Remember submitted text, Up/Down bring it back like a terminal (off by default)
+    txt_input.set_history_enabled(true);
+    txt_input.set_history_limit(50);        // Oldest entries are dropped past this
+    txt_input.set_history(old_messages);    // Fill it from saved data (oldest first)
+    txt_input.add_to_history("hello");
+    let entries = txt_input.get_history();
+    txt_input.clear_history();
+    // When the suggestion dropdown is open Up/Down move through it instead
+
+AUTOCOMPLETE:
+    // Show matching suggestions in a dropdown below the box while typing.
+    // Up/Down highlight a suggestion, Enter or a click picks it and Escape hides the list.
+    txt_input.set_suggestions(vec!["apple".to_string(), "banana".to_string()]);
+    txt_input.set_max_suggestions(5); // Most suggestions shown at once
+    txt_input.clear_suggestions();
+    // The dropdown can cover other widgets, so draw this box after the ones below it
+    // (a UiManager does that for you while the dropdown is open)
+    if let Some(area) = txt_input.suggestions_rect() { }  // Where the open dropdown is
+
+    // Customize key repeat behavior (for arrow keys, Home/End, backspace, delete)
     txt_input.set_key_repeat_delay(0.3);    // Initial delay before key repeat starts (seconds)
     txt_input.set_key_repeat_rate(0.03);    // Time between repeats after initial delay (seconds)
     // Or set both at once
     txt_input.with_key_repeat_settings(0.3, 0.03);
 
+    // Limit how many characters can be entered (e.g. to match a varchar(32) column)
+    txt_input.set_max_length(32);
+    txt_input.clear_max_length();   // Remove the limit again
+    txt_input.set_show_counter(true); // Show a small "12/32" counter in the corner
+    txt_input.set_counter_colors(DARKGRAY, RED); // Normal color, color when close to the limit
+    txt_input.set_counter_warning_threshold(0.9); // Switch colors once 90% of the limit is used
+
+Text longer than the box is clipped to it and scrolls sideways to keep the cursor in view.
+Chinese, Japanese, Korean and other input-method text can be typed on the web, and tapping
+the box on a phone or tablet opens the on-screen keyboard (this needs the ime module:
+pub mod ime; in mod.rs). Keys tapped on a VirtualKeyboard are typed into the focused box
+too (this needs pub mod virtual_keyboard; in mod.rs).
+
 Then in the main loop you would use:
     // Update and draw the textbox in one step
     txt_input.draw();
 */
 use macroquad::prelude::*;
-#[cfg(feature = "scale")]
+use crate::modules::clipboard;
+use crate::modules::focus;
+// Characters the font doesn't have are drawn with the backup fonts (see font_fallback)
+use crate::modules::font_fallback::{draw_text, draw_text_ex, measure_text};
+use crate::modules::glyph_cache;
+use crate::modules::ime;
+use crate::modules::label::TextAlign;
+use crate::modules::theme::{self, draw_round_rect, draw_round_rect_lines, ThemeColor};
+use crate::modules::virtual_keyboard::{self, VirtualKey};
+use unicode_segmentation::UnicodeSegmentation;
+
+// Filter deciding which characters can be typed or pasted into a TextInput
+#[allow(unused)]
+#[derive(Clone)]
+pub enum InputFilter {
+    Numeric,            // Digits 0-9
+    Alphanumeric,       // Letters and digits
+    Charset(String),    // Only the characters in the string
+    Custom(fn(char) -> bool), // Any function that returns true for allowed characters
+}
+
+impl InputFilter {
+    // Check if a character passes the filter
+    #[allow(unused)]
+    pub fn allows(&self, c: char) -> bool {
+        match self {
+            InputFilter::Numeric => c.is_ascii_digit(),
+            InputFilter::Alphanumeric => c.is_alphanumeric(),
+            InputFilter::Charset(chars) => chars.contains(c),
+            InputFilter::Custom(check) => check(c),
+        }
+    }
+}
+
+// Shape of the text cursor
+#[allow(unused)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum CursorStyle {
+    Line,      // Thin vertical line between characters
+    Block,     // Box over the next character
+    Underline, // Line under the next character
+}
+
+// Validator run on the whole text, returns an error message when the text is invalid
+type Validator = Box<dyn Fn(&str) -> Result<(), String>>;
+
+// Callback given the current text (used for on_change and on_submit)
+type TextCallback = Box<dyn FnMut(&str)>;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
 use crate::modules::scale::mouse_position_world as mouse_position;
 
+// Longest gap between clicks (in seconds) that still counts as a double/triple click
+const MULTI_CLICK_TIME: f64 = 0.4;
+
 pub struct TextInput {
     // Make all fields private for complete encapsulation
     x: f32,
@@ -83,20 +274,63 @@ pub struct TextInput {
     cursor_timer: f32,
     cursor_visible: bool,
     font_size: f32,
-    text_color: Color,
-    border_color: Color,
-    background_color: Color,
-    cursor_color: Color,
+    text_color: ThemeColor,
+    border_color: ThemeColor,
+    background_color: ThemeColor,
+    cursor_color: ThemeColor,
     font: Option<Font>,
+    font_id: usize,         // Identifies the font in the shared glyph width cache
     prompt: Option<String>, // New field for prompt text
-    prompt_color: Color,    // Color for the prompt text
+    prompt_color: ThemeColor,    // Color for the prompt text
     // Add key repeat functionality
     key_repeat_delay: f32,  // Initial delay before key starts repeating (in seconds)
     key_repeat_rate: f32,   // How often the key repeats after initial delay (in seconds) 
     key_repeat_timer: f32,  // Timer to track key repeat
     last_key: Option<KeyCode>, // Track the last key that was pressed
     enabled: bool,          // Controls whether the text input can be interacted with
-    disabled_color: Color,  // Color used when the text input is disabled
+    disabled_color: ThemeColor,  // Color used when the text input is disabled
+    max_length: Option<usize>, // Maximum number of characters allowed (None = unlimited)
+    show_counter: bool,     // Whether to draw the "used/max" counter
+    counter_color: ThemeColor,   // Counter color while there is plenty of room left
+    counter_warning_color: ThemeColor, // Counter color once the text is close to the limit
+    counter_warning_threshold: f32, // Fraction of the max length where the warning color starts
+    selection_anchor: Option<usize>, // Fixed end of the selection, the cursor is the moving end
+    selection_color: ThemeColor, // Highlight color for selected text
+    dragging: bool,         // True while the mouse is held down to select text
+    last_click_time: f64,   // When the box was last clicked, for double/triple clicks
+    click_count: u32,       // Clicks in the current double/triple click sequence
+    paste_pending: bool,    // Waiting for clipboard text to arrive (async on the web)
+    scroll_offset: f32,     // How far the text is scrolled left to keep the cursor visible
+    filter: Option<InputFilter>, // Which characters may be typed or pasted
+    validator: Option<Validator>, // Checks the text whenever it changes
+    validation_error: Option<String>, // Message from the validator while the text is invalid
+    error: Option<String>,  // Message set with set_error, shown under the box
+    error_color: ThemeColor,     // Border and message color while there is an error
+    text_align: TextAlign,  // Where the text sits in the box while it is narrower than the box
+    padding: f32,           // Space between the border and the text
+    border_thickness: f32,  // Width of the border line (0.0 for no border)
+    corner_radius: Option<f32>, // Radius of the rounded corners (None uses the theme's)
+    cursor_blink_interval: f32, // Seconds between cursor blinks (0.0 = no blinking)
+    cursor_width: f32,      // Thickness of the cursor line
+    cursor_style: CursorStyle, // Line, block or underline cursor
+    text_changed: bool,     // Set by the editing helpers when the user modified the text
+    on_change: Option<TextCallback>, // Called after the user changes the text
+    on_submit: Option<TextCallback>, // Called when Enter is pressed while active
+    submitted: bool,        // True for the update in which Enter was pressed
+    changed: bool,          // True for the update in which the user changed the text
+    deactivate_on_submit: bool, // Whether pressing Enter also deactivates the box
+    mask: Option<String>,   // Format pattern like "####-##-##" (see set_mask)
+    history_enabled: bool,  // Whether Up/Down recall submitted text
+    history: Vec<String>,   // Submitted text, oldest first
+    history_limit: usize,   // Most entries kept in the history
+    history_index: Option<usize>, // Entry being shown while browsing the history
+    history_draft: String,  // What was typed before browsing started, restored at the end
+    focus_id: usize,        // Id in the focus module's tab order
+    suggestions: Vec<String>, // Autocomplete options shown in a dropdown while typing
+    suggestion_index: Option<usize>, // Highlighted row in the dropdown
+    suggestions_hidden: bool, // Dropdown closed with Escape or a pick, until the text changes
+    max_suggestions: usize, // Most rows shown in the dropdown at once
+    password: bool,         // Draw * for each character instead of the text
 }
 
 impl TextInput {
@@ -112,20 +346,63 @@ impl TextInput {
             cursor_timer: 0.0,
             cursor_visible: true,
             font_size,
-            text_color: BLACK, // Default color for text
-            border_color: DARKGRAY, // Default color for border
-            background_color: LIGHTGRAY, // Default color for background
-            cursor_color: BLACK, // Default color for cursor
+            text_color: ThemeColor::Text, // Default color for text
+            border_color: ThemeColor::Border, // Default color for border
+            background_color: ThemeColor::Surface, // Default color for background
+            cursor_color: ThemeColor::Text, // Default color for cursor
             font: None, // Default to None (use system font)
+            font_id: 0, // The default font's id in the glyph cache
             prompt: None, // Default to None (no prompt text)
-            prompt_color: GRAY, // Default color for prompt text
+            prompt_color: ThemeColor::TextMuted, // Default color for prompt text
             // Initialize key repeat values
             key_repeat_delay: 0.4, // 400ms initial delay before repeat
             key_repeat_rate: 0.05, // 50ms between repeats after initial delay
             key_repeat_timer: 0.0,
             last_key: None,
             enabled: true, // Default to enabled
-            disabled_color: Color::new(0.7, 0.7, 0.7, 0.5), // Semi-transparent gray for disabled state
+            disabled_color: ThemeColor::Custom(Color::new(0.7, 0.7, 0.7, 0.5)), // Semi-transparent gray for disabled state
+            max_length: None, // Default to no limit
+            show_counter: false, // Default to no counter
+            counter_color: ThemeColor::TextMuted, // Default counter color
+            counter_warning_color: ThemeColor::Error, // Default color near the limit
+            counter_warning_threshold: 0.9, // Default to warning at 90% of the limit
+            selection_anchor: None, // Default to no selection
+            selection_color: ThemeColor::Selection, // Translucent blue highlight
+            dragging: false,
+            last_click_time: 0.0,
+            click_count: 0,
+            paste_pending: false,
+            scroll_offset: 0.0,
+            filter: None, // Default to accepting any character
+            validator: None, // Default to no validation
+            validation_error: None,
+            error: None, // Default to no error
+            error_color: ThemeColor::Error, // Default error color
+            text_align: TextAlign::Left, // Default to left alignment
+            padding: 5.0, // Default padding
+            border_thickness: 2.0, // Default border thickness
+            corner_radius: None, // Default to the theme's corners
+            cursor_blink_interval: 0.5, // Default half second blink
+            cursor_width: 1.0, // Default thin cursor
+            cursor_style: CursorStyle::Line, // Default line cursor
+            text_changed: false,
+            on_change: None,
+            on_submit: None,
+            submitted: false,
+            changed: false,
+            deactivate_on_submit: false, // Default to staying active after Enter
+            mask: None, // Default to free text
+            history_enabled: false, // Default to no history
+            history: Vec::new(),
+            history_limit: 50, // Default to the last 50 entries
+            history_index: None,
+            history_draft: String::new(),
+            focus_id: focus::register(), // Added to the end of the tab order
+            suggestions: Vec::new(), // Default to no autocomplete
+            suggestion_index: None,
+            suggestions_hidden: false,
+            max_suggestions: 5, // Default to five rows
+            password: false,
         }
     }
     
@@ -202,24 +479,35 @@ impl TextInput {
     
     // Add a method to change colors
     #[allow(unused)]
-    pub fn with_colors(&mut self, text_color: Color, border_color: Color, background_color: Color, cursor_color: Color) -> &mut Self {
-        self.text_color = text_color;
-        self.border_color = border_color;
-        self.background_color = background_color;
-        self.cursor_color = cursor_color;
+    pub fn with_colors(&mut self, text_color: impl Into<ThemeColor>, border_color: impl Into<ThemeColor>, background_color: impl Into<ThemeColor>, cursor_color: impl Into<ThemeColor>) -> &mut Self {
+        self.text_color = text_color.into();
+        self.border_color = border_color.into();
+        self.background_color = background_color.into();
+        self.cursor_color = cursor_color.into();
         self
     }
 
     // Method to set custom font
     #[allow(unused)]
     pub fn with_font(&mut self, font: Font) -> &mut Self {
+        glyph_cache::forget_font_id(self.font_id);
+        self.font_id = glyph_cache::new_font_id();
         self.font = Some(font);
         self
     }
 
-    // Get the current text content
+    // Get the current text content (without mask separators when a mask is set)
     #[allow(unused)]
     pub fn get_text(&self) -> String {
+        match &self.mask {
+            Some(mask) => self.text.chars().filter(|&c| !is_mask_literal(mask, c)).collect(),
+            None => self.text.clone(),
+        }
+    }
+
+    // Get the text exactly as shown in the box, including mask separators
+    #[allow(unused)]
+    pub fn get_formatted_text(&self) -> String {
         self.text.clone()
     }
     
@@ -227,9 +515,17 @@ impl TextInput {
     #[allow(unused)]
     pub fn set_text<T: Into<String>>(&mut self, text: T) -> &mut Self {
         self.text = text.into();
+        self.truncate_to_max_length();
+        self.selection_anchor = None;
         if self.cursor_index > self.text.len() {
             self.cursor_index = self.text.len();
         }
+        if self.mask.is_some() {
+            self.cursor_index = self.text.len();
+            self.apply_mask();
+        }
+        self.update_scroll();
+        self.validate();
         self
     }
     
@@ -239,12 +535,25 @@ impl TextInput {
         self.active
     }
 
+    // Activating takes keyboard focus away from any other widget
     #[allow(unused)]
     pub fn set_active(&mut self, active: bool) -> &mut Self {
-        self.active = active;
+        if active && self.enabled {
+            focus::set_focus(self.focus_id);
+            self.active = true;
+        } else {
+            focus::release_focus(self.focus_id);
+            self.active = false;
+        }
         self
     }
 
+    // Id used by the focus module (for focus::set_tab_order and friends)
+    #[allow(unused)]
+    pub fn focus_id(&self) -> usize {
+        self.focus_id
+    }
+
     // Cursor index getters/setters
     #[allow(unused)]
     pub fn get_cursor_index(&self) -> usize {
@@ -253,8 +562,10 @@ impl TextInput {
 
     #[allow(unused)]
     pub fn set_cursor_index(&mut self, index: usize) -> &mut Self {
-        if index <= self.text.len() {
+        if index <= self.text.len() && self.text.is_char_boundary(index) {
             self.cursor_index = index;
+            self.selection_anchor = None;
+            self.update_scroll();
         }
         self
     }
@@ -274,54 +585,110 @@ impl TextInput {
     // Color getters/setters
     #[allow(unused)]
     pub fn get_text_color(&self) -> Color {
-        self.text_color
+        self.text_color.color()
     }
 
     #[allow(unused)]
-    pub fn set_text_color(&mut self, color: Color) -> &mut Self {
-        self.text_color = color;
+    pub fn set_text_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.text_color = color.into();
         self
     }
 
     #[allow(unused)]
     pub fn get_border_color(&self) -> Color {
-        self.border_color
+        self.border_color.color()
     }
 
     #[allow(unused)]
-    pub fn set_border_color(&mut self, color: Color) -> &mut Self {
-        self.border_color = color;
+    pub fn set_border_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.border_color = color.into();
         self
     }
 
     #[allow(unused)]
     pub fn get_background_color(&self) -> Color {
-        self.background_color
+        self.background_color.color()
     }
 
     #[allow(unused)]
-    pub fn set_background_color(&mut self, color: Color) -> &mut Self {
-        self.background_color = color;
+    pub fn set_background_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.background_color = color.into();
         self
     }
 
     #[allow(unused)]
     pub fn get_cursor_color(&self) -> Color {
-        self.cursor_color
+        self.cursor_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_cursor_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.cursor_color = color.into();
+        self
+    }
+
+    // Cursor style getters/setters
+    #[allow(unused)]
+    pub fn get_cursor_blink_interval(&self) -> f32 {
+        self.cursor_blink_interval
+    }
+
+    // Seconds the cursor stays on (and off) for each blink, 0.0 keeps it on all the time
+    #[allow(unused)]
+    pub fn set_cursor_blink_interval(&mut self, interval: f32) -> &mut Self {
+        self.cursor_blink_interval = interval.max(0.0);
+        self.cursor_visible = true;
+        self.cursor_timer = 0.0;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_cursor_width(&self) -> f32 {
+        self.cursor_width
+    }
+
+    #[allow(unused)]
+    pub fn set_cursor_width(&mut self, width: f32) -> &mut Self {
+        self.cursor_width = width.max(1.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_cursor_style(&self) -> CursorStyle {
+        self.cursor_style
     }
 
     #[allow(unused)]
-    pub fn set_cursor_color(&mut self, color: Color) -> &mut Self {
-        self.cursor_color = color;
+    pub fn set_cursor_style(&mut self, style: CursorStyle) -> &mut Self {
+        self.cursor_style = style;
         self
     }
 
-    // Font getter/setter
+    // Font getters/setters
     #[allow(unused)]
     pub fn get_font(&self) -> Option<&Font> {
         self.font.as_ref()
     }
 
+    #[allow(unused)]
+    pub fn set_font(&mut self, font: Font) -> &mut Self {
+        glyph_cache::forget_font_id(self.font_id);
+        self.font_id = glyph_cache::new_font_id();
+        self.font = Some(font);
+        self.update_scroll();
+        self
+    }
+
+    // Go back to the theme's font
+    #[allow(unused)]
+    pub fn clear_font(&mut self) -> &mut Self {
+        self.font = None;
+        glyph_cache::forget_font_id(self.font_id);
+        self.font_id = 0;
+        self.update_scroll();
+        self
+    }
+
     // Prompt text getters/setters
     #[allow(unused)]
     pub fn get_prompt(&self) -> Option<&String> {
@@ -336,12 +703,55 @@ impl TextInput {
 
     #[allow(unused)]
     pub fn get_prompt_color(&self) -> Color {
-        self.prompt_color
+        self.prompt_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_prompt_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.prompt_color = color.into();
+        self
+    }
+
+    // Padding, border and corner getters/setters
+    #[allow(unused)]
+    pub fn get_padding(&self) -> f32 {
+        self.padding
+    }
+
+    #[allow(unused)]
+    pub fn set_padding(&mut self, padding: f32) -> &mut Self {
+        self.padding = padding.max(0.0);
+        self.update_scroll();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_border_thickness(&self) -> f32 {
+        self.border_thickness
+    }
+
+    #[allow(unused)]
+    pub fn set_border_thickness(&mut self, thickness: f32) -> &mut Self {
+        self.border_thickness = thickness.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_corner_radius(&self) -> f32 {
+        self.corner_radius.unwrap_or_else(theme::corner_radius)
+    }
+
+    // Round the corners (0.0 for square ones)
+    #[allow(unused)]
+    pub fn set_corner_radius(&mut self, radius: f32) -> &mut Self {
+        self.corner_radius = Some(radius.max(0.0));
+        self
     }
 
+    // Method to set text alignment (left, center or right)
     #[allow(unused)]
-    pub fn set_prompt_color(&mut self, color: Color) -> &mut Self {
-        self.prompt_color = color;
+    pub fn with_alignment(&mut self, alignment: TextAlign) -> &mut Self {
+        self.text_align = alignment;
         self
     }
 
@@ -385,6 +795,8 @@ impl TextInput {
     #[allow(unused)]
     pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
         self.enabled = enabled;
+        // Disabled boxes can't be tabbed to
+        focus::set_focusable(self.focus_id, enabled);
         if !enabled {
             self.active = false; // Deactivate if disabled
         }
@@ -393,277 +805,1416 @@ impl TextInput {
     
     #[allow(unused)]
     pub fn get_disabled_color(&self) -> Color {
-        self.disabled_color
+        self.disabled_color.color()
     }
     
     #[allow(unused)]
-    pub fn set_disabled_color(&mut self, color: Color) -> &mut Self {
-        self.disabled_color = color;
+    pub fn set_disabled_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.disabled_color = color.into();
         self
     }
 
-    // Primary method - both updates and draws the textbox
+    // Maximum length getters/setters (measured in characters, not bytes)
     #[allow(unused)]
-    pub fn draw(&mut self) {
-        self.update_internal();
-        self.draw_internal();
+    pub fn get_max_length(&self) -> Option<usize> {
+        self.max_length
     }
-    
-    // For cases when only drawing is needed without updating
+
     #[allow(unused)]
-    pub fn draw_only(&self) {
-        self.draw_internal();
+    pub fn set_max_length(&mut self, max_length: usize) -> &mut Self {
+        self.max_length = Some(max_length);
+        self.truncate_to_max_length();
+        self
     }
-    
-    // For cases when only updating is needed without drawing
+
     #[allow(unused)]
-    pub fn update_only(&mut self) {
-        self.update_internal();
+    pub fn clear_max_length(&mut self) -> &mut Self {
+        self.max_length = None;
+        self
     }
 
-    // Now private - internal implementation only
-    fn update_internal(&mut self) {
-        // Skip all interaction if disabled
-        if !self.enabled {
-            self.active = false;
-            self.cursor_visible = false;
-            return;
-        }
+    // Counter is only drawn when a max length is set
+    #[allow(unused)]
+    pub fn is_counter_shown(&self) -> bool {
+        self.show_counter
+    }
 
-        if is_mouse_button_pressed(MouseButton::Left) {
-            let (mx, my) = mouse_position();
-            self.active = mx >= self.x && mx <= self.x + self.width && my >= self.y && my <= self.y + self.height;
-    
-            if self.active {
-                // Clicking to place the cursor
-                let text_x = self.x + 5.0;
-                let mouse_pos = mx - text_x;
-                self.cursor_index = 0;
-    
-                let mut cursor_offset = 0.0;
-                while self.cursor_index < self.text.len() {
-                    let char_width = match &self.font {
-                        Some(font) => measure_text(
-                            &self.text[self.cursor_index..self.cursor_index + 1], 
-                            Some(font), 
-                            self.font_size as u16, 
-                            1.0
-                        ).width,
-                        None => measure_text(
-                            &self.text[self.cursor_index..self.cursor_index + 1], 
-                            None, 
-                            self.font_size as u16, 
-                            1.0
-                        ).width,
-                    };
-                    
-                    cursor_offset += char_width;
-                    if cursor_offset > mouse_pos {
-                        break;
-                    }
-                    self.cursor_index += self.text[self.cursor_index..].chars().next().unwrap().len_utf8();
-                }
-            }
-        }
-    
-        if self.active {
-            // Handle typing
-            while let Some(c) = get_char_pressed() {
-                if !c.is_control() {
-                    self.text.insert(self.cursor_index, c);
-                    self.cursor_index += c.len_utf8();
-                }
-            }
-    
-            // Initial key presses
-            let key_delete_pressed = is_key_pressed(KeyCode::Delete);
-            let key_backspace_pressed = is_key_pressed(KeyCode::Backspace);
-            let key_left_pressed = is_key_pressed(KeyCode::Left);
-            let key_right_pressed = is_key_pressed(KeyCode::Right);
-            
-            // Handle initial key presses
-            if key_delete_pressed && self.cursor_index < self.text.len() {
-                if let Some((_, c)) = self.text[self.cursor_index..].char_indices().next() {
-                    let char_len = c.len_utf8();
-                    self.text.replace_range(self.cursor_index..self.cursor_index + char_len, "");
-                }
-                self.last_key = Some(KeyCode::Delete);
-                self.key_repeat_timer = 0.0;
-            } else if key_backspace_pressed && self.cursor_index > 0 {
-                if let Some((prev_offset, _c)) = self.text[..self.cursor_index].char_indices().rev().next() {
-                    self.text.replace_range(prev_offset..self.cursor_index, "");
-                    self.cursor_index = prev_offset;
-                }
-                self.last_key = Some(KeyCode::Backspace);
-                self.key_repeat_timer = 0.0;
-            } else if key_left_pressed && self.cursor_index > 0 {
-                let prev_char = self.text[..self.cursor_index].chars().last().unwrap();
-                let char_len = prev_char.len_utf8();
-                self.cursor_index -= char_len;
-                self.last_key = Some(KeyCode::Left);
-                self.key_repeat_timer = 0.0;
-            } else if key_right_pressed && self.cursor_index < self.text.len() {
-                let next_char = self.text[self.cursor_index..].chars().next().unwrap();
-                let char_len = next_char.len_utf8();
-                self.cursor_index += char_len;
-                self.last_key = Some(KeyCode::Right);
-                self.key_repeat_timer = 0.0;
-            }
+    #[allow(unused)]
+    pub fn set_show_counter(&mut self, show: bool) -> &mut Self {
+        self.show_counter = show;
+        self
+    }
 
-            // Handle key repeat functionality
-            if let Some(key) = self.last_key {
-                if is_key_down(key) {
-                    self.key_repeat_timer += get_frame_time();
-                    if self.key_repeat_timer >= self.key_repeat_delay {
-                        self.key_repeat_timer -= self.key_repeat_rate;
-                        match key {
-                            KeyCode::Left => {
-                                if self.cursor_index > 0 {
-                                    let prev_char = self.text[..self.cursor_index].chars().last().unwrap();
-                                    let char_len = prev_char.len_utf8();
-                                    self.cursor_index -= char_len;
-                                }
-                            }
-                            KeyCode::Right => {
-                                if self.cursor_index < self.text.len() {
-                                    let next_char = self.text[self.cursor_index..].chars().next().unwrap();
-                                    let char_len = next_char.len_utf8();
-                                    self.cursor_index += char_len;
-                                }
-                            }
-                            KeyCode::Delete => {
-                                if self.cursor_index < self.text.len() {
-                                    if let Some((_, c)) = self.text[self.cursor_index..].char_indices().next() {
-                                        let char_len = c.len_utf8();
-                                        self.text.replace_range(self.cursor_index..self.cursor_index + char_len, "");
-                                    }
-                                }
-                            }
-                            KeyCode::Backspace => {
-                                if self.cursor_index > 0 {
-                                    if let Some((prev_offset, _c)) = self.text[..self.cursor_index].char_indices().rev().next() {
-                                        self.text.replace_range(prev_offset..self.cursor_index, "");
-                                        self.cursor_index = prev_offset;
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                } else {
-                    self.last_key = None;
-                    self.key_repeat_timer = 0.0;
-                }
-            }
+    // Counter colors: normal, and once the text gets close to the max length
+    #[allow(unused)]
+    pub fn set_counter_colors(&mut self, normal: impl Into<ThemeColor>, warning: impl Into<ThemeColor>) -> &mut Self {
+        self.counter_color = normal.into();
+        self.counter_warning_color = warning.into();
+        self
+    }
 
-            self.cursor_timer += get_frame_time();
-            if self.cursor_timer >= 0.5 {
-                self.cursor_visible = !self.cursor_visible;
-                self.cursor_timer = 0.0;
-            } 
-        } else {
-            self.cursor_visible = false; 
-        }
+    #[allow(unused)]
+    pub fn get_counter_warning_threshold(&self) -> f32 {
+        self.counter_warning_threshold
     }
-    
-    // Now private - internal implementation only
-    fn draw_internal(&self) {
-        let padding = 5.0;
-        let text_x = self.x + padding;
-        let text_y = self.y + self.height / 2.0 + self.font_size / 2.5;
-    
-        // Draw the background with customizable colors (or disabled color when disabled)
-        if self.enabled {
-            draw_rectangle(self.x, self.y, self.width, self.height, self.background_color);
-        } else {
-            // Use the disabled color for the background when disabled
-            draw_rectangle(self.x, self.y, self.width, self.height, self.disabled_color);
-        }
-        
-        // Draw text with the appropriate font and color based on enabled state
-        let text_color = if self.enabled { self.text_color } else { GRAY };
-        let prompt_color = if self.enabled { self.prompt_color } else { GRAY };
-        
-        if self.text.is_empty() {
-            if let Some(prompt) = &self.prompt {
-                match &self.font {
-                    Some(font) => {
-                        draw_text_ex(
-                            prompt,
-                            text_x,
-                            text_y,
-                            TextParams {
-                                font: Some(font),
-                                font_size: self.font_size as u16,
-                                color: prompt_color,
-                                ..Default::default()
-                            },
-                        );
-                    },
-                    None => {
-                        draw_text(prompt, text_x, text_y, self.font_size, prompt_color);
-                    }
-                }
-            }
-        } else {
-            match &self.font {
-                Some(font) => {
-                    draw_text_ex(
-                        &self.text,
-                        text_x,
-                        text_y,
-                        TextParams {
-                            font: Some(font),
-                            font_size: self.font_size as u16,
-                            color: text_color,
-                            ..Default::default()
-                        },
-                    );
-                },
-                None => {
-                    draw_text(&self.text, text_x, text_y, self.font_size, text_color);
-                }
-            }
-        }
-    
-        // Only show cursor if enabled and active
-        if self.enabled && self.active && self.cursor_visible {
-            let mut cursor_offset = 0.0;
-            if self.cursor_index > 0 {
-                let cursor_text = &self.text[..self.cursor_index];
-                
-                // Calculate cursor position based on font
-                if let Some(font) = &self.font {
-                    // Use custom font for measurement
-                    for c in cursor_text.chars() {
-                        cursor_offset += measure_text(&c.to_string(), Some(font), self.font_size as u16, 1.0).width;
-                    }
-                } else {
-                    // Use default font for measurement
-                    for c in cursor_text.chars() {
-                        cursor_offset += measure_text(&c.to_string(), None, self.font_size as u16, 1.0).width;
-                    }
-                }
-            }
-    
-            // Add a small spacing between the text and cursor (2.0 pixels)
-            let cursor_spacing = 2.0;
-            
-             // Draw the cursor with customizable color and added spacing
-             draw_line(
-                text_x + cursor_offset + cursor_spacing,
-                text_y - self.font_size * 0.7,  // Reduce the height of cursor above text
-                text_x + cursor_offset + cursor_spacing,
-                text_y + 2.0,  // Reduce the height of cursor below text
-                1.0,  // Reduce thickness from 2.0 to 1.0
-                self.cursor_color,
-            );
-        }
-    
-        // Draw the border with customizable color
-        let border_color = if self.enabled { self.border_color } else { GRAY };
-        draw_rectangle_lines(self.x, self.y, self.width, self.height, 2.0, border_color);
+
+    // Fraction of the max length (0.0 - 1.0) at which the counter switches to the warning color
+    #[allow(unused)]
+    pub fn set_counter_warning_threshold(&mut self, threshold: f32) -> &mut Self {
+        self.counter_warning_threshold = threshold.clamp(0.0, 1.0);
+        self
     }
-}
 
+    // Filter getters/setters
+    #[allow(unused)]
+    pub fn get_filter(&self) -> Option<&InputFilter> {
+        self.filter.as_ref()
+    }
 
+    #[allow(unused)]
+    pub fn set_filter(&mut self, filter: InputFilter) -> &mut Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_filter(&mut self) -> &mut Self {
+        self.filter = None;
+        self
+    }
+
+    // Validation methods
+    #[allow(unused)]
+    pub fn set_validator<F>(&mut self, validator: F) -> &mut Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        self.validator = Some(Box::new(validator));
+        self.validate();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_validator(&mut self) -> &mut Self {
+        self.validator = None;
+        self.validation_error = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn is_valid(&self) -> bool {
+        self.validation_error.is_none()
+    }
+
+    #[allow(unused)]
+    pub fn get_validation_error(&self) -> Option<&str> {
+        self.validation_error.as_deref()
+    }
+
+    // Error state getters/setters
+    #[allow(unused)]
+    pub fn get_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    // Show an error under the box (None clears it), it also clears when the user edits the text
+    #[allow(unused)]
+    pub fn set_error(&mut self, error: Option<&str>) -> &mut Self {
+        self.error = error.map(str::to_string);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_error(&mut self) -> &mut Self {
+        self.error = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_error_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.error_color = color.into();
+        self
+    }
+
+    // Mask getters/setters
+    #[allow(unused)]
+    pub fn get_mask(&self) -> Option<&str> {
+        self.mask.as_deref()
+    }
+
+    // # = digit, A = letter, * = any character, anything else is a separator
+    #[allow(unused)]
+    pub fn set_mask<T: Into<String>>(&mut self, mask: T) -> &mut Self {
+        self.mask = Some(mask.into());
+        self.apply_mask();
+        self.update_scroll();
+        self.validate();
+        self
+    }
+
+    // Remove the mask, the separators already in the text stay
+    #[allow(unused)]
+    pub fn clear_mask(&mut self) -> &mut Self {
+        self.mask = None;
+        self
+    }
+
+    // Password getters/setters
+    #[allow(unused)]
+    pub fn is_password(&self) -> bool {
+        self.password
+    }
+
+    #[allow(unused)]
+    pub fn set_password(&mut self, password: bool) -> &mut Self {
+        self.password = password;
+        self.update_scroll();
+        self
+    }
+
+    // History getters/setters
+    #[allow(unused)]
+    pub fn is_history_enabled(&self) -> bool {
+        self.history_enabled
+    }
+
+    #[allow(unused)]
+    pub fn set_history_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.history_enabled = enabled;
+        self.history_index = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_history(&self) -> &[String] {
+        &self.history
+    }
+
+    // Replace the history (oldest first), e.g. with messages loaded from the database
+    #[allow(unused)]
+    pub fn set_history(&mut self, history: Vec<String>) -> &mut Self {
+        self.history = history;
+        self.history_index = None;
+        self.trim_history();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn add_to_history<T: Into<String>>(&mut self, entry: T) -> &mut Self {
+        let entry = entry.into();
+        // Don't store blanks or the same entry twice in a row
+        if !entry.is_empty() && self.history.last() != Some(&entry) {
+            self.history.push(entry);
+            self.trim_history();
+        }
+        self.history_index = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_history(&mut self) -> &mut Self {
+        self.history.clear();
+        self.history_index = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_history_limit(&mut self, limit: usize) -> &mut Self {
+        self.history_limit = limit;
+        self.trim_history();
+        self
+    }
+
+    // Callback setters
+    #[allow(unused)]
+    pub fn set_on_change<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_on_submit<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.on_submit = Some(Box::new(callback));
+        self
+    }
+
+    // True if Enter was pressed while active during the last update
+    #[allow(unused)]
+    pub fn submitted(&self) -> bool {
+        self.submitted
+    }
+
+    // True if the user changed the text (typing, pasting, picking a suggestion) during the last update
+    #[allow(unused)]
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    #[allow(unused)]
+    pub fn set_deactivate_on_submit(&mut self, deactivate: bool) -> &mut Self {
+        self.deactivate_on_submit = deactivate;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_callbacks(&mut self) -> &mut Self {
+        self.on_change = None;
+        self.on_submit = None;
+        self
+    }
+
+    // Selection methods
+    #[allow(unused)]
+    pub fn has_selection(&self) -> bool {
+        self.selection_range().is_some()
+    }
+
+    #[allow(unused)]
+    pub fn get_selected_text(&self) -> String {
+        match self.selection_range() {
+            Some((start, end)) => self.text[start..end].to_string(),
+            None => String::new(),
+        }
+    }
+
+    #[allow(unused)]
+    pub fn select_all(&mut self) -> &mut Self {
+        self.selection_anchor = Some(0);
+        self.cursor_index = self.text.len();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_selection(&mut self) -> &mut Self {
+        self.selection_anchor = None;
+        self
+    }
+
+    // Selected byte range as (start, end), or None when nothing is selected
+    #[allow(unused)]
+    pub fn get_selection(&self) -> Option<(usize, usize)> {
+        self.selection_range()
+    }
+
+    // Select a byte range (the cursor goes to the end), ignored if it isn't on character boundaries
+    #[allow(unused)]
+    pub fn set_selection(&mut self, start: usize, end: usize) -> &mut Self {
+        let (start, end) = (start.min(end), start.max(end));
+        if end <= self.text.len() && self.text.is_char_boundary(start) && self.text.is_char_boundary(end) {
+            self.selection_anchor = Some(start);
+            self.cursor_index = end;
+        }
+        self
+    }
+
+    // Replace the selected text (or insert at the cursor when nothing is selected)
+    #[allow(unused)]
+    pub fn replace_selection(&mut self, text: &str) -> &mut Self {
+        self.delete_selection();
+        self.insert_at_cursor(text);
+        self.update_scroll();
+        self
+    }
+
+    // Clipboard methods
+    #[allow(unused)]
+    pub fn copy_selection(&self) {
+        if self.has_selection() && !self.password {
+            clipboard::set_text(&self.get_selected_text());
+        }
+    }
+
+    #[allow(unused)]
+    pub fn cut_selection(&mut self) -> &mut Self {
+        if self.enabled && self.has_selection() && !self.password {
+            clipboard::set_text(&self.get_selected_text());
+            self.delete_selection();
+        }
+        self
+    }
+
+    // Pastes at the cursor once the clipboard text arrives (the next update on the web)
+    #[allow(unused)]
+    pub fn paste(&mut self) -> &mut Self {
+        if self.enabled {
+            clipboard::request_text();
+            self.paste_pending = true;
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn get_selection_color(&self) -> Color {
+        self.selection_color.color()
+    }
+
+    #[allow(unused)]
+    pub fn set_selection_color(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.selection_color = color.into();
+        self
+    }
+
+    // Autocomplete getters/setters
+    #[allow(unused)]
+    pub fn get_suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    // Suggestions that start with the typed text (ignoring case) are shown below the box
+    #[allow(unused)]
+    pub fn set_suggestions(&mut self, suggestions: Vec<String>) -> &mut Self {
+        self.suggestions = suggestions;
+        self.suggestion_index = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_suggestions(&mut self) -> &mut Self {
+        self.suggestions.clear();
+        self.suggestion_index = None;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_max_suggestions(&mut self, max: usize) -> &mut Self {
+        self.max_suggestions = max;
+        self
+    }
+
+    // The area the suggestion dropdown covers, None while it is closed
+    #[allow(unused)]
+    pub fn suggestions_rect(&self) -> Option<Rect> {
+        let count = self.visible_suggestions().len();
+        (count > 0).then(|| Rect::new(self.x, self.y + self.height, self.width, self.suggestion_row_height() * count as f32))
+    }
+
+    // Primary method - both updates and draws the textbox
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        self.update_internal();
+        self.draw_internal();
+    }
+    
+    // For cases when only drawing is needed without updating
+    #[allow(unused)]
+    pub fn draw_only(&self) {
+        self.draw_internal();
+    }
+    
+    // For cases when only updating is needed without drawing
+    #[allow(unused)]
+    pub fn update_only(&mut self) {
+        self.update_internal();
+    }
+
+    // Number of characters (graphemes, so an emoji or an accented letter counts once) that can
+    // still be added before hitting the max length
+    fn remaining_capacity(&self) -> usize {
+        match self.max_length {
+            Some(max) => max.saturating_sub(self.text.graphemes(true).count()),
+            None => usize::MAX,
+        }
+    }
+
+    // Cut the text down to the max length (used when text is set from code)
+    fn truncate_to_max_length(&mut self) {
+        if let Some(max) = self.max_length {
+            if let Some((byte_index, _)) = self.text.grapheme_indices(true).nth(max) {
+                self.text.truncate(byte_index);
+            }
+            if self.cursor_index > self.text.len() {
+                self.cursor_index = self.text.len();
+            }
+        }
+    }
+
+    // Insert text at the cursor, keeping only as many characters as the max length allows
+    fn insert_at_cursor(&mut self, text: &str) {
+        let filtered: String = text
+            .chars()
+            .filter(|&c| self.filter.as_ref().is_none_or(|filter| filter.allows(c)))
+            .collect();
+        // Whole graphemes only, so a flag or an accent is never cut in half at the limit
+        let allowed: String = filtered.graphemes(true).take(self.remaining_capacity()).collect();
+        self.text.insert_str(self.cursor_index, &allowed);
+        self.cursor_index += allowed.len();
+        self.text_changed |= !allowed.is_empty();
+    }
+
+    // Suggestions matching the current text, in the order they were given
+    fn visible_suggestions(&self) -> Vec<&str> {
+        if !self.active || self.suggestions_hidden || self.text.is_empty() {
+            return Vec::new();
+        }
+        let typed = self.text.to_lowercase();
+        self.suggestions
+            .iter()
+            .filter(|suggestion| suggestion.to_lowercase().starts_with(&typed) && **suggestion != self.text)
+            .take(self.max_suggestions)
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn suggestion_row_height(&self) -> f32 {
+        self.font_size * 1.4
+    }
+
+    // Dropdown row under a point, if any
+    fn suggestion_at(&self, x: f32, y: f32) -> Option<usize> {
+        let top = self.y + self.height;
+        let count = self.visible_suggestions().len();
+        if x < self.x || x > self.x + self.width || y < top {
+            return None;
+        }
+        let row = ((y - top) / self.suggestion_row_height()) as usize;
+        (row < count).then_some(row)
+    }
+
+    // Replace the text with a suggestion and close the dropdown
+    fn accept_suggestion(&mut self, index: usize) {
+        let Some(choice) = self.visible_suggestions().get(index).map(|choice| choice.to_string()) else {
+            return;
+        };
+        self.text = choice;
+        self.truncate_to_max_length();
+        self.cursor_index = self.text.len();
+        self.selection_anchor = None;
+        self.text_changed = true;
+    }
+
+    // Drop the oldest history entries past the limit
+    fn trim_history(&mut self) {
+        if self.history.len() > self.history_limit {
+            let extra = self.history.len() - self.history_limit;
+            self.history.drain(..extra);
+        }
+    }
+
+    // Show an older (Up) or newer (Down) history entry, going past the newest restores the draft
+    fn browse_history(&mut self, older: bool) {
+        if self.history.is_empty() {
+            return;
+        }
+        let next = match (self.history_index, older) {
+            (None, true) => {
+                self.history_draft = self.text.clone();
+                Some(self.history.len() - 1)
+            }
+            (None, false) => return,
+            (Some(index), true) => Some(index.saturating_sub(1)),
+            (Some(index), false) if index + 1 < self.history.len() => Some(index + 1),
+            (Some(_), false) => None,
+        };
+        self.history_index = next;
+        self.text = match next {
+            Some(index) => self.history[index].clone(),
+            None => std::mem::take(&mut self.history_draft),
+        };
+        self.cursor_index = self.text.len();
+        self.selection_anchor = None;
+        self.text_changed = true;
+    }
+
+    // Re-run the validator against the current text
+    fn validate(&mut self) {
+        let value = self.get_text();
+        self.validation_error = match &self.validator {
+            Some(validator) => validator(&value).err(),
+            None => None,
+        };
+    }
+
+    // Re-lay out the text to fit the mask, keeping the cursor after the same typed character
+    fn apply_mask(&mut self) {
+        let Some(mask) = self.mask.clone() else {
+            return;
+        };
+        let slots: Vec<char> = mask.chars().filter(|&m| is_mask_slot(m)).collect();
+
+        // Pull out the typed characters, dropping separators and anything that doesn't fit its slot
+        let mut typed = Vec::new();
+        let mut typed_before_cursor = 0;
+        for (index, c) in self.text.char_indices() {
+            if is_mask_literal(&mask, c) {
+                continue;
+            }
+            let Some(&slot) = slots.get(typed.len()) else {
+                break;
+            };
+            if mask_slot_allows(slot, c) {
+                typed.push(c);
+                if index < self.cursor_index {
+                    typed_before_cursor += 1;
+                }
+            }
+        }
+
+        // Lay them back out, separators are only added once there is something after them
+        let mut formatted = String::new();
+        let mut cursor = 0;
+        let mut placed = 0;
+        for m in mask.chars() {
+            if placed == typed.len() {
+                break;
+            }
+            if is_mask_slot(m) {
+                formatted.push(typed[placed]);
+                placed += 1;
+                if placed == typed_before_cursor {
+                    cursor = formatted.len();
+                }
+            } else {
+                formatted.push(m);
+            }
+        }
+
+        self.text = formatted;
+        self.cursor_index = cursor;
+        self.selection_anchor = None;
+    }
+
+    // True if the character at a char position is a mask separator
+    fn is_mask_separator_at(&self, position: usize) -> bool {
+        self.mask
+            .as_ref()
+            .and_then(|mask| mask.chars().nth(position))
+            .is_some_and(|m| !is_mask_slot(m))
+    }
+
+    // Byte index of the character before the cursor (a whole grapheme cluster, so emoji stay intact)
+    fn prev_char_index(&self) -> usize {
+        self.text[..self.cursor_index]
+            .grapheme_indices(true)
+            .next_back()
+            .map_or(0, |(index, _)| index)
+    }
+
+    // Byte index of the character after the cursor
+    fn next_char_index(&self) -> usize {
+        self.text[self.cursor_index..]
+            .graphemes(true)
+            .next()
+            .map_or(self.cursor_index, |g| self.cursor_index + g.len())
+    }
+
+    // Byte range of the word (or run of spaces/punctuation) around an index
+    fn word_range_at(&self, index: usize) -> (usize, usize) {
+        // Use the character after the index, or the one before it at the end of the text
+        let Some(target) = self.text[index..].chars().next().or_else(|| self.text[..index].chars().next_back()) else {
+            return (index, index);
+        };
+        let same_kind = |c: char| {
+            is_word_char(c) == is_word_char(target) && c.is_whitespace() == target.is_whitespace()
+        };
+        // Whole characters (letters with accents, emoji) are judged by their first code point
+        let start = self.text[..index]
+            .grapheme_indices(true)
+            .rev()
+            .take_while(|&(_, g)| g.chars().next().is_some_and(same_kind))
+            .last()
+            .map_or(index, |(i, _)| i);
+        let end = self.text[index..]
+            .grapheme_indices(true)
+            .find(|&(_, g)| !g.chars().next().is_some_and(same_kind))
+            .map_or(self.text.len(), |(i, _)| index + i);
+        (start, end)
+    }
+
+    // Byte index of the start of the word before the cursor (skips spaces/punctuation first)
+    fn prev_word_index(&self) -> usize {
+        let mut index = self.cursor_index;
+        let mut in_word = false;
+        for (i, g) in self.text[..self.cursor_index].grapheme_indices(true).rev() {
+            if g.chars().next().is_some_and(is_word_char) {
+                in_word = true;
+            } else if in_word {
+                break;
+            }
+            index = i;
+        }
+        index
+    }
+
+    // Byte index of the end of the word after the cursor (skips spaces/punctuation first)
+    fn next_word_index(&self) -> usize {
+        let mut index = self.cursor_index;
+        let mut in_word = false;
+        for (i, g) in self.text[self.cursor_index..].grapheme_indices(true) {
+            if g.chars().next().is_some_and(is_word_char) {
+                in_word = true;
+            } else if in_word {
+                break;
+            }
+            index = self.cursor_index + i + g.len();
+        }
+        index
+    }
+
+    // Move the cursor to a new position, extending the selection when shift is held
+    fn move_to(&mut self, index: usize, extend: bool) {
+        if extend {
+            self.selection_anchor.get_or_insert(self.cursor_index);
+        } else {
+            self.selection_anchor = None;
+        }
+        self.cursor_index = index;
+    }
+
+    // Selected byte range as (start, end), or None when nothing is selected
+    fn selection_range(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?;
+        if anchor == self.cursor_index {
+            None
+        } else {
+            Some((anchor.min(self.cursor_index), anchor.max(self.cursor_index)))
+        }
+    }
+
+    // Remove the selected text, returns true if anything was removed
+    fn delete_selection(&mut self) -> bool {
+        match self.selection_range() {
+            Some((start, end)) => {
+                self.text.replace_range(start..end, "");
+                self.cursor_index = start;
+                self.selection_anchor = None;
+                self.text_changed = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Move the cursor one character (or word) left, extending the selection when shift is held
+    fn move_left(&mut self, extend: bool, by_word: bool) {
+        if by_word {
+            self.move_to(self.prev_word_index(), extend);
+        } else if extend {
+            self.move_to(self.prev_char_index(), true);
+        } else if let Some((start, _)) = self.selection_range() {
+            self.move_to(start, false);
+        } else {
+            self.move_to(self.prev_char_index(), false);
+        }
+    }
+
+    // Move the cursor one character (or word) right, extending the selection when shift is held
+    fn move_right(&mut self, extend: bool, by_word: bool) {
+        if by_word {
+            self.move_to(self.next_word_index(), extend);
+        } else if extend {
+            self.move_to(self.next_char_index(), true);
+        } else if let Some((_, end)) = self.selection_range() {
+            self.move_to(end, false);
+        } else {
+            self.move_to(self.next_char_index(), false);
+        }
+    }
+
+    fn delete_backward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        // Step back over mask separators so Backspace removes the typed character before them
+        while self.cursor_index > 0 && self.is_mask_separator_at(self.text[..self.cursor_index].chars().count() - 1) {
+            self.cursor_index = self.prev_char_index();
+        }
+        if self.cursor_index > 0 {
+            let prev = self.prev_char_index();
+            self.text.replace_range(prev..self.cursor_index, "");
+            self.cursor_index = prev;
+            self.text_changed = true;
+        }
+    }
+
+    fn delete_forward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        // Skip over mask separators so Delete removes the typed character after them
+        let mut start = self.cursor_index;
+        while start < self.text.len() && self.is_mask_separator_at(self.text[..start].chars().count()) {
+            start += self.text[start..].chars().next().map_or(0, char::len_utf8);
+        }
+        if start < self.text.len() {
+            let next = start + self.text[start..].graphemes(true).next().map_or(0, str::len);
+            self.text.replace_range(start..next, "");
+            self.text_changed = true;
+        }
+    }
+
+    // Insert pasted text over the selection, flattening line breaks since the box is single line
+    fn insert_pasted(&mut self, text: &str) {
+        let cleaned: String = text
+            .chars()
+            .map(|c| if c == '\n' || c == '\t' { ' ' } else { c })
+            .filter(|c| !c.is_control())
+            .collect();
+        self.delete_selection();
+        self.insert_at_cursor(&cleaned);
+    }
+
+    // The box's own font, or the theme's when it has none
+    fn current_font(&self) -> Option<Font> {
+        self.font.clone().or_else(theme::font)
+    }
+
+    fn current_font_id(&self) -> usize {
+        if self.font.is_some() { self.font_id } else { theme::font_id() }
+    }
+
+    // Width of a piece of text measured one character at a time (matches how the cursor is placed)
+    fn text_width(&self, text: &str) -> f32 {
+        if self.password {
+            let hidden = "*".repeat(text.graphemes(true).count());
+            return glyph_cache::text_width(&hidden, self.current_font().as_ref(), self.current_font_id(), self.font_size as u16);
+        }
+        glyph_cache::text_width(text, self.current_font().as_ref(), self.current_font_id(), self.font_size as u16)
+    }
+
+    // What is drawn in the box (stars in place of a password)
+    fn shown_text(&self) -> String {
+        if self.password {
+            "*".repeat(self.text.graphemes(true).count())
+        } else {
+            self.text.clone()
+        }
+    }
+
+    // Scroll just enough to keep the cursor inside the box
+    fn update_scroll(&mut self) {
+        let visible_width = (self.width - self.padding * 2.0).max(0.0);
+        let cursor_x = self.text_width(&self.text[..self.cursor_index]);
+        let max_scroll = (self.text_width(&self.text) - visible_width).max(0.0);
+
+        if cursor_x < self.scroll_offset {
+            self.scroll_offset = cursor_x;
+        } else if cursor_x > self.scroll_offset + visible_width {
+            self.scroll_offset = cursor_x - visible_width;
+        }
+        // Don't leave empty space at the end after deleting text
+        self.scroll_offset = self.scroll_offset.clamp(0.0, max_scroll);
+    }
+
+    // Screen x where text of the given width starts, after alignment and scrolling
+    fn aligned_x(&self, content_width: f32) -> f32 {
+        let visible_width = (self.width - self.padding * 2.0).max(0.0);
+        let slack = (visible_width - content_width).max(0.0);
+        let align_offset = match self.text_align {
+            TextAlign::Left => 0.0,
+            TextAlign::Center => slack / 2.0,
+            TextAlign::Right => slack,
+        };
+        self.x + self.padding + align_offset - self.scroll_offset
+    }
+
+    // Byte index of the character boundary closest to an x position on screen
+    fn index_at_x(&self, x: f32) -> usize {
+        let mouse_pos = x - self.aligned_x(self.text_width(&self.text));
+        let mut offset = 0.0;
+        for (index, g) in self.text.grapheme_indices(true) {
+            let char_width = self.text_width(g);
+            if offset + char_width / 2.0 > mouse_pos {
+                return index;
+            }
+            offset += char_width;
+        }
+        self.text.len()
+    }
+
+    // Now private - internal implementation only
+    fn update_internal(&mut self) {
+        self.submitted = false;
+        self.changed = false;
+
+        // Skip all interaction if disabled
+        if !self.enabled {
+            self.active = false;
+            self.cursor_visible = false;
+            ime::disable(self.focus_id);
+            ime::remove_tap_area(self.focus_id);
+            return;
+        }
+
+        // Tapping the box on a touch screen opens the on-screen keyboard (see the ime module)
+        ime::set_tap_area(self.focus_id, Rect::new(self.x, self.y, self.width, self.height));
+        focus::set_area(self.focus_id, Rect::new(self.x, self.y, self.width, self.height));
+
+        // A click on the dropdown is checked before focus is updated, since the dropdown
+        // may hang over another widget that has already taken focus for the click
+        let (mx, my) = mouse_position();
+        let picked = if is_mouse_button_pressed(MouseButton::Left) { self.suggestion_at(mx, my) } else { None };
+        let mut accepted = false;
+        let mut recalled = false; // Text replaced by a history entry this frame
+        if let Some(index) = picked {
+            focus::set_focus(self.focus_id);
+            self.accept_suggestion(index);
+            accepted = true;
+        }
+
+        // Tab / Shift+Tab may move focus here or away from here
+        focus::handle_tab();
+        self.active = focus::is_focused(self.focus_id);
+
+        let mut enter_pressed = false;
+        let shift_down = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        // Ctrl on Windows/Linux, Cmd on macOS
+        let command_down = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl)
+            || is_key_down(KeyCode::LeftSuper) || is_key_down(KeyCode::RightSuper);
+        // Ctrl on Windows/Linux, Alt (Option) on macOS jumps by word
+        let word_down = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl)
+            || is_key_down(KeyCode::LeftAlt) || is_key_down(KeyCode::RightAlt);
+
+        if is_mouse_button_pressed(MouseButton::Left) && picked.is_none() {
+            let was_active = self.active;
+            let clicked_inside = mx >= self.x && mx <= self.x + self.width && my >= self.y && my <= self.y + self.height
+                && focus::is_top_at(self.focus_id, mx, my);
+            // Tapping the on-screen keyboard keeps typing going into this box
+            let on_keyboard = !clicked_inside && virtual_keyboard::covers(mx, my);
+            if clicked_inside {
+                focus::set_focus(self.focus_id);
+            } else if !on_keyboard {
+                focus::release_focus(self.focus_id);
+            }
+            self.active = clicked_inside || (was_active && on_keyboard);
+    
+            if clicked_inside {
+                // Count quick repeated clicks, a double click selects a word and a triple click everything
+                let now = get_time();
+                if was_active && now - self.last_click_time <= MULTI_CLICK_TIME {
+                    self.click_count += 1;
+                } else {
+                    self.click_count = 1;
+                }
+                self.last_click_time = now;
+
+                // Clicking places the cursor, shift+click extends the existing selection
+                let click_index = self.index_at_x(mx);
+                if shift_down && was_active {
+                    self.selection_anchor.get_or_insert(self.cursor_index);
+                    self.cursor_index = click_index;
+                } else if self.click_count == 2 {
+                    let (start, end) = self.word_range_at(click_index);
+                    self.selection_anchor = Some(start);
+                    self.cursor_index = end;
+                } else if self.click_count >= 3 {
+                    self.select_all();
+                } else {
+                    self.selection_anchor = Some(click_index);
+                    self.cursor_index = click_index;
+                }
+                // Dragging after a double/triple click would throw away the word selection
+                self.dragging = self.click_count == 1 || shift_down;
+            } else if !self.active {
+                self.selection_anchor = None;
+            }
+        }
+
+        // Dragging with the mouse held down moves the cursor end of the selection
+        if self.dragging {
+            if is_mouse_button_down(MouseButton::Left) {
+                self.cursor_index = self.index_at_x(mx);
+            } else {
+                self.dragging = false;
+            }
+        }
+    
+        if self.active {
+            // The arrow keys move the cursor, not focus (keyboard mode)
+            focus::use_arrows();
+            if command_down && is_key_pressed(KeyCode::A) {
+                self.select_all();
+            }
+            if command_down && is_key_pressed(KeyCode::C) {
+                self.copy_selection();
+            }
+            if command_down && is_key_pressed(KeyCode::X) {
+                self.cut_selection();
+            }
+            if command_down && is_key_pressed(KeyCode::V) {
+                self.paste();
+            }
+            if self.paste_pending {
+                if let Some(pasted) = clipboard::take_text() {
+                    self.insert_pasted(&pasted);
+                    self.paste_pending = false;
+                }
+            }
+
+            // Up/Down move through the suggestions, Escape hides them
+            let suggestion_count = self.visible_suggestions().len();
+            if suggestion_count > 0 {
+                if is_key_pressed(KeyCode::Down) {
+                    self.suggestion_index = Some(self.suggestion_index.map_or(0, |index| (index + 1) % suggestion_count));
+                }
+                if is_key_pressed(KeyCode::Up) {
+                    self.suggestion_index = Some(self.suggestion_index.map_or(suggestion_count - 1, |index| {
+                        (index + suggestion_count - 1) % suggestion_count
+                    }));
+                }
+                if is_key_pressed(KeyCode::Escape) {
+                    focus::use_escape();
+                    self.suggestions_hidden = true;
+                    self.suggestion_index = None;
+                }
+            } else if self.history_enabled {
+                // Otherwise Up/Down go through the history
+                if is_key_pressed(KeyCode::Up) {
+                    self.browse_history(true);
+                    recalled = true;
+                }
+                if is_key_pressed(KeyCode::Down) {
+                    self.browse_history(false);
+                    recalled = true;
+                }
+            }
+
+            // Enter picks the highlighted suggestion instead of submitting
+            enter_pressed = is_key_pressed(KeyCode::Enter) || is_key_pressed(KeyCode::KpEnter);
+            if enter_pressed {
+                if let Some(index) = self.suggestion_index.filter(|&index| index < suggestion_count) {
+                    self.accept_suggestion(index);
+                    accepted = true;
+                    enter_pressed = false;
+                }
+            }
+
+            // Handle typing (typed text replaces the selection)
+            while let Some(c) = get_char_pressed() {
+                if !c.is_control() && !command_down {
+                    self.delete_selection();
+                    self.insert_at_cursor(c.encode_utf8(&mut [0; 4]));
+                }
+            }
+
+            // Text confirmed through an input method (see the ime module)
+            if let Some(committed) = ime::take_committed() {
+                self.delete_selection();
+                self.insert_at_cursor(&committed);
+            }
+
+            // Keys tapped on the on-screen keyboard (see the virtual_keyboard module)
+            for key in virtual_keyboard::take_keys(self.focus_id) {
+                match key {
+                    VirtualKey::Char(c) => {
+                        self.delete_selection();
+                        self.insert_at_cursor(c.encode_utf8(&mut [0; 4]));
+                    }
+                    VirtualKey::Backspace if self.cursor_index > 0 || self.has_selection() => self.delete_backward(),
+                    VirtualKey::Backspace => {}
+                    VirtualKey::Enter => enter_pressed = true,
+                }
+            }
+    
+            // Initial key presses
+            let key_delete_pressed = is_key_pressed(KeyCode::Delete);
+            let key_backspace_pressed = is_key_pressed(KeyCode::Backspace);
+            let key_left_pressed = is_key_pressed(KeyCode::Left);
+            let key_right_pressed = is_key_pressed(KeyCode::Right);
+            let key_home_pressed = is_key_pressed(KeyCode::Home);
+            let key_end_pressed = is_key_pressed(KeyCode::End);
+            
+            // Handle initial key presses
+            if key_delete_pressed && (self.cursor_index < self.text.len() || self.has_selection()) {
+                self.delete_forward();
+                self.last_key = Some(KeyCode::Delete);
+                self.key_repeat_timer = 0.0;
+            } else if key_backspace_pressed && (self.cursor_index > 0 || self.has_selection()) {
+                self.delete_backward();
+                self.last_key = Some(KeyCode::Backspace);
+                self.key_repeat_timer = 0.0;
+            } else if key_left_pressed {
+                self.move_left(shift_down, word_down);
+                self.last_key = Some(KeyCode::Left);
+                self.key_repeat_timer = 0.0;
+            } else if key_right_pressed {
+                self.move_right(shift_down, word_down);
+                self.last_key = Some(KeyCode::Right);
+                self.key_repeat_timer = 0.0;
+            } else if key_home_pressed {
+                self.move_to(0, shift_down);
+                self.last_key = Some(KeyCode::Home);
+                self.key_repeat_timer = 0.0;
+            } else if key_end_pressed {
+                self.move_to(self.text.len(), shift_down);
+                self.last_key = Some(KeyCode::End);
+                self.key_repeat_timer = 0.0;
+            }
+
+            // Handle key repeat functionality
+            if let Some(key) = self.last_key {
+                if is_key_down(key) {
+                    self.key_repeat_timer += get_frame_time();
+                    if self.key_repeat_timer >= self.key_repeat_delay {
+                        self.key_repeat_timer -= self.key_repeat_rate;
+                        match key {
+                            KeyCode::Left => self.move_left(shift_down, word_down),
+                            KeyCode::Right => self.move_right(shift_down, word_down),
+                            KeyCode::Home => self.move_to(0, shift_down),
+                            KeyCode::End => self.move_to(self.text.len(), shift_down),
+                            KeyCode::Delete => self.delete_forward(),
+                            KeyCode::Backspace => self.delete_backward(),
+                            _ => {}
+                        }
+                    }
+                } else {
+                    self.last_key = None;
+                    self.key_repeat_timer = 0.0;
+                }
+            }
+
+            self.cursor_timer += get_frame_time();
+            if self.cursor_blink_interval <= 0.0 {
+                self.cursor_visible = true;
+            } else if self.cursor_timer >= self.cursor_blink_interval {
+                self.cursor_visible = !self.cursor_visible;
+                self.cursor_timer = 0.0;
+            } 
+        } else {
+            self.cursor_visible = false; 
+        }
+
+        if self.text_changed {
+            self.text_changed = false;
+            self.changed = true;
+            // Typing reopens the dropdown, picking a suggestion or a history entry closes it
+            self.suggestions_hidden = accepted || recalled;
+            // Editing the text stops browsing the history
+            if !recalled {
+                self.history_index = None;
+            }
+            self.suggestion_index = None;
+            // The user is fixing the problem, so drop the error set from code
+            self.error = None;
+            self.apply_mask();
+            self.validate();
+            let value = self.get_text();
+            if let Some(callback) = self.on_change.as_mut() {
+                callback(&value);
+            }
+        }
+        if enter_pressed {
+            self.submitted = true;
+            if self.history_enabled {
+                self.add_to_history(self.text.clone());
+            }
+            if self.deactivate_on_submit {
+                focus::release_focus(self.focus_id);
+                self.active = false;
+                self.cursor_visible = false;
+            }
+            let value = self.get_text();
+            if let Some(callback) = self.on_submit.as_mut() {
+                callback(&value);
+            }
+        }
+        self.update_scroll();
+
+        // Keep the input method's popup next to the cursor
+        if self.active {
+            let cursor_x = self.aligned_x(self.text_width(&self.text)) + self.text_width(&self.text[..self.cursor_index]);
+            ime::enable(self.focus_id, cursor_x, self.y + self.height);
+        } else {
+            ime::disable(self.focus_id);
+        }
+    }
+    
+    // Now private - internal implementation only
+    fn draw_internal(&self) {
+        let padding = self.padding;
+        let text_x = self.aligned_x(self.text_width(&self.text));
+        let text_y = self.y + self.height / 2.0 + self.font_size / 2.5;
+
+        // Border color (red while there is an error)
+        let error = self.error.as_deref().or(self.validation_error.as_deref());
+        let border_color = if !self.enabled {
+            theme::palette().text_muted
+        } else if error.is_some() {
+            self.error_color.color()
+        } else {
+            self.border_color.color()
+        };
+        // Radius is limited to half the height so the ends become semicircles at most
+        let radius = self.get_corner_radius().min(self.height / 2.0).min(self.width / 2.0);
+    
+        // Draw the background with customizable colors (or disabled color when disabled)
+        let background = if self.enabled { self.background_color.color() } else { self.disabled_color.color() };
+        if radius > 0.0 {
+            draw_round_rect(self.x, self.y, self.width, self.height, radius, background);
+        } else {
+            draw_rectangle(self.x, self.y, self.width, self.height, background);
+        }
+        
+        // Keep the text, selection and cursor inside the border
+        let inset = self.border_thickness;
+        push_clip_rect(Rect::new(self.x + inset, self.y + inset, self.width - inset * 2.0, self.height - inset * 2.0));
+
+        // Highlight the selected range behind the text
+        if self.enabled && self.active {
+            if let Some((start, end)) = self.selection_range() {
+                let start_x = text_x + self.text_width(&self.text[..start]);
+                let end_x = text_x + self.text_width(&self.text[..end]);
+                draw_rectangle(
+                    start_x,
+                    text_y - self.font_size * 0.8,
+                    end_x - start_x,
+                    self.font_size,
+                    self.selection_color.color(),
+                );
+            }
+        }
+
+        // Draw text with the appropriate font and color based on enabled state
+        let text_color = if self.enabled { self.text_color.color() } else { theme::palette().text_muted };
+        let prompt_color = if self.enabled { self.prompt_color.color() } else { theme::palette().text_muted };
+        
+        if self.text.is_empty() {
+            if let Some(prompt) = &self.prompt {
+                let text_x = self.aligned_x(self.text_width(prompt));
+                match &self.current_font() {
+                    Some(font) => {
+                        draw_text_ex(
+                            prompt,
+                            text_x,
+                            text_y,
+                            TextParams {
+                                font: Some(font),
+                                font_size: self.font_size as u16,
+                                color: prompt_color,
+                                ..Default::default()
+                            },
+                        );
+                    },
+                    None => {
+                        draw_text(prompt, text_x, text_y, self.font_size, prompt_color);
+                    }
+                }
+            }
+        } else {
+            let shown = self.shown_text();
+            match &self.current_font() {
+                Some(font) => {
+                    draw_text_ex(
+                        &shown,
+                        text_x,
+                        text_y,
+                        TextParams {
+                            font: Some(font),
+                            font_size: self.font_size as u16,
+                            color: text_color,
+                            ..Default::default()
+                        },
+                    );
+                },
+                None => {
+                    draw_text(&shown, text_x, text_y, self.font_size, text_color);
+                }
+            }
+        }
+    
+        // Text still being composed with an input method is drawn underlined at the cursor
+        let preedit = if self.enabled && self.active { ime::preedit() } else { String::new() };
+        if !preedit.is_empty() {
+            let preedit_x = text_x + self.text_width(&self.text[..self.cursor_index]);
+            let preedit_width = self.text_width(&preedit);
+            draw_rectangle(preedit_x, self.y, preedit_width, self.height, self.background_color.color());
+            draw_text_ex(
+                &preedit,
+                preedit_x,
+                text_y,
+                TextParams {
+                    font: self.current_font().as_ref(),
+                    font_size: self.font_size as u16,
+                    color: text_color,
+                    ..Default::default()
+                },
+            );
+            draw_line(preedit_x, text_y + 3.0, preedit_x + preedit_width, text_y + 3.0, 1.0, text_color);
+        }
+
+        // Only show cursor if enabled and active
+        if self.enabled && self.active && self.cursor_visible {
+            // Calculate cursor position based on font (after any text being composed)
+            let cursor_offset = self.text_width(&self.text[..self.cursor_index]) + self.text_width(&preedit);
+    
+            // Block and underline cursors cover the next character (or a space-sized gap at the end)
+            let next_char_width = self.text[self.cursor_index..]
+                .graphemes(true)
+                .next()
+                .filter(|_| preedit.is_empty())
+                .map_or(self.font_size * 0.5, |g| self.text_width(g));
+
+            match self.cursor_style {
+                CursorStyle::Line => {
+                    // Add a small spacing between the text and cursor (2.0 pixels)
+                    let cursor_spacing = 2.0;
+
+                    // Draw the cursor with customizable color and added spacing
+                    draw_line(
+                        text_x + cursor_offset + cursor_spacing,
+                        text_y - self.font_size * 0.7,  // Reduce the height of cursor above text
+                        text_x + cursor_offset + cursor_spacing,
+                        text_y + 2.0,  // Reduce the height of cursor below text
+                        self.cursor_width,
+                        self.cursor_color.color(),
+                    );
+                }
+                CursorStyle::Block => {
+                    // See-through so the character underneath stays readable
+                    let mut block_color = self.cursor_color.color();
+                    block_color.a *= 0.5;
+                    draw_rectangle(
+                        text_x + cursor_offset,
+                        text_y - self.font_size * 0.75,
+                        next_char_width,
+                        self.font_size * 0.75 + 3.0,
+                        block_color,
+                    );
+                }
+                CursorStyle::Underline => {
+                    draw_line(
+                        text_x + cursor_offset,
+                        text_y + 3.0,
+                        text_x + cursor_offset + next_char_width,
+                        text_y + 3.0,
+                        self.cursor_width.max(2.0),
+                        self.cursor_color.color(),
+                    );
+                }
+            }
+        }
+
+        pop_clip_rect();
+    
+        // Draw the border with customizable color and thickness, just inside the edge
+        if self.border_thickness > 0.0 {
+            if radius > 0.0 {
+                let half = self.border_thickness / 2.0;
+                draw_round_rect_lines(
+                    self.x + half,
+                    self.y + half,
+                    self.width - self.border_thickness,
+                    self.height - self.border_thickness,
+                    (radius - half).max(0.0),
+                    self.border_thickness,
+                    border_color,
+                );
+            } else {
+                draw_rectangle_lines(self.x, self.y, self.width, self.height, self.border_thickness, border_color);
+            }
+        }
+
+        // Draw the error message just under the box
+        if let Some(message) = error.filter(|_| self.enabled) {
+            let message_size = self.font_size * 0.6;
+            draw_text_ex(
+                message,
+                self.x,
+                self.y + self.height + message_size + 2.0,
+                TextParams {
+                    font: self.current_font().as_ref(),
+                    font_size: message_size as u16,
+                    color: self.error_color.color(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        // Draw the "used/max" counter in the bottom right corner
+        if self.show_counter {
+            if let Some(max) = self.max_length {
+                let used = self.text.graphemes(true).count();
+                let counter = format!("{}/{}", used, max);
+                let counter_color = if !self.enabled {
+                    theme::palette().text_muted
+                } else if used as f32 >= max as f32 * self.counter_warning_threshold {
+                    self.counter_warning_color.color()
+                } else {
+                    self.counter_color.color()
+                };
+                let counter_size = (self.font_size * 0.5) as u16;
+                let counter_width = measure_text(&counter, self.current_font().as_ref(), counter_size, 1.0).width;
+                draw_text_ex(
+                    &counter,
+                    self.x + self.width - counter_width - padding,
+                    self.y + self.height - padding,
+                    TextParams {
+                        font: self.current_font().as_ref(),
+                        font_size: counter_size,
+                        color: counter_color,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        // Draw the autocomplete dropdown below the box
+        let suggestions = self.visible_suggestions();
+        if !suggestions.is_empty() {
+            let row_height = self.suggestion_row_height();
+            let top = self.y + self.height;
+            let list_height = row_height * suggestions.len() as f32;
+            let (mx, my) = mouse_position();
+            let hovered = self.suggestion_at(mx, my);
+
+            draw_rectangle(self.x, top, self.width, list_height, self.background_color.color());
+            push_clip_rect(Rect::new(self.x, top, self.width, list_height));
+            for (index, suggestion) in suggestions.iter().enumerate() {
+                let row_y = top + row_height * index as f32;
+                if self.suggestion_index == Some(index) || hovered == Some(index) {
+                    draw_rectangle(self.x, row_y, self.width, row_height, self.selection_color.color());
+                }
+                draw_text_ex(
+                    suggestion,
+                    self.x + padding,
+                    row_y + row_height / 2.0 + self.font_size / 2.5,
+                    TextParams {
+                        font: self.current_font().as_ref(),
+                        font_size: self.font_size as u16,
+                        color: self.text_color.color(),
+                        ..Default::default()
+                    },
+                );
+            }
+            pop_clip_rect();
+            draw_rectangle_lines(self.x, top, self.width, list_height, 1.0, self.border_color.color());
+        }
+    }
+}
+
+impl Drop for TextInput {
+    fn drop(&mut self) {
+        focus::unregister(self.focus_id);
+        ime::disable(self.focus_id);
+        ime::remove_tap_area(self.focus_id);
+        glyph_cache::forget_font_id(self.font_id);
+    }
+}
+
+// Mask characters that stand for something typed: # digit, A letter, * anything
+fn is_mask_slot(m: char) -> bool {
+    matches!(m, '#' | 'A' | '*')
+}
+
+fn mask_slot_allows(slot: char, c: char) -> bool {
+    match slot {
+        '#' => c.is_ascii_digit(),
+        'A' => c.is_alphabetic(),
+        _ => true,
+    }
+}
+
+// True if a character is one of the mask's separators
+fn is_mask_literal(mask: &str, c: char) -> bool {
+    mask.chars().any(|m| !is_mask_slot(m) && m == c)
+}
+
+// Letters, numbers and underscores make up words for ctrl+arrow movement
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
@@ -0,0 +1,407 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+One place for the colors, font, corner rounding and spacing the widgets use, with a light,
+a dark and a high contrast theme built in and switching between them while the game runs
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::theme::{self, Theme};
+
+Then switch themes whenever you like (a settings toggle, a key, ...):
+    if btn_dark_mode.click() {
+        theme::set_theme(if theme::is_dark() { Theme::light() } else { Theme::dark() });
+    }
+    clear_background(theme::palette().background);  // At the top of the loop
+Every widget picks the change up the next time it is drawn.
+Theme::high_contrast() is white and yellow on black with bigger text, use it together with
+focus::set_keyboard_mode(true) for players who don't use a mouse or see poorly.
+
+HOW IT WORKS:
+    A theme has a palette of colors that each have a job (surface for the inside of boxes,
+    primary for the accent color, text, border, ...), an optional font, a default font
+    size, a corner radius and the spacing between things. Widgets look their colors up in
+    the active theme every time they draw instead of keeping their own, so nothing has to
+    be rebuilt when the theme changes.
+    Colors you give a widget yourself (with_colors, with_border, ...) are kept no matter
+    which theme is active. They can also be given as a ThemeColor to stay with the theme
+    while using a different job's color:
+        txt_name.with_colors(ThemeColor::SurfaceAlt, ThemeColor::Primary);  // Follows the theme
+        txt_name.with_colors(WHITE, BLUE);                                  // Always these
+    TextButton::new takes its colors directly, so those buttons always use the colors they
+    were made with. Use TextButton::themed for a button that follows the theme.
+
+YOUR OWN THEME:
+    let mut my_theme = Theme::dark();
+    my_theme.palette.primary = ORANGE;
+    my_theme.with_font(assets::load_font("assets/fonts/Roboto.ttf").await.ok())
+        .with_corner_radius(10.0)
+        .with_spacing(12.0);
+    theme::set_theme(my_theme);
+
+OTHER FUNCTIONS:
+    let palette = theme::palette();                 // The active colors (palette.text, ...)
+    let color = ThemeColor::Primary.color();        // One color from the active theme
+    let font = theme::font();                       // Option<Font>, None is the default font
+    let size = theme::font_size();
+    let radius = theme::corner_radius();
+    let gap = theme::spacing();
+    let active = theme::current();                  // A copy of the whole theme
+    let changes = theme::generation();              // Goes up by one every set_theme
+
+DRAWING:
+    The rounded boxes the widgets draw, for your own widgets to match them:
+    theme::draw_round_rect(x, y, w, h, theme::corner_radius(), theme::palette().surface);
+    theme::draw_round_rect_lines(x, y, w, h, theme::corner_radius(), 2.0, theme::palette().border);
+    The edges are faded over a pixel so the corners don't look jagged.
+*/
+
+use macroquad::prelude::*;
+use crate::modules::glyph_cache;
+use std::cell::{Cell, RefCell};
+
+thread_local! {
+    static ACTIVE: RefCell<Theme> = RefCell::new(Theme::light());
+    // The active font's id in the glyph width cache (0 for the default font)
+    static FONT_ID: Cell<usize> = const { Cell::new(0) };
+    // Counts set_theme calls, so caches can tell the theme changed
+    static GENERATION: Cell<u64> = const { Cell::new(0) };
+}
+
+// The colors of a theme, each with its own job
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Palette {
+    pub background: Color,  // Behind everything
+    pub surface: Color,     // The inside of boxes, panels and cards
+    pub surface_alt: Color, // Headers, hovered rows and other things set apart from the surface
+    pub primary: Color,     // The accent (buttons, selected items, focus outlines)
+    pub on_primary: Color,  // Text and icons drawn on the accent
+    pub text: Color,
+    pub text_muted: Color,  // Prompts, footers and disabled text
+    pub border: Color,
+    pub selection: Color,   // Behind selected text
+    pub error: Color,
+    pub success: Color,
+    pub shadow: Color,
+}
+
+#[derive(Clone)]
+pub struct Theme {
+    pub palette: Palette,
+    pub font: Option<Font>, // None uses macroquad's default font
+    pub font_size: u16,
+    pub corner_radius: f32,
+    pub spacing: f32,
+    pub dark: bool,
+}
+
+impl Theme {
+    // Dark text on light boxes
+    #[allow(unused)]
+    pub fn light() -> Self {
+        Self {
+            palette: Palette {
+                background: Color::new(0.94, 0.94, 0.95, 1.0),
+                surface: WHITE,
+                surface_alt: Color::new(0.9, 0.9, 0.92, 1.0),
+                primary: DARKBLUE,
+                on_primary: WHITE,
+                text: BLACK,
+                text_muted: GRAY,
+                border: DARKGRAY,
+                selection: Color::new(0.2, 0.5, 1.0, 0.35),
+                error: RED,
+                success: DARKGREEN,
+                shadow: Color::new(0.0, 0.0, 0.0, 0.25),
+            },
+            font: None,
+            font_size: 24,
+            corner_radius: 6.0,
+            spacing: 8.0,
+            dark: false,
+        }
+    }
+
+    // Light text on dark boxes
+    #[allow(unused)]
+    pub fn dark() -> Self {
+        Self {
+            palette: Palette {
+                background: Color::new(0.1, 0.1, 0.12, 1.0),
+                surface: Color::new(0.17, 0.17, 0.2, 1.0),
+                surface_alt: Color::new(0.24, 0.24, 0.28, 1.0),
+                primary: Color::new(0.3, 0.55, 0.95, 1.0),
+                on_primary: WHITE,
+                text: Color::new(0.92, 0.92, 0.94, 1.0),
+                text_muted: Color::new(0.6, 0.6, 0.65, 1.0),
+                border: Color::new(0.45, 0.45, 0.5, 1.0),
+                selection: Color::new(0.3, 0.55, 0.95, 0.45),
+                error: Color::new(1.0, 0.4, 0.4, 1.0),
+                success: Color::new(0.4, 0.85, 0.5, 1.0),
+                shadow: Color::new(0.0, 0.0, 0.0, 0.5),
+            },
+            font: None,
+            font_size: 24,
+            corner_radius: 6.0,
+            spacing: 8.0,
+            dark: true,
+        }
+    }
+
+    // White and yellow on black with bigger text, for low vision players
+    #[allow(unused)]
+    pub fn high_contrast() -> Self {
+        Self {
+            palette: Palette {
+                background: BLACK,
+                surface: BLACK,
+                surface_alt: Color::new(0.2, 0.2, 0.2, 1.0),
+                primary: YELLOW,
+                on_primary: BLACK,
+                text: WHITE,
+                text_muted: Color::new(0.85, 0.85, 0.85, 1.0),
+                border: WHITE,
+                selection: Color::new(0.0, 0.45, 1.0, 0.7),
+                error: Color::new(1.0, 0.5, 0.5, 1.0),
+                success: Color::new(0.4, 1.0, 0.4, 1.0),
+                shadow: Color::new(0.0, 0.0, 0.0, 0.0),
+            },
+            font: None,
+            font_size: 30,
+            corner_radius: 0.0,
+            spacing: 10.0,
+            dark: true,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn with_palette(&mut self, palette: Palette) -> &mut Self {
+        self.palette = palette;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font(&mut self, font: Option<Font>) -> &mut Self {
+        self.font = font;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_corner_radius(&mut self, radius: f32) -> &mut Self {
+        self.corner_radius = radius.max(0.0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_spacing(&mut self, spacing: f32) -> &mut Self {
+        self.spacing = spacing.max(0.0);
+        self
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+// A color that either follows the active theme (by its job in the palette) or is fixed
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum ThemeColor {
+    Background,
+    Surface,
+    SurfaceAlt,
+    Primary,
+    OnPrimary,
+    Text,
+    TextMuted,
+    Border,
+    Selection,
+    Error,
+    Success,
+    Shadow,
+    Custom(Color), // Always this color, whatever the theme
+}
+
+impl ThemeColor {
+    // The color to draw with right now
+    pub fn color(self) -> Color {
+        let palette = palette();
+        match self {
+            ThemeColor::Background => palette.background,
+            ThemeColor::Surface => palette.surface,
+            ThemeColor::SurfaceAlt => palette.surface_alt,
+            ThemeColor::Primary => palette.primary,
+            ThemeColor::OnPrimary => palette.on_primary,
+            ThemeColor::Text => palette.text,
+            ThemeColor::TextMuted => palette.text_muted,
+            ThemeColor::Border => palette.border,
+            ThemeColor::Selection => palette.selection,
+            ThemeColor::Error => palette.error,
+            ThemeColor::Success => palette.success,
+            ThemeColor::Shadow => palette.shadow,
+            ThemeColor::Custom(color) => color,
+        }
+    }
+}
+
+impl From<Color> for ThemeColor {
+    fn from(color: Color) -> Self {
+        ThemeColor::Custom(color)
+    }
+}
+
+/// Make a theme the active one, widgets use it from their next draw
+#[allow(unused)]
+pub fn set_theme(theme: Theme) {
+    // A new id for the new theme's font, the old one's widths aren't needed any more
+    FONT_ID.with(|id| {
+        glyph_cache::forget_font_id(id.get());
+        id.set(if theme.font.is_some() { glyph_cache::new_font_id() } else { 0 });
+    });
+    ACTIVE.with(|active| *active.borrow_mut() = theme);
+    GENERATION.with(|generation| generation.set(generation.get() + 1));
+}
+
+/// Goes up by one every set_theme, for noticing the theme changed (draw_cache uses it)
+pub fn generation() -> u64 {
+    GENERATION.with(Cell::get)
+}
+
+/// A copy of the active theme
+#[allow(unused)]
+pub fn current() -> Theme {
+    ACTIVE.with(|active| active.borrow().clone())
+}
+
+/// The active theme's colors
+pub fn palette() -> Palette {
+    ACTIVE.with(|active| active.borrow().palette)
+}
+
+/// The active theme's font (None is macroquad's default font)
+#[allow(unused)]
+pub fn font() -> Option<Font> {
+    ACTIVE.with(|active| active.borrow().font.clone())
+}
+
+/// Id of the active theme's font for glyph_cache
+pub fn font_id() -> usize {
+    FONT_ID.with(Cell::get)
+}
+
+#[allow(unused)]
+pub fn font_size() -> u16 {
+    ACTIVE.with(|active| active.borrow().font_size)
+}
+
+#[allow(unused)]
+pub fn corner_radius() -> f32 {
+    ACTIVE.with(|active| active.borrow().corner_radius)
+}
+
+#[allow(unused)]
+pub fn spacing() -> f32 {
+    ACTIVE.with(|active| active.borrow().spacing)
+}
+
+/// Whether the active theme is a dark one
+#[allow(unused)]
+pub fn is_dark() -> bool {
+    ACTIVE.with(|active| active.borrow().dark)
+}
+
+// Width of the soft edge that smooths rounded shapes (macroquad doesn't anti-alias by default)
+const FEATHER: f32 = 1.0;
+
+// Points around a rounded rectangle as (corner center, outward direction) pairs, clockwise from the top-left
+fn round_rect_outline(x: f32, y: f32, w: f32, h: f32, radius: f32) -> Vec<(Vec2, Vec2)> {
+    let radius = radius.min(w / 2.0).min(h / 2.0).max(0.0);
+    // More segments for bigger corners so they stay smooth
+    let segments = (radius.sqrt() * 2.0).clamp(4.0, 24.0) as usize;
+    let corners = [
+        (Vec2::new(x + radius, y + radius), std::f32::consts::PI),             // Top-left
+        (Vec2::new(x + w - radius, y + radius), std::f32::consts::PI * 1.5),   // Top-right
+        (Vec2::new(x + w - radius, y + h - radius), 0.0),                      // Bottom-right
+        (Vec2::new(x + radius, y + h - radius), std::f32::consts::PI * 0.5),   // Bottom-left
+    ];
+    let mut points = Vec::with_capacity(corners.len() * (segments + 1));
+    for (center, start_angle) in corners {
+        for i in 0..=segments {
+            let angle = start_angle + std::f32::consts::FRAC_PI_2 * i as f32 / segments as f32;
+            points.push((center, Vec2::new(angle.cos(), angle.sin())));
+        }
+    }
+    points
+}
+
+// Add a band between two offsets from the outline, fading from inner_color to outer_color
+fn push_band(mesh: &mut Mesh, outline: &[(Vec2, Vec2)], radius: f32, inner: f32, outer: f32, inner_color: Color, outer_color: Color) {
+    let first = mesh.vertices.len() as u16;
+    for &(center, normal) in outline {
+        let inner_point = center + normal * (radius + inner).max(0.0);
+        let outer_point = center + normal * (radius + outer).max(0.0);
+        mesh.vertices.push(Vertex::new(inner_point.x, inner_point.y, 0.0, 0.0, 0.0, inner_color));
+        mesh.vertices.push(Vertex::new(outer_point.x, outer_point.y, 0.0, 0.0, 0.0, outer_color));
+    }
+    let count = outline.len() as u16;
+    for i in 0..count {
+        let next = (i + 1) % count;
+        let (a, b, c, d) = (first + i * 2, first + i * 2 + 1, first + next * 2, first + next * 2 + 1);
+        mesh.indices.extend_from_slice(&[a, b, c, c, b, d]);
+    }
+}
+
+/// Draw a filled rectangle with rounded, softened corners (square when the radius is 0).
+/// Nothing is drawn twice, so see-through colors come out even
+#[allow(unused)]
+pub fn draw_round_rect(x: f32, y: f32, w: f32, h: f32, radius: f32, color: Color) {
+    let radius = radius.min(w / 2.0).min(h / 2.0);
+    if radius <= 0.0 {
+        draw_rectangle(x, y, w, h, color);
+        return;
+    }
+    let outline = round_rect_outline(x, y, w, h, radius);
+    let mut mesh = Mesh { vertices: Vec::new(), indices: Vec::new(), texture: None };
+
+    // Fill as a fan from the middle (the shape is convex)
+    let middle = Vec2::new(x + w / 2.0, y + h / 2.0);
+    mesh.vertices.push(Vertex::new(middle.x, middle.y, 0.0, 0.0, 0.0, color));
+    for &(center, normal) in &outline {
+        let point = center + normal * radius;
+        mesh.vertices.push(Vertex::new(point.x, point.y, 0.0, 0.0, 0.0, color));
+    }
+    let count = outline.len() as u16;
+    for i in 0..count {
+        mesh.indices.extend_from_slice(&[0, i + 1, (i + 1) % count + 1]);
+    }
+
+    // Fade the edge out over a pixel to hide the jagged steps
+    let transparent = Color::new(color.r, color.g, color.b, 0.0);
+    push_band(&mut mesh, &outline, radius, 0.0, FEATHER, color, transparent);
+    draw_mesh(&mesh);
+}
+
+/// Draw a rounded rectangle border (centered on the edge) with softened edges
+#[allow(unused)]
+pub fn draw_round_rect_lines(x: f32, y: f32, w: f32, h: f32, radius: f32, thickness: f32, color: Color) {
+    let radius = radius.min(w / 2.0).min(h / 2.0);
+    let outline = round_rect_outline(x, y, w, h, radius);
+    let mut mesh = Mesh { vertices: Vec::new(), indices: Vec::new(), texture: None };
+    let half = thickness / 2.0;
+    let transparent = Color::new(color.r, color.g, color.b, 0.0);
+
+    push_band(&mut mesh, &outline, radius, -half, half, color, color);
+    push_band(&mut mesh, &outline, radius, half, half + FEATHER, color, transparent);
+    push_band(&mut mesh, &outline, radius, -half - FEATHER, -half, transparent, color);
+    draw_mesh(&mesh);
+}
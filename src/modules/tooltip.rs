@@ -0,0 +1,261 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Shows a small tooltip bubble next to the mouse after it has rested on a widget for a moment
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod tooltip;
+
+This also needs these modules:
+    pub mod scale;
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::tooltip;
+
+HOW IT WORKS:
+    One TooltipManager keeps track of every tooltip. Widgets tell it about their tooltip
+    every frame, either by calling tooltip::hover while the mouse is over them, or by
+    calling tooltip::register with the area the tooltip belongs to (the manager then checks
+    the mouse itself, and the last area registered wins where they overlap). Once the same
+    widget has been hovered for the delay (0.6 seconds by default) its text is shown.
+    Long text is wrapped (300 pixels wide by default) and the bubble is kept inside the
+    screen. Tooltips are drawn by tooltip::draw, which goes at the end of the loop so the
+    bubble is on top of everything else. TextButton does all of this for you with
+    set_tooltip, and so does a Label that is cut off with with_truncate.
+
+Usage examples:
+    // On a button
+    btn_save.set_tooltip("Saves your level to the database");
+
+    // At the end of the loop, after everything else is drawn
+    tooltip::draw();
+    next_frame().await;
+
+    // For your own things (the id just has to be different for each thing)
+    let my_tooltip = tooltip::new_id();
+    tooltip::register(my_tooltip, my_rect, "My own tooltip"); // Every frame, in the loop
+
+    // Or check the mouse yourself
+    if my_rect.contains(mouse_pos) {
+        tooltip::hover(my_tooltip, "My own tooltip");
+    }
+
+    // Change how it looks and how long it waits
+    tooltip::set_delay(1.0);
+    tooltip::set_style(18, WHITE, Color::new(0.1, 0.1, 0.1, 0.9)); // Font size, text, background (default from the theme)
+    tooltip::set_max_width(400.0);
+
+    // Or change several things at once on the manager
+    tooltip::with_manager(|manager| {
+        manager.set_delay(0.3);
+        manager.set_max_width(250.0);
+    });
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale;
+use crate::modules::theme::ThemeColor;
+use std::cell::{Cell, RefCell};
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Space between the text and the edge of the bubble
+const PADDING: f32 = 6.0;
+// How far the bubble sits from the mouse
+const MOUSE_OFFSET: f32 = 16.0;
+
+thread_local! {
+    static MANAGER: RefCell<TooltipManager> = const { RefCell::new(TooltipManager::new()) };
+    // Ids handed out by new_id, counting down so they never meet the focus module's ids
+    static NEXT_ID: Cell<usize> = const { Cell::new(usize::MAX) };
+}
+
+// Keeps track of what is hovered and draws its tooltip
+pub struct TooltipManager {
+    hovered: Option<(usize, f64, String)>, // Widget being hovered, when it started, and its tooltip text
+    hovered_this_frame: bool,              // Whether something was hovered since the last draw
+    regions: Vec<(usize, Rect, String)>,   // Areas registered since the last draw
+    delay: f64,
+    font_size: u16,
+    text_color: ThemeColor,
+    background_color: ThemeColor,
+    max_width: f32,
+}
+
+impl TooltipManager {
+    const fn new() -> Self {
+        Self {
+            hovered: None,
+            hovered_this_frame: false,
+            regions: Vec::new(),
+            delay: 0.6,
+            font_size: 18,
+            text_color: ThemeColor::Surface,
+            background_color: ThemeColor::Text,
+            max_width: 300.0,
+        }
+    }
+
+    // The mouse is over a widget this frame
+    pub fn hover(&mut self, id: usize, text: &str) {
+        self.hovered_this_frame = true;
+        match self.hovered.as_mut() {
+            Some((current, _, current_text)) if *current == id => {
+                if current_text != text {
+                    *current_text = text.to_string();
+                }
+            }
+            _ => self.hovered = Some((id, get_time(), text.to_string())),
+        }
+    }
+
+    // A widget has a tooltip over this area this frame
+    pub fn register(&mut self, id: usize, area: Rect, text: &str) {
+        self.regions.push((id, area, text.to_string()));
+    }
+
+    #[allow(unused)]
+    pub fn set_delay(&mut self, seconds: f64) {
+        self.delay = seconds.max(0.0);
+    }
+
+    #[allow(unused)]
+    pub fn set_style(&mut self, font_size: u16, text_color: impl Into<ThemeColor>, background_color: impl Into<ThemeColor>) {
+        self.font_size = font_size;
+        self.text_color = text_color.into();
+        self.background_color = background_color.into();
+    }
+
+    // Text wider than this wraps onto more lines
+    #[allow(unused)]
+    pub fn set_max_width(&mut self, width: f32) {
+        self.max_width = width.max(self.font_size as f32);
+    }
+
+    // Draw the tooltip if one is due
+    pub fn draw(&mut self) {
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+        // The area registered last is on top
+        let regions = std::mem::take(&mut self.regions);
+        if let Some((id, _, text)) = regions.iter().rev().find(|(_, area, _)| area.contains(mouse)) {
+            self.hover(*id, text);
+        }
+
+        if !std::mem::replace(&mut self.hovered_this_frame, false) {
+            self.hovered = None;
+            return;
+        }
+        let Some(text) = self
+            .hovered
+            .as_ref()
+            .filter(|(_, since, text)| !text.is_empty() && get_time() - since >= self.delay)
+            .map(|(_, _, text)| text.clone())
+        else {
+            return;
+        };
+
+        let lines = self.wrap(&text);
+        let line_height = self.font_size as f32;
+        let text_width = lines.iter().map(|line| measure_text(line, None, self.font_size, 1.0).width).fold(0.0, f32::max);
+        let width = text_width + PADDING * 2.0;
+        let height = line_height * lines.len() as f32 + PADDING * 2.0;
+
+        // Below and to the right of the mouse, flipped (then pushed) to stay inside the view
+        let view = scale::visible_area();
+        let mut x = mouse_x + MOUSE_OFFSET;
+        let mut y = mouse_y + MOUSE_OFFSET;
+        if x + width > view.right() {
+            x = mouse_x - width;
+        }
+        if y + height > view.bottom() {
+            y = mouse_y - height - PADDING;
+        }
+        x = x.min(view.right() - width).max(view.x);
+        y = y.min(view.bottom() - height).max(view.y);
+
+        draw_rectangle(x, y, width, height, self.background_color.color());
+        for (index, line) in lines.iter().enumerate() {
+            let baseline = y + PADDING + line_height * index as f32 + line_height * 0.75;
+            draw_text(line, x + PADDING, baseline, self.font_size as f32, self.text_color.color());
+        }
+    }
+
+    // Split the text into lines no wider than max_width (breaking at spaces where it can)
+    fn wrap(&self, text: &str) -> Vec<String> {
+        let fits = |line: &str| measure_text(line, None, self.font_size, 1.0).width <= self.max_width;
+        let mut lines = Vec::new();
+        for paragraph in text.split('\n') {
+            let mut line = String::new();
+            for word in paragraph.split(' ') {
+                let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+                if fits(&candidate) {
+                    line = candidate;
+                    continue;
+                }
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                // A single word too long for a line is broken wherever it has to be
+                for character in word.chars() {
+                    line.push(character);
+                    if !fits(&line) && line.chars().count() > 1 {
+                        line.pop();
+                        lines.push(std::mem::replace(&mut line, character.to_string()));
+                    }
+                }
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+/// Run code with the tooltip manager (to change several settings at once)
+#[allow(unused)]
+pub fn with_manager<R>(f: impl FnOnce(&mut TooltipManager) -> R) -> R {
+    MANAGER.with(|manager| f(&mut manager.borrow_mut()))
+}
+
+/// Get an id for something that isn't in the focus module but wants a tooltip
+pub fn new_id() -> usize {
+    NEXT_ID.with(|next| {
+        let id = next.get();
+        next.set(id - 1);
+        id
+    })
+}
+
+/// Tell the tooltip system the mouse is over a widget this frame
+pub fn hover(id: usize, text: &str) {
+    with_manager(|manager| manager.hover(id, text));
+}
+
+/// Give a widget a tooltip over an area for this frame (call every frame the widget is drawn)
+#[allow(unused)]
+pub fn register(id: usize, area: Rect, text: &str) {
+    with_manager(|manager| manager.register(id, area, text));
+}
+
+/// Set how long the mouse has to rest on a widget before its tooltip shows
+#[allow(unused)]
+pub fn set_delay(seconds: f64) {
+    with_manager(|manager| manager.set_delay(seconds));
+}
+
+/// Set the font size, text color and background color of tooltips
+#[allow(unused)]
+pub fn set_style(font_size: u16, text_color: Color, background_color: Color) {
+    with_manager(|manager| manager.set_style(font_size, text_color, background_color));
+}
+
+/// Set how wide a tooltip can get before its text wraps
+#[allow(unused)]
+pub fn set_max_width(width: f32) {
+    with_manager(|manager| manager.set_max_width(width));
+}
+
+/// Draw the tooltip if one is due (call once per frame, after everything else is drawn)
+pub fn draw() {
+    with_manager(TooltipManager::draw);
+}
@@ -0,0 +1,489 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+Builds a screen of widgets from a JSON file, so screens can be written as data (and copied
+between projects) instead of as code
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod ui_loader;
+
+This also needs these modules:
+    pub mod assets;
+    pub mod label;
+    pub mod text_area;
+    pub mod text_button;
+    pub mod text_input;
+    pub mod theme;
+    pub mod ui_manager;
+
+Add with the other use statements:
+    use crate::modules::ui_loader::UiLoader;
+
+Write the screen in a file (assets/ui/login.json):
+    {
+        "widgets": [
+            { "type": "label", "id": "lbl_title", "rect": [100, 60], "props": { "text": "Log in", "font_size": 40, "color": "primary" } },
+            { "type": "text_input", "id": "txt_user", "rect": [100, 130, 300, 40], "props": { "prompt": "Username", "max_length": 32 } },
+            { "type": "text_input", "id": "txt_password", "rect": [100, 190, 300, 40], "props": { "prompt": "Password", "password": true } },
+            { "type": "button", "id": "btn_login", "rect": [100, 260, 200, 60], "props": { "text": "Login", "tooltip": "Log in to your account" } }
+        ]
+    }
+Then above the loop section, load it and build it into a UiManager:
+    let mut ui = UiManager::new();
+    let login = UiLoader::load("assets/ui/login.json").await.unwrap();
+    let screen = login.build(&mut ui).unwrap();
+    let btn_login = screen.button("btn_login").unwrap();    // Rc<RefCell<TextButton>>, same as ui.add gives
+    let txt_user = screen.text_input("txt_user").unwrap();
+And use them like any other widget in the manager:
+    ui.draw();
+    if btn_login.borrow().was_clicked() { }
+
+HOW IT WORKS:
+    Each widget has a type, an id (its name in the handles build gives back), a rect and
+    props. The rect is [x, y, width, height], a label can leave the size out ([x, y]) to
+    fit its text. Widgets are added to the manager and to the tab order in the order they
+    are listed. Optional "z": 5 puts one in a layer and "modal": true makes it modal (see
+    Z-ORDER and MODAL LAYER in ui_manager). "parent": "grp_options" makes it hide and fade
+    with a widget listed before it, and "visible": false or "opacity": 0.5 start it hidden
+    or faded (see HIDING AND FADING in ui_manager).
+    Colors are a theme color's name ("primary", "text", "surface_alt", ... any job in the
+    palette, in snake_case), which follows the theme, or a fixed color as "#ff8800",
+    "#ff880080" or [1.0, 0.5, 0.0]. Buttons are themed unless given a color, and take theme
+    colors as they are when the screen is built.
+    A problem in the file (a missing id, an unknown type or prop, a bad color) gives an Err
+    naming the widget and nothing is added to the manager. The same loader can be built
+    more than once, each build makes new widgets.
+
+PROPS:
+    Any widget    "z": 0, "modal": false, "parent": "id", "visible": true, "opacity": 1.0
+                  (next to "props", not in it)
+    group         background (a plain box to hold other widgets)
+    label         text, key (a locale key instead of text), font_size, color, background
+    button        text, font_size, color, hover_color, text_color, tooltip, toggle, enabled
+    text_input    text, prompt, font_size, max_length, password, enabled, text_color,
+                  border_color, background_color
+    text_area     text, prompt, font_size, max_length, enabled, text_color, border_color,
+                  background_color
+    font_size is the theme's font size when it is left out.
+
+OTHER METHODS:
+    let login = UiLoader::parse(include_str!("../../assets/ui/login.json")).unwrap();  // Built into the game
+    let count = login.len();
+    let lbl = screen.label("lbl_title");            // None if there is no label with that id
+    let grp = screen.group("grp_options");
+    let txt = screen.text_area("txt_notes");
+    let ids = screen.ids();                         // In the order they were listed
+    if let Some(WidgetHandle::Button(btn)) = screen.get("btn_login") { }
+*/
+
+use macroquad::prelude::*;
+use crate::modules::assets;
+use crate::modules::label::Label;
+use crate::modules::text_area::TextArea;
+use crate::modules::text_button::TextButton;
+use crate::modules::text_input::TextInput;
+use crate::modules::theme::{self, ThemeColor};
+use crate::modules::ui_manager::{Group, UiManager, Widget};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// The whole file
+#[derive(Deserialize)]
+struct Document {
+    widgets: Vec<WidgetDoc>,
+}
+
+// One widget in the file
+#[derive(Deserialize)]
+struct WidgetDoc {
+    #[serde(rename = "type")]
+    kind: String,
+    id: String,
+    rect: Vec<f32>,
+    #[serde(default)]
+    props: Map<String, Value>,
+    #[serde(default)]
+    z: i32,
+    #[serde(default)]
+    modal: bool,
+    parent: Option<String>,
+    visible: Option<bool>,
+    opacity: Option<f32>,
+}
+
+// A screen read from a file, ready to be built
+pub struct UiLoader {
+    widgets: Vec<WidgetDoc>,
+}
+
+// A widget made by UiLoader::build
+#[allow(unused)]
+pub enum WidgetHandle {
+    Group(Rc<RefCell<Group>>),
+    Label(Rc<RefCell<Label>>),
+    Button(Rc<RefCell<TextButton>>),
+    TextInput(Rc<RefCell<TextInput>>),
+    TextArea(Rc<RefCell<TextArea>>),
+}
+
+// The widgets a build made, by id
+pub struct UiHandles {
+    widgets: HashMap<String, WidgetHandle>,
+    ids: Vec<String>,
+}
+
+impl UiLoader {
+    // Read a screen from a JSON file (works on the web too, see assets)
+    #[allow(unused)]
+    pub async fn load(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let bytes = assets::load_bytes(path).await?;
+        let text = String::from_utf8(bytes).map_err(|_| format!("{} isn't UTF-8 text", path))?;
+        Ok(Self::parse(&text).map_err(|e| format!("{}: {}", path, e))?)
+    }
+
+    // Read a screen from JSON text
+    #[allow(unused)]
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let document: Document = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        let mut seen = Vec::new();
+        for widget in &document.widgets {
+            if widget.id.is_empty() {
+                return Err(format!("A {} has no id", widget.kind));
+            }
+            if seen.contains(&&widget.id) {
+                return Err(format!("{}: the id is used twice", widget.id));
+            }
+            if let Some(parent) = &widget.parent {
+                if !seen.contains(&parent) {
+                    return Err(format!("{}: the parent {} has to be listed before it", widget.id, parent));
+                }
+            }
+            seen.push(&widget.id);
+        }
+        Ok(Self { widgets: document.widgets })
+    }
+
+    // How many widgets are in the screen
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.widgets.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.widgets.is_empty()
+    }
+
+    // Make the widgets and add them to the manager, giving back their handles by id
+    #[allow(unused)]
+    pub fn build(&self, ui: &mut UiManager) -> Result<UiHandles, String> {
+        // Everything is made first so a problem part way through leaves the manager alone
+        let mut made = Vec::with_capacity(self.widgets.len());
+        for widget in &self.widgets {
+            made.push(make(widget).map_err(|e| format!("{}: {}", widget.id, e))?);
+        }
+
+        let mut handles = UiHandles {
+            widgets: HashMap::new(),
+            ids: Vec::new(),
+        };
+        for (widget, handle) in self.widgets.iter().zip(made) {
+            let parent = widget.parent.as_ref().and_then(|parent| handles.widgets.get(parent));
+            match &handle {
+                WidgetHandle::Group(group) => add(ui, group, widget, parent),
+                WidgetHandle::Label(label) => add(ui, label, widget, parent),
+                WidgetHandle::Button(button) => add(ui, button, widget, parent),
+                WidgetHandle::TextInput(input) => add(ui, input, widget, parent),
+                WidgetHandle::TextArea(area) => add(ui, area, widget, parent),
+            }
+            handles.ids.push(widget.id.clone());
+            handles.widgets.insert(widget.id.clone(), handle);
+        }
+        Ok(handles)
+    }
+}
+
+impl UiHandles {
+    #[allow(unused)]
+    pub fn get(&self, id: &str) -> Option<&WidgetHandle> {
+        self.widgets.get(id)
+    }
+
+    #[allow(unused)]
+    pub fn group(&self, id: &str) -> Option<Rc<RefCell<Group>>> {
+        match self.widgets.get(id) {
+            Some(WidgetHandle::Group(group)) => Some(group.clone()),
+            _ => None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn label(&self, id: &str) -> Option<Rc<RefCell<Label>>> {
+        match self.widgets.get(id) {
+            Some(WidgetHandle::Label(label)) => Some(label.clone()),
+            _ => None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn button(&self, id: &str) -> Option<Rc<RefCell<TextButton>>> {
+        match self.widgets.get(id) {
+            Some(WidgetHandle::Button(button)) => Some(button.clone()),
+            _ => None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn text_input(&self, id: &str) -> Option<Rc<RefCell<TextInput>>> {
+        match self.widgets.get(id) {
+            Some(WidgetHandle::TextInput(input)) => Some(input.clone()),
+            _ => None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn text_area(&self, id: &str) -> Option<Rc<RefCell<TextArea>>> {
+        match self.widgets.get(id) {
+            Some(WidgetHandle::TextArea(area)) => Some(area.clone()),
+            _ => None,
+        }
+    }
+
+    // Every id, in the order the widgets were listed
+    #[allow(unused)]
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+}
+
+fn add<W: Widget + 'static>(ui: &mut UiManager, widget: &Rc<RefCell<W>>, doc: &WidgetDoc, parent: Option<&WidgetHandle>) {
+    ui.add_shared(widget.clone());
+    if doc.z != 0 {
+        ui.set_z_index(widget, doc.z);
+    }
+    if doc.modal {
+        ui.set_modal(widget, true);
+    }
+    match parent {
+        Some(WidgetHandle::Group(parent)) => ui.set_parent(widget, parent),
+        Some(WidgetHandle::Label(parent)) => ui.set_parent(widget, parent),
+        Some(WidgetHandle::Button(parent)) => ui.set_parent(widget, parent),
+        Some(WidgetHandle::TextInput(parent)) => ui.set_parent(widget, parent),
+        Some(WidgetHandle::TextArea(parent)) => ui.set_parent(widget, parent),
+        None => ui,
+    };
+    if let Some(visible) = doc.visible {
+        ui.set_visible(widget, visible);
+    }
+    if let Some(opacity) = doc.opacity {
+        ui.set_opacity(widget, opacity);
+    }
+}
+
+// Make one widget from its entry in the file
+fn make(doc: &WidgetDoc) -> Result<WidgetHandle, String> {
+    let (x, y, width, height) = match doc.rect[..] {
+        [x, y] if doc.kind == "label" => (x, y, 0.0, 0.0),
+        [x, y, width, height] => (x, y, width, height),
+        _ => return Err("rect should be [x, y, width, height]".to_string()),
+    };
+    let mut props = doc.props.clone();
+    let font_size = take_f32(&mut props, "font_size")?.unwrap_or(theme::font_size() as f32);
+
+    let handle = match doc.kind.as_str() {
+        "group" => {
+            let mut group = Group::new(x, y, width, height);
+            if let Some(background) = take_color(&mut props, "background")? {
+                group.with_background(background);
+            }
+            WidgetHandle::Group(Rc::new(RefCell::new(group)))
+        }
+        "label" => {
+            let mut label = Label::new(take_string(&mut props, "text")?.unwrap_or_default(), x, y, font_size as u16);
+            if let Some(key) = take_string(&mut props, "key")? {
+                label.bind_key(&key);
+            }
+            let color = take_color(&mut props, "color")?;
+            let background = take_color(&mut props, "background")?;
+            if color.is_some() || background.is_some() {
+                label.with_colors(color.unwrap_or(ThemeColor::Text), background.map(ThemeColor::color));
+            }
+            if doc.rect.len() == 4 {
+                label.with_fixed_size(width, height);
+            }
+            WidgetHandle::Label(Rc::new(RefCell::new(label)))
+        }
+        "button" => {
+            let text = take_string(&mut props, "text")?.unwrap_or_default();
+            let color = take_color(&mut props, "color")?;
+            let hover_color = take_color(&mut props, "hover_color")?;
+            let mut button = match color {
+                Some(color) => {
+                    let color = color.color();
+                    let hover = hover_color.map_or(color, ThemeColor::color);
+                    TextButton::new(x, y, width, height, text, color, hover, font_size as u16)
+                }
+                None => {
+                    let mut button = TextButton::themed(x, y, width, height, text, font_size as u16);
+                    if let Some(hover) = hover_color {
+                        button.with_hover_color(hover.color());
+                    }
+                    button
+                }
+            };
+            if let Some(text_color) = take_color(&mut props, "text_color")? {
+                button.with_text_color(text_color.color());
+            }
+            if let Some(tooltip) = take_string(&mut props, "tooltip")? {
+                button.set_tooltip(&tooltip);
+            }
+            if let Some(toggle) = take_bool(&mut props, "toggle")? {
+                button.with_toggle(toggle);
+            }
+            if let Some(enabled) = take_bool(&mut props, "enabled")? {
+                button.set_enabled(enabled);
+            }
+            WidgetHandle::Button(Rc::new(RefCell::new(button)))
+        }
+        "text_input" => {
+            let mut input = TextInput::new(x, y, width, height, font_size);
+            if let Some(text) = take_string(&mut props, "text")? {
+                input.set_text(text);
+            }
+            if let Some(prompt) = take_string(&mut props, "prompt")? {
+                input.set_prompt(prompt);
+            }
+            if let Some(max_length) = take_f32(&mut props, "max_length")? {
+                input.set_max_length(max_length as usize);
+            }
+            if let Some(password) = take_bool(&mut props, "password")? {
+                input.set_password(password);
+            }
+            if let Some(enabled) = take_bool(&mut props, "enabled")? {
+                input.set_enabled(enabled);
+            }
+            if let Some(color) = take_color(&mut props, "text_color")? {
+                input.set_text_color(color);
+            }
+            if let Some(color) = take_color(&mut props, "border_color")? {
+                input.set_border_color(color);
+            }
+            if let Some(color) = take_color(&mut props, "background_color")? {
+                input.set_background_color(color);
+            }
+            WidgetHandle::TextInput(Rc::new(RefCell::new(input)))
+        }
+        "text_area" => {
+            let mut area = TextArea::new(x, y, width, height, font_size);
+            if let Some(text) = take_string(&mut props, "text")? {
+                area.set_text(text);
+            }
+            if let Some(prompt) = take_string(&mut props, "prompt")? {
+                area.set_prompt(prompt);
+            }
+            if let Some(max_length) = take_f32(&mut props, "max_length")? {
+                area.set_max_length(max_length as usize);
+            }
+            if let Some(enabled) = take_bool(&mut props, "enabled")? {
+                area.set_enabled(enabled);
+            }
+            if let Some(color) = take_color(&mut props, "text_color")? {
+                area.set_text_color(color);
+            }
+            if let Some(color) = take_color(&mut props, "border_color")? {
+                area.set_border_color(color);
+            }
+            if let Some(color) = take_color(&mut props, "background_color")? {
+                area.set_background_color(color);
+            }
+            WidgetHandle::TextArea(Rc::new(RefCell::new(area)))
+        }
+        other => return Err(format!("unknown type \"{}\" (group, label, button, text_input or text_area)", other)),
+    };
+
+    // Catch typos instead of quietly ignoring them
+    if let Some(unknown) = props.keys().next() {
+        return Err(format!("a {} has no prop \"{}\"", doc.kind, unknown));
+    }
+    Ok(handle)
+}
+
+fn take_string(props: &mut Map<String, Value>, key: &str) -> Result<Option<String>, String> {
+    match props.remove(key) {
+        None => Ok(None),
+        Some(Value::String(text)) => Ok(Some(text)),
+        Some(_) => Err(format!("{} should be text", key)),
+    }
+}
+
+fn take_f32(props: &mut Map<String, Value>, key: &str) -> Result<Option<f32>, String> {
+    match props.remove(key) {
+        None => Ok(None),
+        Some(Value::Number(number)) => Ok(number.as_f64().map(|number| number as f32)),
+        Some(_) => Err(format!("{} should be a number", key)),
+    }
+}
+
+fn take_bool(props: &mut Map<String, Value>, key: &str) -> Result<Option<bool>, String> {
+    match props.remove(key) {
+        None => Ok(None),
+        Some(Value::Bool(value)) => Ok(Some(value)),
+        Some(_) => Err(format!("{} should be true or false", key)),
+    }
+}
+
+fn take_color(props: &mut Map<String, Value>, key: &str) -> Result<Option<ThemeColor>, String> {
+    let Some(value) = props.remove(key) else {
+        return Ok(None);
+    };
+    parse_color(&value).map(Some).ok_or_else(|| format!("{} isn't a color (a theme color's name, \"#rrggbb\" or [r, g, b])", key))
+}
+
+// A theme color's name, "#rrggbb", "#rrggbbaa" or [r, g, b] / [r, g, b, a] from 0.0 to 1.0
+fn parse_color(value: &Value) -> Option<ThemeColor> {
+    match value {
+        Value::String(text) => match text.strip_prefix('#') {
+            Some(hex) => parse_hex(hex).map(ThemeColor::Custom),
+            None => theme_color(text),
+        },
+        Value::Array(parts) => {
+            let parts: Vec<f32> = parts.iter().map(|part| part.as_f64().map(|part| part as f32)).collect::<Option<_>>()?;
+            match parts[..] {
+                [r, g, b] => Some(ThemeColor::Custom(Color::new(r, g, b, 1.0))),
+                [r, g, b, a] => Some(ThemeColor::Custom(Color::new(r, g, b, a))),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !matches!(hex.len(), 6 | 8) || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |index: usize| u8::from_str_radix(hex.get(index..index + 2)?, 16).ok();
+    let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+    Some(Color::from_rgba(channel(0)?, channel(2)?, channel(4)?, alpha))
+}
+
+fn theme_color(name: &str) -> Option<ThemeColor> {
+    Some(match name {
+        "background" => ThemeColor::Background,
+        "surface" => ThemeColor::Surface,
+        "surface_alt" => ThemeColor::SurfaceAlt,
+        "primary" => ThemeColor::Primary,
+        "on_primary" => ThemeColor::OnPrimary,
+        "text" => ThemeColor::Text,
+        "text_muted" => ThemeColor::TextMuted,
+        "border" => ThemeColor::Border,
+        "selection" => ThemeColor::Selection,
+        "error" => ThemeColor::Error,
+        "success" => ThemeColor::Success,
+        "shadow" => ThemeColor::Shadow,
+        _ => return None,
+    })
+}
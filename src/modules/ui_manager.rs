@@ -0,0 +1,920 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A Widget trait shared by the widgets, and a UiManager that holds widgets and draws them all
+with one call, giving the mouse only to the top one under it
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod ui_manager;
+
+This also needs these modules (Widget is already done for their widgets):
+    pub mod draw_cache;
+    pub mod focus;
+    pub mod label;
+    pub mod scale;
+    pub mod text_area;
+    pub mod text_button;
+    pub mod text_input;
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::ui_manager::UiManager;
+
+Then above the loop section, hand the widgets to the manager. add gives back a shared
+handle (Rc<RefCell<...>>) for using the widget afterwards:
+    let mut ui = UiManager::new();
+    let txt_name = ui.add(TextInput::new(100.0, 100.0, 300.0, 40.0, 25.0));
+    let btn_save = ui.add(TextButton::new(100.0, 160.0, 200.0, 60.0, "Save", BLUE, RED, 30));
+    let lbl_status = ui.add(Label::new("Ready", 100.0, 260.0, 30));
+
+Then in the loop, one call handles and draws everything:
+    ui.draw();
+    if btn_save.borrow().was_clicked() {
+        let name = txt_name.borrow().get_text();
+        lbl_status.borrow_mut().set_text(format!("Saved {}", name));
+    }
+
+CALLBACKS:
+    Instead of checking was_clicked every frame, hand the manager closures to run. They are
+    called from ui.draw() right after the widget has updated, so they can borrow any
+    widget handle (even the one that fired):
+        let (txt, lbl) = (txt_name.clone(), lbl_status.clone());
+        ui.on_click(&btn_save, move || {
+            lbl.borrow_mut().set_text(format!("Saved {}", txt.borrow().get_text()));
+        });
+        ui.on_change(&txt_name, |text| println!("Name is now {}", text));  // Typed or pasted
+        ui.on_focus(&txt_name, |focused| println!("Name box focused: {}", focused));
+        ui.clear_callbacks(&btn_save);
+    on_click works for TextButton, on_change for TextInput and TextArea and on_focus for
+    any widget that can take keyboard focus. Registering again replaces the old closure.
+
+HOW IT WORKS:
+    Widgets are drawn in the order they were added, so later ones are on top (see Z-ORDER
+    for layers). Only the top widget under the mouse sees it, the ones under it are drawn as if the mouse were
+    somewhere else (the same way scale::set_mouse_blocked hides it while a dialog is
+    open). A widget that is pressed keeps the mouse until the button is let go, so
+    dragging a selection out of a text box still works.
+    Each frame the mouse presses, releases, wheel and key presses are also handed to
+    handle_event: mouse events go to the widgets under the mouse from the top down until
+    one returns true to use the event up. Key presses only go to the widget with keyboard
+    focus (see the focus module, a FocusManager gives focus to the clicked widget).
+    The built-in buttons and text boxes use up the mouse presses on them (and a TextArea
+    the wheel too), so a widget underneath never hears a press meant for them.
+    Then every widget is updated and drawn with draw, from the bottom up.
+
+Z-ORDER:
+    Every widget is in a layer (its z-index, 0 unless changed). A higher layer is always
+    drawn above a lower one and gets the mouse first, whatever order they were added in.
+    Inside a layer the order they were added decides, until it is changed:
+    ui.set_z_index(&pnl_toolbar, 10);       // Above everything in layers below 10
+    let z = ui.z_index(&pnl_toolbar);
+    ui.bring_to_front(&btn_save);           // Top of its layer
+    ui.send_to_back(&lbl_status);           // Bottom of its layer
+    ui.remove(&lbl_status);                 // The handle still works, it just isn't drawn
+    let shared = Rc::new(RefCell::new(TextInput::new(0.0, 0.0, 100.0, 40.0, 20.0)));
+    ui.add_shared(shared.clone());          // For a widget you already have in an Rc
+    if ui.is_hovered(&btn_save) { }         // Top widget under the mouse
+    ui.clear();
+    let lbl_title = ui.add(Cached::new(Label::new("Settings", 50.0, 60.0, 40)));  // Drawn from a picture while it doesn't change (see draw_cache)
+
+HIDING AND FADING:
+    ui.set_visible(&btn_save, false);       // Not drawn and gets no input, still in the manager
+    ui.set_opacity(&btn_save, 0.5);         // Drawn half see-through (0.0 - 1.0)
+    Widgets can belong to another widget, so hiding or fading it does the same to them:
+        let grp_options = ui.add(Group::new(600.0, 100.0, 300.0, 400.0));
+        grp_options.borrow_mut().with_background(ThemeColor::Surface);
+        let btn_sound = ui.add_child(&grp_options, TextButton::themed(620.0, 120.0, 260.0, 50.0, "Sound", 24));
+        ui.set_parent(&lbl_status, &grp_options);   // One that was added already
+        ui.set_opacity(&grp_options, 0.3);          // The buttons inside fade with it
+        ui.set_visible(&grp_options, false);        // And hide with it
+    Opacity multiplies down the family (a 0.5 widget in a 0.5 group is drawn at 0.25) and a
+    widget shows only if it and every widget it belongs to are visible (their own is_visible
+    counts too, so a Label hidden with its set_visible hides its children). Belonging to a
+    widget doesn't move a child or change its layer, add children after their parent so
+    they are drawn on top of it.
+    A hidden or fully see-through widget can't be hovered, clicked or tabbed to, and loses
+    keyboard focus. Faded widgets are drawn into a texture first (see draw_cache), so only
+    fade what needs it, like a panel sliding in or out.
+    let shown = ui.is_visible(&btn_sound);      // Counting its parents
+    let alpha = ui.opacity(&btn_sound);         // Counting its parents
+    ui.clear_parent(&lbl_status);
+    Import Group with:
+        use crate::modules::ui_manager::{Group, UiManager};
+
+MODAL LAYER:
+    ui.set_modal(&pnl_popup, true);
+    While a modal widget is visible it is drawn above every layer and gets all of the mouse
+    and keyboard, wherever the mouse is. The widgets under it aren't hovered, can't be
+    clicked through it and lose keyboard focus until it is gone (focus comes back then, see
+    focus::push_modal). The click that closes it (a click outside, say) is used up too.
+    A TextInput with its suggestion dropdown open is modal by itself, so the dropdown covers
+    widgets added after it and clicking outside it only closes it. Your own widgets do the
+    same by returning the open dropdown's area from popup_rect.
+    if ui.has_modal() { }
+    Dialogs, context menus and menu bar menus are drawn after the manager and sit above
+    all of it: while one is open nothing in the manager gets the mouse or the keyboard.
+
+YOUR OWN WIDGETS:
+    use crate::modules::ui_manager::{UiContext, UiEvent, Widget};
+
+    struct ColorBox { rect: Rect, color: Color }
+
+    impl Widget for ColorBox {
+        fn rect(&self) -> Rect { self.rect }
+        fn update(&mut self, ctx: &UiContext) {
+            if ctx.hovered && is_mouse_button_pressed(MouseButton::Left) { self.color = RED; }
+        }
+        fn draw(&self) {
+            draw_rectangle(self.rect.x, self.rect.y, self.rect.w, self.rect.h, self.color);
+        }
+        // Optional, for reacting to events (return true to use the event up)
+        fn handle_event(&mut self, event: &UiEvent) -> bool {
+            matches!(event, UiEvent::Wheel(_, _))   // Stop the wheel reaching widgets underneath
+        }
+        // Optional, the area of a dropdown it has open (puts it in the modal layer)
+        fn popup_rect(&self) -> Option<Rect> { None }
+        // Optional, true while it animates so draw_cache::Cached doesn't freeze it
+        fn needs_redraw(&self) -> bool { false }
+    }
+    Keep drawing out of update: draw_cache::Cached calls update and draw at different
+    times, so anything drawn in update ends up in the wrong place.
+*/
+
+use macroquad::prelude::*;
+use crate::modules::draw_cache::DrawCache;
+use crate::modules::focus;
+use crate::modules::label::Label;
+use crate::modules::scale;
+use crate::modules::text_area::TextArea;
+use crate::modules::text_button::TextButton;
+use crate::modules::text_input::TextInput;
+use crate::modules::theme::ThemeColor;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Something that happened this frame, handed to Widget::handle_event
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum UiEvent {
+    MousePressed(MouseButton, Vec2),
+    MouseReleased(MouseButton, Vec2),
+    Wheel(f32, f32),
+    KeyPressed(KeyCode),
+}
+
+// Closures the manager runs for a widget (see CALLBACKS above)
+type ClickCallback = Box<dyn FnMut()>;
+type ChangeCallback = Box<dyn FnMut(&str)>;
+type FocusCallback = Box<dyn FnMut(bool)>;
+
+// Room kept around a faded widget's rect for its outlines and shadows
+const FADE_MARGIN: f32 = 6.0;
+
+// What a widget is told each frame when it updates
+#[derive(Clone, Copy, Debug)]
+#[allow(unused)]
+pub struct UiContext {
+    pub mouse: Vec2,     // Mouse position (off screen for widgets that aren't under it)
+    pub hovered: bool,   // This widget is the top one under the mouse (or has the mouse captured)
+    pub focused: bool,   // This widget has keyboard focus
+    pub frame_time: f32, // Seconds since the last frame
+}
+
+pub trait Widget {
+    // The area the widget covers, for finding what is under the mouse
+    fn rect(&self) -> Rect;
+
+    // Handle input and update state, once a frame
+    fn update(&mut self, ctx: &UiContext);
+
+    // Draw the widget
+    fn draw(&self);
+
+    // React to an event, returning true uses it up so widgets underneath don't get it
+    fn handle_event(&mut self, _event: &UiEvent) -> bool {
+        false
+    }
+
+    // Hidden widgets are skipped when finding what is under the mouse
+    fn is_visible(&self) -> bool {
+        true
+    }
+
+    // Id in the focus module, if the widget can take keyboard focus
+    fn focus_id(&self) -> Option<usize> {
+        None
+    }
+
+    // The area of a dropdown or popup the widget has open, which makes it modal until it closes
+    fn popup_rect(&self) -> Option<Rect> {
+        None
+    }
+
+    // True while the widget changes by itself from frame to frame (an animation, bound text),
+    // so draw_cache::Cached draws it every frame instead of keeping a picture of it
+    fn needs_redraw(&self) -> bool {
+        false
+    }
+
+    // True right after an update in which the widget was clicked (for on_click)
+    fn was_clicked(&self) -> bool {
+        false
+    }
+
+    // The new text right after an update in which the user changed it (for on_change)
+    fn changed_text(&self) -> Option<String> {
+        None
+    }
+}
+
+// A widget held by the manager, with the closures registered for it
+struct Entry {
+    widget: Rc<RefCell<dyn Widget>>,
+    on_click: Option<ClickCallback>,
+    on_change: Option<ChangeCallback>,
+    on_focus: Option<FocusCallback>,
+    focused: bool, // Had keyboard focus as of the last draw
+    z: i32,        // Layer, higher is on top
+    modal: bool,   // Set with set_modal
+    parent: Option<Rc<RefCell<dyn Widget>>>, // Hides and fades with this widget (set_parent)
+    visible: bool, // Set with set_visible
+    opacity: f32,  // Set with set_opacity
+    shown: bool,   // Shown as of the last draw, counting its parents
+    focusable: bool,           // Whether it could take focus before it was hidden
+    fade: Option<DrawCache>,   // Where it is drawn while it is see-through
+}
+
+impl Entry {
+    fn new(widget: Rc<RefCell<dyn Widget>>) -> Self {
+        let focused = widget.borrow().focus_id().is_some_and(focus::is_focused);
+        Self {
+            widget,
+            on_click: None,
+            on_change: None,
+            on_focus: None,
+            focused,
+            z: 0,
+            modal: false,
+            parent: None,
+            visible: true,
+            opacity: 1.0,
+            shown: true,
+            focusable: true,
+            fade: None,
+        }
+    }
+
+    // In the modal layer right now
+    fn is_modal(&self) -> bool {
+        let widget = self.widget.borrow();
+        widget.is_visible() && (self.modal || widget.popup_rect().is_some())
+    }
+}
+
+pub struct UiManager {
+    widgets: Vec<Entry>,                   // Bottom to top, sorted by layer
+    captured: Option<usize>,               // Widget a mouse press started on, while it is held
+    hovered: Option<usize>,                // Top widget under the mouse last draw
+    modal_layer: bool,                     // A focus modal layer was pushed for a modal widget
+    blocking: bool,                        // The mouse was left blocked for a modal widget last draw
+}
+
+impl UiManager {
+    #[allow(unused)]
+    pub fn new() -> Self {
+        Self {
+            widgets: Vec::new(),
+            captured: None,
+            hovered: None,
+            modal_layer: false,
+            blocking: false,
+        }
+    }
+
+    // Put a widget on top of the others, returns a handle for using it
+    #[allow(unused)]
+    pub fn add<W: Widget + 'static>(&mut self, widget: W) -> Rc<RefCell<W>> {
+        let handle = Rc::new(RefCell::new(widget));
+        self.add_shared(handle.clone());
+        handle
+    }
+
+    // Put a widget that is already shared on top of the others
+    #[allow(unused)]
+    pub fn add_shared<W: Widget + 'static>(&mut self, widget: Rc<RefCell<W>>) -> &mut Self {
+        self.insert(Entry::new(widget), true);
+        self
+    }
+
+    // Add a widget that hides and fades along with another one (see HIDING AND FADING)
+    #[allow(unused)]
+    pub fn add_child<P: Widget + 'static, W: Widget + 'static>(&mut self, parent: &Rc<RefCell<P>>, widget: W) -> Rc<RefCell<W>> {
+        let handle = self.add(widget);
+        self.set_parent(&handle, parent);
+        handle
+    }
+
+    // Make a widget hide and fade along with another one
+    #[allow(unused)]
+    pub fn set_parent<W: Widget + 'static, P: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>, parent: &Rc<RefCell<P>>) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            let parent: Rc<RefCell<dyn Widget>> = parent.clone();
+            self.widgets[index].parent = Some(parent);
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_parent<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets[index].parent = None;
+        }
+        self
+    }
+
+    // Hide or show a widget and the widgets that belong to it
+    #[allow(unused)]
+    pub fn set_visible<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>, visible: bool) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets[index].visible = visible;
+        }
+        self
+    }
+
+    // Whether a widget is shown, false if it or a widget it belongs to is hidden
+    #[allow(unused)]
+    pub fn is_visible<W: Widget + 'static>(&self, widget: &Rc<RefCell<W>>) -> bool {
+        self.index_of(widget).is_some_and(|index| self.family_state()[index].0)
+    }
+
+    // How see-through a widget and the widgets that belong to it are (0.0 - 1.0)
+    #[allow(unused)]
+    pub fn set_opacity<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>, opacity: f32) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets[index].opacity = opacity.clamp(0.0, 1.0);
+        }
+        self
+    }
+
+    // The opacity a widget is drawn with, counting the widgets it belongs to
+    #[allow(unused)]
+    pub fn opacity<W: Widget + 'static>(&self, widget: &Rc<RefCell<W>>) -> f32 {
+        self.index_of(widget).map_or(0.0, |index| self.family_state()[index].1)
+    }
+
+    // Run a closure each time the widget is clicked
+    #[allow(unused)]
+    pub fn on_click<W, F>(&mut self, widget: &Rc<RefCell<W>>, callback: F) -> &mut Self
+    where
+        W: Widget + 'static,
+        F: FnMut() + 'static,
+    {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets[index].on_click = Some(Box::new(callback));
+        }
+        self
+    }
+
+    // Run a closure with the new text each time the user changes it
+    #[allow(unused)]
+    pub fn on_change<W, F>(&mut self, widget: &Rc<RefCell<W>>, callback: F) -> &mut Self
+    where
+        W: Widget + 'static,
+        F: FnMut(&str) + 'static,
+    {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets[index].on_change = Some(Box::new(callback));
+        }
+        self
+    }
+
+    // Run a closure when the widget gets (true) or loses (false) keyboard focus
+    #[allow(unused)]
+    pub fn on_focus<W, F>(&mut self, widget: &Rc<RefCell<W>>, callback: F) -> &mut Self
+    where
+        W: Widget + 'static,
+        F: FnMut(bool) + 'static,
+    {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets[index].on_focus = Some(Box::new(callback));
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn clear_callbacks<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            let entry = &mut self.widgets[index];
+            entry.on_click = None;
+            entry.on_change = None;
+            entry.on_focus = None;
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn remove<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets.remove(index);
+            self.captured = None;
+            self.hovered = None;
+        }
+        self
+    }
+
+    // Put a widget above the others in its layer
+    #[allow(unused)]
+    pub fn bring_to_front<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            let entry = self.widgets.remove(index);
+            self.insert(entry, true);
+        }
+        self
+    }
+
+    // Put a widget below the others in its layer
+    #[allow(unused)]
+    pub fn send_to_back<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            let entry = self.widgets.remove(index);
+            self.insert(entry, false);
+        }
+        self
+    }
+
+    // Move a widget to another layer (on top of the widgets already in it)
+    #[allow(unused)]
+    pub fn set_z_index<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>, z: i32) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            let mut entry = self.widgets.remove(index);
+            entry.z = z;
+            self.insert(entry, true);
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn z_index<W: Widget + 'static>(&self, widget: &Rc<RefCell<W>>) -> Option<i32> {
+        self.index_of(widget).map(|index| self.widgets[index].z)
+    }
+
+    // While true (and the widget is visible) it is drawn above everything and gets all the input
+    #[allow(unused)]
+    pub fn set_modal<W: Widget + 'static>(&mut self, widget: &Rc<RefCell<W>>, modal: bool) -> &mut Self {
+        if let Some(index) = self.index_of(widget) {
+            self.widgets[index].modal = modal;
+        }
+        self
+    }
+
+    // Whether a widget has the modal layer (set_modal, or a dropdown that is open)
+    #[allow(unused)]
+    pub fn has_modal(&self) -> bool {
+        let state = self.family_state();
+        self.widgets.iter().enumerate().any(|(index, entry)| is_shown(state[index]) && entry.is_modal())
+    }
+
+    // Whether the widget was the top one under the mouse last draw
+    #[allow(unused)]
+    pub fn is_hovered<W: Widget + 'static>(&self, widget: &Rc<RefCell<W>>) -> bool {
+        self.hovered.is_some() && self.hovered == self.index_of(widget)
+    }
+
+    #[allow(unused)]
+    pub fn len(&self) -> usize {
+        self.widgets.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_empty(&self) -> bool {
+        self.widgets.is_empty()
+    }
+
+    #[allow(unused)]
+    pub fn clear(&mut self) -> &mut Self {
+        self.widgets.clear();
+        self.captured = None;
+        self.hovered = None;
+        self
+    }
+
+    // Hand out this frame's events, then update and draw every widget from the bottom up
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        // Free the mouse a modal widget kept last draw, unless a dialog or menu has it now
+        if self.blocking && focus::modal_depth() <= usize::from(self.modal_layer) {
+            scale::set_mouse_blocked(false);
+        }
+        self.blocking = false;
+
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+        let state = self.family_state();
+        let shown: Vec<bool> = state.iter().map(|&state| is_shown(state)).collect();
+        self.update_hidden_focus(&shown);
+        let any_down = [MouseButton::Left, MouseButton::Right, MouseButton::Middle]
+            .into_iter()
+            .any(is_mouse_button_down);
+
+        // The top modal widget has the mouse wherever it is, otherwise the top widget under
+        // the mouse does, unless a press is still holding on to one
+        let modal = (0..self.widgets.len()).rev().find(|&index| shown[index] && self.widgets[index].is_modal());
+        self.update_modal_layer(modal);
+        let under_mouse = modal.or_else(|| {
+            (0..self.widgets.len()).rev().find(|&index| {
+                let widget = self.widgets[index].widget.borrow();
+                shown[index] && widget.is_visible() && widget.rect().contains(mouse)
+            })
+        });
+        if !any_down || modal.is_some() {
+            self.captured = None;
+        }
+        let target = self.captured.filter(|&index| shown[index]).or(under_mouse);
+        self.dispatch_events(mouse, modal, &shown);
+        if self.captured.is_none() && [MouseButton::Left, MouseButton::Right, MouseButton::Middle].into_iter().any(is_mouse_button_pressed) {
+            self.captured = under_mouse;
+        }
+        self.hovered = target;
+
+        // The modal layer is drawn last, above every other layer
+        let (normal, modal_layer): (Vec<usize>, Vec<usize>) = (0..self.widgets.len())
+            .filter(|&index| shown[index])
+            .partition(|&index| !self.widgets[index].is_modal());
+        let mut blocked = scale::is_mouse_blocked();
+        let frame_time = get_frame_time();
+        for index in normal.into_iter().chain(modal_layer) {
+            let entry = &mut self.widgets[index];
+            let hovered = target == Some(index) && !blocked;
+            let hidden_mouse = !hovered;
+            scale::set_mouse_blocked(hidden_mouse);
+            let (clicked, changed_text, focused) = {
+                let mut widget = entry.widget.borrow_mut();
+                let ctx = UiContext {
+                    mouse: if hovered { mouse } else { Vec2::new(-1.0, -1.0) },
+                    hovered,
+                    focused: widget.focus_id().is_some_and(focus::is_focused),
+                    frame_time,
+                };
+                let opacity = state[index].1;
+                if opacity < 1.0 {
+                    // See-through: drawn into a texture that is then drawn faded
+                    let rect = widget.rect();
+                    let mut area = Rect::new(rect.x - FADE_MARGIN, rect.y - FADE_MARGIN, rect.w + FADE_MARGIN * 2.0, rect.h + FADE_MARGIN * 2.0);
+                    if let Some(popup) = widget.popup_rect() {
+                        area = area.combine_with(popup);
+                    }
+                    let fade = entry.fade.get_or_insert_with(DrawCache::new);
+                    fade.with_tint(WHITE.with_alpha(opacity)).mark_dirty();
+                    fade.draw(area, || {
+                        widget.update(&ctx);
+                        widget.draw();
+                    });
+                } else {
+                    entry.fade = None;
+                    widget.update(&ctx);
+                    widget.draw();
+                }
+                (widget.was_clicked(), widget.changed_text(), widget.focus_id().is_some_and(focus::is_focused))
+            };
+            // A widget that blocked or freed the mouse itself (opening a menu or a dialog) wins
+            if scale::is_mouse_blocked() != hidden_mouse {
+                blocked = scale::is_mouse_blocked();
+            }
+
+            // The widget isn't borrowed any more, so the closures can use its handle
+            if focused != entry.focused {
+                entry.focused = focused;
+                if let Some(callback) = entry.on_focus.as_mut() {
+                    callback(focused);
+                }
+            }
+            if let (Some(text), Some(callback)) = (changed_text, entry.on_change.as_mut()) {
+                callback(&text);
+            }
+            if clicked {
+                if let Some(callback) = entry.on_click.as_mut() {
+                    callback();
+                }
+            }
+        }
+        // Nothing drawn after the manager (or a FocusManager) gets a click meant for the modal widget
+        if modal.is_some() && !blocked {
+            blocked = true;
+            self.blocking = true;
+        }
+        scale::set_mouse_blocked(blocked);
+    }
+
+    // Take the keyboard from the other widgets while one is modal, and give it back afterwards
+    fn update_modal_layer(&mut self, modal: Option<usize>) {
+        match modal {
+            Some(index) if !self.modal_layer => {
+                let id = self.widgets[index].widget.borrow().focus_id();
+                // A focused widget (a text box with its dropdown open) already has the keyboard
+                if !id.is_some_and(focus::is_focused) {
+                    let ids: Vec<usize> = id.into_iter().collect();
+                    focus::push_modal(&ids);
+                    if let Some(id) = id {
+                        focus::set_focus(id);
+                    }
+                    self.modal_layer = true;
+                }
+            }
+            None if self.modal_layer => {
+                focus::pop_modal();
+                self.modal_layer = false;
+            }
+            _ => {}
+        }
+    }
+
+    // Whether each widget is visible and its opacity, counting the widgets it belongs to
+    fn family_state(&self) -> Vec<(bool, f32)> {
+        let mut state = vec![None; self.widgets.len()];
+        for index in 0..self.widgets.len() {
+            self.resolve_family(index, &mut state, 0);
+        }
+        state.into_iter().map(|state| state.unwrap_or((true, 1.0))).collect()
+    }
+
+    fn resolve_family(&self, index: usize, state: &mut [Option<(bool, f32)>], depth: usize) -> (bool, f32) {
+        if let Some(known) = state[index] {
+            return known;
+        }
+        let entry = &self.widgets[index];
+        // The depth check stops a widget that (through others) belongs to itself going round forever
+        let parent = entry.parent.as_ref().filter(|_| depth < self.widgets.len()).and_then(|parent| self.index_of_dyn(parent));
+        let resolved = match parent {
+            Some(parent) => {
+                let (visible, opacity) = self.resolve_family(parent, state, depth + 1);
+                let parent_visible = self.widgets[parent].widget.borrow().is_visible();
+                (entry.visible && visible && parent_visible, entry.opacity * opacity)
+            }
+            None => (entry.visible, entry.opacity),
+        };
+        state[index] = Some(resolved);
+        resolved
+    }
+
+    // Take focus away from widgets that were just hidden, and let them have it again once shown
+    fn update_hidden_focus(&mut self, shown: &[bool]) {
+        for (entry, &shown) in self.widgets.iter_mut().zip(shown) {
+            if entry.shown == shown {
+                continue;
+            }
+            entry.shown = shown;
+            let Some(id) = entry.widget.borrow().focus_id() else {
+                continue;
+            };
+            if shown {
+                focus::set_focusable(id, entry.focusable);
+            } else {
+                entry.focusable = focus::is_focusable(id);
+                focus::set_focusable(id, false);
+            }
+        }
+    }
+
+    // Put an entry at the top (or bottom) of its layer
+    fn insert(&mut self, entry: Entry, front: bool) {
+        let index = if front {
+            self.widgets.iter().position(|other| other.z > entry.z)
+        } else {
+            self.widgets.iter().position(|other| other.z >= entry.z)
+        };
+        self.widgets.insert(index.unwrap_or(self.widgets.len()), entry);
+        self.captured = None;
+        self.hovered = None;
+    }
+
+    // Give this frame's events to the widgets until one uses each up (all of them to a modal widget)
+    fn dispatch_events(&mut self, mouse: Vec2, modal: Option<usize>, shown: &[bool]) {
+        if scale::is_mouse_blocked() {
+            return;
+        }
+        let mut events = Vec::new();
+        for button in [MouseButton::Left, MouseButton::Right, MouseButton::Middle] {
+            if is_mouse_button_pressed(button) {
+                events.push(UiEvent::MousePressed(button, mouse));
+            }
+            if is_mouse_button_released(button) {
+                events.push(UiEvent::MouseReleased(button, mouse));
+            }
+        }
+        let (wheel_x, wheel_y) = mouse_wheel();
+        if wheel_x != 0.0 || wheel_y != 0.0 {
+            events.push(UiEvent::Wheel(wheel_x, wheel_y));
+        }
+        events.extend(get_keys_pressed().into_iter().map(UiEvent::KeyPressed));
+
+        if let Some(index) = modal {
+            let mut widget = self.widgets[index].widget.borrow_mut();
+            for event in events {
+                widget.handle_event(&event);
+            }
+            return;
+        }
+        for event in events {
+            match event {
+                UiEvent::KeyPressed(_) => {
+                    // Only the focused widget hears the keyboard
+                    if let Some(entry) = self.widgets.iter().find(|entry| entry.widget.borrow().focus_id().is_some_and(focus::is_focused)) {
+                        entry.widget.borrow_mut().handle_event(&event);
+                    }
+                }
+                _ => {
+                    for (entry, _) in self.widgets.iter().zip(shown).rev().filter(|(_, &shown)| shown) {
+                        let mut widget = entry.widget.borrow_mut();
+                        if widget.is_visible() && widget.rect().contains(mouse) && widget.handle_event(&event) {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn index_of<W: Widget + 'static>(&self, widget: &Rc<RefCell<W>>) -> Option<usize> {
+        let wanted = Rc::as_ptr(widget) as *const ();
+        self.widgets.iter().position(|entry| Rc::as_ptr(&entry.widget) as *const () == wanted)
+    }
+
+    fn index_of_dyn(&self, widget: &Rc<RefCell<dyn Widget>>) -> Option<usize> {
+        let wanted = Rc::as_ptr(widget) as *const ();
+        self.widgets.iter().position(|entry| Rc::as_ptr(&entry.widget) as *const () == wanted)
+    }
+}
+
+// Shown at all (visible and not fully see-through)
+fn is_shown((visible, opacity): (bool, f32)) -> bool {
+    visible && opacity > 0.0
+}
+
+// A plain box for holding other widgets (see HIDING AND FADING), with a background if given one
+pub struct Group {
+    rect: Rect,
+    background: Option<ThemeColor>,
+}
+
+impl Group {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            rect: Rect::new(x, y, width, height),
+            background: None,
+        }
+    }
+
+    // Fill the box with a color
+    #[allow(unused)]
+    pub fn with_background(&mut self, color: impl Into<ThemeColor>) -> &mut Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_rect(&mut self, rect: Rect) -> &mut Self {
+        self.rect = rect;
+        self
+    }
+}
+
+impl Widget for Group {
+    fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    fn update(&mut self, _ctx: &UiContext) {}
+
+    fn draw(&self) {
+        if let Some(color) = self.background {
+            draw_rectangle(self.rect.x, self.rect.y, self.rect.w, self.rect.h, color.color());
+        }
+    }
+}
+
+impl Default for UiManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Widget for TextButton {
+    fn rect(&self) -> Rect {
+        self.get_rect()
+    }
+
+    fn update(&mut self, _ctx: &UiContext) {
+        self.update_only();
+    }
+
+    fn draw(&self) {
+        self.draw_only();
+    }
+
+    // Presses on it are its own
+    fn handle_event(&mut self, event: &UiEvent) -> bool {
+        matches!(event, UiEvent::MousePressed(..) | UiEvent::MouseReleased(..))
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn focus_id(&self) -> Option<usize> {
+        Some(TextButton::focus_id(self))
+    }
+
+    fn was_clicked(&self) -> bool {
+        TextButton::was_clicked(self)
+    }
+
+    // The busy spinner turns
+    fn needs_redraw(&self) -> bool {
+        self.is_busy()
+    }
+}
+
+impl Widget for TextInput {
+    fn rect(&self) -> Rect {
+        let ((x, y), (width, height)) = (self.get_position(), self.get_dimensions());
+        Rect::new(x, y, width, height)
+    }
+
+    fn update(&mut self, _ctx: &UiContext) {
+        self.update_only();
+    }
+
+    fn draw(&self) {
+        self.draw_only();
+    }
+
+    // Presses on it are its own
+    fn handle_event(&mut self, event: &UiEvent) -> bool {
+        matches!(event, UiEvent::MousePressed(..) | UiEvent::MouseReleased(..))
+    }
+
+    fn focus_id(&self) -> Option<usize> {
+        Some(TextInput::focus_id(self))
+    }
+
+    fn popup_rect(&self) -> Option<Rect> {
+        self.suggestions_rect()
+    }
+
+    fn changed_text(&self) -> Option<String> {
+        self.changed().then(|| self.get_text())
+    }
+}
+
+impl Widget for TextArea {
+    fn rect(&self) -> Rect {
+        let ((x, y), (width, height)) = (self.get_position(), self.get_dimensions());
+        Rect::new(x, y, width, height)
+    }
+
+    fn update(&mut self, _ctx: &UiContext) {
+        self.update_only();
+    }
+
+    fn draw(&self) {
+        self.draw_only();
+    }
+
+    // Presses on it are its own, and the wheel scrolls it
+    fn handle_event(&mut self, event: &UiEvent) -> bool {
+        matches!(event, UiEvent::MousePressed(..) | UiEvent::MouseReleased(..) | UiEvent::Wheel(..))
+    }
+
+    fn focus_id(&self) -> Option<usize> {
+        Some(TextArea::focus_id(self))
+    }
+
+    fn changed_text(&self) -> Option<String> {
+        self.changed().then(|| self.get_text())
+    }
+}
+
+impl Widget for Label {
+    fn rect(&self) -> Rect {
+        self.measure()
+    }
+
+    fn update(&mut self, _ctx: &UiContext) {
+        self.update_only();
+    }
+
+    fn draw(&self) {
+        self.draw_only();
+    }
+
+    fn is_visible(&self) -> bool {
+        Label::is_visible(self)
+    }
+
+    fn needs_redraw(&self) -> bool {
+        self.is_animating() || self.is_bound()
+    }
+}
@@ -0,0 +1,364 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+An on-screen keyboard (letters or numbers) that types into the focused text box,
+for touch screens and tablets
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod virtual_keyboard;
+
+This also needs the text_input module (and text_area if you use it), which already call into this one:
+    pub mod text_input;
+and the theme module for its colors:
+    pub mod theme;
+
+Add with the other use statements:
+    use crate::modules::virtual_keyboard::{KeyboardLayout, VirtualKeyboard};
+
+Then above the loop section:
+    let mut kbd = VirtualKeyboard::new(112.0, 500.0, 800.0, 260.0);
+Where the parameters are x, y, width and height
+
+Then in the loop, after the text boxes:
+    kbd.draw();
+
+HOW IT WORKS:
+    Keys tapped on the keyboard go to whichever TextInput or TextArea has focus, the same as
+    typing on a real keyboard: letters are inserted at the cursor, Back deletes and Enter
+    submits (or starts a new line in a TextArea). Tapping the keyboard doesn't take focus away
+    from the text box. By default the keyboard only shows while a text box has focus.
+    Shift makes the next letter a capital, tapping it twice keeps capitals on (caps lock).
+    The 123 / ABC key swaps between the letters and the number pad. Holding Back keeps deleting.
+
+CUSTOMIZATION:
+    kbd.with_layout(KeyboardLayout::Numeric);   // Start on the number pad (default Qwerty)
+    kbd.with_auto_hide(false);                  // Always show it, even with no text box focused
+    kbd.with_colors(DARKGRAY, GRAY, WHITE, Color::new(0.1, 0.1, 0.1, 0.9)); // Keys, pressed keys, text, background (default from the theme)
+    kbd.with_font_size(28);                     // Default 24
+    kbd.set_position(0.0, 508.0);
+    kbd.set_size(1024.0, 260.0);
+    kbd.set_visible(false);                     // Hide it (use this rather than not calling draw)
+    if kbd.is_showing() { }                     // Drawn last frame (e.g. to move things out of its way)
+*/
+
+use macroquad::prelude::*;
+use crate::modules::focus;
+use crate::modules::theme::ThemeColor;
+use std::cell::{Cell, RefCell};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Space between keys
+const GAP: f32 = 6.0;
+// How long Back has to be held before it repeats, and how often it repeats after that
+const REPEAT_DELAY: f64 = 0.5;
+const REPEAT_INTERVAL: f64 = 0.05;
+
+// Which set of keys is showing
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(unused)]
+pub enum KeyboardLayout {
+    Qwerty,  // Letters, with a row of digits along the top
+    Numeric, // A number pad
+}
+
+// A key tapped on the keyboard, waiting for the text box it was meant for
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VirtualKey {
+    Char(char),
+    Backspace,
+    Enter,
+}
+
+thread_local! {
+    // Keys tapped but not picked up yet, with the focus id of the text box they are for
+    static PENDING: RefCell<Vec<(usize, VirtualKey)>> = const { RefCell::new(Vec::new()) };
+    // Where the keyboard was last drawn, clicks there don't take focus from text boxes
+    static AREA: Cell<Option<Rect>> = const { Cell::new(None) };
+    // Text box that asked for keys since the keyboard was last drawn
+    static TEXT_TARGET: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Keys tapped for a text box (call every frame while it is active)
+pub fn take_keys(owner: usize) -> Vec<VirtualKey> {
+    TEXT_TARGET.with(|target| target.set(Some(owner)));
+    PENDING.with(|pending| {
+        let mut pending = pending.borrow_mut();
+        let keys = pending.iter().filter(|(target, _)| *target == owner).map(|(_, key)| *key).collect();
+        pending.clear();
+        keys
+    })
+}
+
+/// Whether a point is on the keyboard (text boxes keep focus when it is clicked)
+pub fn covers(x: f32, y: f32) -> bool {
+    AREA.with(Cell::get).is_some_and(|area| area.contains(Vec2::new(x, y)))
+}
+
+// What a key on the keyboard does
+#[derive(Clone, Copy, PartialEq)]
+enum Key {
+    Char(char),
+    Shift,
+    Backspace,
+    Enter,
+    Space,
+    SwitchLayout,
+}
+
+pub struct VirtualKeyboard {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    layout: KeyboardLayout,
+    shift: bool,
+    caps_lock: bool,
+    last_shift_time: f64,
+    auto_hide: bool,
+    visible: bool,
+    font_size: u16,
+    key_color: ThemeColor,
+    pressed_color: ThemeColor,
+    text_color: ThemeColor,
+    background_color: ThemeColor,
+    held: Option<(Key, f64)>, // Key under the mouse while the button is down, and when the next repeat is due
+}
+
+impl VirtualKeyboard {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            layout: KeyboardLayout::Qwerty,
+            shift: false,
+            caps_lock: false,
+            last_shift_time: -1.0,
+            auto_hide: true,
+            visible: true,
+            font_size: 24,
+            key_color: ThemeColor::SurfaceAlt,
+            pressed_color: ThemeColor::Border,
+            text_color: ThemeColor::Text,
+            background_color: ThemeColor::Surface,
+            held: None,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn with_layout(&mut self, layout: KeyboardLayout) -> &mut Self {
+        self.layout = layout;
+        self
+    }
+
+    // Only show while a text box has focus (default on)
+    #[allow(unused)]
+    pub fn with_auto_hide(&mut self, auto_hide: bool) -> &mut Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
+    // Key, pressed key, text and background colors
+    #[allow(unused)]
+    pub fn with_colors(
+        &mut self,
+        key: impl Into<ThemeColor>,
+        pressed: impl Into<ThemeColor>,
+        text: impl Into<ThemeColor>,
+        background: impl Into<ThemeColor>,
+    ) -> &mut Self {
+        self.key_color = key.into();
+        self.pressed_color = pressed.into();
+        self.text_color = text.into();
+        self.background_color = background.into();
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_font_size(&mut self, font_size: u16) -> &mut Self {
+        self.font_size = font_size;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_size(&mut self, width: f32, height: f32) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_visible(&mut self, visible: bool) -> &mut Self {
+        self.visible = visible;
+        if !visible {
+            AREA.with(|area| area.set(None));
+        }
+        self
+    }
+
+    // Whether the keyboard was drawn last frame
+    #[allow(unused)]
+    pub fn is_showing(&self) -> bool {
+        AREA.with(Cell::get).is_some()
+    }
+
+    // Draw the keyboard and send tapped keys to the focused text box
+    #[allow(unused)]
+    pub fn draw(&mut self) {
+        let target = TEXT_TARGET.with(|target| target.take()).filter(|&id| focus::is_focused(id));
+        if !self.visible || (self.auto_hide && target.is_none()) {
+            AREA.with(|area| area.set(None));
+            self.held = None;
+            return;
+        }
+        AREA.with(|area| area.set(Some(Rect::new(self.x, self.y, self.width, self.height))));
+        draw_rectangle(self.x, self.y, self.width, self.height, self.background_color.color());
+
+        let (mouse_x, mouse_y) = mouse_position();
+        let mouse = Vec2::new(mouse_x, mouse_y);
+        let keys = self.key_rects();
+        let under_mouse = keys.iter().find(|(_, rect)| rect.contains(mouse)).map(|(key, _)| *key);
+
+        // Keys act when pressed, Back repeats while held
+        let mut tapped = None;
+        if is_mouse_button_pressed(MouseButton::Left) {
+            tapped = under_mouse;
+            self.held = under_mouse.map(|key| (key, get_time() + REPEAT_DELAY));
+        } else if let Some((key, next_repeat)) = self.held {
+            if !is_mouse_button_down(MouseButton::Left) || under_mouse != Some(key) {
+                self.held = None;
+            } else if key == Key::Backspace && get_time() >= next_repeat {
+                tapped = Some(key);
+                self.held = Some((key, get_time() + REPEAT_INTERVAL));
+            }
+        }
+        if let Some(key) = tapped {
+            self.press(key, target);
+        }
+
+        let text_color = self.text_color.color();
+        for (key, rect) in &keys {
+            let held = self.held.is_some_and(|(held_key, _)| held_key == *key);
+            let lit = held || (*key == Key::Shift && (self.shift || self.caps_lock));
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, if lit { self.pressed_color.color() } else { self.key_color.color() });
+            let label = self.label(*key);
+            let size = measure_text(&label, None, self.font_size, 1.0);
+            draw_text(
+                &label,
+                rect.x + (rect.w - size.width) / 2.0,
+                rect.y + (rect.h - size.height) / 2.0 + size.offset_y,
+                self.font_size as f32,
+                text_color,
+            );
+            // Caps lock is shown with a line under Shift
+            if *key == Key::Shift && self.caps_lock {
+                draw_line(rect.x + rect.w * 0.3, rect.y + rect.h - 6.0, rect.x + rect.w * 0.7, rect.y + rect.h - 6.0, 2.0, text_color);
+            }
+        }
+    }
+
+    fn press(&mut self, key: Key, target: Option<usize>) {
+        let send = |key: VirtualKey| {
+            if let Some(owner) = target {
+                PENDING.with(|pending| pending.borrow_mut().push((owner, key)));
+            }
+        };
+        match key {
+            Key::Char(c) => {
+                send(VirtualKey::Char(if self.shift || self.caps_lock { c.to_ascii_uppercase() } else { c }));
+                self.shift = false;
+            }
+            Key::Space => send(VirtualKey::Char(' ')),
+            Key::Backspace => send(VirtualKey::Backspace),
+            Key::Enter => send(VirtualKey::Enter),
+            Key::Shift => {
+                // A quick second tap turns on caps lock, any tap while it's on turns it off
+                let now = get_time();
+                if self.caps_lock {
+                    self.caps_lock = false;
+                    self.shift = false;
+                } else if self.shift && now - self.last_shift_time < 0.4 {
+                    self.caps_lock = true;
+                    self.shift = false;
+                } else {
+                    self.shift = !self.shift;
+                }
+                self.last_shift_time = now;
+            }
+            Key::SwitchLayout => {
+                self.layout = match self.layout {
+                    KeyboardLayout::Qwerty => KeyboardLayout::Numeric,
+                    KeyboardLayout::Numeric => KeyboardLayout::Qwerty,
+                };
+            }
+        }
+    }
+
+    // Text drawn on a key
+    fn label(&self, key: Key) -> String {
+        match key {
+            Key::Char(c) if self.shift || self.caps_lock => c.to_ascii_uppercase().to_string(),
+            Key::Char(c) => c.to_string(),
+            Key::Shift => String::from("Shift"),
+            Key::Backspace => String::from("Back"),
+            Key::Enter => String::from("Enter"),
+            Key::Space => String::from("space"),
+            Key::SwitchLayout => String::from(if self.layout == KeyboardLayout::Qwerty { "123" } else { "ABC" }),
+        }
+    }
+
+    // Rows of keys with their widths (1.0 is a normal key)
+    fn rows(&self) -> Vec<Vec<(Key, f32)>> {
+        let chars = |text: &str| text.chars().map(|c| (Key::Char(c), 1.0)).collect::<Vec<_>>();
+        match self.layout {
+            KeyboardLayout::Qwerty => {
+                let mut bottom_letters = vec![(Key::Shift, 1.5)];
+                bottom_letters.extend(chars("zxcvbnm"));
+                bottom_letters.push((Key::Backspace, 1.5));
+                vec![
+                    chars("1234567890"),
+                    chars("qwertyuiop"),
+                    chars("asdfghjkl"),
+                    bottom_letters,
+                    vec![(Key::SwitchLayout, 1.5), (Key::Char(','), 1.0), (Key::Space, 5.0), (Key::Char('.'), 1.0), (Key::Enter, 1.5)],
+                ]
+            }
+            KeyboardLayout::Numeric => vec![
+                chars("123"),
+                chars("456"),
+                chars("789"),
+                vec![(Key::Char('-'), 1.0), (Key::Char('0'), 1.0), (Key::Backspace, 1.0)],
+                vec![(Key::SwitchLayout, 1.0), (Key::Char('.'), 1.0), (Key::Enter, 1.0)],
+            ],
+        }
+    }
+
+    // Where each key is, rows centered across the keyboard
+    fn key_rects(&self) -> Vec<(Key, Rect)> {
+        let rows = self.rows();
+        let widest = rows.iter().map(|row| row.iter().map(|(_, width)| width).sum::<f32>()).fold(1.0, f32::max);
+        let unit = (self.width - GAP) / widest;
+        let row_height = (self.height - GAP) / rows.len() as f32;
+        let mut rects = Vec::new();
+        for (row_index, row) in rows.iter().enumerate() {
+            let row_width: f32 = row.iter().map(|(_, width)| width * unit).sum();
+            let mut x = self.x + GAP + (self.width - GAP - row_width) / 2.0;
+            let y = self.y + GAP + row_height * row_index as f32;
+            for &(key, width) in row {
+                rects.push((key, Rect::new(x, y, width * unit - GAP, row_height - GAP)));
+                x += width * unit;
+            }
+        }
+        rects
+    }
+}
@@ -0,0 +1,420 @@
+/*
+Made by: Draydon Levesque
+Oct 16 2026
+A step-by-step flow (like account -> profile -> confirm) with numbered step markers along
+the top and Back / Next buttons along the bottom
+
+In your mod.rs file located in the modules folder add the following to the end of the file:
+    pub mod wizard;
+
+This also needs these modules:
+    pub mod focus;
+    pub mod scale;
+    pub mod text_button;
+
+Add with the other use statements:
+    use crate::modules::wizard::Wizard;
+
+Then above the loop section:
+    let mut wiz_signup = Wizard::new(150.0, 80.0, 600.0, 500.0);
+    wiz_signup.add_step("Account").add_step("Profile").add_step("Confirm");
+    wiz_signup.on_finish(|| println!("All done"));
+Where the parameters are x, y, width and height (the step markers and buttons are inside).
+
+Then in the loop, draw the current step's widgets between begin() and end():
+    match wiz_signup.begin() {
+        0 => { frm_account.draw(); }
+        1 => { frm_profile.draw(); }
+        _ => { lbl_summary.draw(); }
+    }
+    if wiz_signup.end() {
+        // Finish was pressed (and the on_finish closure has run)
+    }
+begin() draws the frame and step markers and returns the step to show (counting from 0).
+Anything drawn between begin() and end() is cut off at the edges of the content area.
+end() draws and handles the buttons.
+
+CHECKING A STEP BEFORE MOVING ON:
+    // Either keep Next greyed out until the step is filled in (call it every frame)...
+    wiz_signup.set_can_continue(!frm_account.get_text("username").is_empty());
+
+    // ...or check when Next is pressed, the message is shown beside the buttons
+    wiz_signup.with_gate(1, move || {
+        if accepted.get() { Ok(()) } else { Err("Accept the rules first".to_string()) }
+    });
+    The gate closures have to own what they use, share values with Rc<Cell<...>> or
+    Rc<RefCell<...>> to change things in main.
+
+    // Or handle Next yourself, for checks that need your widgets (like Form::submit)
+    wiz_signup.with_manual_next(0);
+    if wiz_signup.next_requested() == Some(0) && frm_account.submit().is_some() {
+        wiz_signup.next();
+    }
+
+HOW IT WORKS:
+    Steps that are done get a tick and can be clicked to go back to them. The last step's
+    Next button says Finish. Going back never runs the gates, going forward always does.
+
+OTHER METHODS:
+    wiz_signup.next();                              // Move on (skips the gates)
+    wiz_signup.back();
+    wiz_signup.set_step(0);                         // Jump to a step
+    wiz_signup.reset();                             // Back to the first step, nothing done
+    let step = wiz_signup.current_step();
+    let count = wiz_signup.step_count();
+    if wiz_signup.is_finished() { }
+    wiz_signup.with_button_text("Back", "Next", "Create account");
+    wiz_signup.with_colors(DARKBLUE, WHITE, Color::new(0.95, 0.95, 0.95, 1.0)); // Accent, text on accent, background (default from the theme)
+    let area = wiz_signup.content_rect();           // Where the step's widgets go
+    wiz_signup.set_position(100.0, 50.0);
+*/
+
+use macroquad::prelude::*;
+use crate::modules::scale::{pop_clip_rect, push_clip_rect};
+use crate::modules::text_button::TextButton;
+use crate::modules::theme::{self, ThemeColor};
+
+use crate::modules::scale::mouse_position_world as mouse_position;
+
+// Checks a step before moving past it, returns the message to show when it isn't ready
+type StepGate = Box<dyn FnMut() -> Result<(), String>>;
+type FinishCallback = Box<dyn FnMut()>;
+
+// Height of the step markers row and the buttons row
+const HEADER_HEIGHT: f32 = 70.0;
+const FOOTER_HEIGHT: f32 = 60.0;
+
+struct Step {
+    title: String,
+    gate: Option<StepGate>,
+    manual: bool, // Next only asks, the caller moves on with next()
+}
+
+pub struct Wizard {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    steps: Vec<Step>,
+    current: usize,
+    reached: usize, // Furthest step gone to, the ones before it are done
+    can_continue: bool,
+    error: Option<String>,
+    next_requested: Option<usize>,
+    finished: bool,
+    on_finish: Option<FinishCallback>,
+    back_button: TextButton,
+    next_button: TextButton,
+    next_text: String,
+    finish_text: String,
+    accent_color: ThemeColor,
+    accent_text_color: ThemeColor,
+    background_color: ThemeColor,
+    clipping: bool, // True between begin() and end()
+}
+
+impl Wizard {
+    #[allow(unused)]
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let mut wizard = Self {
+            x,
+            y,
+            width,
+            height,
+            steps: Vec::new(),
+            current: 0,
+            reached: 0,
+            can_continue: true,
+            error: None,
+            next_requested: None,
+            finished: false,
+            on_finish: None,
+            back_button: TextButton::themed(0.0, 0.0, 120.0, 40.0, "Back", 22),
+            next_button: TextButton::themed(0.0, 0.0, 120.0, 40.0, "Next", 22),
+            next_text: String::from("Next"),
+            finish_text: String::from("Finish"),
+            accent_color: ThemeColor::Primary,
+            accent_text_color: ThemeColor::OnPrimary,
+            background_color: ThemeColor::Surface,
+            clipping: false,
+        };
+        wizard.layout_buttons();
+        wizard
+    }
+
+    #[allow(unused)]
+    pub fn add_step(&mut self, title: &str) -> &mut Self {
+        self.steps.push(Step {
+            title: title.to_string(),
+            gate: None,
+            manual: false,
+        });
+        self.update_buttons();
+        self
+    }
+
+    // Check run when Next is pressed on a step, it only moves on when this gives Ok
+    #[allow(unused)]
+    pub fn with_gate<F: FnMut() -> Result<(), String> + 'static>(&mut self, step: usize, gate: F) -> &mut Self {
+        if let Some(step) = self.steps.get_mut(step) {
+            step.gate = Some(Box::new(gate));
+        }
+        self
+    }
+
+    // Next on this step only sets next_requested(), call next() once the step checks out
+    #[allow(unused)]
+    pub fn with_manual_next(&mut self, step: usize) -> &mut Self {
+        if let Some(step) = self.steps.get_mut(step) {
+            step.manual = true;
+        }
+        self
+    }
+
+    // Run when Finish is pressed on the last step (after its gate)
+    #[allow(unused)]
+    pub fn on_finish<F: FnMut() + 'static>(&mut self, callback: F) -> &mut Self {
+        self.on_finish = Some(Box::new(callback));
+        self
+    }
+
+    #[allow(unused)]
+    pub fn with_button_text(&mut self, back: &str, next: &str, finish: &str) -> &mut Self {
+        self.back_button.set_text(back);
+        self.next_text = next.to_string();
+        self.finish_text = finish.to_string();
+        self.update_buttons();
+        self
+    }
+
+    // Accent for the current step and buttons, text drawn on the accent, and the background
+    #[allow(unused)]
+    pub fn with_colors(&mut self, accent: impl Into<ThemeColor>, accent_text: impl Into<ThemeColor>, background: impl Into<ThemeColor>) -> &mut Self {
+        self.accent_color = accent.into();
+        self.accent_text_color = accent_text.into();
+        self.background_color = background.into();
+        // A fixed accent takes the Next button off the theme too
+        if let ThemeColor::Custom(accent) = self.accent_color {
+            self.next_button.with_text_color(self.accent_text_color.color());
+            self.next_button.normal_color = accent;
+        }
+        self
+    }
+
+    // Whether Next can be pressed on the current step (goes back to true when the step changes)
+    #[allow(unused)]
+    pub fn set_can_continue(&mut self, can_continue: bool) -> &mut Self {
+        self.can_continue = can_continue;
+        self
+    }
+
+    // The step whose Next was pressed this frame, for steps set with with_manual_next
+    #[allow(unused)]
+    pub fn next_requested(&self) -> Option<usize> {
+        self.next_requested
+    }
+
+    // Move to the next step without running its gate (finishes on the last step)
+    #[allow(unused)]
+    pub fn next(&mut self) -> &mut Self {
+        if self.current + 1 < self.steps.len() {
+            self.go_to(self.current + 1);
+        } else if !self.steps.is_empty() && !self.finished {
+            self.finished = true;
+            if let Some(callback) = &mut self.on_finish {
+                callback();
+            }
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn back(&mut self) -> &mut Self {
+        if self.current > 0 {
+            self.go_to(self.current - 1);
+        }
+        self
+    }
+
+    #[allow(unused)]
+    pub fn set_step(&mut self, step: usize) -> &mut Self {
+        if step < self.steps.len() {
+            self.go_to(step);
+        }
+        self
+    }
+
+    // Start over from the first step
+    #[allow(unused)]
+    pub fn reset(&mut self) -> &mut Self {
+        self.reached = 0;
+        self.finished = false;
+        self.go_to(0);
+        self
+    }
+
+    #[allow(unused)]
+    pub fn current_step(&self) -> usize {
+        self.current
+    }
+
+    #[allow(unused)]
+    pub fn step_count(&self) -> usize {
+        self.steps.len()
+    }
+
+    #[allow(unused)]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    #[allow(unused)]
+    pub fn set_position(&mut self, x: f32, y: f32) -> &mut Self {
+        self.x = x;
+        self.y = y;
+        self.layout_buttons();
+        self
+    }
+
+    // The area between the step markers and the buttons
+    #[allow(unused)]
+    pub fn content_rect(&self) -> Rect {
+        Rect::new(self.x, self.y + HEADER_HEIGHT, self.width, (self.height - HEADER_HEIGHT - FOOTER_HEIGHT).max(0.0))
+    }
+
+    // Draw the frame and step markers and start clipping to the content, returns the step to show
+    #[allow(unused)]
+    pub fn begin(&mut self) -> usize {
+        self.next_requested = None;
+        draw_rectangle(self.x, self.y, self.width, self.height, self.background_color.color());
+        let palette = theme::palette();
+        draw_rectangle_lines(self.x, self.y, self.width, self.height, 1.0, palette.border);
+        draw_line(self.x, self.y + HEADER_HEIGHT, self.x + self.width, self.y + HEADER_HEIGHT, 1.0, palette.surface_alt);
+        draw_line(self.x, self.y + self.height - FOOTER_HEIGHT, self.x + self.width, self.y + self.height - FOOTER_HEIGHT, 1.0, palette.surface_alt);
+        self.draw_markers();
+
+        push_clip_rect(self.content_rect());
+        self.clipping = true;
+        self.current
+    }
+
+    // Stop clipping, then draw and handle the buttons. Returns true on the frame Finish worked
+    #[allow(unused)]
+    pub fn end(&mut self) -> bool {
+        if self.clipping {
+            pop_clip_rect();
+            self.clipping = false;
+        }
+        if self.steps.is_empty() {
+            return false;
+        }
+
+        let was_finished = self.finished;
+        self.back_button.set_enabled(self.current > 0);
+        self.next_button.set_enabled(self.can_continue && !self.finished);
+        if self.back_button.click() {
+            self.back();
+        }
+        if self.next_button.click() {
+            self.try_next();
+        }
+
+        if let Some(error) = &self.error {
+            let button_left = self.next_button.get_x().min(self.back_button.get_x());
+            push_clip_rect(Rect::new(self.x, self.y + self.height - FOOTER_HEIGHT, button_left - self.x, FOOTER_HEIGHT));
+            draw_text(error, self.x + 15.0, self.y + self.height - FOOTER_HEIGHT / 2.0 + 6.0, 20.0, theme::palette().error);
+            pop_clip_rect();
+        }
+        !was_finished && self.finished
+    }
+
+    // Next was pressed: run the gate, then move on (or leave it to the caller)
+    fn try_next(&mut self) {
+        let Some(step) = self.steps.get_mut(self.current) else {
+            return;
+        };
+        if let Some(gate) = &mut step.gate {
+            if let Err(message) = gate() {
+                self.error = Some(message);
+                return;
+            }
+        }
+        self.error = None;
+        if step.manual {
+            self.next_requested = Some(self.current);
+        } else {
+            self.next();
+        }
+    }
+
+    fn go_to(&mut self, step: usize) {
+        self.current = step;
+        self.reached = self.reached.max(step);
+        self.can_continue = true;
+        self.error = None;
+        self.update_buttons();
+    }
+
+    // Numbered circles joined by lines, with the step titles under them
+    fn draw_markers(&mut self) {
+        let count = self.steps.len();
+        if count == 0 {
+            return;
+        }
+        let radius = 14.0;
+        let slot = self.width / count as f32;
+        let center_y = self.y + 26.0;
+        let (mouse_x, mouse_y) = mouse_position();
+        let mut clicked = None;
+        let palette = theme::palette();
+
+        for (i, step) in self.steps.iter().enumerate() {
+            let center_x = self.x + slot * (i as f32 + 0.5);
+            if i + 1 < count {
+                let line_color = if i < self.reached { self.accent_color.color() } else { palette.surface_alt };
+                draw_line(center_x + radius, center_y, center_x + slot - radius, center_y, 2.0, line_color);
+            }
+
+            let done = i < self.reached && i != self.current;
+            let fill = if i == self.current || done { self.accent_color.color() } else { palette.surface_alt };
+            draw_circle(center_x, center_y, radius, fill);
+            if done {
+                // A tick for finished steps
+                draw_line(center_x - 6.0, center_y, center_x - 1.5, center_y + 5.0, 2.5, self.accent_text_color.color());
+                draw_line(center_x - 1.5, center_y + 5.0, center_x + 6.5, center_y - 5.0, 2.5, self.accent_text_color.color());
+            } else {
+                let number = (i + 1).to_string();
+                let size = measure_text(&number, None, 20, 1.0);
+                let color = if i == self.current { self.accent_text_color.color() } else { palette.text_muted };
+                draw_text(&number, center_x - size.width / 2.0, center_y + size.offset_y / 2.0, 20.0, color);
+            }
+
+            let size = measure_text(&step.title, None, 18, 1.0);
+            let title_color = if i == self.current { palette.text } else { palette.text_muted };
+            draw_text(&step.title, center_x - size.width / 2.0, center_y + radius + 18.0, 18.0, title_color);
+
+            // Steps already reached can be clicked to go back to them
+            let marker = Rect::new(center_x - radius, center_y - radius, radius * 2.0, radius * 2.0);
+            if i <= self.reached && i != self.current && !self.finished && is_mouse_button_pressed(MouseButton::Left) && marker.contains(Vec2::new(mouse_x, mouse_y)) {
+                clicked = Some(i);
+            }
+        }
+        if let Some(step) = clicked {
+            self.go_to(step);
+        }
+    }
+
+    fn update_buttons(&mut self) {
+        let last = self.current + 1 >= self.steps.len();
+        let text = if last { &self.finish_text } else { &self.next_text };
+        self.next_button.set_text(text.clone());
+    }
+
+    // Back and Next in the bottom right corner
+    fn layout_buttons(&mut self) {
+        let button_y = self.y + self.height - FOOTER_HEIGHT + (FOOTER_HEIGHT - 40.0) / 2.0;
+        let next_x = self.x + self.width - 15.0 - 120.0;
+        self.next_button.update_position(next_x, button_y, None, None);
+        self.back_button.update_position(next_x - 10.0 - 120.0, button_y, None, None);
+    }
+}